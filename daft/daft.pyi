@@ -120,6 +120,29 @@ class JoinStrategy(Enum):
         """
         ...
 
+class NullKeyBehavior(Enum):
+    """
+    How a grouped aggregation treats rows whose group keys are null.
+
+    | GroupTogether - Rows with null keys form their own group.
+    | Drop          - Rows with any null key are dropped before aggregating.
+    """
+
+    GroupTogether: int
+    Drop: int
+
+    @staticmethod
+    def from_null_key_behavior_str(null_keys: str) -> NullKeyBehavior:
+        """
+        Create a NullKeyBehavior from its string representation.
+
+        Args:
+            null_keys: String representation of the null key behavior. This is the same as the enum
+                attribute name (but snake-case), e.g. ``NullKeyBehavior.from_null_key_behavior_str("drop")`` would
+                return ``NullKeyBehavior.Drop``.
+        """
+        ...
+
 class CountMode(Enum):
     """
     Supported count modes for Daft's count aggregation.
@@ -514,6 +537,57 @@ class GCSConfig:
         """Replaces values if provided, returning a new GCSConfig"""
         ...
 
+class HttpConfig:
+    """
+    I/O configuration for accessing HTTP(S) URLs.
+    """
+
+    max_connections: int
+    connect_timeout_ms: int | None
+    timeout_ms: int | None
+    num_tries: int
+    retry_initial_backoff_ms: int
+    headers: list[tuple[str, str]]
+    decompress: bool
+    max_connections_per_host: int | None
+    max_redirects: int | None
+    http_proxy: str | None
+    https_proxy: str | None
+    no_proxy: list[str]
+
+    def __init__(
+        self,
+        max_connections: int | None = None,
+        connect_timeout_ms: int | None = None,
+        timeout_ms: int | None = None,
+        num_tries: int | None = None,
+        retry_initial_backoff_ms: int | None = None,
+        headers: list[tuple[str, str]] | None = None,
+        decompress: bool | None = None,
+        max_connections_per_host: int | None = None,
+        max_redirects: int | None = None,
+        http_proxy: str | None = None,
+        https_proxy: str | None = None,
+        no_proxy: list[str] | None = None,
+    ): ...
+    def replace(
+        self,
+        max_connections: int | None = None,
+        connect_timeout_ms: int | None = None,
+        timeout_ms: int | None = None,
+        num_tries: int | None = None,
+        retry_initial_backoff_ms: int | None = None,
+        headers: list[tuple[str, str]] | None = None,
+        decompress: bool | None = None,
+        max_connections_per_host: int | None = None,
+        max_redirects: int | None = None,
+        http_proxy: str | None = None,
+        https_proxy: str | None = None,
+        no_proxy: list[str] | None = None,
+    ) -> HttpConfig:
+        """Replaces values if provided, returning a new HttpConfig"""
+        ...
+
 class IOConfig:
     """
     Configuration for the native I/O layer, e.g. credentials for accessing cloud storage systems.
@@ -522,15 +596,26 @@ class IOConfig:
     s3: S3Config
     azure: AzureConfig
     gcs: GCSConfig
+    http: HttpConfig
 
-    def __init__(self, s3: S3Config | None = None, azure: AzureConfig | None = None, gcs: GCSConfig | None = None): ...
+    def __init__(
+        self,
+        s3: S3Config | None = None,
+        azure: AzureConfig | None = None,
+        gcs: GCSConfig | None = None,
+        http: HttpConfig | None = None,
+    ): ...
     @staticmethod
     def from_json(input: str) -> IOConfig:
         """
         Recreate an IOConfig from a JSON string.
         """
     def replace(
-        self, s3: S3Config | None = None, azure: AzureConfig | None = None, gcs: GCSConfig | None = None
+        self,
+        s3: S3Config | None = None,
+        azure: AzureConfig | None = None,
+        gcs: GCSConfig | None = None,
+        http: HttpConfig | None = None,
     ) -> IOConfig:
         """Replaces values if provided, returning a new IOConfig"""
         ...
@@ -893,12 +978,28 @@ class PyExpr:
     def round(self, decimal: int) -> PyExpr: ...
     def if_else(self, if_true: PyExpr, if_false: PyExpr) -> PyExpr: ...
     def count(self, mode: CountMode) -> PyExpr: ...
+    def count_distinct(self) -> PyExpr: ...
     def sum(self) -> PyExpr: ...
+    def product(self) -> PyExpr: ...
     def mean(self) -> PyExpr: ...
+    def median(self) -> PyExpr: ...
+    def mode(self) -> PyExpr: ...
+    def quantile(self, q: float) -> PyExpr: ...
+    def stddev(self, ddof: int) -> PyExpr: ...
+    def variance(self, ddof: int) -> PyExpr: ...
     def min(self) -> PyExpr: ...
     def max(self) -> PyExpr: ...
     def any_value(self, ignore_nulls: bool) -> PyExpr: ...
+    def first(self) -> PyExpr: ...
+    def last(self) -> PyExpr: ...
+    def bit_and(self) -> PyExpr: ...
+    def bit_or(self) -> PyExpr: ...
+    def bit_xor(self) -> PyExpr: ...
+    def any(self) -> PyExpr: ...
+    def all(self) -> PyExpr: ...
+    def string_agg(self, separator: str) -> PyExpr: ...
     def agg_list(self) -> PyExpr: ...
+    def agg_set(self) -> PyExpr: ...
     def agg_concat(self) -> PyExpr: ...
     def explode(self) -> PyExpr: ...
     def __abs__(self) -> PyExpr: ...
@@ -966,7 +1067,12 @@ class PyExpr:
     def list_max(self) -> PyExpr: ...
     def struct_get(self, name: str) -> PyExpr: ...
     def url_download(
-        self, max_connections: int, raise_error_on_failure: bool, multi_thread: bool, config: IOConfig
+        self,
+        max_connections: int | None,
+        raise_error_on_failure: bool,
+        multi_thread: bool,
+        max_size_bytes: int | None,
+        config: IOConfig,
     ) -> PyExpr: ...
     def partitioning_days(self) -> PyExpr: ...
     def partitioning_hours(self) -> PyExpr: ...
@@ -1073,12 +1179,31 @@ class PySeries:
     def is_null(self) -> PySeries: ...
     def not_null(self) -> PySeries: ...
     def fill_null(self, fill_value: PySeries) -> PySeries: ...
+    def python_apply(self, func: Callable[[Any], Any], skip_nulls: bool) -> PySeries: ...
     def murmur3_32(self) -> PySeries: ...
     def to_str_values(self) -> PySeries: ...
     def _debug_bincode_serialize(self) -> bytes: ...
     @staticmethod
     def _debug_bincode_deserialize(b: bytes) -> PySeries: ...
 
+class WindowFunction:
+    """
+    A function computed over the rows of each window partition, producing one value per row.
+    """
+
+    @staticmethod
+    def agg(expr: PyExpr) -> WindowFunction:
+        """
+        A running aggregation over the rows up to and including the current one.
+        """
+        ...
+    @staticmethod
+    def row_number(name: str) -> WindowFunction: ...
+    @staticmethod
+    def rank(name: str) -> WindowFunction: ...
+    @staticmethod
+    def dense_rank(name: str) -> WindowFunction: ...
+
 class PyTable:
     def schema(self) -> PySchema: ...
     def cast_to_schema(self, schema: PySchema) -> PyTable: ...
@@ -1088,6 +1213,13 @@ class PyTable:
     def sort(self, sort_keys: list[PyExpr], descending: list[bool]) -> PyTable: ...
     def argsort(self, sort_keys: list[PyExpr], descending: list[bool]) -> PySeries: ...
     def agg(self, to_agg: list[PyExpr], group_by: list[PyExpr]) -> PyTable: ...
+    def window(
+        self,
+        window_functions: list[WindowFunction],
+        partition_by: list[PyExpr],
+        order_by: list[PyExpr],
+        descending: list[bool],
+    ) -> PyTable: ...
     def hash_join(self, right: PyTable, left_on: list[PyExpr], right_on: list[PyExpr]) -> PyTable: ...
     def sort_merge_join(
         self, right: PyTable, left_on: list[PyExpr], right_on: list[PyExpr], is_sorted: bool
@@ -1147,6 +1279,13 @@ class PyMicroPartition:
     def sort(self, sort_keys: list[PyExpr], descending: list[bool]) -> PyMicroPartition: ...
     def argsort(self, sort_keys: list[PyExpr], descending: list[bool]) -> PySeries: ...
     def agg(self, to_agg: list[PyExpr], group_by: list[PyExpr]) -> PyMicroPartition: ...
+    def window(
+        self,
+        window_functions: list[WindowFunction],
+        partition_by: list[PyExpr],
+        order_by: list[PyExpr],
+        descending: list[bool],
+    ) -> PyMicroPartition: ...
     def hash_join(self, right: PyMicroPartition, left_on: list[PyExpr], right_on: list[PyExpr]) -> PyMicroPartition: ...
     def sort_merge_join(
         self, right: PyMicroPartition, left_on: list[PyExpr], right_on: list[PyExpr], is_sorted: bool
@@ -1252,7 +1391,20 @@ class LogicalPlanBuilder:
     def coalesce(self, num_partitions: int) -> LogicalPlanBuilder: ...
     def distinct(self) -> LogicalPlanBuilder: ...
     def sample(self, fraction: float, with_replacement: bool, seed: int | None) -> LogicalPlanBuilder: ...
-    def aggregate(self, agg_exprs: list[PyExpr], groupby_exprs: list[PyExpr]) -> LogicalPlanBuilder: ...
+    def aggregate(
+        self,
+        agg_exprs: list[PyExpr],
+        groupby_exprs: list[PyExpr],
+        null_keys: NullKeyBehavior,
+        having: PyExpr | None,
+    ) -> LogicalPlanBuilder: ...
+    def window(
+        self,
+        window_functions: list[WindowFunction],
+        partition_by: list[PyExpr],
+        order_by: list[PyExpr],
+        descending: list[bool],
+    ) -> LogicalPlanBuilder: ...
     def join(
         self,
         right: LogicalPlanBuilder,
@@ -27,7 +27,15 @@ from typing import (
 from daft.api_annotations import DataframePublicAPI
 from daft.context import get_context
 from daft.convert import InputListType
-from daft.daft import FileFormat, IOConfig, JoinStrategy, JoinType, ResourceRequest
+from daft.daft import (
+    FileFormat,
+    IOConfig,
+    JoinStrategy,
+    JoinType,
+    NullKeyBehavior,
+    ResourceRequest,
+    WindowFunction,
+)
 from daft.dataframe.preview import DataFramePreview
 from daft.datatype import DataType
 from daft.errors import ExpressionTypeError
@@ -1036,8 +1044,14 @@ class DataFrame:
         builder = self._builder.explode(parsed_exprs)
         return DataFrame(builder)
 
-    def _agg(self, to_agg: List[Expression], group_by: Optional[ExpressionsProjection] = None) -> "DataFrame":
-        builder = self._builder.agg(to_agg, list(group_by) if group_by is not None else None)
+    def _agg(
+        self,
+        to_agg: List[Expression],
+        group_by: Optional[ExpressionsProjection] = None,
+        null_keys: NullKeyBehavior = NullKeyBehavior.GroupTogether,
+        having: Optional[Expression] = None,
+    ) -> "DataFrame":
+        builder = self._builder.agg(to_agg, list(group_by) if group_by is not None else None, null_keys, having)
         return DataFrame(builder)
 
     def _agg_tuple_to_expression(self, agg_tuple: Tuple[ColumnInputType, str]) -> Expression:
@@ -1076,6 +1090,7 @@ class DataFrame:
         fn: Callable[[Expression], Expression],
         cols: Tuple[ColumnInputOrListType, ...],
         group_by: Optional[ExpressionsProjection] = None,
+        null_keys: NullKeyBehavior = NullKeyBehavior.GroupTogether,
     ) -> "DataFrame":
         if len(cols) == 0:
             warnings.warn("No columns specified; performing aggregation on all columns.")
@@ -1083,10 +1098,29 @@ class DataFrame:
             groupby_name_set = set() if group_by is None else group_by.to_name_set()
             cols = tuple(c for c in self.column_names if c not in groupby_name_set)
         exprs = self._inputs_to_expressions(cols)
-        return self._agg([fn(c) for c in exprs], group_by)
+        return self._agg([fn(c) for c in exprs], group_by, null_keys)
+
+    def _map_groups(
+        self,
+        udf: Expression,
+        group_by: Optional[ExpressionsProjection] = None,
+        null_keys: NullKeyBehavior = NullKeyBehavior.GroupTogether,
+    ) -> "DataFrame":
+        builder = self._builder.map_groups(udf, list(group_by) if group_by is not None else None, null_keys)
+        return DataFrame(builder)
 
-    def _map_groups(self, udf: Expression, group_by: Optional[ExpressionsProjection] = None) -> "DataFrame":
-        builder = self._builder.map_groups(udf, list(group_by) if group_by is not None else None)
+    def _window(
+        self,
+        window_functions: List[Union[Expression, WindowFunction]],
+        partition_by: Optional[List[Expression]] = None,
+        order_by: Optional[List[Expression]] = None,
+        desc: Union[bool, List[bool]] = False,
+    ) -> "DataFrame":
+        functions = [WindowFunction.agg(f._expr) if isinstance(f, Expression) else f for f in window_functions]
+        order_by = order_by if order_by is not None else []
+        if not isinstance(desc, list):
+            desc = [desc] * len(order_by)
+        builder = self._builder.window(functions, partition_by if partition_by is not None else [], order_by, desc)
         return DataFrame(builder)
 
     @DataframePublicAPI
@@ -1201,16 +1235,23 @@ class DataFrame:
         return self._agg(self._inputs_to_expressions(to_agg), group_by=None)
 
     @DataframePublicAPI
-    def groupby(self, *group_by: ColumnInputOrListType) -> "GroupedDataFrame":
+    def groupby(self, *group_by: ColumnInputOrListType, null_keys: str = "group_together") -> "GroupedDataFrame":
         """Performs a GroupBy on the DataFrame for aggregation
 
         Args:
             *group_by (Union[str, Expression]): columns to group by
+            null_keys (str): How rows with null group keys are grouped; currently "group_together", which puts them
+                into their own group, and "drop", which drops any row with a null key, are supported.
+                The default is "group_together".
 
         Returns:
             GroupedDataFrame: DataFrame to Aggregate
         """
-        return GroupedDataFrame(self, ExpressionsProjection(self._inputs_to_expressions(group_by)))
+        return GroupedDataFrame(
+            self,
+            ExpressionsProjection(self._inputs_to_expressions(group_by)),
+            NullKeyBehavior.from_null_key_behavior_str(null_keys),
+        )
 
     def _materialize_results(self) -> None:
         """Materializes the results of for this DataFrame and hold a pointer to the results."""
@@ -1600,6 +1641,7 @@ class DataFrame:
 class GroupedDataFrame:
     df: DataFrame
     group_by: ExpressionsProjection
+    null_keys: NullKeyBehavior = NullKeyBehavior.GroupTogether
 
     def __post_init__(self):
         resolved_groupby_schema = self.group_by.resolve_schema(self.df._builder.schema())
@@ -1620,7 +1662,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped sums.
         """
-        return self.df._apply_agg_fn(Expression.sum, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.sum, cols, self.group_by, self.null_keys)
 
     def mean(self, *cols: ColumnInputType) -> "DataFrame":
         """Performs grouped mean on this GroupedDataFrame.
@@ -1631,7 +1673,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped mean.
         """
-        return self.df._apply_agg_fn(Expression.mean, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.mean, cols, self.group_by, self.null_keys)
 
     def min(self, *cols: ColumnInputType) -> "DataFrame":
         """Perform grouped min on this GroupedDataFrame.
@@ -1642,7 +1684,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped min.
         """
-        return self.df._apply_agg_fn(Expression.min, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.min, cols, self.group_by, self.null_keys)
 
     def max(self, *cols: ColumnInputType) -> "DataFrame":
         """Performs grouped max on this GroupedDataFrame.
@@ -1653,7 +1695,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped max.
         """
-        return self.df._apply_agg_fn(Expression.max, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.max, cols, self.group_by, self.null_keys)
 
     def any_value(self, *cols: ColumnInputType) -> "DataFrame":
         """Returns an arbitrary value on this GroupedDataFrame.
@@ -1665,7 +1707,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with any values.
         """
-        return self.df._apply_agg_fn(Expression.any_value, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.any_value, cols, self.group_by, self.null_keys)
 
     def count(self, *cols: ColumnInputType) -> "DataFrame":
         """Performs grouped count on this GroupedDataFrame.
@@ -1673,7 +1715,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped count per column.
         """
-        return self.df._apply_agg_fn(Expression.count, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.count, cols, self.group_by, self.null_keys)
 
     def agg_list(self, *cols: ColumnInputType) -> "DataFrame":
         """Performs grouped list on this GroupedDataFrame.
@@ -1681,7 +1723,7 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped list per column.
         """
-        return self.df._apply_agg_fn(Expression.agg_list, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.agg_list, cols, self.group_by, self.null_keys)
 
     def agg_concat(self, *cols: ColumnInputType) -> "DataFrame":
         """Performs grouped concat on this GroupedDataFrame.
@@ -1689,9 +1731,9 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped concatenated list per column.
         """
-        return self.df._apply_agg_fn(Expression.agg_concat, cols, self.group_by)
+        return self.df._apply_agg_fn(Expression.agg_concat, cols, self.group_by, self.null_keys)
 
-    def agg(self, *to_agg: ColumnInputOrListType) -> "DataFrame":
+    def agg(self, *to_agg: ColumnInputOrListType, having: Optional[Expression] = None) -> "DataFrame":
         """Perform aggregations on this GroupedDataFrame. Allows for mixed aggregations.
 
         Example:
@@ -1704,11 +1746,15 @@ class GroupedDataFrame:
 
         Args:
             *to_agg (Expression): aggregation expressions
+            having (Optional[Expression]): predicate on the group keys and aggregation outputs; groups which
+                don't satisfy it are dropped from the result, like SQL's ``HAVING``. Defaults to None.
 
         Returns:
             DataFrame: DataFrame with grouped aggregations
         """
-        return self.df._agg(self.df._inputs_to_expressions(to_agg), group_by=self.group_by)
+        return self.df._agg(
+            self.df._inputs_to_expressions(to_agg), group_by=self.group_by, null_keys=self.null_keys, having=having
+        )
 
     def map_groups(self, udf: Expression) -> "DataFrame":
         """Apply a user-defined function to each group. The name of the resultant column will default to the name of the first input column.
@@ -1739,4 +1785,4 @@ class GroupedDataFrame:
         Returns:
             DataFrame: DataFrame with grouped aggregations
         """
-        return self.df._map_groups(udf, group_by=self.group_by)
+        return self.df._map_groups(udf, group_by=self.group_by, null_keys=self.null_keys)
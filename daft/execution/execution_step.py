@@ -11,7 +11,7 @@ if sys.version_info < (3, 8):
 else:
     from typing import Protocol
 
-from daft.daft import FileFormat, IOConfig, JoinType, ResourceRequest, ScanTask
+from daft.daft import FileFormat, IOConfig, JoinType, ResourceRequest, ScanTask, WindowFunction
 from daft.expressions import Expression, ExpressionsProjection, col
 from daft.logical.map_partition_ops import MapPartitionOp
 from daft.logical.schema import Schema
@@ -608,17 +608,46 @@ class MonotonicallyIncreasingId(SingleOutputInstruction):
         ]
 
 
+@dataclass(frozen=True)
+class Window(SingleOutputInstruction):
+    window_functions: list[WindowFunction]
+    partition_by: ExpressionsProjection
+    order_by: ExpressionsProjection
+    descending: list[bool]
+
+    def run(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        return self._window(inputs)
+
+    def _window(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
+        [input] = inputs
+        return [input.window(self.window_functions, self.partition_by, self.order_by, self.descending)]
+
+    def run_partial_metadata(self, input_metadatas: list[PartialPartitionMetadata]) -> list[PartialPartitionMetadata]:
+        # Every input row is kept, but the rows are reordered.
+        [input_meta] = input_metadatas
+        return [
+            PartialPartitionMetadata(
+                num_rows=input_meta.num_rows,
+                size_bytes=None,
+            )
+        ]
+
+
 @dataclass(frozen=True)
 class Aggregate(SingleOutputInstruction):
     to_agg: list[Expression]
     group_by: ExpressionsProjection | None
+    having: Expression | None = None
 
     def run(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
         return self._aggregate(inputs)
 
     def _aggregate(self, inputs: list[MicroPartition]) -> list[MicroPartition]:
         [input] = inputs
-        return [input.agg(self.to_agg, self.group_by)]
+        result = input.agg(self.to_agg, self.group_by)
+        if self.having is not None:
+            result = result.filter(ExpressionsProjection([self.having]))
+        return [result]
 
     def run_partial_metadata(self, input_metadatas: list[PartialPartitionMetadata]) -> list[PartialPartitionMetadata]:
         # Can't derive anything.
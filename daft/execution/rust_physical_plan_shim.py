@@ -10,6 +10,7 @@ from daft.daft import (
     PySchema,
     ResourceRequest,
     ScanTask,
+    WindowFunction,
 )
 from daft.execution import execution_step, physical_plan
 from daft.expressions import Expression, ExpressionsProjection
@@ -95,10 +96,12 @@ def local_aggregate(
     input: physical_plan.InProgressPhysicalPlan[PartitionT],
     agg_exprs: list[PyExpr],
     group_by: list[PyExpr],
+    having: PyExpr | None = None,
 ) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
     aggregation_step = execution_step.Aggregate(
         to_agg=[Expression._from_pyexpr(pyexpr) for pyexpr in agg_exprs],
         group_by=ExpressionsProjection([Expression._from_pyexpr(pyexpr) for pyexpr in group_by]),
+        having=Expression._from_pyexpr(having) if having is not None else None,
     )
 
     return physical_plan.pipeline_instruction(
@@ -108,6 +111,27 @@ def local_aggregate(
     )
 
 
+def window(
+    input: physical_plan.InProgressPhysicalPlan[PartitionT],
+    window_functions: list[WindowFunction],
+    partition_by: list[PyExpr],
+    order_by: list[PyExpr],
+    descending: list[bool],
+) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
+    window_step = execution_step.Window(
+        window_functions=window_functions,
+        partition_by=ExpressionsProjection([Expression._from_pyexpr(pyexpr) for pyexpr in partition_by]),
+        order_by=ExpressionsProjection([Expression._from_pyexpr(pyexpr) for pyexpr in order_by]),
+        descending=descending,
+    )
+
+    return physical_plan.pipeline_instruction(
+        child_plan=input,
+        pipeable_instruction=window_step,
+        resource_request=ResourceRequest(),
+    )
+
+
 def sample(
     input: physical_plan.InProgressPhysicalPlan[PartitionT], fraction: float, with_replacement: bool, seed: int | None
 ) -> physical_plan.InProgressPhysicalPlan[PartitionT]:
@@ -366,16 +366,70 @@ class Expression:
         expr = self._expr.count(mode)
         return Expression._from_pyexpr(expr)
 
+    def count_distinct(self) -> Expression:
+        """Counts the number of distinct non-null values in the expression"""
+        expr = self._expr.count_distinct()
+        return Expression._from_pyexpr(expr)
+
     def sum(self) -> Expression:
         """Calculates the sum of the values in the expression"""
         expr = self._expr.sum()
         return Expression._from_pyexpr(expr)
 
+    def product(self) -> Expression:
+        """Calculates the product of the values in the expression. Integers are widened to 64 bits like in
+        :meth:`sum`, and overflowing that raises an error.
+        """
+        expr = self._expr.product()
+        return Expression._from_pyexpr(expr)
+
     def mean(self) -> Expression:
         """Calculates the mean of the values in the expression"""
         expr = self._expr.mean()
         return Expression._from_pyexpr(expr)
 
+    def median(self) -> Expression:
+        """Calculates the median of the values in the expression, averaging the two middle values when there is an
+        even number of them. Null values are ignored.
+        """
+        expr = self._expr.median()
+        return Expression._from_pyexpr(expr)
+
+    def mode(self) -> Expression:
+        """Returns the most frequent non-null value in the expression, breaking ties by whichever value occurs first"""
+        expr = self._expr.mode()
+        return Expression._from_pyexpr(expr)
+
+    def quantile(self, q: float) -> Expression:
+        """Calculates the ``q``-th quantile of the values in the expression, linearly interpolating between the two
+        closest values. Null values are ignored.
+
+        Args:
+            q: the quantile to compute, which must be between 0 and 1 (e.g. 0.95 for the 95th percentile)
+        """
+        expr = self._expr.quantile(q)
+        return Expression._from_pyexpr(expr)
+
+    def stddev(self, ddof: int = 1) -> Expression:
+        """Calculates the standard deviation of the values in the expression. Null values are ignored.
+
+        Args:
+            ddof: delta degrees of freedom, where the divisor used is ``N - ddof`` for ``N`` values. Defaults to 1 for
+                the sample standard deviation, use 0 for the population standard deviation.
+        """
+        expr = self._expr.stddev(ddof)
+        return Expression._from_pyexpr(expr)
+
+    def variance(self, ddof: int = 1) -> Expression:
+        """Calculates the variance of the values in the expression. Null values are ignored.
+
+        Args:
+            ddof: delta degrees of freedom, where the divisor used is ``N - ddof`` for ``N`` values. Defaults to 1 for
+                the sample variance, use 0 for the population variance.
+        """
+        expr = self._expr.variance(ddof)
+        return Expression._from_pyexpr(expr)
+
     def min(self) -> Expression:
         """Calculates the minimum value in the expression"""
         expr = self._expr.min()
@@ -395,11 +449,71 @@ class Expression:
         expr = self._expr.any_value(ignore_nulls)
         return Expression._from_pyexpr(expr)
 
+    def first(self) -> Expression:
+        """Returns the first non-null value in the expression, in the order that the values are seen by the
+        aggregation. The order is deterministic if the values are sorted before being aggregated, and is otherwise
+        the order of the input.
+        """
+        expr = self._expr.first()
+        return Expression._from_pyexpr(expr)
+
+    def last(self) -> Expression:
+        """Returns the last non-null value in the expression, in the order that the values are seen by the
+        aggregation. The order is deterministic if the values are sorted before being aggregated, and is otherwise
+        the order of the input.
+        """
+        expr = self._expr.last()
+        return Expression._from_pyexpr(expr)
+
+    def bit_and(self) -> Expression:
+        """Calculates the bitwise AND of the values in an integer expression"""
+        expr = self._expr.bit_and()
+        return Expression._from_pyexpr(expr)
+
+    def bit_or(self) -> Expression:
+        """Calculates the bitwise OR of the values in an integer expression"""
+        expr = self._expr.bit_or()
+        return Expression._from_pyexpr(expr)
+
+    def bit_xor(self) -> Expression:
+        """Calculates the bitwise XOR of the values in an integer expression"""
+        expr = self._expr.bit_xor()
+        return Expression._from_pyexpr(expr)
+
+    def any(self) -> Expression:
+        """Whether any of the values in a boolean expression are true. Nulls follow three-valued logic:
+        the result is null if no value is true and at least one value is null.
+        """
+        expr = self._expr.any()
+        return Expression._from_pyexpr(expr)
+
+    def all(self) -> Expression:
+        """Whether all of the values in a boolean expression are true. Nulls follow three-valued logic:
+        the result is null if no value is false and at least one value is null.
+        """
+        expr = self._expr.all()
+        return Expression._from_pyexpr(expr)
+
+    def string_agg(self, separator: str) -> Expression:
+        """Aggregates the non-null values in a string expression into a single string, with ``separator`` placed
+        between consecutive values. The result is null if there are no non-null values.
+
+        Args:
+            separator: String placed between consecutive values
+        """
+        expr = self._expr.string_agg(separator)
+        return Expression._from_pyexpr(expr)
+
     def agg_list(self) -> Expression:
         """Aggregates the values in the expression into a list"""
         expr = self._expr.agg_list()
         return Expression._from_pyexpr(expr)
 
+    def agg_set(self) -> Expression:
+        """Aggregates the distinct non-null values in the expression into a list"""
+        expr = self._expr.agg_set()
+        return Expression._from_pyexpr(expr)
+
     def agg_concat(self) -> Expression:
         """Aggregates the values in the expression into a single string by concatenating them"""
         expr = self._expr.agg_concat()
@@ -582,12 +696,14 @@ class ExpressionNamespace:
 class ExpressionUrlNamespace(ExpressionNamespace):
     def download(
         self,
-        max_connections: int = 32,
+        max_connections: int | None = 32,
         on_error: Literal["raise"] | Literal["null"] = "raise",
         io_config: IOConfig | None = None,
         use_native_downloader: bool = True,
+        multithreaded_io: bool | None = None,
+        max_size_bytes: int | None = None,
     ) -> Expression:
-        """Treats each string as a URL, and downloads the bytes contents as a bytes column
+        """Treats each string (or UTF-8 encoded bytes) as a URL, and downloads the bytes contents as a bytes column
 
         .. NOTE::
             If you are observing excessive S3 issues (such as timeouts, DNS errors or slowdown errors) during URL downloads,
@@ -599,12 +715,20 @@ class ExpressionUrlNamespace(ExpressionNamespace):
 
         Args:
             max_connections: The maximum number of connections to use per thread to use for downloading URLs. Defaults to 32.
+                None picks a default from the number of available cores, and keeps the `max_connections` of `io_config`.
             on_error: Behavior when a URL download error is encountered - "raise" to raise the error immediately or "null" to log
                 the error but fallback to a Null value. Defaults to "raise".
-            io_config: IOConfig to use when accessing remote storage. Note that the S3Config's `max_connections` parameter will be overridden
-                with `max_connections` that is passed in as a kwarg.
+            io_config: IOConfig to use when accessing remote storage. Note that the S3Config's and HttpConfig's `max_connections` parameters
+                will be overridden with `max_connections` that is passed in as a kwarg, unless it is None.
             use_native_downloader (bool): Use the native downloader rather than python based one.
                 Defaults to True.
+            multithreaded_io (bool | None): Whether to run downloads on the multithreaded I/O runtime (whose number of worker threads
+                can be configured with :func:`daft.io.set_io_pool_num_threads`) rather than a single-threaded one. The multithreaded
+                runtime spreads TLS and response decoding across cores, which helps with high `max_connections` or large responses.
+                Defaults to None, which uses the multithreaded runtime unless running on the Ray runner.
+            max_size_bytes (int | None): The maximum number of bytes to download for each URL. Bodies are read incrementally, and a
+                download is treated as an error (subject to `on_error`) as soon as it exceeds this limit, or immediately if the
+                response's `Content-Length` already does. Defaults to None, which places no limit on download sizes.
 
         Returns:
             Expression: a Binary expression which is the bytes contents of the URL, or None if an error occured during download
@@ -618,23 +742,35 @@ class ExpressionUrlNamespace(ExpressionNamespace):
             else:
                 raise NotImplemented(f"Unimplemented on_error option: {on_error}.")
 
-            if not (isinstance(max_connections, int) and max_connections > 0):
+            if max_connections is not None and not (isinstance(max_connections, int) and max_connections > 0):
                 raise ValueError(f"Invalid value for `max_connections`: {max_connections}")
 
+            if max_size_bytes is not None and not (isinstance(max_size_bytes, int) and max_size_bytes >= 0):
+                raise ValueError(f"Invalid value for `max_size_bytes`: {max_size_bytes}")
+
             # Use the `max_connections` kwarg to override the value in S3Config
             # This is because the max parallelism is actually `min(S3Config's max_connections, url_download's max_connections)` under the hood.
             # However, default max_connections on S3Config is only 8, and even if we specify 32 here we are bottlenecked there.
             # Therefore for S3 downloads, we override `max_connections` kwarg to have the intended effect.
+            # Similarly for HTTP downloads, the HttpConfig's `max_connections` sizes the shared keep-alive connection pool.
             io_config = context.get_context().daft_planning_config.default_io_config if io_config is None else io_config
-            io_config = io_config.replace(s3=io_config.s3.replace(max_connections=max_connections))
+            if max_connections is not None:
+                io_config = io_config.replace(
+                    s3=io_config.s3.replace(max_connections=max_connections),
+                    http=io_config.http.replace(max_connections=max_connections),
+                )
 
-            using_ray_runner = context.get_context().is_ray_runner
+            if multithreaded_io is None:
+                # Ray runs many workers per node, so each worker defaults to a single I/O thread to avoid oversubscription
+                multithreaded_io = not context.get_context().is_ray_runner
             return Expression._from_pyexpr(
-                self._expr.url_download(max_connections, raise_on_error, not using_ray_runner, io_config)
+                self._expr.url_download(max_connections, raise_on_error, multithreaded_io, max_size_bytes, io_config)
             )
         else:
             from daft.udf_library import url_udfs
 
+            if max_connections is None:
+                return url_udfs.download_udf(Expression._from_pyexpr(self._expr), on_error=on_error)
             return url_udfs.download_udf(
                 Expression._from_pyexpr(self._expr),
                 max_worker_threads=max_connections,
@@ -5,6 +5,7 @@ import sys
 from daft.daft import (
     AzureConfig,
     GCSConfig,
+    HttpConfig,
     IOConfig,
     S3Config,
     set_io_pool_num_threads,
@@ -47,6 +48,7 @@ __all__ = [
     "S3Config",
     "AzureConfig",
     "GCSConfig",
+    "HttpConfig",
     "set_io_pool_num_threads",
     "DataCatalogType",
     "DataCatalogTable",
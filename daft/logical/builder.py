@@ -3,9 +3,9 @@ from __future__ import annotations
 import pathlib
 from typing import TYPE_CHECKING
 
-from daft.daft import CountMode, FileFormat, IOConfig, JoinStrategy, JoinType
+from daft.daft import CountMode, FileFormat, IOConfig, JoinStrategy, JoinType, NullKeyBehavior
 from daft.daft import LogicalPlanBuilder as _LogicalPlanBuilder
-from daft.daft import PyDaftExecutionConfig, ResourceRequest, ScanOperatorHandle
+from daft.daft import PyDaftExecutionConfig, ResourceRequest, ScanOperatorHandle, WindowFunction
 from daft.expressions import Expression, col
 from daft.logical.schema import Schema
 from daft.runners.partitioning import PartitionCacheEntry
@@ -104,7 +104,9 @@ class LogicalPlanBuilder:
     def count(self) -> LogicalPlanBuilder:
         # TODO(Clark): Add dedicated logical/physical ops when introducing metadata-based count optimizations.
         first_col = col(self.schema().column_names()[0])
-        builder = self._builder.aggregate([first_col.count(CountMode.All)._expr], [])
+        builder = self._builder.aggregate(
+            [first_col.count(CountMode.All)._expr], [], NullKeyBehavior.GroupTogether, None
+        )
         builder = builder.project([first_col.alias("count")._expr], ResourceRequest())
         return LogicalPlanBuilder(builder)
 
@@ -140,14 +142,34 @@ class LogicalPlanBuilder:
         self,
         to_agg: list[Expression],
         group_by: list[Expression] | None,
+        null_keys: NullKeyBehavior = NullKeyBehavior.GroupTogether,
+        having: Expression | None = None,
     ) -> LogicalPlanBuilder:
         group_by_pyexprs = [expr._expr for expr in group_by] if group_by is not None else []
-        builder = self._builder.aggregate([expr._expr for expr in to_agg], group_by_pyexprs)
+        having_pyexpr = having._expr if having is not None else None
+        builder = self._builder.aggregate([expr._expr for expr in to_agg], group_by_pyexprs, null_keys, having_pyexpr)
         return LogicalPlanBuilder(builder)
 
-    def map_groups(self, udf: Expression, group_by: list[Expression] | None) -> LogicalPlanBuilder:
+    def map_groups(
+        self,
+        udf: Expression,
+        group_by: list[Expression] | None,
+        null_keys: NullKeyBehavior = NullKeyBehavior.GroupTogether,
+    ) -> LogicalPlanBuilder:
         group_by_pyexprs = [expr._expr for expr in group_by] if group_by is not None else []
-        builder = self._builder.aggregate([udf._expr], group_by_pyexprs)
+        builder = self._builder.aggregate([udf._expr], group_by_pyexprs, null_keys, None)
+        return LogicalPlanBuilder(builder)
+
+    def window(
+        self,
+        window_functions: list[WindowFunction],
+        partition_by: list[Expression],
+        order_by: list[Expression],
+        descending: list[bool],
+    ) -> LogicalPlanBuilder:
+        partition_by_pyexprs = [expr._expr for expr in partition_by]
+        order_by_pyexprs = [expr._expr for expr in order_by]
+        builder = self._builder.window(window_functions, partition_by_pyexprs, order_by_pyexprs, descending)
         return LogicalPlanBuilder(builder)
 
     def join(  # type: ignore[override]
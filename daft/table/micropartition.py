@@ -14,6 +14,7 @@ from daft.daft import (
     JsonConvertOptions,
     JsonParseOptions,
     JsonReadOptions,
+    WindowFunction,
 )
 from daft.daft import PyMicroPartition as _PyMicroPartition
 from daft.daft import PyTable as _PyTable
@@ -222,6 +223,19 @@ class MicroPartition:
         group_by_pyexprs = [e._expr for e in group_by] if group_by is not None else []
         return MicroPartition._from_pymicropartition(self._micropartition.agg(to_agg_pyexprs, group_by_pyexprs))
 
+    def window(
+        self,
+        window_functions: list[WindowFunction],
+        partition_by: ExpressionsProjection,
+        order_by: ExpressionsProjection,
+        descending: list[bool],
+    ) -> MicroPartition:
+        partition_by_pyexprs = [e._expr for e in partition_by]
+        order_by_pyexprs = [e._expr for e in order_by]
+        return MicroPartition._from_pymicropartition(
+            self._micropartition.window(window_functions, partition_by_pyexprs, order_by_pyexprs, descending)
+        )
+
     def quantiles(self, num: int) -> MicroPartition:
         return MicroPartition._from_pymicropartition(self._micropartition.quantiles(num))
 
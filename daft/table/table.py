@@ -14,6 +14,7 @@ from daft.daft import (
     JsonConvertOptions,
     JsonParseOptions,
     JsonReadOptions,
+    WindowFunction,
 )
 from daft.daft import PyTable as _PyTable
 from daft.daft import ScanTask as _ScanTask
@@ -306,6 +307,19 @@ class Table:
         group_by_pyexprs = [e._expr for e in group_by] if group_by is not None else []
         return Table._from_pytable(self._table.agg(to_agg_pyexprs, group_by_pyexprs))
 
+    def window(
+        self,
+        window_functions: list[WindowFunction],
+        partition_by: ExpressionsProjection,
+        order_by: ExpressionsProjection,
+        descending: list[bool],
+    ) -> Table:
+        partition_by_pyexprs = [e._expr for e in partition_by]
+        order_by_pyexprs = [e._expr for e in order_by]
+        return Table._from_pytable(
+            self._table.window(window_functions, partition_by_pyexprs, order_by_pyexprs, descending)
+        )
+
     def quantiles(self, num: int) -> Table:
         return Table._from_pytable(self._table.quantiles(num))
 
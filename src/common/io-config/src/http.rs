@@ -0,0 +1,137 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct HttpConfig {
+    pub max_connections_per_io_thread: u32,
+    pub connect_timeout_ms: Option<u64>,
+    pub timeout_ms: Option<u64>,
+    pub num_tries: u32,
+    pub retry_initial_backoff_ms: u64,
+    pub headers: Vec<(String, String)>,
+    pub decompress: bool,
+    pub max_connections_per_host: Option<u32>,
+    pub max_redirects: Option<u32>,
+    pub http_proxy: Option<String>,
+    pub https_proxy: Option<String>,
+    pub no_proxy: Vec<String>,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        HttpConfig {
+            max_connections_per_io_thread: 32,
+            connect_timeout_ms: None,
+            timeout_ms: None,
+            num_tries: 1,
+            retry_initial_backoff_ms: 1000,
+            headers: vec![],
+            decompress: false,
+            max_connections_per_host: None,
+            max_redirects: None,
+            http_proxy: None,
+            https_proxy: None,
+            no_proxy: vec![],
+        }
+    }
+}
+
+impl HttpConfig {
+    /// Names of the custom headers, which are displayed instead of the full headers since values
+    /// often hold credentials (e.g. `Authorization`)
+    fn header_names(&self) -> Vec<&str> {
+        self.headers.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    /// Proxy URLs with any credentials they embed replaced by `***`, for display
+    fn redacted_proxy(proxy: &str) -> String {
+        let (scheme, rest) = proxy.split_once("://").unwrap_or(("", proxy));
+        let authority_end = rest.find('/').unwrap_or(rest.len());
+        match rest[..authority_end].rfind('@') {
+            Some(at) if scheme.is_empty() => format!("***{}", &rest[at..]),
+            Some(at) => format!("{scheme}://***{}", &rest[at..]),
+            None => proxy.to_string(),
+        }
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!(
+            "Max connections = {}",
+            self.max_connections_per_io_thread
+        ));
+        if let Some(connect_timeout_ms) = &self.connect_timeout_ms {
+            res.push(format!("Connect timeout ms = {}", connect_timeout_ms));
+        }
+        if let Some(timeout_ms) = &self.timeout_ms {
+            res.push(format!("Timeout ms = {}", timeout_ms));
+        }
+        res.push(format!("Num tries = {}", self.num_tries));
+        res.push(format!(
+            "Retry initial backoff ms = {}",
+            self.retry_initial_backoff_ms
+        ));
+        if !self.headers.is_empty() {
+            res.push(format!("Headers = [{}]", self.header_names().join(", ")));
+        }
+        res.push(format!("Decompress = {}", self.decompress));
+        if let Some(max_connections_per_host) = &self.max_connections_per_host {
+            res.push(format!(
+                "Max connections per host = {}",
+                max_connections_per_host
+            ));
+        }
+        if let Some(max_redirects) = &self.max_redirects {
+            res.push(format!("Max redirects = {}", max_redirects));
+        }
+        if let Some(http_proxy) = &self.http_proxy {
+            res.push(format!("HTTP proxy = {}", Self::redacted_proxy(http_proxy)));
+        }
+        if let Some(https_proxy) = &self.https_proxy {
+            res.push(format!(
+                "HTTPS proxy = {}",
+                Self::redacted_proxy(https_proxy)
+            ));
+        }
+        if !self.no_proxy.is_empty() {
+            res.push(format!("No proxy = [{}]", self.no_proxy.join(", ")));
+        }
+        res
+    }
+}
+
+impl Display for HttpConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        write!(
+            f,
+            "HttpConfig
+    max_connections_per_io_thread: {}
+    connect_timeout_ms: {:?}
+    timeout_ms: {:?}
+    num_tries: {}
+    retry_initial_backoff_ms: {}
+    headers: {:?}
+    decompress: {}
+    max_connections_per_host: {:?}
+    max_redirects: {:?}
+    http_proxy: {:?}
+    https_proxy: {:?}
+    no_proxy: {:?}",
+            self.max_connections_per_io_thread,
+            self.connect_timeout_ms,
+            self.timeout_ms,
+            self.num_tries,
+            self.retry_initial_backoff_ms,
+            self.header_names(),
+            self.decompress,
+            self.max_connections_per_host,
+            self.max_redirects,
+            self.http_proxy.as_deref().map(Self::redacted_proxy),
+            self.https_proxy.as_deref().map(Self::redacted_proxy),
+            self.no_proxy
+        )
+    }
+}
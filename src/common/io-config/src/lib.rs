@@ -4,6 +4,9 @@ pub mod python;
 mod azure;
 mod config;
 mod gcs;
+mod http;
 mod s3;
 
-pub use crate::{azure::AzureConfig, config::IOConfig, gcs::GCSConfig, s3::S3Config};
+pub use crate::{
+    azure::AzureConfig, config::IOConfig, gcs::GCSConfig, http::HttpConfig, s3::S3Config,
+};
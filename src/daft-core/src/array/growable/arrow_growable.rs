@@ -4,12 +4,12 @@ use common_error::DaftResult;
 
 use crate::{
     array::{
-        ops::{as_arrow::AsArrow, from_arrow::FromArrow},
+        ops::{as_arrow::AsArrow, from_arrow::FromArrow, full::FullNull},
         DataArray,
     },
     datatypes::{
         BinaryType, BooleanType, DaftArrowBackedType, DaftDataType, ExtensionArray, Field,
-        Float32Type, Float64Type, Int128Type, Int16Type, Int32Type, Int64Type, Int8Type, NullType,
+        Float32Type, Float64Type, Int128Type, Int16Type, Int32Type, Int64Type, Int8Type, NullArray,
         UInt16Type, UInt32Type, UInt64Type, UInt8Type, Utf8Type,
     },
     DataType, IntoSeries, Series,
@@ -43,6 +43,11 @@ where
         self.arrow2_growable.extend_validity(additional)
     }
 
+    #[inline]
+    fn len(&self) -> usize {
+        self.arrow2_growable.len()
+    }
+
     #[inline]
     fn build(&mut self) -> DaftResult<Series> {
         let arrow_array = self.arrow2_growable.as_box();
@@ -51,21 +56,49 @@ where
     }
 }
 
-pub type ArrowNullGrowable<'a> =
-    ArrowBackedDataArrayGrowable<'a, NullType, arrow2::array::growable::GrowableNull>;
+/// Nulls hold no data, so only the number of elements needs to be tracked. This doesn't wrap arrow2's
+/// `GrowableNull`, which can't be reset after building.
+pub struct ArrowNullGrowable<'a> {
+    name: String,
+    dtype: DataType,
+    len: usize,
+    _phantom: PhantomData<&'a ()>,
+}
 
 impl<'a> ArrowNullGrowable<'a> {
     pub fn new(name: &str, dtype: &DataType) -> Self {
-        let arrow2_growable = arrow2::array::growable::GrowableNull::new(dtype.to_arrow().unwrap());
         Self {
             name: name.to_string(),
             dtype: dtype.clone(),
-            arrow2_growable,
+            len: 0,
             _phantom: PhantomData,
         }
     }
 }
 
+impl<'a> Growable for ArrowNullGrowable<'a> {
+    #[inline]
+    fn extend(&mut self, _index: usize, _start: usize, len: usize) {
+        self.len += len;
+    }
+
+    #[inline]
+    fn add_nulls(&mut self, additional: usize) {
+        self.len += additional;
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    fn build(&mut self) -> DaftResult<Series> {
+        let len = std::mem::take(&mut self.len);
+        Ok(NullArray::full_null(&self.name, &self.dtype, len).into_series())
+    }
+}
+
 macro_rules! impl_arrow_backed_data_array_growable {
     ($growable_name:ident, $daft_type:ty, $arrow2_growable_type:ty) => {
         pub type $growable_name<'a> =
@@ -205,9 +238,77 @@ impl<'a> Growable for ArrowExtensionGrowable<'a> {
         self.child_growable.extend_validity(additional)
     }
     #[inline]
+    fn len(&self) -> usize {
+        self.child_growable.len()
+    }
+    #[inline]
     fn build(&mut self) -> DaftResult<Series> {
         let arr = self.child_growable.as_box();
         let field = Arc::new(Field::new(self.name.clone(), self.dtype.clone()));
         Ok(ExtensionArray::from_arrow(field, arr)?.into_series())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::{
+            growable::{Growable, GrowableArray},
+            ops::as_arrow::AsArrow,
+        },
+        datatypes::{Int64Array, Utf8Array},
+        DataType,
+    };
+
+    #[test]
+    fn test_arrow_growable_extend_from_two_arrays() -> DaftResult<()> {
+        let left = Int64Array::from(("a", vec![0, 1, 2, 3]));
+        let right = Int64Array::from(("b", vec![10, 11, 12]));
+
+        let mut growable =
+            Int64Array::make_growable("foo", &DataType::Int64, vec![&left, &right], true, 8);
+        growable.extend(0, 1, 2);
+        growable.extend(1, 0, 3);
+        growable.add_nulls(2);
+        growable.extend(0, 3, 1);
+
+        let result = growable.build()?;
+        assert_eq!(result.name(), "foo");
+        assert_eq!(
+            result.i64()?.into_iter().collect::<Vec<_>>(),
+            vec![
+                Some(&1),
+                Some(&2),
+                Some(&10),
+                Some(&11),
+                Some(&12),
+                None,
+                None,
+                Some(&3)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_arrow_growable_concat_slices() -> DaftResult<()> {
+        let left = Utf8Array::from(("a", ["x", "y", "z"].as_slice()));
+        let right = Utf8Array::from(("b", ["u", "v"].as_slice()));
+
+        // Concatenating whole arrays is the same as extending with the full range of each in turn
+        let mut growable =
+            Utf8Array::make_growable("foo", &DataType::Utf8, vec![&left, &right], false, 5);
+        growable.extend(0, 0, left.len());
+        growable.extend(1, 0, right.len());
+
+        let result = growable.build()?;
+        assert_eq!(result.data_type(), &DataType::Utf8);
+        assert_eq!(
+            result.utf8()?.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some("x"), Some("y"), Some("z"), Some("u"), Some("v")]
+        );
+        Ok(())
+    }
+}
@@ -27,8 +27,14 @@ impl<'a> ArrowBitmapGrowable<'a> {
         self.mutable_bitmap.extend_constant(additional, false)
     }
 
-    pub fn build(self) -> arrow2::bitmap::Bitmap {
-        self.mutable_bitmap.clone().into()
+    pub fn reserve(&mut self, additional: usize) {
+        self.mutable_bitmap.reserve(additional)
+    }
+
+    /// Builds the bitmap grown so far, resetting this growable so that it can keep growing a new bitmap from the
+    /// same source bitmaps
+    pub fn build(&mut self) -> arrow2::bitmap::Bitmap {
+        std::mem::take(&mut self.mutable_bitmap).into()
     }
 }
 
@@ -79,11 +79,22 @@ impl<'a> Growable for FixedSizeListGrowable<'a> {
         }
     }
 
-    fn build(&mut self) -> DaftResult<Series> {
-        let grown_validity = std::mem::take(&mut self.growable_validity);
+    fn len(&self) -> usize {
+        self.child_growable.len() / self.element_fixed_len
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.child_growable
+            .reserve(additional * self.element_fixed_len);
 
+        if let Some(growable_validity) = &mut self.growable_validity {
+            growable_validity.reserve(additional);
+        }
+    }
+
+    fn build(&mut self) -> DaftResult<Series> {
         let built_child = self.child_growable.build()?;
-        let built_validity = grown_validity.map(|v| v.build());
+        let built_validity = self.growable_validity.as_mut().map(|v| v.build());
         Ok(FixedSizeListArray::new(
             Field::new(self.name.clone(), self.dtype.clone()),
             built_child,
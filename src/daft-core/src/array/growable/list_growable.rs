@@ -90,12 +90,23 @@ impl<'a> Growable for ListGrowable<'a> {
         self.growable_offsets.extend_constant(additional);
     }
 
+    fn len(&self) -> usize {
+        self.growable_offsets.len_proxy()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        // The number of child elements in the upcoming lists is unknown, so only the top level is reserved
+        if let Some(growable_validity) = &mut self.growable_validity {
+            growable_validity.reserve(additional);
+        }
+        self.growable_offsets.reserve(additional);
+    }
+
     fn build(&mut self) -> DaftResult<Series> {
         let grown_offsets = std::mem::take(&mut self.growable_offsets);
-        let grown_validity = std::mem::take(&mut self.growable_validity);
 
         let built_child = self.child_growable.build()?;
-        let built_validity = grown_validity.map(|v| v.build());
+        let built_validity = self.growable_validity.as_mut().map(|v| v.build());
         let built_offsets = grown_offsets.into();
         Ok(ListArray::new(
             Field::new(self.name.clone(), self.dtype.clone()),
@@ -106,3 +117,80 @@ impl<'a> Growable for ListGrowable<'a> {
         .into_series())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use arrow2::{bitmap::Bitmap, offset::OffsetsBuffer};
+    use common_error::DaftResult;
+
+    use crate::{
+        array::{
+            growable::{Growable, GrowableArray},
+            ListArray,
+        },
+        datatypes::{Field, Int64Array},
+        DataType, IntoSeries,
+    };
+
+    fn get_i64_list_array(
+        values: Vec<i64>,
+        offsets: Vec<i64>,
+        validity: Option<&[bool]>,
+    ) -> DaftResult<ListArray> {
+        Ok(ListArray::new(
+            Field::new("foo", DataType::List(Box::new(DataType::Int64))),
+            Int64Array::from(("item", values)).into_series(),
+            OffsetsBuffer::try_from(offsets)?,
+            validity.map(Bitmap::from),
+        ))
+    }
+
+    #[test]
+    fn test_list_growable_variable_length_lists() -> DaftResult<()> {
+        // [[0, 1], [], None, [2]]
+        let left = get_i64_list_array(
+            vec![0, 1, 2],
+            vec![0, 2, 2, 2, 3],
+            Some(&[true, true, false, true]),
+        )?;
+        // [[], [3, 4, 5]]
+        let right = get_i64_list_array(vec![3, 4, 5], vec![0, 0, 3], None)?;
+
+        let dtype = DataType::List(Box::new(DataType::Int64));
+        let mut growable = ListArray::make_growable("foo", &dtype, vec![&left, &right], true, 9);
+        growable.extend(0, 0, 4);
+        growable.add_nulls(1);
+        growable.extend(1, 0, 2);
+        growable.extend(0, 1, 2);
+        let result = growable.build()?;
+
+        let lists = result
+            .list()?
+            .into_iter()
+            .map(|list| {
+                list.map(|list| {
+                    list.i64()
+                        .unwrap()
+                        .into_iter()
+                        .map(|v| *v.unwrap())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            lists,
+            vec![
+                Some(vec![0, 1]),
+                Some(vec![]),
+                None,
+                Some(vec![2]),
+                None,
+                Some(vec![]),
+                Some(vec![3, 4, 5]),
+                Some(vec![]),
+                None,
+            ]
+        );
+        Ok(())
+    }
+}
@@ -6,7 +6,7 @@ use crate::{
     datatypes::{
         logical::LogicalArray, DaftDataType, DaftLogicalType, DateType, Decimal128Type,
         DurationType, EmbeddingType, Field, FixedShapeImageType, FixedShapeTensorType, ImageType,
-        MapType, TensorType, TimeType, TimestampType,
+        IntervalType, MapType, TensorType, TimeType, TimestampType,
     },
     DataType, IntoSeries, Series,
 };
@@ -36,6 +36,14 @@ where
         self.physical_growable.add_nulls(additional)
     }
     #[inline]
+    fn len(&self) -> usize {
+        self.physical_growable.len()
+    }
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        self.physical_growable.reserve(additional)
+    }
+    #[inline]
     fn build(&mut self) -> DaftResult<Series> {
         let physical_arr = self.physical_growable.build()?;
         let arr = LogicalArray::<L>::new(
@@ -76,6 +84,7 @@ macro_rules! impl_logical_growable {
 
 impl_logical_growable!(LogicalTimestampGrowable, TimestampType);
 impl_logical_growable!(LogicalDurationGrowable, DurationType);
+impl_logical_growable!(LogicalIntervalGrowable, IntervalType);
 impl_logical_growable!(LogicalDateGrowable, DateType);
 impl_logical_growable!(LogicalTimeGrowable, TimeType);
 impl_logical_growable!(LogicalEmbeddingGrowable, EmbeddingType);
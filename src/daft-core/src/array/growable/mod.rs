@@ -1,11 +1,12 @@
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 
 use crate::{
     array::{FixedSizeListArray, ListArray, StructArray},
     datatypes::{
         logical::{
             DateArray, Decimal128Array, DurationArray, EmbeddingArray, FixedShapeImageArray,
-            FixedShapeTensorArray, ImageArray, MapArray, TensorArray, TimeArray, TimestampArray,
+            FixedShapeTensorArray, ImageArray, IntervalArray, MapArray, TensorArray, TimeArray,
+            TimestampArray,
         },
         BinaryArray, BooleanArray, ExtensionArray, Float32Array, Float64Array, Int128Array,
         Int16Array, Int32Array, Int64Array, Int8Array, NullArray, UInt16Array, UInt32Array,
@@ -62,6 +63,29 @@ pub fn make_growable<'a>(
     })
 }
 
+/// Fallible version of [`make_growable`], which returns an error instead of panicking when no [`Growable`] can be
+/// built for `dtype`, or when the provided `series` don't all have `dtype` as their [`DataType`].
+pub fn try_make_growable<'a>(
+    name: &str,
+    dtype: &DataType,
+    series: Vec<&'a Series>,
+    use_validity: bool,
+    capacity: usize,
+) -> DaftResult<Box<dyn Growable + 'a>> {
+    if matches!(dtype, DataType::Unknown) {
+        return Err(DaftError::TypeError(format!(
+            "Cannot create a Growable for DataType: {dtype}"
+        )));
+    }
+    if let Some(mismatched) = series.iter().find(|s| s.data_type() != dtype) {
+        return Err(DaftError::TypeError(format!(
+            "Cannot create a Growable for DataType: {dtype} from a Series with DataType: {}",
+            mismatched.data_type()
+        )));
+    }
+    Ok(make_growable(name, dtype, series, use_validity, capacity))
+}
+
 /// Describes a struct that can be extended from slices of other pre-existing Series.
 /// This is very useful for abstracting many "physical" operations such as takes, broadcasts,
 /// filters and more.
@@ -75,7 +99,23 @@ pub trait Growable {
     /// Extends this [`Growable`] with null elements
     fn add_nulls(&mut self, additional: usize);
 
-    /// Builds an array from the [`Growable`]
+    /// Number of elements added to this [`Growable`] so far, across both [`Growable::extend`] and
+    /// [`Growable::add_nulls`]
+    fn len(&self) -> usize;
+
+    /// Whether no elements have been added to this [`Growable`] so far
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Reserves capacity for at least `additional` more elements, so that callers about to make many small
+    /// [`Growable::extend`] calls can pre-size the growable once. This is a no-op for growables that cannot
+    /// reserve capacity up-front, such as those backed by arrow2 growables.
+    fn reserve(&mut self, _additional: usize) {}
+
+    /// Builds an array from the elements added to the [`Growable`] since it was created or last built, and resets
+    /// it to be empty. The [`Growable`] can keep being extended from the same source arrays afterwards, so calling
+    /// this repeatedly emits successive, non-overlapping chunks.
     fn build(&mut self) -> DaftResult<Series>;
 }
 
@@ -191,6 +231,7 @@ impl_growable_array!(
     logical_growable::LogicalTimestampGrowable<'a>
 );
 impl_growable_array!(DurationArray, logical_growable::LogicalDurationGrowable<'a>);
+impl_growable_array!(IntervalArray, logical_growable::LogicalIntervalGrowable<'a>);
 impl_growable_array!(DateArray, logical_growable::LogicalDateGrowable<'a>);
 impl_growable_array!(TimeArray, logical_growable::LogicalTimeGrowable<'a>);
 impl_growable_array!(
@@ -212,3 +253,142 @@ impl_growable_array!(
     logical_growable::LogicalDecimal128Growable<'a>
 );
 impl_growable_array!(MapArray, logical_growable::LogicalMapGrowable<'a>);
+
+#[cfg(test)]
+mod tests {
+    use common_error::{DaftError, DaftResult};
+
+    use crate::{
+        array::StructArray,
+        datatypes::{Field, Int64Array, Utf8Array},
+        DataType, IntoSeries, Series,
+    };
+
+    use super::{make_growable, try_make_growable};
+
+    #[test]
+    fn test_try_make_growable() -> DaftResult<()> {
+        let arr = Int64Array::from(("foo", vec![0, 1, 2])).into_series();
+        let struct_dtype = DataType::Struct(vec![Field::new("foo", DataType::Int64)]);
+        let struct_arr = StructArray::new(
+            Field::new("foo", struct_dtype.clone()),
+            vec![arr.clone()],
+            None,
+        )
+        .into_series();
+
+        for series in [&arr, &struct_arr] {
+            let mut growable =
+                try_make_growable("foo", series.data_type(), vec![series], false, 3)?;
+            growable.extend(0, 0, 3);
+            assert_eq!(growable.build()?.to_arrow(), series.to_arrow());
+        }
+
+        // Types that can't be grown, and series that don't match the requested type, are errors rather than panics
+        assert!(matches!(
+            try_make_growable("foo", &DataType::Unknown, vec![], false, 0),
+            Err(DaftError::TypeError(_))
+        ));
+        assert!(matches!(
+            try_make_growable("foo", &DataType::Utf8, vec![&arr], false, 0),
+            Err(DaftError::TypeError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_growable_build_chunks() -> DaftResult<()> {
+        let arr = Int64Array::from(("foo", vec![0, 1, 2, 3])).into_series();
+        let utf8_arr =
+            Utf8Array::from(("foo", ["a", "bb", "ccc", "dddd"].as_slice())).into_series();
+        let list_arr = Series::concat(&[&arr.slice(0, 2)?.agg_list(None)?, &arr.agg_list(None)?])?;
+        let null_arr = Series::full_null("foo", &DataType::Null, 4);
+        let struct_dtype = DataType::Struct(vec![Field::new("foo", DataType::Int64)]);
+        let struct_arr = StructArray::new(
+            Field::new("foo", struct_dtype.clone()),
+            vec![arr.clone()],
+            None,
+        )
+        .into_series();
+
+        for series in [&arr, &utf8_arr, &list_arr, &null_arr, &struct_arr] {
+            let mut growable = make_growable("foo", series.data_type(), vec![series], true, 0);
+            growable.extend(0, 0, 1);
+            growable.add_nulls(1);
+            let first = growable.build()?;
+            assert!(growable.is_empty());
+
+            growable.extend(0, 1, 1);
+            growable.add_nulls(2);
+            let second = growable.build()?;
+
+            // Each chunk only holds the elements added since the previous build, including their nulls and offsets
+            let expected_first = Series::concat(&[
+                &series.slice(0, 1)?,
+                &Series::full_null("foo", series.data_type(), 1),
+            ])?;
+            let expected_second = Series::concat(&[
+                &series.slice(1, 2)?,
+                &Series::full_null("foo", series.data_type(), 2),
+            ])?;
+            assert_eq!(first.to_arrow(), expected_first.to_arrow());
+            assert_eq!(second.to_arrow(), expected_second.to_arrow());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_growable_len() -> DaftResult<()> {
+        let arr = Int64Array::from(("foo", vec![0, 1, 2])).into_series();
+        let list_dtype = DataType::List(Box::new(DataType::Int64));
+        let list_arr = arr.agg_list(None)?;
+        let struct_dtype = DataType::Struct(vec![Field::new("foo", DataType::Int64)]);
+        let struct_arr = StructArray::new(
+            Field::new("bar", struct_dtype.clone()),
+            vec![arr.clone()],
+            None,
+        )
+        .into_series();
+
+        for (dtype, series) in [
+            (DataType::Int64, &arr),
+            (list_dtype, &list_arr),
+            (struct_dtype, &struct_arr),
+        ] {
+            let mut growable = make_growable("bar", &dtype, vec![series], true, 0);
+            assert!(growable.is_empty());
+            growable.extend(0, 0, 1);
+            growable.add_nulls(2);
+            growable.extend(0, 0, 1);
+            assert_eq!(growable.len(), 4);
+            assert!(!growable.is_empty());
+            assert_eq!(growable.build()?.len(), 4);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_growable_reserve() -> DaftResult<()> {
+        let dtype = DataType::Struct(vec![Field::new("bar", DataType::Int64)]);
+        let child = Int64Array::from(("bar", vec![0, 1, 2])).into_series();
+        let arr =
+            StructArray::new(Field::new("foo", dtype.clone()), vec![child], None).into_series();
+
+        // Reserving up-front fans out to the children, and growing past the reserved capacity is still fine
+        let mut growable = make_growable("foo", &dtype, vec![&arr], true, 0);
+        growable.reserve(100);
+        for _ in 0..100 {
+            growable.extend(0, 1, 2);
+        }
+        growable.reserve(1);
+        growable.add_nulls(1);
+
+        let result = growable.build()?;
+        assert_eq!(result.len(), 201);
+        let child = result.struct_()?.children[0].i64()?.clone();
+        assert_eq!(child.get(0), Some(1));
+        assert_eq!(child.get(199), Some(2));
+        assert_eq!(child.get(200), None);
+        Ok(())
+    }
+}
@@ -18,6 +18,12 @@ pub struct PythonGrowable<'a> {
 }
 
 impl<'a> PythonGrowable<'a> {
+    /// Appends a single object, as when building an array from computed values rather than from `arr_refs`.
+    /// A Python `None` becomes a null.
+    pub fn push(&mut self, obj: pyo3::PyObject) {
+        self.buffer.push(obj);
+    }
+
     pub fn new(
         name: &str,
         dtype: &DataType,
@@ -43,20 +49,30 @@ impl<'a> Growable for PythonGrowable<'a> {
             .as_any()
             .downcast_ref::<PseudoArrowArray<pyo3::PyObject>>()
             .unwrap();
-        let pynone = pyo3::Python::with_gil(|py| py.None());
-        for obj in slice_to_copy.iter() {
-            match obj {
-                None => self.buffer.push(pynone.clone()),
-                Some(obj) => self.buffer.push(obj.clone()),
-            }
-        }
+        // Cloning a PyObject bumps its refcount, so hold the GIL once across the whole slice rather than
+        // letting each clone acquire it separately
+        pyo3::Python::with_gil(|py| {
+            self.buffer
+                .extend(slice_to_copy.iter().map(|obj| match obj {
+                    None => py.None(),
+                    Some(obj) => obj.clone_ref(py),
+                }));
+        });
     }
     #[inline]
     fn add_nulls(&mut self, additional: usize) {
-        let pynone = pyo3::Python::with_gil(|py| py.None());
-        for _ in 0..additional {
-            self.buffer.push(pynone.clone());
-        }
+        pyo3::Python::with_gil(|py| {
+            self.buffer
+                .extend(std::iter::repeat_with(|| py.None()).take(additional));
+        });
+    }
+    #[inline]
+    fn len(&self) -> usize {
+        self.buffer.len()
+    }
+    #[inline]
+    fn reserve(&mut self, additional: usize) {
+        self.buffer.reserve(additional);
     }
     #[inline]
     fn build(&mut self) -> common_error::DaftResult<Series> {
@@ -13,6 +13,8 @@ pub struct StructGrowable<'a> {
     dtype: DataType,
     children_growables: Vec<Box<dyn Growable + 'a>>,
     growable_validity: Option<ArrowBitmapGrowable<'a>>,
+    /// Number of rows added so far, which is tracked separately since a struct may have no children
+    len: usize,
 }
 
 impl<'a> StructGrowable<'a> {
@@ -55,6 +57,7 @@ impl<'a> StructGrowable<'a> {
                     dtype: dtype.clone(),
                     children_growables,
                     growable_validity,
+                    len: 0,
                 }
             }
             _ => panic!("Cannot create StructGrowable from dtype: {}", dtype),
@@ -72,6 +75,7 @@ impl<'a> Growable for StructGrowable<'a> {
             Some(growable_validity) => growable_validity.extend(index, start, len),
             None => (),
         }
+        self.len += len;
     }
 
     fn add_nulls(&mut self, additional: usize) {
@@ -83,17 +87,32 @@ impl<'a> Growable for StructGrowable<'a> {
             Some(growable_validity) => growable_validity.add_nulls(additional),
             None => (),
         }
+        self.len += additional;
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        for child_growable in &mut self.children_growables {
+            child_growable.reserve(additional);
+        }
+
+        if let Some(growable_validity) = &mut self.growable_validity {
+            growable_validity.reserve(additional);
+        }
     }
 
     fn build(&mut self) -> DaftResult<Series> {
-        let grown_validity = std::mem::take(&mut self.growable_validity);
+        self.len = 0;
 
         let built_children = self
             .children_growables
             .iter_mut()
             .map(|cg| cg.build())
             .collect::<DaftResult<Vec<_>>>()?;
-        let built_validity = grown_validity.map(|v| v.build());
+        let built_validity = self.growable_validity.as_mut().map(|v| v.build());
         Ok(StructArray::new(
             Field::new(self.name.clone(), self.dtype.clone()),
             built_children,
@@ -102,3 +121,60 @@ impl<'a> Growable for StructGrowable<'a> {
         .into_series())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use arrow2::bitmap::Bitmap;
+    use common_error::DaftResult;
+
+    use crate::{
+        array::{
+            growable::{Growable, GrowableArray},
+            ops::as_arrow::AsArrow,
+            StructArray,
+        },
+        datatypes::{Field, Int64Array, Utf8Array},
+        DataType, IntoSeries,
+    };
+
+    #[test]
+    fn test_struct_growable_round_trip_with_null_parents() -> DaftResult<()> {
+        let dtype = DataType::Struct(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Utf8),
+        ]);
+        let a = Int64Array::from(("a", vec![0, 1, 2, 3])).into_series();
+        let b = Utf8Array::from(("b", ["w", "x", "y", "z"].as_slice())).into_series();
+        let arr = StructArray::new(
+            Field::new("foo", dtype.clone()),
+            vec![a, b],
+            Some(Bitmap::from(&[true, false, true, false])),
+        );
+
+        let mut growable = StructArray::make_growable("foo", &dtype, vec![&arr], true, 6);
+        growable.extend(0, 0, 4);
+        growable.add_nulls(1);
+        growable.extend(0, 2, 1);
+        let result = growable.build()?;
+        let result = result.struct_()?;
+
+        assert_eq!(result.len(), 6);
+        assert_eq!(
+            result.validity().unwrap().iter().collect::<Vec<_>>(),
+            vec![true, false, true, false, false, true]
+        );
+
+        // Every child is null wherever its parent is null
+        let a = result.get("a")?;
+        assert_eq!(
+            a.i64()?.into_iter().collect::<Vec<_>>(),
+            vec![Some(&0), None, Some(&2), None, None, Some(&2)]
+        );
+        let b = result.get("b")?;
+        assert_eq!(
+            b.utf8()?.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some("w"), None, Some("y"), None, None, Some("y")]
+        );
+        Ok(())
+    }
+}
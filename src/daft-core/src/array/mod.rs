@@ -68,10 +68,18 @@ where
         self.data().len()
     }
 
+    /// Number of nulls in this array, counted from the set bits of its validity rather than by iterating over its
+    /// values, and 0 without looking at any bitmap when the array has no validity. Python arrays mark their `None`
+    /// objects as nulls in their validity, so these are counted too.
     pub fn null_count(&self) -> usize {
         self.data().null_count()
     }
 
+    /// Number of non-null values in this array, see [`DataArray::null_count`].
+    pub fn valid_count(&self) -> usize {
+        self.len() - self.null_count()
+    }
+
     pub fn data_type(&self) -> &DataType {
         &self.field.dtype
     }
@@ -81,6 +89,11 @@ where
     }
 
     pub fn with_validity_slice(&self, validity: &[bool]) -> DaftResult<Self> {
+        self.with_validity_bitmap(Bitmap::from(validity))
+    }
+
+    /// Like [`DataArray::with_validity_slice`], but takes an already built bitmap rather than a slice of bools.
+    pub fn with_validity_bitmap(&self, validity: Bitmap) -> DaftResult<Self> {
         if validity.len() != self.data.len() {
             return Err(DaftError::ValueError(format!(
                 "validity mask length does not match DataArray length, {} vs {}",
@@ -88,7 +101,7 @@ where
                 self.data.len()
             )));
         }
-        let with_bitmap = self.data.with_validity(Some(Bitmap::from(validity)));
+        let with_bitmap = self.data.with_validity(Some(validity));
         DataArray::new(self.field.clone(), with_bitmap)
     }
 
@@ -104,22 +117,33 @@ where
         DataArray::new(self.field.clone(), with_bitmap)
     }
 
+    /// Validity of this array, where unset bits are nulls. `None` means that every row is valid.
     pub fn validity(&self) -> Option<&Bitmap> {
         self.data.validity()
     }
 
+    /// Returns the rows in `start..end` of this array. This is zero-copy: the slice is a view which shares its
+    /// buffers with this array, and slicing a Python array neither clones nor increfs its objects.
+    /// Errors if `start > end` or `end` is past the end of the array.
     pub fn slice(&self, start: usize, end: usize) -> DaftResult<Self> {
         if start > end {
             return Err(DaftError::ValueError(format!(
                 "Trying to slice array with negative length, start: {start} vs end: {end}"
             )));
         }
+        if end > self.len() {
+            return Err(DaftError::ValueError(format!(
+                "Trying to slice array of length {} past its end: {end}",
+                self.len()
+            )));
+        }
         let sliced = self.data.sliced(start, end - start);
         Self::new(self.field.clone(), sliced)
     }
 
+    /// Returns the first `num` rows of this array, or all of them if it has fewer.
     pub fn head(&self, num: usize) -> DaftResult<Self> {
-        self.slice(0, num)
+        self.slice(0, num.min(self.len()))
     }
 
     pub fn data(&self) -> &dyn arrow2::array::Array {
@@ -134,6 +158,30 @@ where
         Self::new(Arc::new(self.field.rename(name)), self.data.clone()).unwrap()
     }
 
+    /// Replaces the field of this array, e.g. to change its metadata, sharing the data rather than copying it. The
+    /// type of `field` must have the same arrow representation as the data, which is checked as in
+    /// [`FromArrow`](ops::from_arrow::FromArrow) rather than panicking as [`DataArray::new`] would.
+    pub fn with_field<F: Into<Arc<Field>>>(&self, field: F) -> DaftResult<Self> {
+        let field = field.into();
+        if !field.dtype.is_physical() {
+            return Err(DaftError::TypeError(format!(
+                "Can only construct DataArray for PhysicalTypes, got {}",
+                field.dtype
+            )));
+        }
+        if let Ok(expected_arrow_dtype) = field.dtype.to_arrow()
+            && &expected_arrow_dtype != self.data.data_type()
+        {
+            return Err(DaftError::TypeError(format!(
+                "Attempting to set field with type {} (arrow type {:?}) on DataArray with arrow type {:?}",
+                field.dtype,
+                expected_arrow_dtype,
+                self.data.data_type()
+            )));
+        }
+        Self::new(field, self.data.clone())
+    }
+
     pub fn field(&self) -> &Field {
         &self.field
     }
@@ -147,3 +195,136 @@ where
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use arrow2::bitmap::Bitmap;
+    use common_error::{DaftError, DaftResult};
+
+    use crate::{
+        array::ops::{as_arrow::AsArrow, full::FullNull},
+        datatypes::{DataType, Field, Int64Array, NullArray, Utf8Array},
+    };
+
+    #[test]
+    fn slice_interior() -> DaftResult<()> {
+        let ints = Int64Array::from(("i", vec![0, 1, 2, 3, 4]));
+        let sliced = ints.slice(1, 4)?;
+        assert_eq!(sliced.name(), "i");
+        assert_eq!(sliced.as_arrow().values().as_slice(), [1, 2, 3]);
+        // The slice is a view into the same buffer
+        assert_eq!(
+            sliced.as_arrow().values().as_ptr(),
+            ints.as_arrow().values()[1..].as_ptr()
+        );
+
+        let strings = Utf8Array::from(("s", ["a", "b", "c"].as_slice()));
+        assert!(strings.slice(3, 3)?.is_empty());
+        assert_eq!(
+            strings.slice(0, 2)?.as_arrow().iter().collect::<Vec<_>>(),
+            [Some("a"), Some("b")]
+        );
+        assert_eq!(strings.head(10)?.len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn slice_out_of_bounds() {
+        let ints = Int64Array::from(("i", vec![0, 1, 2]));
+        assert!(matches!(ints.slice(2, 1), Err(DaftError::ValueError(_))));
+        assert!(matches!(ints.slice(1, 4), Err(DaftError::ValueError(_))));
+        assert!(matches!(ints.slice(4, 4), Err(DaftError::ValueError(_))));
+    }
+
+    #[test]
+    fn with_validity_bitmap_matches_slice() -> DaftResult<()> {
+        let ints = Int64Array::from(("i", vec![0, 1, 2]));
+        let from_slice = ints.with_validity_slice(&[true, false, true])?;
+        let from_bitmap = ints.with_validity_bitmap(Bitmap::from([true, false, true]))?;
+        assert_eq!(from_bitmap.validity(), from_slice.validity());
+        assert_eq!(from_bitmap.null_count(), 1);
+
+        assert!(matches!(
+            ints.with_validity_bitmap(Bitmap::from([true, false])),
+            Err(DaftError::ValueError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn rename_and_with_field_share_data() -> DaftResult<()> {
+        let ints = Int64Array::from(("i", vec![0, 1, 2]));
+
+        let renamed = ints.rename("j");
+        assert_eq!(renamed.name(), "j");
+        assert_eq!(ints.name(), "i");
+        assert_eq!(
+            renamed.as_arrow().values().as_ptr(),
+            ints.as_arrow().values().as_ptr()
+        );
+
+        let metadata = BTreeMap::from([("key".to_string(), "value".to_string())]);
+        let field = Field::new("k", DataType::Int64).with_metadata(metadata.clone());
+        let with_field = ints.with_field(field)?;
+        assert_eq!(with_field.name(), "k");
+        assert_eq!(*with_field.field().metadata, metadata);
+        assert_eq!(
+            with_field.as_arrow().values().as_ptr(),
+            ints.as_arrow().values().as_ptr()
+        );
+
+        assert!(matches!(
+            ints.with_field(Field::new("i", DataType::Utf8)),
+            Err(DaftError::TypeError(_))
+        ));
+        assert!(matches!(
+            ints.with_field(Field::new("i", DataType::Date)),
+            Err(DaftError::TypeError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn null_and_valid_counts() -> DaftResult<()> {
+        let ints = Int64Array::from(("i", vec![0, 1, 2, 3]));
+        assert!(ints.validity().is_none());
+        assert_eq!((ints.null_count(), ints.valid_count()), (0, 4));
+
+        let ints = ints.with_validity_slice(&[true, false, false, true])?;
+        assert_eq!((ints.null_count(), ints.valid_count()), (2, 2));
+        // Counts follow slicing of the validity
+        let sliced = ints.slice(1, 4)?;
+        assert_eq!((sliced.null_count(), sliced.valid_count()), (2, 1));
+
+        let strings = Utf8Array::from((
+            "s",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("a"),
+                None,
+                Some(""),
+            ])),
+        ));
+        assert_eq!((strings.null_count(), strings.valid_count()), (1, 2));
+
+        let nulls = NullArray::full_null("n", &DataType::Null, 3);
+        assert_eq!((nulls.null_count(), nulls.valid_count()), (3, 0));
+
+        // Python `None` objects are marked as nulls in the validity
+        #[cfg(feature = "python")]
+        {
+            use pyo3::{Python, ToPyObject};
+
+            pyo3::prepare_freethreaded_python();
+            let objects = Python::with_gil(|py| {
+                vec![1.to_object(py), py.None(), "a".to_object(py), py.None()]
+            });
+            let objects = crate::datatypes::PythonArray::from(("p", objects));
+            assert_eq!((objects.null_count(), objects.valid_count()), (2, 2));
+            let sliced = objects.slice(1, 3)?;
+            assert_eq!((sliced.null_count(), sliced.valid_count()), (1, 1));
+        }
+        Ok(())
+    }
+}
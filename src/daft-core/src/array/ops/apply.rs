@@ -21,3 +21,32 @@ where
         Ok(DataArray::from((self.name(), Box::new(result_arr))))
     }
 }
+
+#[cfg(feature = "python")]
+impl crate::datatypes::PythonArray {
+    /// Calls `func` on each element of this array, holding the GIL once across the whole array. Null elements stay
+    /// null when `skip_nulls` is set, and are otherwise passed to `func` as `None`. Results of `None` are null.
+    pub fn apply(&self, func: pyo3::PyObject, skip_nulls: bool) -> DaftResult<Self> {
+        use pyo3::Python;
+
+        use crate::array::{
+            growable::{Growable, GrowableArray},
+            ops::as_arrow::AsArrow,
+        };
+
+        let mut growable =
+            Self::make_growable(self.name(), self.data_type(), vec![], false, self.len());
+        Python::with_gil(|py| -> DaftResult<()> {
+            for value in self.as_arrow().iter() {
+                let result = match value {
+                    None if skip_nulls => py.None(),
+                    None => func.call1(py, (py.None(),))?,
+                    Some(value) => func.call1(py, (value.clone_ref(py),))?,
+                };
+                growable.push(result);
+            }
+            Ok(())
+        })?;
+        Ok(growable.build()?.downcast::<Self>()?.clone())
+    }
+}
@@ -4,11 +4,12 @@ use arrow2::{array::PrimitiveArray, compute::arithmetics::basic};
 
 use crate::{
     array::DataArray,
-    datatypes::{DaftNumericType, Float64Array, Int64Array, Utf8Array},
+    datatypes::{DaftNumericType, Utf8Array},
     kernels::utf8::add_utf8_arrays,
 };
 
 use common_error::{DaftError, DaftResult};
+use num_traits::Zero;
 
 use super::{as_arrow::AsArrow, full::FullNull};
 /// Helper function to perform arithmetic operations on a DataArray
@@ -114,17 +115,101 @@ where
     }
 }
 
-impl Div for &Float64Array {
-    type Output = DaftResult<Float64Array>;
+impl<T> Div for &DataArray<T>
+where
+    T: DaftNumericType,
+    T::Native: basic::NativeArithmetics,
+{
+    type Output = DaftResult<DataArray<T>>;
+    /// Divides the rows of the arrays. Floats follow IEEE 754, so dividing by zero gives inf or NaN, while
+    /// dividing integers by zero is an error.
     fn div(self, rhs: Self) -> Self::Output {
-        arithmetic_helper(self, rhs, basic::div, |l, r| l / r)
+        if rhs.data_type().is_floating() {
+            return arithmetic_helper(self, rhs, basic::div, |l, r| l / r);
+        }
+        check_no_zero_divisor(self.name(), rhs.as_arrow().iter().flatten().copied())?;
+        if rhs.data().null_count() == 0 {
+            arithmetic_helper(self, rhs, basic::div, |l, r| l / r)
+        } else {
+            // Integer division panics on zeros, which may be the values under nulls, so only divide valid values
+            valid_arithmetic_helper(self, rhs, |l, r| l / r)
+        }
     }
 }
 
-impl Div for &Int64Array {
-    type Output = DaftResult<Int64Array>;
-    fn div(self, rhs: Self) -> Self::Output {
-        arithmetic_helper(self, rhs, basic::div, |l, r| l / r)
+fn check_no_zero_divisor<N: Zero>(
+    name: &str,
+    mut divisors: impl Iterator<Item = N>,
+) -> DaftResult<()> {
+    if divisors.any(|v| v.is_zero()) {
+        return Err(DaftError::ComputeError(format!(
+            "Integer division by zero when dividing {name}"
+        )));
+    }
+    Ok(())
+}
+
+/// Like [`arithmetic_helper`], but only applies `operation` to pairs of valid values rather than to every slot.
+fn valid_arithmetic_helper<T, F>(
+    lhs: &DataArray<T>,
+    rhs: &DataArray<T>,
+    operation: F,
+) -> DaftResult<DataArray<T>>
+where
+    T: DaftNumericType,
+    F: Fn(T::Native, T::Native) -> T::Native,
+{
+    let arrow_array = match (lhs.len(), rhs.len()) {
+        (a, b) if a == b => binary_with_nulls(lhs.as_arrow(), rhs.as_arrow(), operation),
+        (_, 1) => match rhs.get(0) {
+            None => return Ok(DataArray::full_null(lhs.name(), lhs.data_type(), lhs.len())),
+            Some(r) => {
+                let values_iter = lhs.as_arrow().iter().map(|l| l.map(|l| operation(*l, r)));
+                unsafe { PrimitiveArray::from_trusted_len_iter_unchecked(values_iter) }
+            }
+        },
+        (1, _) => match lhs.get(0) {
+            None => return Ok(DataArray::full_null(lhs.name(), lhs.data_type(), rhs.len())),
+            Some(l) => {
+                let values_iter = rhs.as_arrow().iter().map(|r| r.map(|r| operation(l, *r)));
+                unsafe { PrimitiveArray::from_trusted_len_iter_unchecked(values_iter) }
+            }
+        },
+        (a, b) => {
+            return Err(DaftError::ValueError(format!(
+                "Cannot apply operation on arrays of different lengths: {a} vs {b}"
+            )))
+        }
+    };
+    Ok(DataArray::from((lhs.name(), Box::new(arrow_array))))
+}
+
+impl<T> DataArray<T>
+where
+    T: DaftNumericType,
+{
+    /// Adds `rhs` to each row, keeping null rows null.
+    pub fn add_scalar(&self, rhs: T::Native) -> DaftResult<Self> {
+        self.apply(|v| v + rhs)
+    }
+
+    /// Subtracts `rhs` from each row, keeping null rows null.
+    pub fn sub_scalar(&self, rhs: T::Native) -> DaftResult<Self> {
+        self.apply(|v| v - rhs)
+    }
+
+    /// Multiplies each row by `rhs`, keeping null rows null.
+    pub fn mul_scalar(&self, rhs: T::Native) -> DaftResult<Self> {
+        self.apply(|v| v * rhs)
+    }
+
+    /// Divides each row by `rhs`, keeping null rows null. As for dividing arrays, dividing integers by zero is an
+    /// error while floats follow IEEE 754.
+    pub fn div_scalar(&self, rhs: T::Native) -> DaftResult<Self> {
+        if !self.data_type().is_floating() {
+            check_no_zero_divisor(self.name(), std::iter::once(rhs))?;
+        }
+        self.apply(|v| v / rhs)
     }
 }
 
@@ -202,3 +287,76 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::{DaftError, DaftResult};
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{Float64Array, Int32Array, Int64Array},
+    };
+
+    fn values(array: &Int64Array) -> Vec<Option<i64>> {
+        array.as_arrow().iter().map(|v| v.copied()).collect()
+    }
+
+    #[test]
+    fn arithmetic_on_arrays() -> DaftResult<()> {
+        let lhs = Int64Array::from_iter("l", vec![Some(6), None, Some(-9), Some(4)].into_iter());
+        let rhs = Int64Array::from_iter("r", vec![Some(3), Some(2), None, Some(-2)].into_iter());
+        assert_eq!(values(&(&lhs + &rhs)?), [Some(9), None, None, Some(2)]);
+        assert_eq!(values(&(&lhs - &rhs)?), [Some(3), None, None, Some(6)]);
+        assert_eq!(values(&(&lhs * &rhs)?), [Some(18), None, None, Some(-8)]);
+        assert_eq!(values(&(&lhs / &rhs)?), [Some(2), None, None, Some(-2)]);
+
+        // Length 1 arrays are broadcast
+        let scalar = Int64Array::from(("s", vec![2]));
+        assert_eq!(
+            values(&(&lhs / &scalar)?),
+            [Some(3), None, Some(-4), Some(2)]
+        );
+        assert_eq!(
+            values(&(&scalar - &rhs)?),
+            [Some(-1), Some(0), None, Some(4)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn arithmetic_with_scalars() -> DaftResult<()> {
+        let array = Int64Array::from_iter("a", vec![Some(10), None, Some(-4)].into_iter());
+        assert_eq!(values(&array.add_scalar(1)?), [Some(11), None, Some(-3)]);
+        assert_eq!(values(&array.sub_scalar(1)?), [Some(9), None, Some(-5)]);
+        assert_eq!(values(&array.mul_scalar(-2)?), [Some(-20), None, Some(8)]);
+        assert_eq!(values(&array.div_scalar(4)?), [Some(2), None, Some(-1)]);
+
+        let small = Int32Array::from(("i", vec![7]));
+        assert_eq!(small.add_scalar(1)?.as_arrow().value(0), 8);
+        Ok(())
+    }
+
+    #[test]
+    fn division_by_zero() -> DaftResult<()> {
+        let ints = Int64Array::from(("a", vec![1, 2]));
+        let zeros = Int64Array::from_iter("z", vec![Some(1), Some(0)].into_iter());
+        assert!(matches!(&ints / &zeros, Err(DaftError::ComputeError(_))));
+        assert!(matches!(
+            ints.div_scalar(0),
+            Err(DaftError::ComputeError(_))
+        ));
+
+        // Zeros under nulls are never divided by
+        let null_zero = Int64Array::from(("z", vec![1, 0]))
+            .with_validity(Some(arrow2::bitmap::Bitmap::from([true, false])))?;
+        assert_eq!(values(&(&ints / &null_zero)?), [Some(1), None]);
+
+        let floats = Float64Array::from(("f", vec![1.0, -1.0, 0.0]));
+        let result = floats.div_scalar(0.0)?;
+        let result = result.as_arrow().values();
+        assert_eq!(result[0], f64::INFINITY);
+        assert_eq!(result[1], f64::NEG_INFINITY);
+        assert!(result[2].is_nan());
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+use base64::{
+    engine::general_purpose::{STANDARD, URL_SAFE},
+    Engine,
+};
+use common_error::{DaftError, DaftResult};
+
+use crate::datatypes::{BinaryArray, Utf8Array};
+
+use super::as_arrow::AsArrow;
+
+/// Padded base64 engine with the standard alphabet, or the URL-safe one which uses `-` and `_` in place of `+` and `/`
+fn engine(url_safe: bool) -> &'static base64::engine::GeneralPurpose {
+    if url_safe {
+        &URL_SAFE
+    } else {
+        &STANDARD
+    }
+}
+
+impl BinaryArray {
+    /// Encodes each value as a padded base64 string, with the URL-safe alphabet if `url_safe` is set.
+    pub fn to_base64(&self, url_safe: bool) -> DaftResult<Utf8Array> {
+        let engine = engine(url_safe);
+        let base64_iter = self
+            .as_arrow()
+            .iter()
+            .map(|val| val.map(|bytes| engine.encode(bytes)));
+        Ok(Utf8Array::from_iter(self.name(), base64_iter))
+    }
+}
+
+impl Utf8Array {
+    /// Decodes each padded base64 string, in the URL-safe alphabet if `url_safe` is set, into the bytes it encodes.
+    /// Strings which aren't valid base64 are null, or an error if `strict` is set.
+    pub fn from_base64(&self, url_safe: bool, strict: bool) -> DaftResult<BinaryArray> {
+        let engine = engine(url_safe);
+        let bytes = self
+            .as_arrow()
+            .iter()
+            .map(|val| match val {
+                Some(val) => match engine.decode(val) {
+                    Ok(bytes) => Ok(Some(bytes)),
+                    Err(e) if strict => Err(DaftError::ValueError(format!(
+                        "Error in from_base64: {val:?} is not valid base64: {e}"
+                    ))),
+                    Err(_) => Ok(None),
+                },
+                None => Ok(None),
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(BinaryArray::from_iter(self.name(), bytes.into_iter()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::{DaftError, DaftResult};
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{BinaryArray, Utf8Array},
+    };
+
+    #[test]
+    fn base64_round_trip() -> DaftResult<()> {
+        let bytes = BinaryArray::from_iter(
+            "b",
+            vec![Some(vec![0xfb, 0xff, 0x01]), None, Some(b"daft".to_vec())].into_iter(),
+        );
+        let standard = bytes.to_base64(false)?;
+        assert_eq!(standard.name(), "b");
+        assert_eq!(
+            standard.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some("+/8B"), None, Some("ZGFmdA==")]
+        );
+        let url_safe = bytes.to_base64(true)?;
+        assert_eq!(
+            url_safe.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some("-_8B"), None, Some("ZGFmdA==")]
+        );
+
+        let expected = bytes.as_arrow().iter().collect::<Vec<_>>();
+        let decoded = standard.from_base64(false, true)?;
+        assert_eq!(decoded.as_arrow().iter().collect::<Vec<_>>(), expected);
+        let decoded = url_safe.from_base64(true, true)?;
+        assert_eq!(decoded.as_arrow().iter().collect::<Vec<_>>(), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn from_base64_invalid() -> DaftResult<()> {
+        // URL-safe characters aren't part of the standard alphabet
+        let base64 = Utf8Array::from(("s", ["ZGFmdA==", "-_8B", "not base64!"].as_slice()));
+        assert_eq!(
+            base64
+                .from_base64(false, false)?
+                .as_arrow()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(b"daft".as_slice()), None, None]
+        );
+        assert!(matches!(
+            base64.from_base64(false, true),
+            Err(DaftError::ValueError(_))
+        ));
+        Ok(())
+    }
+}
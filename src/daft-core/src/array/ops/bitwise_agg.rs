@@ -0,0 +1,65 @@
+use std::ops::{BitAnd, BitOr, BitXor};
+
+use arrow2::array::PrimitiveArray;
+
+use crate::{array::DataArray, datatypes::*};
+
+use common_error::DaftResult;
+
+use super::{as_arrow::AsArrow, DaftBitwiseAggable, GroupIndices};
+
+/// Reduces the non-null values of `array`, or of each group if there are `groups`, with `op`.
+/// Groups without any non-null values reduce to null.
+fn reduce_valid<T>(
+    array: &DataArray<T>,
+    groups: Option<&GroupIndices>,
+    op: impl Fn(T::Native, T::Native) -> T::Native,
+) -> DaftResult<DataArray<T>>
+where
+    T: DaftIntegerType,
+    T::Native: Ord,
+{
+    let arrow_array = array.as_arrow();
+    let result = match groups {
+        Some(groups) => PrimitiveArray::from_trusted_len_iter(groups.iter().map(|g| {
+            g.iter()
+                .filter_map(|i| arrow_array.get(*i as usize))
+                .reduce(&op)
+        })),
+        None => PrimitiveArray::from([arrow_array.iter().flatten().copied().reduce(&op)]),
+    };
+    Ok(DataArray::from((array.name(), Box::new(result))))
+}
+
+impl<T> DaftBitwiseAggable for &DataArray<T>
+where
+    T: DaftIntegerType,
+    T::Native:
+        Ord + BitAnd<Output = T::Native> + BitOr<Output = T::Native> + BitXor<Output = T::Native>,
+{
+    type Output = DaftResult<DataArray<T>>;
+
+    fn bit_and(&self) -> Self::Output {
+        reduce_valid(self, None, BitAnd::bitand)
+    }
+
+    fn bit_or(&self) -> Self::Output {
+        reduce_valid(self, None, BitOr::bitor)
+    }
+
+    fn bit_xor(&self) -> Self::Output {
+        reduce_valid(self, None, BitXor::bitxor)
+    }
+
+    fn grouped_bit_and(&self, groups: &GroupIndices) -> Self::Output {
+        reduce_valid(self, Some(groups), BitAnd::bitand)
+    }
+
+    fn grouped_bit_or(&self, groups: &GroupIndices) -> Self::Output {
+        reduce_valid(self, Some(groups), BitOr::bitor)
+    }
+
+    fn grouped_bit_xor(&self, groups: &GroupIndices) -> Self::Output {
+        reduce_valid(self, Some(groups), BitXor::bitxor)
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{array::DataArray, datatypes::*};
+
+use common_error::DaftResult;
+
+use super::{as_arrow::AsArrow, DaftBoolAggable, GroupIndices};
+
+/// Whether any of `values` is true, following three-valued logic:
+/// true if any value is true, otherwise null if any value is null, otherwise false.
+fn any_of(values: &mut dyn Iterator<Item = Option<bool>>) -> Option<bool> {
+    let mut saw_null = false;
+    for value in values {
+        match value {
+            Some(true) => return Some(true),
+            Some(false) => {}
+            None => saw_null = true,
+        }
+    }
+    (!saw_null).then_some(false)
+}
+
+/// Whether all of `values` are true, following three-valued logic:
+/// false if any value is false, otherwise null if any value is null, otherwise true.
+fn all_of(values: &mut dyn Iterator<Item = Option<bool>>) -> Option<bool> {
+    any_of(&mut values.map(|value| value.map(|v| !v))).map(|any_false| !any_false)
+}
+
+/// Reduces the values of `array`, or of each group if there are `groups`, with `op`.
+fn reduce(
+    array: &BooleanArray,
+    groups: Option<&GroupIndices>,
+    op: fn(&mut dyn Iterator<Item = Option<bool>>) -> Option<bool>,
+) -> DaftResult<BooleanArray> {
+    let arrow_array = array.as_arrow();
+    let result: Vec<Option<bool>> = match groups {
+        Some(groups) => groups
+            .iter()
+            .map(|g| op(&mut g.iter().map(|i| arrow_array.get(*i as usize))))
+            .collect(),
+        None => vec![op(&mut arrow_array.iter())],
+    };
+    Ok(BooleanArray::from((array.name(), result.as_slice())))
+}
+
+impl DaftBoolAggable for &BooleanArray {
+    type Output = DaftResult<BooleanArray>;
+
+    fn any(&self) -> Self::Output {
+        reduce(self, None, any_of)
+    }
+
+    fn all(&self) -> Self::Output {
+        reduce(self, None, all_of)
+    }
+
+    fn grouped_any(&self, groups: &GroupIndices) -> Self::Output {
+        reduce(self, Some(groups), any_of)
+    }
+
+    fn grouped_all(&self, groups: &GroupIndices) -> Self::Output {
+        reduce(self, Some(groups), all_of)
+    }
+}
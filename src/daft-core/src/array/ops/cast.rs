@@ -10,15 +10,15 @@ use crate::{
     datatypes::{
         logical::{
             DateArray, Decimal128Array, DurationArray, EmbeddingArray, FixedShapeImageArray,
-            FixedShapeTensorArray, ImageArray, LogicalArray, LogicalArrayImpl, MapArray,
-            TensorArray, TimeArray, TimestampArray,
+            FixedShapeTensorArray, ImageArray, IntervalArray, LogicalArray, LogicalArrayImpl,
+            MapArray, TensorArray, TimeArray, TimestampArray,
         },
-        DaftArrowBackedType, DaftLogicalType, DataType, Field, ImageMode, Int64Array, TimeUnit,
-        UInt64Array, Utf8Array,
+        DaftArrowBackedType, DaftLogicalType, DaftNumericType, DataType, Field, ImageMode,
+        Int64Array, TimeUnit, UInt64Array, Utf8Array,
     },
     series::{IntoSeries, Series},
     utils::display_table::display_time64,
-    with_match_daft_logical_primitive_types,
+    with_match_daft_logical_primitive_types, with_match_numeric_daft_types,
 };
 use common_error::{DaftError, DaftResult};
 
@@ -32,16 +32,16 @@ use arrow2::{
     offset::Offsets,
 };
 use indexmap::IndexMap;
+use num_traits::{Bounded, NumCast, Zero};
 
 #[cfg(feature = "python")]
 use {
     crate::array::pseudo_arrow::PseudoArrowArray,
     crate::datatypes::PythonArray,
     crate::ffi,
-    crate::with_match_numeric_daft_types,
     log,
     ndarray::IntoDimension,
-    num_traits::{NumCast, ToPrimitive},
+    num_traits::ToPrimitive,
     numpy::{PyArray3, PyReadonlyArrayDyn},
     pyo3::prelude::*,
     std::iter,
@@ -253,6 +253,72 @@ where
     }
 }
 
+/// How a numeric cast treats values which are out of range for the target type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CastPolicy {
+    /// Behave like an `as` cast in Rust: integers keep their low bits, while floats cast to integers saturate.
+    Wrap,
+    /// Clamp values to the smallest or largest value of the target type. NaN becomes 0 for integer targets.
+    Saturate,
+    /// Fail the cast if any value is out of range.
+    ErrorOnOverflow,
+}
+
+impl<T> DataArray<T>
+where
+    T: DaftNumericType,
+{
+    /// Casts this array to the numeric type `dtype`, applying `policy` to values that do not fit in it.
+    /// Null values stay null.
+    pub fn cast_with_policy(&self, dtype: &DataType, policy: CastPolicy) -> DaftResult<Series> {
+        match dtype {
+            DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Float32
+            | DataType::Float64 => {}
+            _ => {
+                return Err(DaftError::TypeError(format!(
+                "Cast with an overflow policy is only supported to numeric types, but got {dtype}"
+            )))
+            }
+        };
+        if policy == CastPolicy::Wrap {
+            return self.cast(dtype);
+        }
+
+        with_match_numeric_daft_types!(dtype, |$U| {
+            type Target = <$U as DaftNumericType>::Native;
+            let zero = T::Native::zero();
+            let values = self
+                .as_arrow()
+                .iter()
+                .enumerate()
+                .map(|(i, v)| match v {
+                    None => Ok(None),
+                    Some(v) => match (<Target as NumCast>::from(*v), policy) {
+                        (Some(cast), _) => Ok(Some(cast)),
+                        (None, CastPolicy::Saturate) if *v < zero => Ok(Some(<Target as Bounded>::min_value())),
+                        (None, CastPolicy::Saturate) if *v > zero => Ok(Some(<Target as Bounded>::max_value())),
+                        (None, CastPolicy::Saturate) => Ok(Some(<Target as Zero>::zero())),
+                        (None, _) => Err(DaftError::ComputeError(format!(
+                            "Overflow when casting {} to {dtype}: value {v} at index {i} is out of range",
+                            self.data_type()
+                        ))),
+                    },
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            let arrow_array = Box::new(arrow2::array::PrimitiveArray::<Target>::from(values));
+            Ok(DataArray::<$U>::from((self.name(), arrow_array)).into_series())
+        })
+    }
+}
+
 impl DateArray {
     pub fn cast(&self, dtype: &DataType) -> DaftResult<Series> {
         let date_array = self
@@ -419,6 +485,19 @@ impl TimeArray {
     }
 }
 
+impl IntervalArray {
+    pub fn cast(&self, dtype: &DataType) -> DaftResult<Series> {
+        match dtype {
+            DataType::Interval(..) if dtype == self.data_type() => Ok(self.clone().into_series()),
+            _ => Err(DaftError::TypeError(format!(
+                "Cannot cast {} to {}",
+                self.data_type(),
+                dtype
+            ))),
+        }
+    }
+}
+
 impl DurationArray {
     pub fn cast(&self, dtype: &DataType) -> DaftResult<Series> {
         match dtype {
@@ -1822,3 +1901,107 @@ where
         .into_series())
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::{DaftError, DaftResult};
+
+    use super::CastPolicy;
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{DataType, Float64Array, Int64Array},
+    };
+
+    fn cast_to_i32(values: &[Option<i64>], policy: CastPolicy) -> DaftResult<Vec<Option<i32>>> {
+        let array = Int64Array::from_iter("a", values.iter().copied());
+        let result = array.cast_with_policy(&DataType::Int32, policy)?;
+        assert_eq!(*result.data_type(), DataType::Int32);
+        Ok(result
+            .i32()?
+            .as_arrow()
+            .iter()
+            .map(|v| v.copied())
+            .collect())
+    }
+
+    const EDGES: [Option<i64>; 5] = [
+        Some(i32::MAX as i64),
+        Some(i32::MAX as i64 + 1),
+        None,
+        Some(i32::MIN as i64),
+        Some(i32::MIN as i64 - 1),
+    ];
+
+    #[test]
+    fn cast_wrap() -> DaftResult<()> {
+        assert_eq!(
+            cast_to_i32(&EDGES, CastPolicy::Wrap)?,
+            [
+                Some(i32::MAX),
+                Some(i32::MIN),
+                None,
+                Some(i32::MIN),
+                Some(i32::MAX)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn cast_saturate() -> DaftResult<()> {
+        assert_eq!(
+            cast_to_i32(&EDGES, CastPolicy::Saturate)?,
+            [
+                Some(i32::MAX),
+                Some(i32::MAX),
+                None,
+                Some(i32::MIN),
+                Some(i32::MIN)
+            ]
+        );
+
+        let floats = Float64Array::from_iter(
+            "a",
+            vec![Some(1e20), Some(-1e20), Some(f64::NAN), Some(-2.5)].into_iter(),
+        );
+        let result = floats.cast_with_policy(&DataType::Int64, CastPolicy::Saturate)?;
+        assert_eq!(
+            result
+                .i64()?
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            [Some(i64::MAX), Some(i64::MIN), Some(0), Some(-2)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn cast_error_on_overflow() -> DaftResult<()> {
+        assert_eq!(
+            cast_to_i32(
+                &[Some(i32::MAX as i64), None, Some(i32::MIN as i64)],
+                CastPolicy::ErrorOnOverflow
+            )?,
+            [Some(i32::MAX), None, Some(i32::MIN)]
+        );
+        for value in [i32::MAX as i64 + 1, i32::MIN as i64 - 1] {
+            assert!(matches!(
+                cast_to_i32(&[Some(value)], CastPolicy::ErrorOnOverflow),
+                Err(DaftError::ComputeError(_))
+            ));
+        }
+
+        let unsigned = Int64Array::from(("a", vec![-1]));
+        assert!(matches!(
+            unsigned.cast_with_policy(&DataType::UInt64, CastPolicy::ErrorOnOverflow),
+            Err(DaftError::ComputeError(_))
+        ));
+        assert!(matches!(
+            unsigned.cast_with_policy(&DataType::Utf8, CastPolicy::ErrorOnOverflow),
+            Err(DaftError::TypeError(_))
+        ));
+        Ok(())
+    }
+}
@@ -16,3 +16,28 @@ where
         self.apply(|v| v.ceil())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{array::ops::as_arrow::AsArrow, datatypes::Float64Array};
+
+    #[test]
+    fn ceil_preserves_nulls() -> DaftResult<()> {
+        let floats = Float64Array::from_iter(
+            "f",
+            vec![Some(1.5), None, Some(-1.5), Some(3.0)].into_iter(),
+        );
+        let result = floats.ceil()?;
+        assert_eq!(
+            result
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            [Some(2.0), None, Some(-1.0), Some(3.0)]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{array::DataArray, datatypes::DaftNumericType};
+
+use common_error::{DaftError, DaftResult};
+
+impl<T: DaftNumericType> DataArray<T> {
+    /// Clamps each value into `[min, max]`, where an omitted bound leaves that side unbounded. Nulls stay null.
+    pub fn clip(&self, min: Option<T::Native>, max: Option<T::Native>) -> DaftResult<Self> {
+        if let (Some(min), Some(max)) = (min, max) && min > max {
+            return Err(DaftError::ValueError(format!(
+                "Cannot clip {} with a min of {min:?} greater than its max of {max:?}",
+                self.name()
+            )));
+        }
+        self.apply(|v| match (min, max) {
+            (Some(min), _) if v < min => min,
+            (_, Some(max)) if v > max => max,
+            _ => v,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::{DaftError, DaftResult};
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{Float64Array, Int64Array},
+    };
+
+    #[test]
+    fn clip_ints() -> DaftResult<()> {
+        let ints = Int64Array::from_iter("i", vec![Some(-5), Some(0), None, Some(7)].into_iter());
+        let values = |array: Int64Array| {
+            array
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            values(ints.clip(Some(0), None)?),
+            [Some(0), Some(0), None, Some(7)]
+        );
+        assert_eq!(
+            values(ints.clip(None, Some(3))?),
+            [Some(-5), Some(0), None, Some(3)]
+        );
+        assert_eq!(
+            values(ints.clip(Some(-1), Some(3))?),
+            [Some(-1), Some(0), None, Some(3)]
+        );
+        assert_eq!(ints.clip(Some(-1), Some(3))?.name(), "i");
+        Ok(())
+    }
+
+    #[test]
+    fn clip_floats() -> DaftResult<()> {
+        let floats = Float64Array::from(("f", vec![-1.5, 0.25, 2.5]));
+        let clipped = floats.clip(Some(0.0), Some(1.0))?;
+        assert_eq!(clipped.as_arrow().values().as_slice(), [0.0, 0.25, 1.0]);
+        Ok(())
+    }
+
+    #[test]
+    fn clip_min_greater_than_max() {
+        let ints = Int64Array::from(("i", vec![1, 2, 3]));
+        assert!(matches!(
+            ints.clip(Some(3), Some(1)),
+            Err(DaftError::ValueError(_))
+        ));
+    }
+}
@@ -1464,7 +1464,10 @@ impl DaftCompare<&[u8]> for BinaryArray {
 
 #[cfg(test)]
 mod tests {
-    use crate::{array::ops::DaftCompare, datatypes::Int64Array};
+    use crate::{
+        array::ops::DaftCompare,
+        datatypes::{BooleanArray, Float64Array, Int64Array, Utf8Array},
+    };
     use common_error::DaftResult;
 
     #[test]
@@ -1638,4 +1641,96 @@ mod tests {
         assert_eq!(result[..], [None, None, Some(false)]);
         Ok(())
     }
+
+    #[test]
+    fn compare_float64_arrays_with_nulls() -> DaftResult<()> {
+        let lhs = Float64Array::from_iter(
+            "a",
+            vec![Some(1.5), None, Some(-2.0), Some(3.0)].into_iter(),
+        );
+        let rhs =
+            Float64Array::from_iter("b", vec![Some(1.5), Some(0.0), None, Some(2.5)].into_iter());
+        let collect =
+            |r: DaftResult<_>| -> DaftResult<Vec<_>> { Ok(r?.into_iter().collect::<Vec<_>>()) };
+        assert_eq!(
+            collect(lhs.equal(&rhs))?,
+            [Some(true), None, None, Some(false)]
+        );
+        assert_eq!(
+            collect(lhs.not_equal(&rhs))?,
+            [Some(false), None, None, Some(true)]
+        );
+        assert_eq!(
+            collect(lhs.lt(&rhs))?,
+            [Some(false), None, None, Some(false)]
+        );
+        assert_eq!(
+            collect(lhs.lte(&rhs))?,
+            [Some(true), None, None, Some(false)]
+        );
+        assert_eq!(
+            collect(lhs.gt(&rhs))?,
+            [Some(false), None, None, Some(true)]
+        );
+        assert_eq!(
+            collect(lhs.gte(&rhs))?,
+            [Some(true), None, None, Some(true)]
+        );
+
+        let result: Vec<_> = lhs.gt(0.0).into_iter().collect();
+        assert_eq!(result[..], [Some(true), None, Some(false), Some(true)]);
+        Ok(())
+    }
+
+    #[test]
+    fn compare_utf8_arrays_lexicographically() -> DaftResult<()> {
+        let lhs = Utf8Array::from((
+            "a",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("apple"),
+                None,
+                Some("b"),
+                Some("abc"),
+            ])),
+        ));
+        let rhs = Utf8Array::from(("b", ["apple", "x", "ab", "abd"].as_slice()));
+        let collect =
+            |r: DaftResult<_>| -> DaftResult<Vec<_>> { Ok(r?.into_iter().collect::<Vec<_>>()) };
+        assert_eq!(
+            collect(lhs.equal(&rhs))?,
+            [Some(true), None, Some(false), Some(false)]
+        );
+        assert_eq!(
+            collect(lhs.not_equal(&rhs))?,
+            [Some(false), None, Some(true), Some(true)]
+        );
+        assert_eq!(
+            collect(lhs.lt(&rhs))?,
+            [Some(false), None, Some(false), Some(true)]
+        );
+        assert_eq!(
+            collect(lhs.lte(&rhs))?,
+            [Some(true), None, Some(false), Some(true)]
+        );
+        assert_eq!(
+            collect(lhs.gt(&rhs))?,
+            [Some(false), None, Some(true), Some(false)]
+        );
+        assert_eq!(
+            collect(lhs.gte(&rhs))?,
+            [Some(true), None, Some(true), Some(false)]
+        );
+
+        // Scalars and length 1 arrays are broadcast
+        assert_eq!(
+            collect(lhs.lt("b"))?,
+            [Some(true), None, Some(false), Some(true)]
+        );
+        let scalar = Utf8Array::from(("s", ["b"].as_slice()));
+        assert_eq!(
+            collect(scalar.gt(&lhs))?,
+            [Some(true), None, Some(false), Some(true)]
+        );
+        Ok(())
+    }
 }
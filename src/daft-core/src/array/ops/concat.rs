@@ -1,115 +1,88 @@
-use arrow2::array::Array;
-
-use crate::{array::DataArray, datatypes::DaftPhysicalType};
+use crate::{
+    array::{
+        growable::{Growable, GrowableArray},
+        ops::full::FullNull,
+        DataArray,
+    },
+    datatypes::DaftPhysicalType,
+};
 use common_error::{DaftError, DaftResult};
 
-#[cfg(feature = "python")]
-use crate::array::pseudo_arrow::PseudoArrowArray;
-
-macro_rules! impl_variable_length_concat {
-    ($fn_name:ident, $arrow_type:ty, $create_fn: ident) => {
-        fn $fn_name(arrays: &[&dyn arrow2::array::Array]) -> DaftResult<Box<$arrow_type>> {
-            let mut num_rows: usize = 0;
-            let mut num_bytes: usize = 0;
-            let mut need_validity = false;
-            for arr in arrays {
-                let arr = arr.as_any().downcast_ref::<$arrow_type>().unwrap();
-
-                num_rows += arr.len();
-                num_bytes += arr.values().len();
-                need_validity |= arr.validity().map(|v| v.unset_bits() > 0).unwrap_or(false);
-            }
-            let mut offsets = arrow2::offset::Offsets::<i64>::with_capacity(num_rows);
-
-            let mut validity = if need_validity {
-                Some(arrow2::bitmap::MutableBitmap::with_capacity(num_rows))
-            } else {
-                None
-            };
-            let mut buffer = Vec::<u8>::with_capacity(num_bytes);
-
-            for arr in arrays {
-                let arr = arr.as_any().downcast_ref::<$arrow_type>().unwrap();
-                offsets.try_extend_from_slice(arr.offsets(), 0, arr.len())?;
-                if let Some(ref mut bitmap) = validity {
-                    if let Some(b) = arr.validity() {
-                        bitmap.extend_from_bitmap(b);
-                    } else {
-                        bitmap.extend_constant(arr.len(), true);
-                    }
-                }
-                let range = (*arr.offsets().first() as usize)..(*arr.offsets().last() as usize);
-                buffer.extend_from_slice(&arr.values().as_slice()[range]);
-            }
-            let dtype = arrays.first().unwrap().data_type().clone();
-            #[allow(unused_unsafe)]
-            let result_array = unsafe {
-                <$arrow_type>::$create_fn(
-                    dtype,
-                    offsets.into(),
-                    buffer.into(),
-                    validity.map(|v| v.into()),
-                )
-            }?;
-            Ok(Box::new(result_array))
-        }
-    };
-}
-impl_variable_length_concat!(
-    utf8_concat,
-    arrow2::array::Utf8Array<i64>,
-    try_new_unchecked
-);
-impl_variable_length_concat!(binary_concat, arrow2::array::BinaryArray<i64>, try_new);
-
 impl<T> DataArray<T>
 where
     T: DaftPhysicalType,
+    DataArray<T>: GrowableArray,
 {
+    /// Concatenates `arrays` into a new array by copying them into the [`Growable`] for their type, so that
+    /// Python and extension arrays are concatenated the same way as arrow-backed ones. All arrays must have the
+    /// same dtype. The result takes the name of the first array, or is empty and unnamed if `arrays` is empty.
     pub fn concat(arrays: &[&Self]) -> DaftResult<Self> {
-        if arrays.is_empty() {
-            return Err(DaftError::ValueError(
-                "Need at least 1 array to perform concat".to_string(),
-            ));
-        }
+        let Some(first) = arrays.first() else {
+            return Ok(Self::empty("", &T::get_dtype()));
+        };
 
         if arrays.len() == 1 {
-            return Ok((*arrays.first().unwrap()).clone());
+            return Ok((*first).clone());
         }
 
-        let field = &arrays.first().unwrap().field;
-
-        let arrow_arrays: Vec<_> = arrays.iter().map(|s| s.data.as_ref()).collect();
-        match field.dtype {
-            #[cfg(feature = "python")]
-            crate::datatypes::DataType::Python => {
-                use pyo3::prelude::*;
+        if let Some(mismatched) = arrays
+            .iter()
+            .find(|arr| arr.data_type() != first.data_type())
+        {
+            return Err(DaftError::TypeError(format!(
+                "Array concat requires all data types to match, {} vs {}",
+                first.data_type(),
+                mismatched.data_type()
+            )));
+        }
 
-                let cat_array = Box::new(PseudoArrowArray::concatenate(
-                    arrow_arrays
-                        .iter()
-                        .map(|s| {
-                            s.as_any()
-                                .downcast_ref::<PseudoArrowArray<PyObject>>()
-                                .unwrap()
-                        })
-                        .collect(),
-                ));
-                DataArray::new(field.clone(), cat_array)
-            }
-            crate::DataType::Utf8 => {
-                let cat_array = utf8_concat(arrow_arrays.as_slice())?;
-                DataArray::new(field.clone(), cat_array)
-            }
-            crate::DataType::Binary => {
-                let cat_array = binary_concat(arrow_arrays.as_slice())?;
-                DataArray::new(field.clone(), cat_array)
-            }
-            _ => {
-                let cat_array: Box<dyn Array> =
-                    arrow2::compute::concatenate::concatenate(arrow_arrays.as_slice())?;
-                DataArray::try_from((field.clone(), cat_array))
-            }
+        let mut growable = <Self as GrowableArray>::make_growable(
+            first.name(),
+            first.data_type(),
+            arrays.to_vec(),
+            arrays.iter().any(|arr| arr.data.null_count() > 0),
+            arrays.iter().map(|arr| arr.len()).sum(),
+        );
+        for (i, arr) in arrays.iter().enumerate() {
+            growable.extend(i, 0, arr.len());
         }
+        growable.build()?.downcast::<Self>().cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{DataType, Int64Array, Utf8Array},
+    };
+
+    #[test]
+    fn concat_primitive_arrays() -> DaftResult<()> {
+        let a = Int64Array::from_iter("a", vec![Some(1), None].into_iter());
+        let b = Int64Array::from(("b", Vec::<i64>::new()));
+        let c = Int64Array::from(("c", vec![3, 4]));
+
+        let result = Int64Array::concat(&[&a, &b, &c])?;
+        assert_eq!(result.name(), "a");
+        assert_eq!(
+            result
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            [Some(1), None, Some(3), Some(4)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn concat_empty_input() -> DaftResult<()> {
+        let result = Utf8Array::concat(&[])?;
+        assert!(result.is_empty());
+        assert_eq!(*result.data_type(), DataType::Utf8);
+        Ok(())
     }
 }
@@ -0,0 +1,106 @@
+use std::{collections::HashSet, hash::Hash, iter::repeat, sync::Arc};
+
+use arrow2::{self, types::NativeType};
+
+use crate::{array::DataArray, datatypes::*};
+
+use common_error::DaftResult;
+
+use super::{as_arrow::AsArrow, DaftCountDistinctAggable, GroupIndices};
+
+/// Number of distinct keys in `keys`
+fn count_distinct<K: Hash + Eq>(keys: impl Iterator<Item = K>) -> u64 {
+    keys.collect::<HashSet<_>>().len() as u64
+}
+
+/// Number of distinct non-null values of each group, where `get` returns the key of the value at an index
+fn grouped_count_distinct<K: Hash + Eq>(
+    groups: &GroupIndices,
+    get: impl Fn(usize) -> Option<K>,
+) -> Vec<u64> {
+    groups
+        .iter()
+        .map(|g| count_distinct(g.iter().filter_map(|i| get(*i as usize))))
+        .collect()
+}
+
+/// Bytes of a native value, zero-padded to a fixed width so they can be hashed without allocating
+pub(super) fn native_key<N: NativeType>(value: &N) -> [u8; 16] {
+    let mut key = [0u8; 16];
+    let bytes = value.to_le_bytes();
+    key[..bytes.as_ref().len()].copy_from_slice(bytes.as_ref());
+    key
+}
+
+fn count_distinct_result(name: &str, count: u64) -> DaftResult<DataArray<UInt64Type>> {
+    let result_arrow_array = Box::new(arrow2::array::PrimitiveArray::from([Some(count)]));
+    DataArray::<UInt64Type>::new(
+        Arc::new(Field::new(name, DataType::UInt64)),
+        result_arrow_array,
+    )
+}
+
+impl<T> DaftCountDistinctAggable for &DataArray<T>
+where
+    T: DaftNumericType,
+{
+    type Output = DaftResult<DataArray<UInt64Type>>;
+
+    fn count_distinct(&self) -> Self::Output {
+        let count = count_distinct(self.as_arrow().iter().flatten().map(native_key));
+        count_distinct_result(self.name(), count)
+    }
+
+    fn grouped_count_distinct(&self, groups: &GroupIndices) -> Self::Output {
+        let arrow_array = self.as_arrow();
+        let counts_per_group =
+            grouped_count_distinct(groups, |i| arrow_array.get(i).as_ref().map(native_key));
+        Ok(DataArray::<UInt64Type>::from((
+            self.name(),
+            counts_per_group,
+        )))
+    }
+}
+
+macro_rules! impl_daft_count_distinct_aggable {
+    ($arr:ident, $key:expr) => {
+        impl DaftCountDistinctAggable for &$arr {
+            type Output = DaftResult<DataArray<UInt64Type>>;
+
+            fn count_distinct(&self) -> Self::Output {
+                let count = count_distinct(self.as_arrow().iter().flatten().map($key));
+                count_distinct_result(self.name(), count)
+            }
+
+            fn grouped_count_distinct(&self, groups: &GroupIndices) -> Self::Output {
+                let arrow_array = self.as_arrow();
+                let counts_per_group =
+                    grouped_count_distinct(groups, |i| arrow_array.get(i).map($key));
+                Ok(DataArray::<UInt64Type>::from((
+                    self.name(),
+                    counts_per_group,
+                )))
+            }
+        }
+    };
+}
+
+impl_daft_count_distinct_aggable!(Utf8Array, str::as_bytes);
+impl_daft_count_distinct_aggable!(BinaryArray, std::convert::identity);
+impl_daft_count_distinct_aggable!(BooleanArray, std::convert::identity);
+
+impl DaftCountDistinctAggable for &NullArray {
+    type Output = DaftResult<DataArray<UInt64Type>>;
+
+    fn count_distinct(&self) -> Self::Output {
+        count_distinct_result(self.name(), 0)
+    }
+
+    fn grouped_count_distinct(&self, groups: &GroupIndices) -> Self::Output {
+        let counts_per_group: Vec<u64> = repeat(0).take(groups.len()).collect();
+        Ok(DataArray::<UInt64Type>::from((
+            self.name(),
+            counts_per_group,
+        )))
+    }
+}
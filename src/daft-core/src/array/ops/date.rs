@@ -6,7 +6,7 @@ use crate::{
     DataType,
 };
 use arrow2::compute::arithmetics::ArraySub;
-use chrono::{NaiveDate, NaiveTime, Timelike};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike, Utc};
 use common_error::{DaftError, DaftResult};
 
 use super::as_arrow::AsArrow;
@@ -161,38 +161,297 @@ impl TimestampArray {
         ))
     }
 
-    pub fn hour(&self) -> DaftResult<UInt32Array> {
+    /// Applies `component` to the local date and time of each timestamp in the timezone of this array, or to the
+    /// naive date and time if it has no timezone.
+    fn local_component<T: arrow2::types::NativeType>(
+        &self,
+        component: impl Fn(NaiveDateTime) -> T,
+    ) -> DaftResult<arrow2::array::PrimitiveArray<T>> {
         let physical = self.physical.as_arrow();
         let DataType::Timestamp(timeunit, tz) = self.data_type() else {
             unreachable!("Timestamp array must have Timestamp datatype")
         };
         let tu = timeunit.to_arrow();
-        let date_arrow = match tz {
-            Some(tz) => match arrow2::temporal_conversions::parse_offset(tz) {
-                Ok(tz) => Ok(arrow2::array::UInt32Array::from_iter(physical.iter().map(
-                    |ts| {
-                        ts.map(|ts| {
-                            arrow2::temporal_conversions::timestamp_to_datetime(*ts, tu, &tz).hour()
+        match tz {
+            Some(tz) => {
+                if let Ok(tz) = arrow2::temporal_conversions::parse_offset(tz) {
+                    Ok(physical
+                        .iter()
+                        .map(|ts| {
+                            ts.map(|ts| {
+                                component(
+                                    arrow2::temporal_conversions::timestamp_to_datetime(
+                                        *ts, tu, &tz,
+                                    )
+                                    .naive_local(),
+                                )
+                            })
                         })
-                    },
-                ))),
-                Err(e) => Err(DaftError::TypeError(format!(
-                    "Cannot parse timezone in Timestamp datatype: {}, error: {}",
-                    tz, e
-                ))),
-            },
-            None => Ok(arrow2::array::UInt32Array::from_iter(physical.iter().map(
-                |ts| {
+                        .collect())
+                } else if let Ok(tz) = arrow2::temporal_conversions::parse_offset_tz(tz) {
+                    Ok(physical
+                        .iter()
+                        .map(|ts| {
+                            ts.map(|ts| {
+                                component(
+                                    arrow2::temporal_conversions::timestamp_to_datetime(
+                                        *ts, tu, &tz,
+                                    )
+                                    .naive_local(),
+                                )
+                            })
+                        })
+                        .collect())
+                } else {
+                    Err(DaftError::TypeError(format!(
+                        "Cannot parse timezone in Timestamp datatype: {}",
+                        tz
+                    )))
+                }
+            }
+            None => Ok(physical
+                .iter()
+                .map(|ts| {
                     ts.map(|ts| {
-                        arrow2::temporal_conversions::timestamp_to_naive_datetime(*ts, tu).hour()
+                        component(arrow2::temporal_conversions::timestamp_to_naive_datetime(
+                            *ts, tu,
+                        ))
                     })
-                },
-            ))),
-        }?;
+                })
+                .collect()),
+        }
+    }
+
+    pub fn year(&self) -> DaftResult<Int32Array> {
+        let year_arr = self.local_component(|dt| dt.year())?;
+        Ok((self.name(), Box::new(year_arr)).into())
+    }
 
-        UInt32Array::new(
-            std::sync::Arc::new(Field::new(self.name(), DataType::UInt32)),
-            Box::new(date_arrow),
+    pub fn month(&self) -> DaftResult<UInt32Array> {
+        let month_arr = self.local_component(|dt| dt.month())?;
+        Ok((self.name(), Box::new(month_arr)).into())
+    }
+
+    pub fn day(&self) -> DaftResult<UInt32Array> {
+        let day_arr = self.local_component(|dt| dt.day())?;
+        Ok((self.name(), Box::new(day_arr)).into())
+    }
+
+    pub fn hour(&self) -> DaftResult<UInt32Array> {
+        let hour_arr = self.local_component(|dt| dt.hour())?;
+        Ok((self.name(), Box::new(hour_arr)).into())
+    }
+
+    pub fn minute(&self) -> DaftResult<UInt32Array> {
+        let minute_arr = self.local_component(|dt| dt.minute())?;
+        Ok((self.name(), Box::new(minute_arr)).into())
+    }
+
+    pub fn second(&self) -> DaftResult<UInt32Array> {
+        let second_arr = self.local_component(|dt| dt.second())?;
+        Ok((self.name(), Box::new(second_arr)).into())
+    }
+
+    /// Rounds each timestamp down to the start of `unit`, which is one of "second", "minute", "hour", "day", "week",
+    /// "month" or "year", in the timezone of this array. Weeks start on Monday, as in ISO 8601. Truncated local times
+    /// which don't exist in the timezone, having been skipped by a daylight saving change, are null.
+    pub fn truncate(&self, unit: &str) -> DaftResult<TimestampArray> {
+        /// Truncates the local time of each timestamp in `tz`, and converts it back to a timestamp
+        fn truncate_in<Tz: TimeZone>(
+            physical: &arrow2::array::PrimitiveArray<i64>,
+            timeunit: &TimeUnit,
+            tz: &Tz,
+            truncate: impl Fn(NaiveDateTime) -> Option<NaiveDateTime>,
+        ) -> arrow2::array::PrimitiveArray<i64> {
+            let tu = timeunit.to_arrow();
+            physical
+                .iter()
+                .map(|ts| {
+                    let ts = arrow2::temporal_conversions::timestamp_to_datetime(*ts?, tu, tz);
+                    let truncated = tz
+                        .from_local_datetime(&truncate(ts.naive_local())?)
+                        .earliest()?
+                        .naive_utc();
+                    match timeunit {
+                        TimeUnit::Seconds => Some(truncated.timestamp()),
+                        TimeUnit::Milliseconds => Some(truncated.timestamp_millis()),
+                        TimeUnit::Microseconds => Some(truncated.timestamp_micros()),
+                        TimeUnit::Nanoseconds => truncated.timestamp_nanos_opt(),
+                    }
+                })
+                .collect()
+        }
+
+        let truncate: fn(NaiveDateTime) -> Option<NaiveDateTime> = match unit {
+            "second" => |dt| dt.with_nanosecond(0),
+            "minute" => |dt| dt.date().and_hms_opt(dt.hour(), dt.minute(), 0),
+            "hour" => |dt| dt.date().and_hms_opt(dt.hour(), 0, 0),
+            "day" => |dt| dt.date().and_hms_opt(0, 0, 0),
+            "week" => |dt| {
+                let days_since_monday = dt.weekday().num_days_from_monday() as i64;
+                (dt.date() - Duration::days(days_since_monday)).and_hms_opt(0, 0, 0)
+            },
+            "month" => |dt| dt.date().with_day(1)?.and_hms_opt(0, 0, 0),
+            "year" => |dt| NaiveDate::from_ymd_opt(dt.year(), 1, 1)?.and_hms_opt(0, 0, 0),
+            _ => {
+                return Err(DaftError::ValueError(format!(
+                    "Cannot truncate timestamps to unit {unit:?}, expected one of second, minute, hour, day, week, month or year"
+                )))
+            }
+        };
+
+        let physical = self.physical.as_arrow();
+        let DataType::Timestamp(timeunit, tz) = self.data_type() else {
+            unreachable!("Timestamp array must have Timestamp datatype")
+        };
+        let truncated = match tz {
+            Some(tz) => {
+                if let Ok(tz) = arrow2::temporal_conversions::parse_offset(tz) {
+                    truncate_in(physical, timeunit, &tz, truncate)
+                } else if let Ok(tz) = arrow2::temporal_conversions::parse_offset_tz(tz) {
+                    truncate_in(physical, timeunit, &tz, truncate)
+                } else {
+                    return Err(DaftError::TypeError(format!(
+                        "Cannot parse timezone in Timestamp datatype: {}",
+                        tz
+                    )));
+                }
+            }
+            None => truncate_in(physical, timeunit, &Utc, truncate),
+        };
+        Ok(TimestampArray::new(
+            self.field.clone(),
+            Int64Array::from((self.name(), Box::new(truncated))),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::{DaftError, DaftResult};
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{logical::TimestampArray, Field, Int64Array, TimeUnit},
+        DataType,
+    };
+
+    fn timestamps(unit: TimeUnit, tz: Option<&str>, values: Vec<Option<i64>>) -> TimestampArray {
+        TimestampArray::new(
+            Field::new("ts", DataType::Timestamp(unit, tz.map(|tz| tz.to_string()))),
+            Int64Array::from_iter("ts", values.into_iter()),
         )
     }
+
+    fn components(ts: &TimestampArray) -> DaftResult<Vec<Option<[i64; 6]>>> {
+        let (year, month, day) = (ts.year()?, ts.month()?, ts.day()?);
+        let (hour, minute, second) = (ts.hour()?, ts.minute()?, ts.second()?);
+        Ok((0..ts.len())
+            .map(|i| {
+                Some([
+                    year.as_arrow().get(i)? as i64,
+                    month.as_arrow().get(i)? as i64,
+                    day.as_arrow().get(i)? as i64,
+                    hour.as_arrow().get(i)? as i64,
+                    minute.as_arrow().get(i)? as i64,
+                    second.as_arrow().get(i)? as i64,
+                ])
+            })
+            .collect())
+    }
+
+    #[test]
+    fn timestamp_components() -> DaftResult<()> {
+        // 1999-12-31T23:59:59, null, 2000-02-29T12:30:05
+        let naive = timestamps(
+            TimeUnit::Seconds,
+            None,
+            vec![Some(946684799), None, Some(951827405)],
+        );
+        assert_eq!(naive.year()?.name(), "ts");
+        assert_eq!(
+            components(&naive)?,
+            vec![
+                Some([1999, 12, 31, 23, 59, 59]),
+                None,
+                Some([2000, 2, 29, 12, 30, 5])
+            ]
+        );
+
+        // The same instants in milliseconds are moved across the year and month boundaries by the timezone
+        let millis = vec![Some(946684799_000), None, Some(951827405_000)];
+        assert_eq!(
+            components(&timestamps(
+                TimeUnit::Milliseconds,
+                Some("+01:00"),
+                millis.clone()
+            ))?,
+            vec![
+                Some([2000, 1, 1, 0, 59, 59]),
+                None,
+                Some([2000, 2, 29, 13, 30, 5])
+            ]
+        );
+        assert_eq!(
+            components(&timestamps(
+                TimeUnit::Milliseconds,
+                Some("America/New_York"),
+                millis
+            ))?,
+            vec![
+                Some([1999, 12, 31, 18, 59, 59]),
+                None,
+                Some([2000, 2, 29, 7, 30, 5])
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn truncate_timestamps() -> DaftResult<()> {
+        // 2023-03-15T13:45:30.250 (a Wednesday), null
+        let ts = timestamps(
+            TimeUnit::Milliseconds,
+            None,
+            vec![Some(1678887930_250), None],
+        );
+        let truncated = |unit| -> DaftResult<Vec<Option<i64>>> {
+            let truncated = ts.truncate(unit)?;
+            assert_eq!(truncated.data_type(), ts.data_type());
+            Ok(truncated
+                .physical
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect())
+        };
+
+        assert_eq!(truncated("second")?, vec![Some(1678887930_000), None]);
+        assert_eq!(truncated("hour")?, vec![Some(1678885200_000), None]);
+        assert_eq!(truncated("day")?, vec![Some(1678838400_000), None]);
+        // Monday 2023-03-13
+        assert_eq!(truncated("week")?, vec![Some(1678665600_000), None]);
+        // 2023-03-01T00:00:00
+        assert_eq!(truncated("month")?, vec![Some(1677628800_000), None]);
+        assert_eq!(truncated("year")?, vec![Some(1672531200_000), None]);
+        assert!(matches!(
+            ts.truncate("fortnight"),
+            Err(DaftError::ValueError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn truncate_timestamps_in_timezone() -> DaftResult<()> {
+        // 2023-03-01T02:00:00Z is still February 28th in New York, so it truncates to 2023-02-01T00:00:00-05:00
+        let ts = timestamps(
+            TimeUnit::Seconds,
+            Some("America/New_York"),
+            vec![Some(1677636000)],
+        );
+        let month = ts.truncate("month")?;
+        assert_eq!(month.physical.as_arrow().value(0), 1675227600);
+        assert_eq!(ts.truncate("day")?.physical.as_arrow().value(0), 1677560400);
+        Ok(())
+    }
 }
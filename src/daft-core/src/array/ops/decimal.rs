@@ -0,0 +1,208 @@
+use std::iter::repeat;
+
+use common_error::{DaftError, DaftResult};
+
+use crate::{
+    array::DataArray,
+    datatypes::{logical::Decimal128Array, DataType, Field},
+};
+
+use super::as_arrow::AsArrow;
+
+/// Largest precision that fits in the 128 bits of a Decimal128
+pub const MAX_DECIMAL128_PRECISION: usize = 38;
+
+fn precision_and_scale(dtype: &DataType) -> (usize, usize) {
+    match dtype {
+        DataType::Decimal128(precision, scale) => (*precision, *scale),
+        dtype => panic!("Wrong dtype for Decimal128Array: {dtype}"),
+    }
+}
+
+impl Decimal128Array {
+    /// Physical values of this array rescaled to `scale`, which must be at least the scale of this array
+    fn rescaled_values(&self, scale: usize, op_name: &str) -> DaftResult<Vec<Option<i128>>> {
+        let (_, self_scale) = precision_and_scale(self.data_type());
+        let factor = 10i128.pow((scale - self_scale) as u32);
+        self.as_arrow()
+            .iter()
+            .map(|v| match v {
+                Some(v) => v.checked_mul(factor).map(Some).ok_or_else(|| {
+                    DaftError::ComputeError(format!(
+                        "Overflow in decimal {op_name}: {v} can not be rescaled to scale {scale}"
+                    ))
+                }),
+                None => Ok(None),
+            })
+            .collect()
+    }
+
+    /// Applies `op` to the physical values of each pair of rows, broadcasting arrays of length 1. When `rescale` is
+    /// set, both sides are first rescaled to the scale of `output_type`, as addition and subtraction need. Results
+    /// that overflow or exceed the precision of `output_type` are an error rather than wrapping.
+    fn checked_binary_op(
+        &self,
+        rhs: &Decimal128Array,
+        output_type: &DataType,
+        rescale: bool,
+        op_name: &str,
+        op: impl Fn(i128, i128) -> Option<i128>,
+    ) -> DaftResult<Decimal128Array> {
+        let (precision, scale) = precision_and_scale(output_type);
+        let (lhs_values, rhs_values) = if rescale {
+            (
+                self.rescaled_values(scale, op_name)?,
+                rhs.rescaled_values(scale, op_name)?,
+            )
+        } else {
+            (
+                self.as_arrow().iter().map(|v| v.copied()).collect(),
+                rhs.as_arrow().iter().map(|v| v.copied()).collect(),
+            )
+        };
+        let len = match (lhs_values.len(), rhs_values.len()) {
+            (a, b) if a == b => a,
+            (a, 1) => a,
+            (1, b) => b,
+            (a, b) => {
+                return Err(DaftError::ValueError(format!(
+                    "Cannot apply operation on arrays of different lengths: {a} vs {b}"
+                )))
+            }
+        };
+        let broadcast = |values: Vec<Option<i128>>| -> Box<dyn Iterator<Item = Option<i128>>> {
+            if values.len() == len {
+                Box::new(values.into_iter())
+            } else {
+                Box::new(repeat(values[0]).take(len))
+            }
+        };
+
+        let max_value = 10i128.pow(precision as u32) - 1;
+        let result = broadcast(lhs_values)
+            .zip(broadcast(rhs_values))
+            .map(|(l, r)| match (l, r) {
+                (Some(l), Some(r)) => match op(l, r) {
+                    Some(v) if v.abs() <= max_value => Ok(Some(v)),
+                    _ => Err(DaftError::ComputeError(format!(
+                        "Overflow in decimal {op_name}: result does not fit in {output_type}"
+                    ))),
+                },
+                _ => Ok(None),
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let physical = Box::new(arrow2::array::PrimitiveArray::<i128>::from(result));
+        Ok(Decimal128Array::new(
+            Field::new(self.name(), output_type.clone()),
+            DataArray::from((self.name(), physical)),
+        ))
+    }
+
+    pub fn add(&self, rhs: &Decimal128Array, output_type: &DataType) -> DaftResult<Self> {
+        self.checked_binary_op(rhs, output_type, true, "addition", i128::checked_add)
+    }
+
+    pub fn sub(&self, rhs: &Decimal128Array, output_type: &DataType) -> DaftResult<Self> {
+        self.checked_binary_op(rhs, output_type, true, "subtraction", i128::checked_sub)
+    }
+
+    /// Multiplies the decimals of each row, where the scale of `output_type` must be the sum of the input scales
+    pub fn mul(&self, rhs: &Decimal128Array, output_type: &DataType) -> DaftResult<Self> {
+        self.checked_binary_op(rhs, output_type, false, "multiplication", i128::checked_mul)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::{DaftError, DaftResult};
+
+    use crate::{
+        array::ops::{as_arrow::AsArrow, DaftCompare},
+        datatypes::{DataType, Field},
+        series::Series,
+    };
+
+    fn decimals(name: &str, values: Vec<Option<i128>>, precision: usize, scale: usize) -> Series {
+        let arrow_array = arrow2::array::PrimitiveArray::<i128>::from(values)
+            .to(arrow2::datatypes::DataType::Decimal(precision, scale));
+        Series::from_arrow(
+            Arc::new(Field::new(name, DataType::Decimal128(precision, scale))),
+            Box::new(arrow_array),
+        )
+        .unwrap()
+    }
+
+    fn physical_values(series: &Series) -> DaftResult<Vec<Option<i128>>> {
+        Ok(series
+            .decimal128()?
+            .physical
+            .as_arrow()
+            .iter()
+            .map(|v| v.copied())
+            .collect())
+    }
+
+    #[test]
+    fn test_decimal_from_arrow() -> DaftResult<()> {
+        let series = decimals("d", vec![Some(12345), None, Some(-5)], 7, 2);
+        assert_eq!(*series.data_type(), DataType::Decimal128(7, 2));
+        assert_eq!(physical_values(&series)?, vec![Some(12345), None, Some(-5)]);
+        assert_eq!(series.str_value(0)?, "123.45");
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal_add_reconciles_scale() -> DaftResult<()> {
+        // 1.50 + 2.125, 0.01 + null, -3.00 + 0.001
+        let lhs = decimals("lhs", vec![Some(150), Some(1), Some(-300)], 5, 2);
+        let rhs = decimals("rhs", vec![Some(2125), None, Some(1)], 6, 3);
+
+        let sum = (&lhs + &rhs)?;
+        assert_eq!(sum.name(), "lhs");
+        assert_eq!(*sum.data_type(), DataType::Decimal128(7, 3));
+        assert_eq!(physical_values(&sum)?, vec![Some(3625), None, Some(-2999)]);
+        assert_eq!(sum.str_value(0)?, "3.625");
+
+        let difference = (&lhs - &rhs)?;
+        assert_eq!(*difference.data_type(), DataType::Decimal128(7, 3));
+        assert_eq!(
+            physical_values(&difference)?,
+            vec![Some(-625), None, Some(-3001)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal_mul_and_compare() -> DaftResult<()> {
+        let lhs = decimals("lhs", vec![Some(150), Some(-200)], 5, 2);
+        let rhs = decimals("rhs", vec![Some(15), Some(-20)], 3, 1);
+
+        // 1.50 * 1.5 = 2.250, -2.00 * -2.0 = 4.000
+        let product = (&lhs * &rhs)?;
+        assert_eq!(*product.data_type(), DataType::Decimal128(8, 3));
+        assert_eq!(physical_values(&product)?, vec![Some(2250), Some(4000)]);
+
+        // Comparing across scales compares the values, not the physical integers
+        assert_eq!(
+            lhs.equal(&rhs)?.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some(true), Some(true)]
+        );
+        assert_eq!(
+            lhs.lt(&decimals("rhs", vec![Some(16), Some(-21)], 3, 1))?
+                .as_arrow()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(true), Some(false)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_decimal_overflow_errors() {
+        let max = decimals("max", vec![Some(10i128.pow(38) - 1)], 38, 0);
+        assert!(matches!(&max + &max, Err(DaftError::ComputeError(_))));
+        assert!(matches!(&max * &max, Err(DaftError::ComputeError(_))));
+    }
+}
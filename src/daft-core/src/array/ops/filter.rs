@@ -9,7 +9,7 @@ use crate::{
     DataType,
 };
 use arrow2::bitmap::utils::SlicesIterator;
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 
 use super::{as_arrow::AsArrow, full::FullNull};
 
@@ -18,6 +18,7 @@ where
     T: DaftArrowBackedType,
 {
     pub fn filter(&self, mask: &BooleanArray) -> DaftResult<Self> {
+        check_mask_length(mask, self.len())?;
         let result = arrow2::compute::filter::filter(self.data(), mask.as_arrow())?;
         Self::try_from((self.field.clone(), result))
     }
@@ -26,57 +27,24 @@ where
 #[cfg(feature = "python")]
 impl crate::datatypes::PythonArray {
     pub fn filter(&self, mask: &BooleanArray) -> DaftResult<Self> {
-        use arrow2::array::Array;
-        use pyo3::PyObject;
-
-        use crate::array::pseudo_arrow::PseudoArrowArray;
-        use crate::datatypes::PythonType;
-
-        let mask = mask.as_arrow();
-
-        // Apply the filter mask to the data values, regardless of validity.
-        let new_values = {
-            mask.iter()
-                .map(|x| x.unwrap_or(false))
-                .zip(self.as_arrow().values().iter())
-                .filter_map(|(f, item)| if f { Some(item.clone()) } else { None })
-                .collect::<Vec<PyObject>>()
-        };
-
-        // Apply the filter mask to the validity bitmap.
-        let new_validity = {
-            self.as_arrow()
-                .validity()
-                .map(|old_validity| {
-                    let old_validity_array = {
-                        &arrow2::array::BooleanArray::new(
-                            arrow2::datatypes::DataType::Boolean,
-                            old_validity.clone(),
-                            None,
-                        )
-                    };
-                    arrow2::compute::filter::filter(old_validity_array, mask)
-                })
-                .transpose()?
-                .map(|new_validity_dynarray| {
-                    let new_validity_iter = new_validity_dynarray
-                        .as_any()
-                        .downcast_ref::<arrow2::array::BooleanArray>()
-                        .unwrap()
-                        .iter();
-                    arrow2::bitmap::Bitmap::from_iter(
-                        new_validity_iter.map(|valid| valid.unwrap_or(false)),
-                    )
-                })
-        };
-
-        let arrow_array: Box<dyn arrow2::array::Array> =
-            Box::new(PseudoArrowArray::new(new_values.into(), new_validity));
-
-        DataArray::<PythonType>::new(self.field().clone().into(), arrow_array)
+        check_mask_length(mask, self.len())?;
+        generic_filter(self, mask, self.name(), self.data_type())
+    }
+}
+
+/// Checks that `mask` has a value for each of the `len` rows being filtered.
+fn check_mask_length(mask: &BooleanArray, len: usize) -> DaftResult<()> {
+    if mask.len() != len {
+        return Err(DaftError::ValueError(format!(
+            "Lengths for filter do not match, array {len} vs mask {}",
+            mask.len()
+        )));
     }
+    Ok(())
 }
 
+/// Keeps the rows of `arr` where `mask` is true by copying each run of kept rows into a [`Growable`].
+/// Null mask values drop their row.
 fn generic_filter<Arr>(
     arr: &Arr,
     mask: &BooleanArray,
@@ -111,18 +79,69 @@ where
 
 impl ListArray {
     pub fn filter(&self, mask: &BooleanArray) -> DaftResult<Self> {
+        check_mask_length(mask, self.len())?;
         generic_filter(self, mask, self.name(), self.data_type())
     }
 }
 
 impl FixedSizeListArray {
     pub fn filter(&self, mask: &BooleanArray) -> DaftResult<Self> {
+        check_mask_length(mask, self.len())?;
         generic_filter(self, mask, self.name(), self.data_type())
     }
 }
 
 impl StructArray {
     pub fn filter(&self, mask: &BooleanArray) -> DaftResult<Self> {
+        check_mask_length(mask, self.len())?;
         generic_filter(self, mask, self.name(), self.data_type())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::{DaftError, DaftResult};
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{BooleanArray, Int64Array, Utf8Array},
+    };
+
+    fn mask(values: Vec<Option<bool>>) -> BooleanArray {
+        BooleanArray::from(("mask", values.as_slice()))
+    }
+
+    #[test]
+    fn filter_with_null_mask_values() -> DaftResult<()> {
+        let ints = Int64Array::from_iter("i", vec![Some(1), None, Some(3), Some(4)].into_iter());
+        let filtered = ints.filter(&mask(vec![Some(true), Some(true), None, Some(false)]))?;
+        assert_eq!(filtered.name(), "i");
+        assert_eq!(
+            filtered
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            [Some(1), None]
+        );
+
+        let strings = Utf8Array::from(("s", ["a", "b", "c"].as_slice()));
+        let filtered = strings.filter(&mask(vec![None, Some(true), Some(true)]))?;
+        assert_eq!(
+            filtered.as_arrow().iter().collect::<Vec<_>>(),
+            [Some("b"), Some("c")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn filter_all_false_and_mismatched_length() -> DaftResult<()> {
+        let ints = Int64Array::from(("i", vec![1, 2]));
+        assert!(ints.filter(&mask(vec![Some(false), None]))?.is_empty());
+        assert!(matches!(
+            ints.filter(&mask(vec![Some(true)])),
+            Err(DaftError::ValueError(_))
+        ));
+        Ok(())
+    }
+}
@@ -3,9 +3,14 @@ use std::sync::Arc;
 use common_error::{DaftError, DaftResult};
 
 use crate::{
-    array::{DataArray, FixedSizeListArray, ListArray, StructArray},
+    array::{
+        growable::{Growable, GrowableArray},
+        ops::full::FullNull,
+        DataArray, FixedSizeListArray, ListArray, StructArray,
+    },
     datatypes::{
-        logical::LogicalArray, DaftDataType, DaftLogicalType, DaftPhysicalType, Field, FieldRef,
+        logical::LogicalArray, pack_days_ms, DaftArrayType, DaftDataType, DaftLogicalType,
+        DaftPhysicalType, Field, FieldRef, IntervalUnit,
     },
     DataType, Series,
 };
@@ -16,10 +21,56 @@ where
     Self: Sized,
 {
     fn from_arrow(field: FieldRef, arrow_arr: Box<dyn arrow2::array::Array>) -> DaftResult<Self>;
+
+    /// Instantiates a single array from several chunks of arrow arrays (e.g. as read from arrow IPC), concatenating
+    /// them through a [`Growable`](crate::array::growable::Growable) so that types without an arrow2 equivalent such
+    /// as Python objects are supported as well. No chunks yields an empty array.
+    fn from_arrow_chunks(
+        field: FieldRef,
+        chunks: Vec<Box<dyn arrow2::array::Array>>,
+    ) -> DaftResult<Self>
+    where
+        Self: GrowableArray + FullNull + DaftArrayType + Clone + 'static,
+    {
+        let chunk_lens = chunks.iter().map(|chunk| chunk.len()).collect::<Vec<_>>();
+        let mut arrays = chunks
+            .into_iter()
+            .map(|chunk| Self::from_arrow(field.clone(), chunk))
+            .collect::<DaftResult<Vec<_>>>()?;
+        match arrays.len() {
+            0 => Ok(Self::empty(&field.name, &field.dtype)),
+            1 => Ok(arrays.remove(0)),
+            _ => {
+                let mut growable = Self::make_growable(
+                    &field.name,
+                    &field.dtype,
+                    arrays.iter().collect(),
+                    false,
+                    chunk_lens.iter().sum(),
+                );
+                for (i, len) in chunk_lens.into_iter().enumerate() {
+                    growable.extend(i, 0, len);
+                }
+                Ok(growable.build()?.downcast::<Self>()?.clone())
+            }
+        }
+    }
 }
 
 impl<T: DaftPhysicalType> FromArrow for DataArray<T> {
     fn from_arrow(field: FieldRef, arrow_arr: Box<dyn arrow2::array::Array>) -> DaftResult<Self> {
+        // Check the arrow datatype here so that mismatches surface as an error at the boundary, rather than as a
+        // panic when constructing the DataArray. Types without an arrow equivalent (e.g. Python) aren't checked.
+        if let Ok(expected_arrow_dtype) = field.dtype.to_physical().to_arrow()
+            && &expected_arrow_dtype != arrow_arr.data_type()
+        {
+            return Err(DaftError::TypeError(format!(
+                "Attempting to create Daft DataArray with type {} (arrow type {:?}) from arrow array with type {:?}",
+                field.dtype,
+                expected_arrow_dtype,
+                arrow_arr.data_type()
+            )));
+        }
         DataArray::<T>::try_from((field.clone(), arrow_arr))
     }
 }
@@ -35,6 +86,7 @@ where
             // Currently, .to_type does not work for Map in Arrow2 because it requires physical types to be equivalent,
             // but the physical type of MapArray in Arrow2 is a MapArray, not a ListArray
             DataType::Map(..) => arrow_arr,
+            DataType::Interval(unit) => interval_to_physical(&field, unit, arrow_arr)?,
             _ => arrow_arr.to_type(data_array_field.dtype.to_arrow()?),
         };
         let physical = <L::PhysicalType as DaftDataType>::ArrayType::from_arrow(
@@ -45,6 +97,41 @@ where
     }
 }
 
+/// Converts an arrow2 interval array into the Int64 array that physically backs a Daft interval, since arrow2
+/// represents day-time intervals as pairs of i32s.
+fn interval_to_physical(
+    field: &Field,
+    unit: &IntervalUnit,
+    arrow_arr: Box<dyn arrow2::array::Array>,
+) -> DaftResult<Box<dyn arrow2::array::Array>> {
+    use arrow2::{array::PrimitiveArray, compute::arity::unary, datatypes::DataType as ArrowType};
+
+    if arrow_arr.data_type() != &ArrowType::Interval(unit.to_arrow()) {
+        return Err(DaftError::TypeError(format!(
+            "Attempting to create Daft IntervalArray with type {} from arrow array with type {:?}",
+            field.dtype,
+            arrow_arr.data_type()
+        )));
+    }
+    let physical = match unit {
+        IntervalUnit::YearMonth => {
+            let arrow_arr = arrow_arr
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i32>>()
+                .unwrap();
+            unary(arrow_arr, |v| v as i64, ArrowType::Int64)
+        }
+        IntervalUnit::DayTime => {
+            let arrow_arr = arrow_arr
+                .as_any()
+                .downcast_ref::<PrimitiveArray<arrow2::types::days_ms>>()
+                .unwrap();
+            unary(arrow_arr, pack_days_ms, ArrowType::Int64)
+        }
+    };
+    Ok(physical.boxed())
+}
+
 impl FromArrow for FixedSizeListArray {
     fn from_arrow(field: FieldRef, arrow_arr: Box<dyn arrow2::array::Array>) -> DaftResult<Self> {
         match (&field.dtype, arrow_arr.data_type()) {
@@ -147,3 +234,181 @@ impl FromArrow for StructArray {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow2::{array::Int64Array, bitmap::Bitmap, offset::OffsetsBuffer};
+    use common_error::{DaftError, DaftResult};
+
+    use crate::{
+        array::{ops::as_arrow::AsArrow, FixedSizeListArray, ListArray},
+        datatypes::{Field, IntervalUnit},
+        DataType, Series,
+    };
+
+    use super::FromArrow;
+
+    fn arrow_child_field() -> Box<arrow2::datatypes::Field> {
+        Box::new(arrow2::datatypes::Field::new(
+            "item",
+            arrow2::datatypes::DataType::Int64,
+            true,
+        ))
+    }
+
+    #[test]
+    fn test_from_arrow_mismatched_dtype() {
+        let field = Arc::new(Field::new("foo", DataType::Utf8));
+        let arrow_arr = Int64Array::from(vec![Some(0), None]).boxed();
+        let err = crate::datatypes::Utf8Array::from_arrow(field, arrow_arr).unwrap_err();
+        assert!(
+            matches!(&err, DaftError::TypeError(msg) if msg.contains("Utf8") && msg.contains("Int64")),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_from_arrow_chunks() -> DaftResult<()> {
+        let field = Arc::new(Field::new("foo", DataType::Int64));
+        let chunks = vec![
+            Int64Array::from(vec![Some(0), None]).boxed(),
+            Int64Array::from(Vec::<Option<i64>>::new()).boxed(),
+            Int64Array::from(vec![Some(3), Some(4), None]).boxed(),
+        ];
+        let arr = crate::datatypes::Int64Array::from_arrow_chunks(field.clone(), chunks)?;
+        assert_eq!(arr.name(), "foo");
+        assert_eq!(
+            arr.into_iter().collect::<Vec<_>>(),
+            vec![Some(&0), None, Some(&3), Some(&4), None]
+        );
+
+        let arr = crate::datatypes::Int64Array::from_arrow_chunks(field.clone(), vec![])?;
+        assert_eq!(arr.data_type(), &DataType::Int64);
+        assert!(arr.is_empty());
+
+        // Nested types are concatenated through their growables too
+        let list_field = Arc::new(Field::new("foo", DataType::List(Box::new(DataType::Int64))));
+        let list_chunk =
+            |values: Vec<i64>, offsets: Vec<i64>| -> DaftResult<Box<dyn arrow2::array::Array>> {
+                Ok(arrow2::array::ListArray::<i64>::new(
+                    list_field.dtype.to_arrow()?,
+                    OffsetsBuffer::try_from(offsets)?,
+                    Int64Array::from_vec(values).boxed(),
+                    None,
+                )
+                .boxed())
+            };
+        let arr = ListArray::from_arrow_chunks(
+            list_field.clone(),
+            vec![
+                list_chunk(vec![0, 1], vec![0, 2])?,
+                list_chunk(vec![], vec![0, 0])?,
+                list_chunk(vec![2], vec![0, 1])?,
+            ],
+        )?;
+        assert_eq!(arr.offsets().as_slice(), &[0, 2, 2, 3]);
+        assert_eq!(
+            arr.flat_child.i64()?.into_iter().collect::<Vec<_>>(),
+            vec![Some(&0), Some(&1), Some(&2)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_from_arrow() -> DaftResult<()> {
+        // [[0, 1], [], None, [2, None, 4]]
+        let values = Int64Array::from(vec![Some(0), Some(1), Some(2), None, Some(4)]);
+        let offsets = OffsetsBuffer::try_from(vec![0i64, 2, 2, 2, 5])?;
+        let validity = Bitmap::from(&[true, true, false, true]);
+        let arrow_arr = arrow2::array::ListArray::<i64>::new(
+            arrow2::datatypes::DataType::LargeList(arrow_child_field()),
+            offsets.clone(),
+            values.clone().boxed(),
+            Some(validity.clone()),
+        );
+
+        let field = Arc::new(Field::new("foo", DataType::List(Box::new(DataType::Int64))));
+        let arr = ListArray::from_arrow(field, arrow_arr.boxed())?;
+        assert_eq!(arr.name(), "foo");
+        assert_eq!(arr.offsets(), &offsets);
+        assert_eq!(arr.validity(), Some(&validity));
+        assert_eq!(arr.flat_child.data_type(), &DataType::Int64);
+        assert_eq!(arr.flat_child.i64()?.as_arrow(), &values);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed_size_list_from_arrow() -> DaftResult<()> {
+        // [[0, 1], None, [None, 3]]
+        let values = Int64Array::from(vec![Some(0), Some(1), Some(0), Some(0), None, Some(3)]);
+        let validity = Bitmap::from(&[true, false, true]);
+        let arrow_arr = arrow2::array::FixedSizeListArray::new(
+            arrow2::datatypes::DataType::FixedSizeList(arrow_child_field(), 2),
+            values.clone().boxed(),
+            Some(validity.clone()),
+        );
+
+        let field = Arc::new(Field::new(
+            "foo",
+            DataType::FixedSizeList(Box::new(DataType::Int64), 2),
+        ));
+        let arr = FixedSizeListArray::from_arrow(field.clone(), arrow_arr.clone().boxed())?;
+        assert_eq!(arr.name(), "foo");
+        assert_eq!(arr.len(), 3);
+        assert_eq!(arr.validity(), Some(&validity));
+        assert_eq!(arr.flat_child.i64()?.as_arrow(), &values);
+
+        // Mismatched element lengths are rejected rather than reinterpreting the child values
+        let mismatched = Arc::new(Field::new(
+            "foo",
+            DataType::FixedSizeList(Box::new(DataType::Int64), 3),
+        ));
+        assert!(FixedSizeListArray::from_arrow(mismatched, arrow_arr.boxed()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_interval_round_trip() -> DaftResult<()> {
+        // 14 months, None, -1 month
+        let year_month = arrow2::array::PrimitiveArray::<i32>::from(vec![Some(14), None, Some(-1)])
+            .to(arrow2::datatypes::DataType::Interval(
+                arrow2::datatypes::IntervalUnit::YearMonth,
+            ));
+        let field = Arc::new(Field::new(
+            "foo",
+            DataType::Interval(IntervalUnit::YearMonth),
+        ));
+        let series = Series::from_arrow(field, year_month.clone().boxed())?;
+        assert_eq!(
+            *series.data_type(),
+            DataType::Interval(IntervalUnit::YearMonth)
+        );
+        assert_eq!(series.str_value(0)?, "14 months");
+        assert_eq!(series.str_value(1)?, "None");
+        assert_eq!(
+            series.to_arrow().as_ref(),
+            &year_month as &dyn arrow2::array::Array
+        );
+
+        // 3 days 500 ms, -2 days -1 ms, None
+        let day_time = arrow2::array::PrimitiveArray::<arrow2::types::days_ms>::from(vec![
+            Some(arrow2::types::days_ms::new(3, 500)),
+            Some(arrow2::types::days_ms::new(-2, -1)),
+            None,
+        ]);
+        let field = Arc::new(Field::new("foo", DataType::Interval(IntervalUnit::DayTime)));
+        let series = Series::from_arrow(field.clone(), day_time.clone().boxed())?;
+        assert_eq!(series.str_value(0)?, "3 days 500 ms");
+        assert_eq!(series.str_value(1)?, "-2 days -1 ms");
+        assert_eq!(
+            series.to_arrow().as_ref(),
+            &day_time as &dyn arrow2::array::Array
+        );
+
+        // Physically the values must be of the interval's own unit
+        assert!(Series::from_arrow(field, year_month.boxed()).is_err());
+        Ok(())
+    }
+}
@@ -2,8 +2,8 @@ use crate::{
     array::{DataArray, FixedSizeListArray, ListArray},
     datatypes::{
         logical::{
-            DateArray, Decimal128Array, DurationArray, LogicalArrayImpl, MapArray, TimeArray,
-            TimestampArray,
+            DateArray, Decimal128Array, DurationArray, IntervalArray, LogicalArrayImpl, MapArray,
+            TimeArray, TimestampArray,
         },
         BinaryArray, BooleanArray, DaftLogicalType, DaftNumericType, ExtensionArray, NullArray,
         Utf8Array,
@@ -71,6 +71,7 @@ impl_array_arrow_get!(Decimal128Array, i128);
 impl_array_arrow_get!(DateArray, i32);
 impl_array_arrow_get!(TimeArray, i64);
 impl_array_arrow_get!(DurationArray, i64);
+impl_array_arrow_get!(IntervalArray, i64);
 impl_array_arrow_get!(TimestampArray, i64);
 
 impl NullArray {
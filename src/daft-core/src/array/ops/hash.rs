@@ -218,3 +218,134 @@ fn murmur3_32_hash_from_iter_no_nulls<B: AsRef<[u8]>>(
         .collect::<Vec<_>>();
     Ok(Int32Array::from((name, hashes)))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use common_error::DaftResult;
+    use xxhash_rust::xxh3::{xxh3_64, xxh3_64_with_seed};
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{BinaryArray, Int64Array, UInt64Array, Utf8Array},
+        kernels::hashing::{NULL_HASH, NULL_MARKER},
+    };
+
+    fn hashes(array: &UInt64Array) -> Vec<u64> {
+        array.as_arrow().values_iter().copied().collect()
+    }
+
+    #[test]
+    fn hash_is_stable() -> DaftResult<()> {
+        let ints = Int64Array::from_iter("a", vec![Some(42), None, Some(-1)].into_iter());
+        let hashed = ints.hash(None)?;
+        assert_eq!(hashed.name(), "a");
+        assert_eq!(hashed.data().null_count(), 0);
+        assert_eq!(
+            hashes(&hashed),
+            [
+                xxh3_64(&42i64.to_le_bytes()),
+                NULL_HASH,
+                xxh3_64(&(-1i64).to_le_bytes())
+            ]
+        );
+        // Hashing again gives the same values
+        assert_eq!(hashes(&ints.hash(None)?), hashes(&hashed));
+
+        // Nulls hash to the sentinel whatever the physical array holds under them
+        let strings = Utf8Array::from((
+            "s",
+            Box::new(
+                arrow2::array::Utf8Array::<i64>::from_slice(["daft", "hidden"])
+                    .with_validity(Some(arrow2::bitmap::Bitmap::from([true, false]))),
+            ),
+        ));
+        assert_eq!(hashes(&strings.hash(None)?), [xxh3_64(b"daft"), NULL_HASH]);
+
+        let seed = UInt64Array::from(("seed", vec![7, 7, 7]));
+        assert_eq!(
+            hashes(&ints.hash(Some(&seed))?),
+            [
+                xxh3_64_with_seed(&42i64.to_le_bytes(), 7),
+                xxh3_64_with_seed(NULL_MARKER, 7),
+                xxh3_64_with_seed(&(-1i64).to_le_bytes(), 7)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn hash_null_differs_from_empty() -> DaftResult<()> {
+        let strings = Utf8Array::from((
+            "s",
+            Box::new(arrow2::array::Utf8Array::<i64>::from([Some(""), None])),
+        ));
+        let binary = BinaryArray::from((
+            "b",
+            Box::new(arrow2::array::BinaryArray::<i64>::from([
+                Some(b"".as_slice()),
+                None,
+            ])),
+        ));
+        let seed = UInt64Array::from(("seed", vec![7, 7]));
+        for (hashed, null_hash) in [
+            (strings.hash(None)?, NULL_HASH),
+            (
+                strings.hash(Some(&seed))?,
+                xxh3_64_with_seed(NULL_MARKER, 7),
+            ),
+            (binary.hash(None)?, NULL_HASH),
+            (binary.hash(Some(&seed))?, xxh3_64_with_seed(NULL_MARKER, 7)),
+        ] {
+            let hashed = hashes(&hashed);
+            assert_eq!(hashed[1], null_hash);
+            assert_ne!(hashed[0], hashed[1]);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn hash_null_mixes_in_seed() -> DaftResult<()> {
+        // Chaining a null column onto the hashes of distinct rows keeps them distinct
+        let nulls = Int64Array::from_iter("a", vec![None; 3].into_iter());
+        let seed = UInt64Array::from(("seed", vec![1, 2, 3]));
+        let hashed = hashes(&nulls.hash(Some(&seed))?);
+        assert_eq!(hashed.iter().collect::<HashSet<_>>().len(), 3);
+        assert!(!hashed.contains(&NULL_HASH));
+        Ok(())
+    }
+
+    #[test]
+    fn hash_distinct_values_rarely_collide() -> DaftResult<()> {
+        let ints = Int64Array::from(("a", (0..10_000).collect::<Vec<i64>>()));
+        assert_eq!(
+            hashes(&ints.hash(None)?)
+                .into_iter()
+                .collect::<HashSet<_>>()
+                .len(),
+            10_000
+        );
+
+        let values = (0..10_000)
+            .map(|i| format!("value-{i}"))
+            .collect::<Vec<_>>();
+        let strings = Utf8Array::from(("s", values.as_slice()));
+        assert_eq!(
+            hashes(&strings.hash(None)?)
+                .into_iter()
+                .collect::<HashSet<_>>()
+                .len(),
+            10_000
+        );
+
+        let binary = BinaryArray::from((
+            "b",
+            Box::new(arrow2::array::BinaryArray::<i64>::from_iter_values(
+                values.iter().map(|v| v.as_bytes()),
+            )),
+        ));
+        assert_eq!(hashes(&binary.hash(None)?), hashes(&strings.hash(None)?));
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use std::fmt::Write;
+
+use common_error::{DaftError, DaftResult};
+
+use crate::datatypes::{BinaryArray, Utf8Array};
+
+use super::as_arrow::AsArrow;
+
+/// Bytes encoded by the hex string `val`, or None if it has an odd length or a character that isn't a hex digit
+fn decode_hex(val: &str) -> Option<Vec<u8>> {
+    if val.len() % 2 != 0 {
+        return None;
+    }
+    val.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let high = (pair[0] as char).to_digit(16)?;
+            let low = (pair[1] as char).to_digit(16)?;
+            Some((high * 16 + low) as u8)
+        })
+        .collect()
+}
+
+impl BinaryArray {
+    /// Encodes each value as a string of two lowercase hex digits per byte.
+    pub fn to_hex(&self) -> DaftResult<Utf8Array> {
+        let hex_iter = self.as_arrow().iter().map(|val| {
+            val.map(|bytes| {
+                bytes
+                    .iter()
+                    .fold(String::with_capacity(bytes.len() * 2), |mut hex, byte| {
+                        write!(hex, "{byte:02x}").unwrap();
+                        hex
+                    })
+            })
+        });
+        Ok(Utf8Array::from_iter(self.name(), hex_iter))
+    }
+}
+
+impl Utf8Array {
+    /// Decodes each string of hex digits, in either case, into the bytes it encodes. Strings with an odd length or a
+    /// character that isn't a hex digit are null, or an error if `strict` is set.
+    pub fn from_hex(&self, strict: bool) -> DaftResult<BinaryArray> {
+        let bytes = self
+            .as_arrow()
+            .iter()
+            .map(|val| match val {
+                Some(val) => match decode_hex(val) {
+                    Some(bytes) => Ok(Some(bytes)),
+                    None if strict => Err(DaftError::ValueError(format!(
+                        "Error in from_hex: {val:?} is not a valid hex string"
+                    ))),
+                    None => Ok(None),
+                },
+                None => Ok(None),
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        Ok(BinaryArray::from_iter(self.name(), bytes.into_iter()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::{DaftError, DaftResult};
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{BinaryArray, Utf8Array},
+    };
+
+    #[test]
+    fn hex_round_trip() -> DaftResult<()> {
+        let bytes = BinaryArray::from_iter(
+            "b",
+            vec![Some(vec![0x00, 0xab, 0x7f, 0xff]), None, Some(vec![])].into_iter(),
+        );
+        let hex = bytes.to_hex()?;
+        assert_eq!(hex.name(), "b");
+        assert_eq!(
+            hex.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some("00ab7fff"), None, Some("")]
+        );
+        let decoded = hex.from_hex(true)?;
+        assert_eq!(
+            decoded.as_arrow().iter().collect::<Vec<_>>(),
+            bytes.as_arrow().iter().collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn from_hex_invalid() -> DaftResult<()> {
+        let hex = Utf8Array::from(("h", ["ABcd", "abc", "zz"].as_slice()));
+        assert_eq!(
+            hex.from_hex(false)?.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some([0xab, 0xcd].as_slice()), None, None]
+        );
+        assert!(matches!(hex.from_hex(true), Err(DaftError::ValueError(_))));
+        Ok(())
+    }
+}
@@ -4,7 +4,7 @@ use crate::array::{DataArray, FixedSizeListArray, ListArray, StructArray};
 use crate::datatypes::{BooleanArray, DaftPhysicalType};
 use crate::{DataType, IntoSeries, Series};
 use arrow2::array::Array;
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 
 use super::as_arrow::AsArrow;
 
@@ -132,6 +132,38 @@ where
     }
 }
 
+impl BooleanArray {
+    /// Selects the value of `if_true` for each row where this predicate is true and the value of `if_false` where
+    /// it is false, like a SQL `CASE WHEN`. Rows where the predicate is null are null. Unlike
+    /// [`DataArray::if_else`], nothing is broadcast: all three arrays must have the same length.
+    pub fn select<T>(
+        &self,
+        if_true: &DataArray<T>,
+        if_false: &DataArray<T>,
+    ) -> DaftResult<DataArray<T>>
+    where
+        T: DaftPhysicalType,
+        DataArray<T>: GrowableArray + IntoSeries,
+    {
+        if if_true.data_type() != if_false.data_type() {
+            return Err(DaftError::TypeError(format!(
+                "Select requires both branches to have the same data type, {} vs {}",
+                if_true.data_type(),
+                if_false.data_type()
+            )));
+        }
+        if if_true.len() != self.len() || if_false.len() != self.len() {
+            return Err(DaftError::ValueError(format!(
+                "Lengths for select do not match, predicate {} vs if_true {} vs if_false {}",
+                self.len(),
+                if_true.len(),
+                if_false.len()
+            )));
+        }
+        if_true.if_else(if_false, self)
+    }
+}
+
 macro_rules! impl_if_else_nested_array {
     ($arr:ident) => {
         impl<'a> $arr {
@@ -159,3 +191,59 @@ macro_rules! impl_if_else_nested_array {
 impl_if_else_nested_array!(ListArray);
 impl_if_else_nested_array!(FixedSizeListArray);
 impl_if_else_nested_array!(StructArray);
+
+#[cfg(test)]
+mod tests {
+    use common_error::{DaftError, DaftResult};
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{BooleanArray, Int64Array, Utf8Array},
+    };
+
+    #[test]
+    fn select_with_null_predicates() -> DaftResult<()> {
+        let predicate = BooleanArray::from((
+            "p",
+            [Some(true), None, Some(false), Some(true), Some(false)].as_slice(),
+        ));
+        let if_true = Int64Array::from_iter(
+            "t",
+            vec![Some(1), Some(2), Some(3), None, Some(5)].into_iter(),
+        );
+        let if_false = Int64Array::from_iter(
+            "f",
+            vec![Some(-1), Some(-2), None, Some(-4), Some(-5)].into_iter(),
+        );
+        let result = predicate.select(&if_true, &if_false)?;
+        assert_eq!(result.name(), "t");
+        assert_eq!(
+            result
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            [Some(1), None, None, None, Some(-5)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn select_without_null_predicates() -> DaftResult<()> {
+        let predicate = BooleanArray::from(("p", [false, false, true].as_slice()));
+        let if_true = Utf8Array::from(("t", ["a", "b", "c"].as_slice()));
+        let if_false = Utf8Array::from(("f", ["x", "y", "z"].as_slice()));
+        let result = predicate.select(&if_true, &if_false)?;
+        assert_eq!(
+            result.as_arrow().iter().collect::<Vec<_>>(),
+            [Some("x"), Some("y"), Some("c")]
+        );
+
+        let short = Utf8Array::from(("f", ["x"].as_slice()));
+        assert!(matches!(
+            predicate.select(&if_true, &short),
+            Err(DaftError::ValueError(_))
+        ));
+        Ok(())
+    }
+}
@@ -9,9 +9,8 @@ use crate::{
 
 use super::as_arrow::AsArrow;
 use super::{full::FullNull, DaftIsIn};
-use crate::utils::hashable_float_wrapper::FloatWrapper;
 use common_error::DaftResult;
-use std::collections::{BTreeSet, HashSet};
+use std::collections::HashSet;
 
 macro_rules! collect_to_set_and_check_membership {
     ($self:expr, $rhs:expr) => {{
@@ -48,14 +47,24 @@ macro_rules! impl_is_in_floating_array {
             type Output = DaftResult<BooleanArray>;
 
             fn is_in(&self, rhs: &$arr) -> Self::Output {
+                // Floats are looked up by their bits, after mapping -0.0 to 0.0 and all NaNs to one NaN so that
+                // they match the same values as under the float ordering.
+                let canonical_bits = |value: $T| {
+                    if value.is_nan() {
+                        $T::NAN.to_bits()
+                    } else {
+                        (value + 0.0).to_bits()
+                    }
+                };
                 let set = rhs
                     .as_arrow()
                     .iter()
-                    .filter_map(|item| item.map(|value| FloatWrapper(*value)))
-                    .collect::<BTreeSet<FloatWrapper<$T>>>();
-                let result = self.as_arrow().iter().map(|option| {
-                    option.and_then(|value| Some(set.contains(&FloatWrapper(*value))))
-                });
+                    .filter_map(|item| item.map(|value| canonical_bits(*value)))
+                    .collect::<HashSet<_>>();
+                let result = self
+                    .as_arrow()
+                    .iter()
+                    .map(|option| option.map(|value| set.contains(&canonical_bits(*value))));
                 Ok(BooleanArray::from_iter(self.name(), result))
             }
         }
@@ -91,3 +100,62 @@ impl DaftIsIn<&NullArray> for NullArray {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::{as_arrow::AsArrow, DaftIsIn},
+        datatypes::{BooleanArray, Float64Array, Int64Array, Utf8Array},
+    };
+
+    fn values(array: &BooleanArray) -> Vec<Option<bool>> {
+        array.as_arrow().iter().collect()
+    }
+
+    #[test]
+    fn is_in_integers() -> DaftResult<()> {
+        let probe = Int64Array::from_iter("a", vec![Some(1), None, Some(3), Some(4)].into_iter());
+        let set = Int64Array::from_iter("set", vec![Some(4), None, Some(1)].into_iter());
+        let result = probe.is_in(&set)?;
+        assert_eq!(result.name(), "a");
+        assert_eq!(values(&result), [Some(true), None, Some(false), Some(true)]);
+
+        let empty = Int64Array::from(("set", Vec::<i64>::new()));
+        assert_eq!(
+            values(&probe.is_in(&empty)?),
+            [Some(false), None, Some(false), Some(false)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn is_in_strings() -> DaftResult<()> {
+        let probe = Utf8Array::from((
+            "a",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("x"),
+                None,
+                Some("yy"),
+            ])),
+        ));
+        let set = Utf8Array::from(("set", ["yy", "z"].as_slice()));
+        assert_eq!(values(&probe.is_in(&set)?), [Some(false), None, Some(true)]);
+        Ok(())
+    }
+
+    #[test]
+    fn is_in_floats() -> DaftResult<()> {
+        let probe = Float64Array::from_iter(
+            "a",
+            vec![Some(-0.0), Some(f64::NAN), None, Some(1.5), Some(2.0)].into_iter(),
+        );
+        let set = Float64Array::from(("set", vec![0.0, f64::NAN, 1.5]));
+        assert_eq!(
+            values(&probe.is_in(&set)?),
+            [Some(true), Some(true), None, Some(true), Some(false)]
+        );
+        Ok(())
+    }
+}
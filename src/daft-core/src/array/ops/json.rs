@@ -1,9 +1,14 @@
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
-use crate::datatypes::Utf8Array;
+use crate::{
+    array::StructArray,
+    datatypes::{Field, Utf8Array},
+    schema::Schema,
+    DataType,
+};
 use arrow2;
 
-use super::as_arrow::AsArrow;
+use super::{as_arrow::AsArrow, from_arrow::FromArrow};
 use common_error::{DaftError, DaftResult};
 use itertools::Itertools;
 use jaq_interpret::{Ctx, Filter, FilterT, ParseCtx, RcIter};
@@ -45,7 +50,204 @@ fn compile_filter(query: &str) -> DaftResult<Filter> {
     Ok(compiled_filter)
 }
 
+/// Daft type of a JSON value, where integers that fit in an i64 are Int64 and other numbers Float64
+fn infer_json_dtype(value: &Value) -> DataType {
+    match value {
+        Value::Null => DataType::Null,
+        Value::Bool(_) => DataType::Boolean,
+        Value::Number(n) if n.is_i64() => DataType::Int64,
+        Value::Number(_) => DataType::Float64,
+        Value::String(_) => DataType::Utf8,
+        Value::Array(values) => DataType::List(Box::new(
+            values
+                .iter()
+                .map(infer_json_dtype)
+                .reduce(merge_json_dtypes)
+                .unwrap_or(DataType::Null),
+        )),
+        Value::Object(object) => DataType::Struct(
+            object
+                .iter()
+                .map(|(key, value)| Field::new(key, infer_json_dtype(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// Type which can hold values of both `left` and `right`, falling back to holding the JSON text as Utf8
+fn merge_json_dtypes(left: DataType, right: DataType) -> DataType {
+    match (left, right) {
+        (left, right) if left == right => left,
+        (DataType::Null, other) | (other, DataType::Null) => other,
+        (DataType::Int64, DataType::Float64) | (DataType::Float64, DataType::Int64) => {
+            DataType::Float64
+        }
+        (DataType::List(left), DataType::List(right)) => {
+            DataType::List(Box::new(merge_json_dtypes(*left, *right)))
+        }
+        (DataType::Struct(mut fields), DataType::Struct(right_fields)) => {
+            for right_field in right_fields {
+                match fields.iter_mut().find(|f| f.name == right_field.name) {
+                    Some(field) => {
+                        field.dtype = merge_json_dtypes(field.dtype.clone(), right_field.dtype)
+                    }
+                    None => fields.push(right_field),
+                }
+            }
+            DataType::Struct(fields)
+        }
+        _ => DataType::Utf8,
+    }
+}
+
+/// Arrow array of type `dtype` holding `values`, where values of another type are null, except for Utf8 which holds
+/// the JSON text of values that aren't strings.
+fn json_to_arrow(
+    values: &[Option<&Value>],
+    dtype: &DataType,
+) -> DaftResult<Box<dyn arrow2::array::Array>> {
+    Ok(match dtype {
+        DataType::Null => Box::new(arrow2::array::NullArray::new(
+            arrow2::datatypes::DataType::Null,
+            values.len(),
+        )),
+        DataType::Boolean => Box::new(arrow2::array::BooleanArray::from_iter(
+            values.iter().map(|v| v.and_then(Value::as_bool)),
+        )),
+        DataType::Int64 => Box::new(arrow2::array::PrimitiveArray::<i64>::from_iter(
+            values.iter().map(|v| v.and_then(Value::as_i64)),
+        )),
+        DataType::Float64 => Box::new(arrow2::array::PrimitiveArray::<f64>::from_iter(
+            values.iter().map(|v| v.and_then(Value::as_f64)),
+        )),
+        DataType::Utf8 => Box::new(arrow2::array::Utf8Array::<i64>::from_iter(
+            values.iter().map(|v| match v {
+                None | Some(Value::Null) => None,
+                Some(Value::String(s)) => Some(s.clone()),
+                Some(v) => Some(v.to_string()),
+            }),
+        )),
+        DataType::List(child_dtype) => {
+            let arrays = values
+                .iter()
+                .map(|v| v.and_then(Value::as_array))
+                .collect::<Vec<_>>();
+            let child_values = arrays
+                .iter()
+                .flatten()
+                .flat_map(|array| array.iter().map(Some))
+                .collect::<Vec<_>>();
+            let offsets = arrow2::offset::Offsets::try_from_lengths(
+                arrays
+                    .iter()
+                    .map(|array| array.map_or(0, |array| array.len())),
+            )?;
+            Box::new(arrow2::array::ListArray::<i64>::new(
+                dtype.to_arrow()?,
+                offsets.into(),
+                json_to_arrow(&child_values, child_dtype)?,
+                Some(arrays.iter().map(|array| array.is_some()).collect()),
+            ))
+        }
+        DataType::Struct(fields) => {
+            let objects = values
+                .iter()
+                .map(|v| v.and_then(Value::as_object))
+                .collect::<Vec<_>>();
+            let children = fields
+                .iter()
+                .map(|field| {
+                    let child_values = objects
+                        .iter()
+                        .map(|object| object.and_then(|object| object.get(&field.name)))
+                        .collect::<Vec<_>>();
+                    json_to_arrow(&child_values, &field.dtype)
+                })
+                .collect::<DaftResult<Vec<_>>>()?;
+            Box::new(arrow2::array::StructArray::new(
+                dtype.to_arrow()?,
+                children,
+                Some(objects.iter().map(|object| object.is_some()).collect()),
+            ))
+        }
+        dtype => {
+            return Err(DaftError::TypeError(format!(
+                "Error in json_decode: cannot decode JSON into {dtype}"
+            )))
+        }
+    })
+}
+
+/// Step of a JSONPath expression into a value
+#[derive(Debug, PartialEq)]
+enum JsonPathStep {
+    Key(String),
+    Index(usize),
+}
+
+/// Parses a JSONPath expression made of `.key`, `[index]` and `['key']` or `["key"]` steps, optionally starting
+/// with `$`, e.g. `$.foo[0]['bar baz']`
+fn parse_json_path(path: &str) -> DaftResult<Vec<JsonPathStep>> {
+    let invalid =
+        |reason: &str| DaftError::ValueError(format!("Error parsing json path ({path}): {reason}"));
+    let mut rest = path.strip_prefix('$').unwrap_or(path);
+    let mut steps = vec![];
+    while !rest.is_empty() {
+        if let Some(after_dot) = rest.strip_prefix('.') {
+            let end = after_dot.find(['.', '[']).unwrap_or(after_dot.len());
+            if end == 0 {
+                return Err(invalid("expected a key after '.'"));
+            }
+            steps.push(JsonPathStep::Key(after_dot[..end].to_string()));
+            rest = &after_dot[end..];
+        } else if let Some(after_bracket) = rest.strip_prefix('[') {
+            let end = after_bracket
+                .find(']')
+                .ok_or_else(|| invalid("unclosed '['"))?;
+            let inner = &after_bracket[..end];
+            let quoted = ['\'', '"'].into_iter().find_map(|quote| {
+                inner
+                    .strip_prefix(quote)
+                    .and_then(|inner| inner.strip_suffix(quote))
+            });
+            steps.push(match quoted {
+                Some(key) => JsonPathStep::Key(key.to_string()),
+                None => JsonPathStep::Index(
+                    inner
+                        .parse()
+                        .map_err(|_| invalid("expected an index or a quoted key in '[]'"))?,
+                ),
+            });
+            rest = &after_bracket[end + 1..];
+        } else {
+            return Err(invalid("expected '.' or '['"));
+        }
+    }
+    Ok(steps)
+}
+
 impl Utf8Array {
+    /// Extracts the value at the JSONPath expression `path` from each JSON string, where `path` is made of `.key`,
+    /// `[index]` and `['key']` steps, e.g. `$.foo[0]['bar baz']`. Strings are extracted as is, and other values as
+    /// JSON text. Rows that are malformed JSON, or where the path is absent or holds a JSON null, are null. Unlike
+    /// [`Utf8Array::json_query`], this doesn't run a jq filter for each row.
+    pub fn json_extract(&self, path: &str) -> DaftResult<Utf8Array> {
+        let steps = parse_json_path(path)?;
+        let extracted = self.as_arrow().iter().map(|opt| {
+            let json = serde_json::from_str::<Value>(opt?).ok()?;
+            let value = steps.iter().try_fold(&json, |value, step| match step {
+                JsonPathStep::Key(key) => value.get(key),
+                JsonPathStep::Index(index) => value.get(index),
+            })?;
+            match value {
+                Value::Null => None,
+                Value::String(s) => Some(s.clone()),
+                value => Some(value.to_string()),
+            }
+        });
+        Ok(Utf8Array::from_iter(self.name(), extracted))
+    }
+
     pub fn json_query(&self, query: &str) -> DaftResult<Utf8Array> {
         let compiled_filter = compile_filter(query)?;
         let inputs = RcIter::new(core::iter::empty());
@@ -77,6 +279,41 @@ impl Utf8Array {
 
         Ok(Utf8Array::from((self.name(), Box::new(arrow_result))))
     }
+
+    /// Parses each string as a JSON object into a row of a struct with the fields of `schema`, or with fields inferred
+    /// from all rows if it is None, in the order they are first seen. Nested objects become nested structs and arrays
+    /// become lists, while values that don't match the type of their field are null, except for Utf8 fields which
+    /// hold the JSON text of such values. Strings which aren't JSON objects are null, or an error if `strict` is set.
+    pub fn json_decode(&self, schema: Option<&Schema>, strict: bool) -> DaftResult<StructArray> {
+        let values = self
+            .as_arrow()
+            .iter()
+            .map(|opt| match opt {
+                Some(s) => match serde_json::from_str::<Value>(s) {
+                    Ok(value @ Value::Object(_)) => Ok(Some(value)),
+                    _ if strict => Err(DaftError::ValueError(format!(
+                        "Error in json_decode: {s:?} is not a JSON object"
+                    ))),
+                    _ => Ok(None),
+                },
+                None => Ok(None),
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        let dtype = match schema {
+            Some(schema) => DataType::Struct(schema.fields.values().cloned().collect()),
+            None => values
+                .iter()
+                .flatten()
+                .map(infer_json_dtype)
+                .fold(DataType::Struct(vec![]), merge_json_dtypes),
+        };
+        let arrow_array = json_to_arrow(
+            &values.iter().map(Option::as_ref).collect::<Vec<_>>(),
+            &dtype,
+        )?;
+        StructArray::from_arrow(Arc::new(Field::new(self.name(), dtype)), arrow_array)
+    }
 }
 
 #[cfg(test)]
@@ -102,4 +339,149 @@ mod tests {
         assert_eq!(result.as_arrow().value(2), "3");
         Ok(())
     }
+
+    #[test]
+    fn test_json_decode_with_schema() -> DaftResult<()> {
+        let data = Utf8Array::from((
+            "data",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some(r#"{"a": 1, "b": {"c": "x"}}"#),
+                None,
+                Some(r#"{"a": "not an int", "extra": true}"#),
+                Some("not json"),
+            ])),
+        ));
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Struct(vec![Field::new("c", DataType::Utf8)])),
+        ])?;
+
+        let decoded = data.json_decode(Some(&schema), false)?;
+        assert_eq!(decoded.name(), "data");
+        assert_eq!(
+            *decoded.data_type(),
+            DataType::Struct(schema.fields.values().cloned().collect())
+        );
+        assert_eq!(
+            (0..decoded.len())
+                .map(|i| decoded.is_valid(i))
+                .collect::<Vec<_>>(),
+            vec![true, false, true, false]
+        );
+        let a = decoded.children[0].i64()?;
+        assert_eq!(
+            a.as_arrow().iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            vec![Some(1), None, None, None]
+        );
+        let b = decoded.children[1].struct_()?;
+        assert_eq!(b.children[0].utf8()?.get(0), Some("x"));
+        assert_eq!(b.children[0].utf8()?.get(2), None);
+
+        // The malformed row is an error when strict
+        assert!(matches!(
+            data.json_decode(Some(&schema), true),
+            Err(DaftError::ValueError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_decode_infers_schema() -> DaftResult<()> {
+        let data = Utf8Array::from((
+            "data",
+            [
+                r#"{"a": 1, "b": {"c": [1, 2]}}"#,
+                r#"{"a": 2.5, "b": null, "d": "x"}"#,
+            ]
+            .as_slice(),
+        ));
+        let decoded = data.json_decode(None, true)?;
+        assert_eq!(
+            *decoded.data_type(),
+            DataType::Struct(vec![
+                Field::new("a", DataType::Float64),
+                Field::new(
+                    "b",
+                    DataType::Struct(vec![Field::new(
+                        "c",
+                        DataType::List(Box::new(DataType::Int64))
+                    )])
+                ),
+                Field::new("d", DataType::Utf8),
+            ])
+        );
+        assert_eq!(
+            decoded.children[0].f64()?.as_arrow().values().as_slice(),
+            [1.0, 2.5]
+        );
+        let b = decoded.children[1].struct_()?;
+        assert!(b.is_valid(0) && !b.is_valid(1));
+        let c = b.children[0].list()?.get(0).unwrap();
+        assert_eq!(c.i64()?.as_arrow().values().as_slice(), [1, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_extract() -> DaftResult<()> {
+        let data = Utf8Array::from((
+            "data",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some(r#"{"foo": {"bar": [1, {"baz qux": "x"}]}}"#),
+                Some(r#"{"foo": {"bar": []}}"#),
+                None,
+                Some("not json"),
+                Some(r#"{"foo": null}"#),
+            ])),
+        ));
+        let extract = |path| -> DaftResult<Vec<Option<String>>> {
+            let result = data.json_extract(path)?;
+            assert_eq!(result.name(), "data");
+            Ok(result
+                .as_arrow()
+                .iter()
+                .map(|v| v.map(|v| v.to_string()))
+                .collect())
+        };
+
+        assert_eq!(
+            extract("$.foo.bar[1]['baz qux']")?,
+            vec![Some("x".to_string()), None, None, None, None]
+        );
+        assert_eq!(
+            extract(r#"$.foo["bar"][0]"#)?,
+            vec![Some("1".to_string()), None, None, None, None]
+        );
+        assert_eq!(
+            extract("$.foo")?,
+            vec![
+                Some(r#"{"bar":[1,{"baz qux":"x"}]}"#.to_string()),
+                Some(r#"{"bar":[]}"#.to_string()),
+                None,
+                None,
+                None
+            ]
+        );
+        assert_eq!(extract("$.missing.path")?, vec![None; 5]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_json_path() -> DaftResult<()> {
+        assert_eq!(
+            parse_json_path("$.a[2]['b.c']")?,
+            vec![
+                JsonPathStep::Key("a".to_string()),
+                JsonPathStep::Index(2),
+                JsonPathStep::Key("b.c".to_string())
+            ]
+        );
+        assert_eq!(parse_json_path("$")?, vec![]);
+        for invalid in ["$.", "$[0", "$[x]", "$a", "$.a..b"] {
+            assert!(matches!(
+                parse_json_path(invalid),
+                Err(DaftError::ValueError(_))
+            ));
+        }
+        Ok(())
+    }
 }
@@ -357,6 +357,14 @@ macro_rules! impl_aggs_list_array {
                 Series::concat(agg_refs.as_slice()).map(|s| s.rename(self.name()))
             }
 
+            pub fn count_distinct(&self) -> DaftResult<Series> {
+                self.agg_helper(|s| s.count_distinct(None))
+            }
+
+            pub fn distinct(&self) -> DaftResult<Series> {
+                self.agg_helper(|s| s.agg_set(None))
+            }
+
             pub fn sum(&self) -> DaftResult<Series> {
                 self.agg_helper(|s| s.sum(None))
             }
@@ -365,6 +373,26 @@ macro_rules! impl_aggs_list_array {
                 self.agg_helper(|s| s.mean(None))
             }
 
+            pub fn median(&self) -> DaftResult<Series> {
+                self.agg_helper(|s| s.median(None))
+            }
+
+            pub fn mode(&self) -> DaftResult<Series> {
+                self.agg_helper(|s| s.mode(None))
+            }
+
+            pub fn quantile(&self, q: f64) -> DaftResult<Series> {
+                self.agg_helper(|s| s.quantile(None, q))
+            }
+
+            pub fn stddev(&self, ddof: u64) -> DaftResult<Series> {
+                self.agg_helper(|s| s.stddev(None, ddof))
+            }
+
+            pub fn variance(&self, ddof: u64) -> DaftResult<Series> {
+                self.agg_helper(|s| s.variance(None, ddof))
+            }
+
             pub fn min(&self) -> DaftResult<Series> {
                 self.agg_helper(|s| s.min(None))
             }
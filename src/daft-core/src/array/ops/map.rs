@@ -0,0 +1,155 @@
+use common_error::{DaftError, DaftResult};
+
+use crate::{
+    array::ops::DaftCompare,
+    datatypes::{logical::MapArray, DataType, UInt64Array},
+    series::{IntoSeries, Series},
+};
+
+impl MapArray {
+    /// Looks up `key` in the map of each row, giving the value of the first entry with that key, so duplicate keys
+    /// resolve to their earliest entry. Rows which are null or have no entry for the key give null. `key` holds
+    /// either a single key for all rows or one key per row.
+    pub fn map_get(&self, key: &Series) -> DaftResult<Series> {
+        let (key_field, value_field) = match self.data_type() {
+            DataType::Map(inner) => match inner.as_ref() {
+                DataType::Struct(fields) if fields.len() == 2 => (&fields[0], &fields[1]),
+                _ => {
+                    return Err(DaftError::TypeError(format!(
+                        "Expected map entries to be a struct of a key and a value, but got {inner}"
+                    )))
+                }
+            },
+            _ => unreachable!("MapArray must have DataType::Map(..)"),
+        };
+        if key.len() != 1 && key.len() != self.len() {
+            return Err(DaftError::ValueError(format!(
+                "Expected a single key or one key per row for map lookup, but got {} keys for {} rows",
+                key.len(),
+                self.len()
+            )));
+        }
+        if self.is_empty() {
+            return Ok(Series::empty(self.name(), &value_field.dtype));
+        }
+
+        let entries = self.physical.flat_child.struct_()?;
+        let (keys, values) = (&entries.children[0], &entries.children[1]);
+        let offsets = self.physical.offsets();
+
+        // Compare each entry with the key of the row it belongs to.
+        let mut entry_key_indices = vec![0u64; keys.len()];
+        if key.len() != 1 {
+            for row in 0..self.len() {
+                let (start, end) = offsets.start_end(row);
+                entry_key_indices[start..end].fill(row as u64);
+            }
+        }
+        let entry_keys = key
+            .cast(&key_field.dtype)?
+            .take(&UInt64Array::from(("", entry_key_indices)).into_series())?;
+        let matches = keys.equal(&entry_keys)?;
+
+        let value_indices = (0..self.len())
+            .map(|row| {
+                if !self.physical.is_valid(row) {
+                    return None;
+                }
+                let (start, end) = offsets.start_end(row);
+                (start..end)
+                    .find(|i| matches.get(*i) == Some(true))
+                    .map(|i| i as u64)
+            })
+            .collect::<Vec<_>>();
+        let value_indices = UInt64Array::from_iter("", value_indices.into_iter());
+        Ok(values
+            .take(&value_indices.into_series())?
+            .rename(self.name()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common_error::{DaftError, DaftResult};
+
+    use crate::{
+        datatypes::{logical::MapArray, DataType, Field, Utf8Array},
+        series::{IntoSeries, Series},
+    };
+
+    /// Map column of string keys to int64 values, with rows `{a: 1, b: 2}`, null, `{}` and `{b: 3, a: 4, b: 5}`.
+    fn map_series() -> DaftResult<Series> {
+        let entry_fields = vec![
+            arrow2::datatypes::Field::new("key", arrow2::datatypes::DataType::LargeUtf8, false),
+            arrow2::datatypes::Field::new("value", arrow2::datatypes::DataType::Int64, true),
+        ];
+        let entries = arrow2::array::StructArray::new(
+            arrow2::datatypes::DataType::Struct(entry_fields.clone()),
+            vec![
+                Box::new(arrow2::array::Utf8Array::<i64>::from_slice([
+                    "a", "b", "b", "a", "b",
+                ])),
+                Box::new(arrow2::array::Int64Array::from_slice([1, 2, 3, 4, 5])),
+            ],
+            None,
+        );
+        let map_type = arrow2::datatypes::DataType::Map(
+            Box::new(arrow2::datatypes::Field::new(
+                "entries",
+                arrow2::datatypes::DataType::Struct(entry_fields),
+                false,
+            )),
+            false,
+        );
+        let map_array = arrow2::array::MapArray::new(
+            map_type,
+            vec![0, 2, 2, 2, 5].try_into().unwrap(),
+            Box::new(entries),
+            Some(arrow2::bitmap::Bitmap::from([true, false, true, true])),
+        );
+        let dtype = DataType::Map(Box::new(DataType::Struct(vec![
+            Field::new("key", DataType::Utf8),
+            Field::new("value", DataType::Int64),
+        ])));
+        Series::from_arrow(Arc::new(Field::new("m", dtype)), Box::new(map_array))
+    }
+
+    fn values(series: &Series) -> DaftResult<Vec<Option<i64>>> {
+        Ok(series.i64()?.into_iter().map(|v| v.copied()).collect())
+    }
+
+    #[test]
+    fn map_get_single_key() -> DaftResult<()> {
+        let maps = map_series()?;
+        let maps = maps.downcast::<MapArray>()?;
+
+        let key = Utf8Array::from(("key", ["a"].as_slice())).into_series();
+        let result = maps.map_get(&key)?;
+        assert_eq!(result.name(), "m");
+        assert_eq!(*result.data_type(), DataType::Int64);
+        assert_eq!(values(&result)?, [Some(1), None, None, Some(4)]);
+
+        // Duplicate keys resolve to their first entry
+        let key = Utf8Array::from(("key", ["b"].as_slice())).into_series();
+        assert_eq!(
+            values(&maps.map_get(&key)?)?,
+            [Some(2), None, None, Some(3)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn map_get_key_per_row() -> DaftResult<()> {
+        let maps = map_series()?;
+        let maps = maps.downcast::<MapArray>()?;
+
+        let keys = Utf8Array::from(("key", ["b", "a", "a", "c"].as_slice())).into_series();
+        assert_eq!(values(&maps.map_get(&keys)?)?, [Some(2), None, None, None]);
+
+        let keys = Utf8Array::from(("key", ["a", "b"].as_slice())).into_series();
+        assert!(matches!(maps.map_get(&keys), Err(DaftError::ValueError(_))));
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use num_traits::Float;
+
+use crate::{
+    array::DataArray,
+    datatypes::{DaftFloatType, DaftNumericType},
+};
+
+use common_error::DaftResult;
+
+macro_rules! impl_float_math_functions {
+    ($($name:ident),*) => {
+        impl<T: DaftFloatType> DataArray<T>
+        where
+            T: DaftNumericType,
+            T::Native: Float,
+        {
+            $(
+                pub fn $name(&self) -> DaftResult<Self> {
+                    self.apply(|v| v.$name())
+                }
+            )*
+        }
+    };
+}
+
+// These follow IEEE 754, so nulls stay null while values outside the domain of a function give NaN rather than
+// null, e.g. `ln` and `sqrt` of a negative number. `ln` and `log10` of zero give negative infinity.
+impl_float_math_functions!(sin, cos, tan, exp, ln, log10, sqrt);
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{E, PI};
+
+    use common_error::DaftResult;
+
+    use crate::{array::ops::as_arrow::AsArrow, datatypes::Float64Array};
+
+    fn values(array: Float64Array) -> Vec<Option<f64>> {
+        array.as_arrow().iter().map(|v| v.copied()).collect()
+    }
+
+    fn assert_close(actual: Vec<Option<f64>>, expected: &[Option<f64>]) {
+        assert_eq!(actual.len(), expected.len());
+        for (actual, expected) in actual.iter().zip(expected) {
+            match (actual, expected) {
+                (Some(a), Some(e)) => assert!((a - e).abs() < 1e-12, "{a} != {e}"),
+                (a, e) => assert_eq!(a, e),
+            }
+        }
+    }
+
+    #[test]
+    fn trigonometric_functions() -> DaftResult<()> {
+        let angles = Float64Array::from_iter(
+            "a",
+            vec![Some(0.0), Some(PI / 2.0), None, Some(PI / 4.0)].into_iter(),
+        );
+        assert_close(
+            values(angles.sin()?),
+            &[Some(0.0), Some(1.0), None, Some(0.5f64.sqrt())],
+        );
+        assert_close(
+            values(angles.cos()?),
+            &[Some(1.0), Some(0.0), None, Some(0.5f64.sqrt())],
+        );
+        assert_close(
+            values(Float64Array::from(("a", vec![0.0, PI / 4.0])).tan()?),
+            &[Some(0.0), Some(1.0)],
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn exp_log_and_sqrt() -> DaftResult<()> {
+        let floats = Float64Array::from_iter("f", vec![Some(1.0), None, Some(100.0)].into_iter());
+        assert_close(
+            values(floats.exp()?),
+            &[Some(E), None, Some(100.0f64.exp())],
+        );
+        assert_close(
+            values(floats.ln()?),
+            &[Some(0.0), None, Some(100.0f64.ln())],
+        );
+        assert_close(values(floats.log10()?), &[Some(0.0), None, Some(2.0)]);
+        assert_close(values(floats.sqrt()?), &[Some(1.0), None, Some(10.0)]);
+        Ok(())
+    }
+
+    #[test]
+    fn out_of_domain_gives_nan() -> DaftResult<()> {
+        let floats = Float64Array::from(("f", vec![-1.0, 0.0]));
+        let ln = floats.ln()?;
+        assert!(ln.as_arrow().value(0).is_nan());
+        assert_eq!(ln.as_arrow().value(1), f64::NEG_INFINITY);
+        assert!(floats.log10()?.as_arrow().value(0).is_nan());
+        assert!(floats.sqrt()?.as_arrow().value(0).is_nan());
+        assert_eq!(floats.sqrt()?.null_count(), 0);
+        Ok(())
+    }
+}
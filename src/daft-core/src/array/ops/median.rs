@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use arrow2;
+
+use crate::{array::DataArray, datatypes::*};
+
+use common_error::DaftResult;
+
+use super::DaftMedianAggable;
+
+use super::as_arrow::AsArrow;
+
+use crate::array::ops::GroupIndices;
+
+/// Median of `values`, averaging the two middle values when there is an even number of them.
+/// Returns None if there are no values.
+fn median_of(mut values: Vec<f64>) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_unstable_by(|a, b| a.total_cmp(b));
+    let mid = values.len() / 2;
+    if values.len() % 2 == 0 {
+        Some((values[mid - 1] + values[mid]) / 2.0)
+    } else {
+        Some(values[mid])
+    }
+}
+
+impl DaftMedianAggable for &DataArray<Float64Type> {
+    type Output = DaftResult<DataArray<Float64Type>>;
+
+    fn median(&self) -> Self::Output {
+        let result = median_of(self.as_arrow().iter().flatten().copied().collect());
+        let arrow_array = Box::new(arrow2::array::PrimitiveArray::from([result]));
+
+        DataArray::new(
+            Arc::new(Field::new(self.field.name.clone(), DataType::Float64)),
+            arrow_array,
+        )
+    }
+
+    fn grouped_median(&self, groups: &GroupIndices) -> Self::Output {
+        use arrow2::array::PrimitiveArray;
+        let arrow_array = self.as_arrow();
+        let median_per_group = groups.iter().map(|g| {
+            median_of(
+                g.iter()
+                    .filter_map(|i| arrow_array.get(*i as usize))
+                    .collect(),
+            )
+        });
+        let median_array = Box::new(PrimitiveArray::from_trusted_len_iter(median_per_group));
+        Ok(DataArray::from((self.field.name.as_ref(), median_array)))
+    }
+}
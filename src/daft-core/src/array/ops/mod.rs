@@ -4,15 +4,21 @@ mod arange;
 mod arithmetic;
 pub mod arrow2;
 pub mod as_arrow;
+mod base64;
+mod bitwise_agg;
+mod bool_agg;
 pub(crate) mod broadcast;
 pub(crate) mod cast;
 mod ceil;
+mod clip;
 mod compare_agg;
 mod comparison;
 mod concat;
 mod concat_agg;
 mod count;
+mod count_distinct;
 mod date;
+mod decimal;
 mod filter;
 mod float;
 mod floor;
@@ -21,6 +27,7 @@ pub mod full;
 mod get;
 pub(crate) mod groups;
 mod hash;
+mod hex;
 mod if_else;
 pub(crate) mod image;
 mod is_in;
@@ -28,22 +35,36 @@ mod json;
 mod len;
 mod list;
 mod list_agg;
+mod map;
+mod math;
 mod mean;
+mod median;
+mod mode;
+mod negate;
 mod null;
 mod pairwise;
+mod product;
+mod quantile;
 mod repr;
 mod round;
 mod search_sorted;
 mod sign;
 mod sort;
+mod string_agg;
 mod struct_;
 mod sum;
 mod take;
 pub(crate) mod tensor;
+pub mod to_arrow;
 mod truncate;
+mod unique;
 mod utf8;
+mod value_counts;
+mod variance;
 
-pub use sort::{build_multi_array_bicompare, build_multi_array_compare};
+pub use cast::CastPolicy;
+pub use decimal::MAX_DECIMAL128_PRECISION;
+pub use sort::{build_multi_array_bicompare, build_multi_array_compare, stable_argsort_multikey};
 
 use common_error::DaftResult;
 
@@ -118,18 +139,78 @@ pub trait DaftCountAggable {
     fn grouped_count(&self, groups: &GroupIndices, mode: CountMode) -> Self::Output;
 }
 
+pub trait DaftCountDistinctAggable {
+    type Output;
+    fn count_distinct(&self) -> Self::Output;
+    fn grouped_count_distinct(&self, groups: &GroupIndices) -> Self::Output;
+}
+
 pub trait DaftSumAggable {
     type Output;
     fn sum(&self) -> Self::Output;
     fn grouped_sum(&self, groups: &GroupIndices) -> Self::Output;
 }
 
+pub trait DaftProductAggable {
+    type Output;
+    fn product(&self) -> Self::Output;
+    fn grouped_product(&self, groups: &GroupIndices) -> Self::Output;
+}
+
 pub trait DaftMeanAggable {
     type Output;
     fn mean(&self) -> Self::Output;
     fn grouped_mean(&self, groups: &GroupIndices) -> Self::Output;
 }
 
+pub trait DaftMedianAggable {
+    type Output;
+    fn median(&self) -> Self::Output;
+    fn grouped_median(&self, groups: &GroupIndices) -> Self::Output;
+}
+
+pub trait DaftModeAggable {
+    type Output;
+    fn mode(&self) -> Self::Output;
+    fn grouped_mode(&self, groups: &GroupIndices) -> Self::Output;
+}
+
+pub trait DaftQuantileAggable {
+    type Output;
+    fn quantile(&self, q: f64) -> Self::Output;
+    fn grouped_quantile(&self, groups: &GroupIndices, q: f64) -> Self::Output;
+}
+
+pub trait DaftVarianceAggable {
+    type Output;
+    fn variance(&self, ddof: u64) -> Self::Output;
+    fn grouped_variance(&self, groups: &GroupIndices, ddof: u64) -> Self::Output;
+}
+
+pub trait DaftBitwiseAggable {
+    type Output;
+    fn bit_and(&self) -> Self::Output;
+    fn bit_or(&self) -> Self::Output;
+    fn bit_xor(&self) -> Self::Output;
+    fn grouped_bit_and(&self, groups: &GroupIndices) -> Self::Output;
+    fn grouped_bit_or(&self, groups: &GroupIndices) -> Self::Output;
+    fn grouped_bit_xor(&self, groups: &GroupIndices) -> Self::Output;
+}
+
+pub trait DaftStringAggable {
+    type Output;
+    fn string_agg(&self, separator: &str) -> Self::Output;
+    fn grouped_string_agg(&self, groups: &GroupIndices, separator: &str) -> Self::Output;
+}
+
+pub trait DaftBoolAggable {
+    type Output;
+    fn any(&self) -> Self::Output;
+    fn all(&self) -> Self::Output;
+    fn grouped_any(&self, groups: &GroupIndices) -> Self::Output;
+    fn grouped_all(&self, groups: &GroupIndices) -> Self::Output;
+}
+
 pub trait DaftCompareAggable {
     type Output;
     fn min(&self) -> Self::Output;
@@ -0,0 +1,107 @@
+use std::{collections::HashMap, hash::Hash};
+
+use arrow2;
+
+use crate::{
+    array::{ops::full::FullNull, DataArray},
+    datatypes::*,
+};
+
+use common_error::DaftResult;
+
+use super::{as_arrow::AsArrow, count_distinct::native_key, DaftModeAggable, GroupIndices};
+
+/// Index of the most frequent non-null value among `indices`, where `get` returns the key of the value at an index.
+/// Ties are broken by which value occurs first.
+fn mode_index<K: Hash + Eq>(
+    indices: impl Iterator<Item = u64>,
+    get: &impl Fn(usize) -> Option<K>,
+) -> Option<u64> {
+    let mut counts: HashMap<K, (u64, u64)> = HashMap::new();
+    for idx in indices {
+        if let Some(key) = get(idx as usize) {
+            counts.entry(key).or_insert((idx, 0)).1 += 1;
+        }
+    }
+    counts
+        .into_values()
+        .max_by(|(first_a, count_a), (first_b, count_b)| {
+            count_a.cmp(count_b).then(first_b.cmp(first_a))
+        })
+        .map(|(first, _)| first)
+}
+
+fn mode_indices<K: Hash + Eq>(
+    len: usize,
+    groups: Option<&GroupIndices>,
+    get: impl Fn(usize) -> Option<K>,
+) -> UInt64Array {
+    let indices = match groups {
+        Some(groups) => arrow2::array::PrimitiveArray::from_trusted_len_iter(
+            groups.iter().map(|g| mode_index(g.iter().copied(), &get)),
+        ),
+        None => arrow2::array::PrimitiveArray::from([mode_index(0..len as u64, &get)]),
+    };
+    UInt64Array::from(("", Box::new(indices)))
+}
+
+impl<T> DaftModeAggable for &DataArray<T>
+where
+    T: DaftNumericType,
+{
+    type Output = DaftResult<DataArray<T>>;
+
+    fn mode(&self) -> Self::Output {
+        let arrow_array = self.as_arrow();
+        self.take(&mode_indices(self.len(), None, |i| {
+            arrow_array.get(i).as_ref().map(native_key)
+        }))
+    }
+
+    fn grouped_mode(&self, groups: &GroupIndices) -> Self::Output {
+        let arrow_array = self.as_arrow();
+        self.take(&mode_indices(self.len(), Some(groups), |i| {
+            arrow_array.get(i).as_ref().map(native_key)
+        }))
+    }
+}
+
+macro_rules! impl_daft_mode_aggable {
+    ($arr:ident) => {
+        impl DaftModeAggable for &$arr {
+            type Output = DaftResult<$arr>;
+
+            fn mode(&self) -> Self::Output {
+                let arrow_array = self.as_arrow();
+                self.take(&mode_indices(self.len(), None, |i| arrow_array.get(i)))
+            }
+
+            fn grouped_mode(&self, groups: &GroupIndices) -> Self::Output {
+                let arrow_array = self.as_arrow();
+                self.take(&mode_indices(self.len(), Some(groups), |i| {
+                    arrow_array.get(i)
+                }))
+            }
+        }
+    };
+}
+
+impl_daft_mode_aggable!(Utf8Array);
+impl_daft_mode_aggable!(BinaryArray);
+impl_daft_mode_aggable!(BooleanArray);
+
+impl DaftModeAggable for &NullArray {
+    type Output = DaftResult<NullArray>;
+
+    fn mode(&self) -> Self::Output {
+        Ok(NullArray::full_null(self.name(), self.data_type(), 1))
+    }
+
+    fn grouped_mode(&self, groups: &GroupIndices) -> Self::Output {
+        Ok(NullArray::full_null(
+            self.name(),
+            self.data_type(),
+            groups.len(),
+        ))
+    }
+}
@@ -0,0 +1,54 @@
+use num_traits::Signed;
+
+use crate::{array::DataArray, datatypes::DaftNumericType};
+
+use common_error::DaftResult;
+
+impl<T: DaftNumericType> DataArray<T>
+where
+    T::Native: Signed,
+{
+    /// Negates each value, preserving nulls. Only signed types can be negated, so unsigned arrays don't have this
+    /// method.
+    pub fn negate(&self) -> DaftResult<Self> {
+        self.apply(|v| -v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{Float64Array, Int64Array},
+    };
+
+    #[test]
+    fn abs_sign_and_negate_ints() -> DaftResult<()> {
+        let ints = Int64Array::from_iter("i", vec![Some(-3), Some(0), None, Some(5)].into_iter());
+        let values = |array: Int64Array| {
+            array
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(values(ints.abs()?), [Some(3), Some(0), None, Some(5)]);
+        assert_eq!(values(ints.sign()?), [Some(-1), Some(0), None, Some(1)]);
+        assert_eq!(values(ints.negate()?), [Some(3), Some(0), None, Some(-5)]);
+        Ok(())
+    }
+
+    #[test]
+    fn abs_sign_and_negate_floats() -> DaftResult<()> {
+        let floats = Float64Array::from(("f", vec![-2.5, 1.5]));
+        let values = |array: Float64Array| array.as_arrow().values().to_vec();
+
+        assert_eq!(values(floats.abs()?), [2.5, 1.5]);
+        assert_eq!(values(floats.sign()?), [-1.0, 1.0]);
+        assert_eq!(values(floats.negate()?), [2.5, -1.5]);
+        Ok(())
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
 };
 use common_error::DaftResult;
 
-use super::{DaftIsNull, DaftNotNull};
+use super::{as_arrow::AsArrow, DaftIsNull, DaftNotNull};
 
 impl<T> DataArray<T>
 where
@@ -156,3 +156,120 @@ impl StructArray {
         }
     }
 }
+
+impl<T> DataArray<T>
+where
+    T: DaftNumericType,
+{
+    /// Replaces the nulls of this array with `fill_value`, giving an array without nulls.
+    pub fn fill_null(&self, fill_value: T::Native) -> DaftResult<Self> {
+        if self.data.null_count() == 0 {
+            return Ok(self.clone());
+        }
+        let values = self
+            .as_arrow()
+            .iter()
+            .map(|v| v.copied().unwrap_or(fill_value));
+        let arrow_array = arrow2::array::PrimitiveArray::from_trusted_len_values_iter(values);
+        Ok(DataArray::from((self.name(), Box::new(arrow_array))))
+    }
+}
+
+impl BooleanArray {
+    /// Replaces the nulls of this array with `fill_value`, giving an array without nulls.
+    pub fn fill_null(&self, fill_value: bool) -> DaftResult<Self> {
+        if self.data.null_count() == 0 {
+            return Ok(self.clone());
+        }
+        let values = self.as_arrow().iter().map(|v| v.unwrap_or(fill_value));
+        let arrow_array = arrow2::array::BooleanArray::from_trusted_len_values_iter(values);
+        Ok(BooleanArray::from((self.name(), arrow_array)))
+    }
+}
+
+impl Utf8Array {
+    /// Replaces the nulls of this array with `fill_value`, giving an array without nulls.
+    pub fn fill_null(&self, fill_value: &str) -> DaftResult<Self> {
+        if self.data.null_count() == 0 {
+            return Ok(self.clone());
+        }
+        let values = self.as_arrow().iter().map(|v| v.unwrap_or(fill_value));
+        let arrow_array = arrow2::array::Utf8Array::<i64>::from_trusted_len_values_iter(values);
+        Ok(Utf8Array::from((self.name(), Box::new(arrow_array))))
+    }
+}
+
+#[cfg(feature = "python")]
+impl PythonArray {
+    /// Replaces the nulls of this array with `fill_value`, giving an array without nulls.
+    pub fn fill_null(&self, fill_value: pyo3::PyObject) -> DaftResult<Self> {
+        use crate::array::pseudo_arrow::PseudoArrowArray;
+
+        let values = self
+            .as_arrow()
+            .iter()
+            .map(|v| v.cloned().unwrap_or_else(|| fill_value.clone()))
+            .collect::<Vec<_>>();
+        let arrow_array: Box<dyn arrow2::array::Array> =
+            Box::new(PseudoArrowArray::new(values.into(), None));
+        DataArray::<PythonType>::new(self.field.clone(), arrow_array)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{BooleanArray, Int64Array, Utf8Array},
+    };
+
+    #[test]
+    fn fill_null_numeric_and_boolean() -> DaftResult<()> {
+        let array = Int64Array::from_iter("a", vec![Some(1), None, Some(3), None].into_iter());
+        let filled = array.fill_null(-1)?;
+        assert_eq!(filled.name(), "a");
+        assert_eq!(filled.data().null_count(), 0);
+        assert_eq!(filled.as_arrow().values().as_slice(), [1, -1, 3, -1]);
+
+        let array = BooleanArray::from((
+            "b",
+            arrow2::array::BooleanArray::from(vec![Some(false), None, Some(true)]),
+        ));
+        let filled = array.fill_null(true)?;
+        assert_eq!(filled.data().null_count(), 0);
+        assert_eq!(
+            filled.as_arrow().values_iter().collect::<Vec<_>>(),
+            [false, true, true]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn fill_null_utf8() -> DaftResult<()> {
+        let array = Utf8Array::from((
+            "s",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                None,
+                Some("x"),
+                Some(""),
+                None,
+            ])),
+        ));
+        let filled = array.fill_null("missing")?;
+        assert_eq!(filled.data().null_count(), 0);
+        assert_eq!(
+            filled.as_arrow().values_iter().collect::<Vec<_>>(),
+            ["missing", "x", "", "missing"]
+        );
+
+        // Arrays without nulls are returned unchanged
+        let no_nulls = filled.fill_null("other")?;
+        assert_eq!(
+            no_nulls.as_arrow().values_iter().collect::<Vec<_>>(),
+            ["missing", "x", "", "missing"]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use arrow2;
+
+use super::DaftProductAggable;
+
+use super::as_arrow::AsArrow;
+
+use crate::array::ops::GroupIndices;
+use crate::{array::DataArray, datatypes::*};
+
+use common_error::{DaftError, DaftResult};
+
+/// Product of `values`, or None if there are no values. `mul` returns None if a multiplication overflows.
+fn product_of<N: Copy>(
+    values: impl Iterator<Item = N>,
+    mul: impl Fn(N, N) -> Option<N>,
+) -> DaftResult<Option<N>> {
+    let mut product = None;
+    for value in values {
+        product = Some(match product {
+            None => value,
+            Some(product) => mul(product, value).ok_or_else(|| {
+                DaftError::ComputeError("Overflow when computing product".to_string())
+            })?,
+        });
+    }
+    Ok(product)
+}
+
+macro_rules! impl_daft_product_agg {
+    ($T:ident, $mul:expr) => {
+        impl DaftProductAggable for &DataArray<$T> {
+            type Output = DaftResult<DataArray<$T>>;
+
+            fn product(&self) -> Self::Output {
+                let product_value = product_of(self.as_arrow().iter().flatten().copied(), $mul)?;
+                let arrow_array = Box::new(arrow2::array::PrimitiveArray::from([product_value]));
+                DataArray::new(self.field.clone(), arrow_array)
+            }
+
+            fn grouped_product(&self, groups: &GroupIndices) -> Self::Output {
+                let arrow_array = self.as_arrow();
+                let product_per_group = groups
+                    .iter()
+                    .map(|g| {
+                        product_of(g.iter().filter_map(|i| arrow_array.get(*i as usize)), $mul)
+                    })
+                    .collect::<DaftResult<Vec<_>>>()?;
+                let product_array =
+                    Box::new(arrow2::array::PrimitiveArray::from(product_per_group));
+                Ok(DataArray::from((self.field.name.as_ref(), product_array)))
+            }
+        }
+    };
+}
+
+impl_daft_product_agg!(Int64Type, i64::checked_mul);
+impl_daft_product_agg!(UInt64Type, u64::checked_mul);
+impl_daft_product_agg!(Float32Type, |a: f32, b: f32| Some(a * b));
+impl_daft_product_agg!(Float64Type, |a: f64, b: f64| Some(a * b));
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use arrow2;
+
+use crate::{array::DataArray, datatypes::*};
+
+use common_error::DaftResult;
+
+use super::DaftQuantileAggable;
+
+use super::as_arrow::AsArrow;
+
+use crate::array::ops::GroupIndices;
+
+/// The `q`-th quantile of `values`, linearly interpolating between the two closest ranks.
+/// Returns None if there are no values.
+fn quantile_of(mut values: Vec<f64>, q: f64) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_unstable_by(|a, b| a.total_cmp(b));
+    let rank = q * (values.len() - 1) as f64;
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    Some(values[lower] + (values[upper] - values[lower]) * (rank - lower as f64))
+}
+
+impl DaftQuantileAggable for &DataArray<Float64Type> {
+    type Output = DaftResult<DataArray<Float64Type>>;
+
+    fn quantile(&self, q: f64) -> Self::Output {
+        let result = quantile_of(self.as_arrow().iter().flatten().copied().collect(), q);
+        let arrow_array = Box::new(arrow2::array::PrimitiveArray::from([result]));
+
+        DataArray::new(
+            Arc::new(Field::new(self.field.name.clone(), DataType::Float64)),
+            arrow_array,
+        )
+    }
+
+    fn grouped_quantile(&self, groups: &GroupIndices, q: f64) -> Self::Output {
+        use arrow2::array::PrimitiveArray;
+        let arrow_array = self.as_arrow();
+        let quantile_per_group = groups.iter().map(|g| {
+            quantile_of(
+                g.iter()
+                    .filter_map(|i| arrow_array.get(*i as usize))
+                    .collect(),
+                q,
+            )
+        });
+        let quantile_array = Box::new(PrimitiveArray::from_trusted_len_iter(quantile_per_group));
+        Ok(DataArray::from((self.field.name.as_ref(), quantile_array)))
+    }
+}
@@ -5,10 +5,11 @@ use crate::{
     datatypes::{
         logical::{
             DateArray, Decimal128Array, DurationArray, EmbeddingArray, FixedShapeImageArray,
-            FixedShapeTensorArray, ImageArray, MapArray, TensorArray, TimeArray, TimestampArray,
+            FixedShapeTensorArray, ImageArray, IntervalArray, MapArray, TensorArray, TimeArray,
+            TimestampArray,
         },
-        BinaryArray, BooleanArray, DaftNumericType, ExtensionArray, ImageFormat, NullArray,
-        UInt64Array, Utf8Array,
+        unpack_days_ms, BinaryArray, BooleanArray, DaftNumericType, ExtensionArray, ImageFormat,
+        IntervalUnit, NullArray, UInt64Array, Utf8Array,
     },
     utils::display_table::{display_date32, display_decimal128, display_time64, display_timestamp},
     with_match_daft_types, DataType, Series,
@@ -149,6 +150,20 @@ impl DateArray {
     }
 }
 
+impl IntervalArray {
+    pub fn str_value(&self, idx: usize) -> DaftResult<String> {
+        let val = self.get(idx);
+        match (val, self.data_type()) {
+            (None, _) => Ok("None".to_string()),
+            (Some(v), DataType::Interval(IntervalUnit::YearMonth)) => Ok(format!("{v} months")),
+            (Some(v), _) => {
+                let v = unpack_days_ms(v);
+                Ok(format!("{} days {} ms", v.days(), v.milliseconds()))
+            }
+        }
+    }
+}
+
 impl TimeArray {
     pub fn str_value(&self, idx: usize) -> DaftResult<String> {
         let res = self.get(idx).map_or_else(
@@ -355,6 +370,7 @@ impl_array_html_value!(Decimal128Array);
 impl_array_html_value!(DateArray);
 impl_array_html_value!(TimeArray);
 impl_array_html_value!(DurationArray);
+impl_array_html_value!(IntervalArray);
 impl_array_html_value!(TimestampArray);
 impl_array_html_value!(EmbeddingArray);
 
@@ -5,23 +5,88 @@ use crate::datatypes::{Float32Array, Float64Array};
 use common_error::DaftResult;
 
 impl Float32Array {
+    /// Rounds each value to `decimal` places, see [`Float64Array::round`].
     pub fn round(&self, decimal: i32) -> DaftResult<Self> {
         if decimal == 0 {
             self.apply(|v| v.round())
-        } else {
+        } else if decimal > 0 {
             let multiplier: f64 = 10.0.pow(decimal);
             self.apply(|v| ((v as f64 * multiplier).round() / multiplier) as f32)
+        } else {
+            let divisor: f64 = 10.0.pow(-decimal);
+            self.apply(|v| ((v as f64 / divisor).round() * divisor) as f32)
         }
     }
 }
 
 impl Float64Array {
+    /// Rounds each value to `decimal` places, where a negative `decimal` rounds to tens, hundreds and so on.
+    /// Values half-way between two candidates are rounded away from zero, so 2.5 rounds to 3 and -2.5 to -3.
+    /// Nulls stay null.
     pub fn round(&self, decimal: i32) -> DaftResult<Self> {
         if decimal == 0 {
             self.apply(|v| v.round())
-        } else {
+        } else if decimal > 0 {
             let multiplier: f64 = 10.0.pow(decimal);
             self.apply(|v| ((v * multiplier).round() / multiplier))
+        } else {
+            // Divide by the exact power of ten rather than multiplying by its inexact inverse.
+            let divisor: f64 = 10.0.pow(-decimal);
+            self.apply(|v| ((v / divisor).round() * divisor))
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{Float32Array, Float64Array},
+    };
+
+    fn values(array: &Float64Array) -> Vec<Option<f64>> {
+        array.as_arrow().iter().map(|v| v.copied()).collect()
+    }
+
+    #[test]
+    fn round_decimal_places() -> DaftResult<()> {
+        let floats = Float64Array::from_iter(
+            "f",
+            vec![Some(1.2345), None, Some(-6.789), Some(1250.0)].into_iter(),
+        );
+        assert_eq!(
+            values(&floats.round(2)?),
+            [Some(1.23), None, Some(-6.79), Some(1250.0)]
+        );
+        assert_eq!(
+            values(&floats.round(-2)?),
+            [Some(0.0), None, Some(-0.0), Some(1300.0)]
+        );
+        assert_eq!(
+            values(&floats.round(-1)?),
+            [Some(0.0), None, Some(-10.0), Some(1250.0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn round_half_away_from_zero() -> DaftResult<()> {
+        let floats = Float64Array::from(("f", vec![0.5, 1.5, 2.5, -2.5, 0.125]));
+        assert_eq!(
+            values(&floats.round(0)?),
+            [Some(1.0), Some(2.0), Some(3.0), Some(-3.0), Some(0.0)]
+        );
+        assert_eq!(floats.round(2)?.as_arrow().value(4), 0.13);
+        assert_eq!(
+            Float32Array::from(("f", vec![25.0f32, -2.5]))
+                .round(-1)?
+                .as_arrow()
+                .values()
+                .as_slice(),
+            [30.0, -0.0]
+        );
+        Ok(())
+    }
+}
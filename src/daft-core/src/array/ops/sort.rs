@@ -3,21 +3,25 @@ use crate::{
     datatypes::{
         logical::{
             DateArray, Decimal128Array, DurationArray, EmbeddingArray, FixedShapeImageArray,
-            FixedShapeTensorArray, ImageArray, MapArray, TensorArray, TimeArray, TimestampArray,
+            FixedShapeTensorArray, ImageArray, IntervalArray, MapArray, TensorArray, TimeArray,
+            TimestampArray,
         },
         BinaryArray, BooleanArray, DaftIntegerType, DaftNumericType, ExtensionArray, Float32Array,
-        Float64Array, NullArray, Utf8Array,
+        Float64Array, NullArray, UInt64Array, Utf8Array,
     },
     kernels::search_sorted::{build_compare_with_nulls, cmp_float},
     series::Series,
 };
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 
 #[cfg(feature = "python")]
 use crate::datatypes::PythonArray;
 
 use arrow2::{
-    array::ord::{self, DynComparator},
+    array::{
+        ord::{self, DynComparator},
+        Array,
+    },
     types::Index,
 };
 
@@ -59,6 +63,209 @@ pub fn build_multi_array_bicompare(
     Ok(combined_comparator)
 }
 
+/// Stable permutation of the rows `0..len` which sorts them by `compare`, an ordering on valid rows. Null rows, as
+/// given by `is_valid`, are placed before or after all valid rows, and rows that compare equal keep their order.
+fn stable_argsort_by(
+    name: &str,
+    len: usize,
+    descending: bool,
+    nulls_first: bool,
+    is_valid: impl Fn(usize) -> bool,
+    compare: impl Fn(usize, usize) -> std::cmp::Ordering,
+) -> UInt64Array {
+    let mut indices = (0..len).collect::<Vec<_>>();
+    indices.sort_by(|a, b| match (is_valid(*a), is_valid(*b)) {
+        (true, true) if descending => compare(*a, *b).reverse(),
+        (true, true) => compare(*a, *b),
+        (false, false) => std::cmp::Ordering::Equal,
+        (false, true) if nulls_first => std::cmp::Ordering::Less,
+        (true, false) if !nulls_first => std::cmp::Ordering::Less,
+        _ => std::cmp::Ordering::Greater,
+    });
+    UInt64Array::from((
+        name,
+        indices.into_iter().map(|i| i as u64).collect::<Vec<_>>(),
+    ))
+}
+
+/// Stable permutation which sorts rows lexicographically by `sort_keys`, with the direction and null placement of
+/// each key given by `descending` and `nulls_first`. Rows that are equal on all keys keep their order.
+pub fn stable_argsort_multikey(
+    sort_keys: &[Series],
+    descending: &[bool],
+    nulls_first: &[bool],
+) -> DaftResult<UInt64Array> {
+    if sort_keys.len() != descending.len() || sort_keys.len() != nulls_first.len() {
+        return Err(DaftError::ValueError(format!(
+            "sort_keys, descending and nulls_first length must match, got {} vs {} vs {}",
+            sort_keys.len(),
+            descending.len(),
+            nulls_first.len()
+        )));
+    }
+    let Some(first) = sort_keys.first() else {
+        return Err(DaftError::ValueError(
+            "Need at least 1 sort key to argsort".to_string(),
+        ));
+    };
+    if let Some(mismatched) = sort_keys.iter().find(|s| s.len() != first.len()) {
+        return Err(DaftError::ValueError(format!(
+            "All sort keys must have the same length, got {} vs {}",
+            first.len(),
+            mismatched.len()
+        )));
+    }
+
+    let arrays = sort_keys.iter().map(|s| s.to_arrow()).collect::<Vec<_>>();
+    let mut key_cmps = Vec::with_capacity(arrays.len());
+    for ((array, desc), nulls_first) in arrays.iter().zip(descending).zip(nulls_first) {
+        key_cmps.push((
+            array.as_ref(),
+            build_compare_with_nulls(array.as_ref(), array.as_ref(), false)?,
+            *desc,
+            *nulls_first,
+        ));
+    }
+    let mut indices = (0..first.len()).collect::<Vec<_>>();
+    indices.sort_by(|a, b| {
+        for (array, cmp, desc, nulls_first) in key_cmps.iter() {
+            let ordering = match (array.is_valid(*a), array.is_valid(*b)) {
+                (true, true) if *desc => cmp(*a, *b).reverse(),
+                (true, true) => cmp(*a, *b),
+                (false, false) => std::cmp::Ordering::Equal,
+                (false, true) if *nulls_first => std::cmp::Ordering::Less,
+                (true, false) if !*nulls_first => std::cmp::Ordering::Less,
+                _ => std::cmp::Ordering::Greater,
+            };
+            if ordering != std::cmp::Ordering::Equal {
+                return ordering;
+            }
+        }
+        std::cmp::Ordering::Equal
+    });
+    Ok(UInt64Array::from((
+        first.name(),
+        indices.into_iter().map(|i| i as u64).collect::<Vec<_>>(),
+    )))
+}
+
+impl<T> DataArray<T>
+where
+    T: DaftNumericType,
+{
+    /// Stable permutation which sorts this array, placing nulls first or last as given by `nulls_first`. NaNs sort
+    /// after all other floats.
+    pub fn stable_argsort(&self, descending: bool, nulls_first: bool) -> DaftResult<UInt64Array> {
+        let arrow_array = self.as_arrow();
+        let values = arrow_array.values().as_slice();
+        #[allow(clippy::eq_op)]
+        let is_nan = |v: &T::Native| v != v;
+        Ok(stable_argsort_by(
+            self.name(),
+            self.len(),
+            descending,
+            nulls_first,
+            |i| arrow_array.is_valid(i),
+            |a, b| {
+                let (l, r) = (&values[a], &values[b]);
+                l.partial_cmp(r)
+                    .unwrap_or_else(|| is_nan(l).cmp(&is_nan(r)))
+            },
+        ))
+    }
+}
+
+macro_rules! impl_binary_like_stable_argsort {
+    ($da:ident) => {
+        impl $da {
+            /// Stable permutation which sorts this array, placing nulls first or last as given by `nulls_first`.
+            pub fn stable_argsort(
+                &self,
+                descending: bool,
+                nulls_first: bool,
+            ) -> DaftResult<UInt64Array> {
+                let arrow_array = self.as_arrow();
+                Ok(stable_argsort_by(
+                    self.name(),
+                    self.len(),
+                    descending,
+                    nulls_first,
+                    |i| arrow_array.is_valid(i),
+                    |a, b| unsafe {
+                        arrow_array
+                            .value_unchecked(a)
+                            .cmp(arrow_array.value_unchecked(b))
+                    },
+                ))
+            }
+        }
+    };
+}
+
+impl_binary_like_stable_argsort!(BinaryArray);
+impl_binary_like_stable_argsort!(Utf8Array);
+
+#[cfg(feature = "python")]
+impl PythonArray {
+    /// Stable permutation which sorts this array by comparing its Python objects, placing nulls first or last as
+    /// given by `nulls_first`. Errors raised when comparing objects, e.g. of unorderable types, are returned.
+    pub fn stable_argsort(&self, descending: bool, nulls_first: bool) -> DaftResult<UInt64Array> {
+        use pyo3::Python;
+
+        let arrow_array = self.as_arrow();
+        let values = arrow_array.values();
+        Python::with_gil(|py| {
+            let error = std::cell::RefCell::new(None);
+            let result = stable_argsort_by(
+                self.name(),
+                self.len(),
+                descending,
+                nulls_first,
+                |i| arrow_array.is_valid(i),
+                |a, b| {
+                    values[a]
+                        .as_ref(py)
+                        .compare(values[b].as_ref(py))
+                        .unwrap_or_else(|err| {
+                            error.borrow_mut().get_or_insert(err);
+                            std::cmp::Ordering::Equal
+                        })
+                },
+            );
+            match error.into_inner() {
+                Some(err) => Err(err.into()),
+                None => Ok(result),
+            }
+        })
+    }
+}
+
+impl<T> DataArray<T>
+where
+    T: DaftNumericType,
+{
+    /// Sorts this array stably, placing nulls first or last as given by `nulls_first`.
+    pub fn stable_sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+        self.take(&self.stable_argsort(descending, nulls_first)?)
+    }
+}
+
+macro_rules! impl_stable_sort {
+    ($da:ty) => {
+        impl $da {
+            /// Sorts this array stably, placing nulls first or last as given by `nulls_first`.
+            pub fn stable_sort(&self, descending: bool, nulls_first: bool) -> DaftResult<Self> {
+                self.take(&self.stable_argsort(descending, nulls_first)?)
+            }
+        }
+    };
+}
+
+impl_stable_sort!(BinaryArray);
+impl_stable_sort!(Utf8Array);
+#[cfg(feature = "python")]
+impl_stable_sort!(PythonArray);
+
 impl<T> DataArray<T>
 where
     T: DaftIntegerType,
@@ -594,8 +801,8 @@ impl ExtensionArray {
 
 #[cfg(feature = "python")]
 impl PythonArray {
-    pub fn sort(&self, _descending: bool) -> DaftResult<Self> {
-        todo!("impl sort for python array")
+    pub fn sort(&self, descending: bool) -> DaftResult<Self> {
+        self.stable_sort(descending, descending)
     }
 }
 
@@ -627,6 +834,13 @@ impl DurationArray {
     }
 }
 
+impl IntervalArray {
+    pub fn sort(&self, descending: bool) -> DaftResult<Self> {
+        let new_array = self.physical.sort(descending)?;
+        Ok(Self::new(self.field.clone(), new_array))
+    }
+}
+
 impl TimestampArray {
     pub fn sort(&self, descending: bool) -> DaftResult<Self> {
         let new_array = self.physical.sort(descending)?;
@@ -663,3 +877,111 @@ impl FixedShapeTensorArray {
         todo!("impl sort for FixedShapeTensorArray")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use super::stable_argsort_multikey;
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{Float64Array, Int64Array, UInt64Array, Utf8Array},
+        series::IntoSeries,
+    };
+
+    fn indices(array: &UInt64Array) -> Vec<u64> {
+        array.as_arrow().values().to_vec()
+    }
+
+    #[test]
+    fn stable_argsort_keeps_order_of_equal_keys() -> DaftResult<()> {
+        let ints = Int64Array::from(("i", vec![2, 1, 2, 1, 0]));
+        assert_eq!(
+            indices(&ints.stable_argsort(false, false)?),
+            [4, 1, 3, 0, 2]
+        );
+        assert_eq!(indices(&ints.stable_argsort(true, false)?), [0, 2, 1, 3, 4]);
+
+        let strings = Utf8Array::from(("s", ["b", "a", "b", "a"].as_slice()));
+        assert_eq!(
+            indices(&strings.stable_argsort(false, false)?),
+            [1, 3, 0, 2]
+        );
+        assert_eq!(indices(&strings.stable_argsort(true, false)?), [0, 2, 1, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn stable_argsort_null_placement() -> DaftResult<()> {
+        let floats = Float64Array::from_iter(
+            "f",
+            vec![None, Some(1.0), Some(f64::NAN), None, Some(-1.0)].into_iter(),
+        );
+        assert_eq!(
+            indices(&floats.stable_argsort(false, false)?),
+            [4, 1, 2, 0, 3]
+        );
+        assert_eq!(
+            indices(&floats.stable_argsort(false, true)?),
+            [0, 3, 4, 1, 2]
+        );
+        assert_eq!(
+            indices(&floats.stable_argsort(true, false)?),
+            [2, 1, 4, 0, 3]
+        );
+        assert_eq!(
+            indices(&floats.stable_argsort(true, true)?),
+            [0, 3, 2, 1, 4]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn stable_argsort_multiple_keys() -> DaftResult<()> {
+        let first =
+            Int64Array::from_iter("a", vec![Some(1), None, Some(1), Some(0), None].into_iter());
+        let second = Utf8Array::from(("b", ["x", "y", "z", "x", "x"].as_slice()));
+        let result = stable_argsort_multikey(
+            &[first.into_series(), second.into_series()],
+            &[false, true],
+            &[true, false],
+        )?;
+        assert_eq!(result.name(), "a");
+        assert_eq!(indices(&result), [1, 4, 3, 2, 0]);
+        Ok(())
+    }
+
+    #[test]
+    fn stable_sort_values() -> DaftResult<()> {
+        let ints = Int64Array::from_iter("i", vec![Some(2), None, Some(0), Some(1)].into_iter());
+        let values = |a: &Int64Array| a.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(
+            values(&ints.stable_sort(false, false)?),
+            [Some(0), Some(1), Some(2), None]
+        );
+        assert_eq!(
+            values(&ints.stable_sort(true, true)?),
+            [None, Some(2), Some(1), Some(0)]
+        );
+        assert_eq!(ints.stable_sort(false, false)?.name(), "i");
+
+        let strings = Utf8Array::from((
+            "s",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("b"),
+                None,
+                Some("a"),
+            ])),
+        ));
+        let values = |a: &Utf8Array| a.as_arrow().iter().collect::<Vec<_>>();
+        assert_eq!(
+            values(&strings.stable_sort(false, true)?),
+            [None, Some("a"), Some("b")]
+        );
+        assert_eq!(
+            values(&strings.stable_sort(true, false)?),
+            [Some("b"), Some("a"), None]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{array::DataArray, datatypes::*};
+
+use common_error::DaftResult;
+
+use super::{as_arrow::AsArrow, DaftStringAggable, GroupIndices};
+
+/// Joins the non-null `values` with `separator`, or returns null if there are none.
+fn join_valid<'a>(
+    values: impl Iterator<Item = Option<&'a str>>,
+    separator: &str,
+) -> Option<String> {
+    let mut values = values.flatten();
+    let first = values.next()?;
+    let mut joined = first.to_string();
+    for value in values {
+        joined.push_str(separator);
+        joined.push_str(value);
+    }
+    Some(joined)
+}
+
+impl DaftStringAggable for &DataArray<Utf8Type> {
+    type Output = DaftResult<DataArray<Utf8Type>>;
+
+    fn string_agg(&self, separator: &str) -> Self::Output {
+        let joined = join_valid(self.as_arrow().iter(), separator);
+        let arrow_array = arrow2::array::Utf8Array::<i64>::from([joined]);
+        Ok(DataArray::from((self.name(), Box::new(arrow_array))))
+    }
+
+    fn grouped_string_agg(&self, groups: &GroupIndices, separator: &str) -> Self::Output {
+        let arrow_array = self.as_arrow();
+        let joined_per_group = groups
+            .iter()
+            .map(|g| join_valid(g.iter().map(|i| arrow_array.get(*i as usize)), separator));
+        let arrow_array = arrow2::array::Utf8Array::<i64>::from_iter(joined_per_group);
+        Ok(DataArray::from((self.name(), Box::new(arrow_array))))
+    }
+}
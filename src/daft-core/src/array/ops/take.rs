@@ -6,7 +6,8 @@ use crate::{
     datatypes::{
         logical::{
             DateArray, Decimal128Array, DurationArray, EmbeddingArray, FixedShapeImageArray,
-            FixedShapeTensorArray, ImageArray, MapArray, TensorArray, TimeArray, TimestampArray,
+            FixedShapeTensorArray, ImageArray, IntervalArray, MapArray, TensorArray, TimeArray,
+            TimestampArray,
         },
         BinaryArray, BooleanArray, DaftIntegerType, DaftNumericType, ExtensionArray, NullArray,
         Utf8Array,
@@ -14,10 +15,57 @@ use crate::{
     DataType,
 };
 use arrow2::types::Index;
-use common_error::DaftResult;
+use common_error::{DaftError, DaftResult};
 
 use super::as_arrow::AsArrow;
 
+/// Checks that every non-null index in `idx` can be taken from an array of length `len`.
+fn check_take_bounds<I>(idx: &DataArray<I>, len: usize) -> DaftResult<()>
+where
+    I: DaftIntegerType,
+    <I as DaftNumericType>::Native: arrow2::types::Index,
+{
+    match idx
+        .as_arrow()
+        .iter()
+        .flatten()
+        .find(|i| i.to_usize() >= len)
+    {
+        Some(i) => Err(DaftError::ValueError(format!(
+            "Take index {} is out of bounds for an array of length {len}",
+            i.to_usize()
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Fills `growable`, which copies from the single array being taken from, with the rows at `idx`.
+/// Null indices give null rows, and runs of consecutive indices are copied with a single extend.
+fn extend_with_indices<I>(growable: &mut impl Growable, idx: &DataArray<I>)
+where
+    I: DaftIntegerType,
+    <I as DaftNumericType>::Native: arrow2::types::Index,
+{
+    let mut run: Option<(usize, usize)> = None;
+    for i in idx {
+        match (i.map(|i| i.to_usize()), run) {
+            (Some(i), Some((start, len))) if start + len == i => run = Some((start, len + 1)),
+            (i, _) => {
+                if let Some((start, len)) = run.take() {
+                    growable.extend(0, start, len);
+                }
+                match i {
+                    Some(i) => run = Some((i, 1)),
+                    None => growable.add_nulls(1),
+                }
+            }
+        }
+    }
+    if let Some((start, len)) = run {
+        growable.extend(0, start, len);
+    }
+}
+
 impl<T> DataArray<T>
 where
     T: DaftNumericType,
@@ -27,12 +75,14 @@ where
         I: DaftIntegerType,
         <I as DaftNumericType>::Native: arrow2::types::Index,
     {
+        check_take_bounds(idx, self.len())?;
         let result = arrow2::compute::take::take(self.data(), idx.as_arrow())?;
         Self::try_from((self.field.clone(), result))
     }
 }
 
-// Default implementations of take op for DataArray and LogicalArray.
+// Take op for DataArrays which gathers rows with the array's Growable, so that Python and extension arrays
+// are handled the same way as arrow-backed ones.
 macro_rules! impl_dataarray_take {
     ($ArrayT:ty) => {
         impl $ArrayT {
@@ -41,8 +91,16 @@ macro_rules! impl_dataarray_take {
                 I: DaftIntegerType,
                 <I as DaftNumericType>::Native: arrow2::types::Index,
             {
-                let result = arrow2::compute::take::take(self.data(), idx.as_arrow())?;
-                Self::try_from((self.field.clone(), result))
+                check_take_bounds(idx, self.len())?;
+                let mut growable = <$ArrayT as GrowableArray>::make_growable(
+                    self.name(),
+                    self.data_type(),
+                    vec![self],
+                    idx.data().null_count() > 0,
+                    idx.len(),
+                );
+                extend_with_indices(&mut growable, idx);
+                Ok(growable.build()?.downcast::<$ArrayT>()?.clone())
             }
         }
     };
@@ -68,10 +126,13 @@ impl_dataarray_take!(BooleanArray);
 impl_dataarray_take!(BinaryArray);
 impl_dataarray_take!(NullArray);
 impl_dataarray_take!(ExtensionArray);
+#[cfg(feature = "python")]
+impl_dataarray_take!(crate::datatypes::PythonArray);
 impl_logicalarray_take!(Decimal128Array);
 impl_logicalarray_take!(DateArray);
 impl_logicalarray_take!(TimeArray);
 impl_logicalarray_take!(DurationArray);
+impl_logicalarray_take!(IntervalArray);
 impl_logicalarray_take!(TimestampArray);
 impl_logicalarray_take!(EmbeddingArray);
 impl_logicalarray_take!(ImageArray);
@@ -80,76 +141,13 @@ impl_logicalarray_take!(TensorArray);
 impl_logicalarray_take!(FixedShapeTensorArray);
 impl_logicalarray_take!(MapArray);
 
-#[cfg(feature = "python")]
-impl crate::datatypes::PythonArray {
-    pub fn take<I>(&self, idx: &DataArray<I>) -> DaftResult<Self>
-    where
-        I: DaftIntegerType,
-        <I as DaftNumericType>::Native: arrow2::types::Index,
-    {
-        use crate::array::pseudo_arrow::PseudoArrowArray;
-        use crate::datatypes::PythonType;
-
-        use arrow2::array::Array;
-        use pyo3::prelude::*;
-
-        let indices = idx.as_arrow();
-
-        let old_values = self.as_arrow().values();
-
-        // Execute take on the data values, ignoring validity.
-        let new_values: Vec<PyObject> = {
-            let py_none = Python::with_gil(|py: Python| py.None());
-
-            indices
-                .iter()
-                .map(|maybe_idx| match maybe_idx {
-                    Some(idx) => old_values[arrow2::types::Index::to_usize(idx)].clone(),
-                    None => py_none.clone(),
-                })
-                .collect()
-        };
-
-        // Execute take on the validity bitmap using arrow2::compute.
-        let new_validity = {
-            self.as_arrow()
-                .validity()
-                .map(|old_validity| {
-                    let old_validity_array = {
-                        &arrow2::array::BooleanArray::new(
-                            arrow2::datatypes::DataType::Boolean,
-                            old_validity.clone(),
-                            None,
-                        )
-                    };
-                    arrow2::compute::take::take(old_validity_array, indices)
-                })
-                .transpose()?
-                .map(|new_validity_dynarray| {
-                    let new_validity_iter = new_validity_dynarray
-                        .as_any()
-                        .downcast_ref::<arrow2::array::BooleanArray>()
-                        .unwrap()
-                        .iter();
-                    arrow2::bitmap::Bitmap::from_iter(
-                        new_validity_iter.map(|valid| valid.unwrap_or(false)),
-                    )
-                })
-        };
-
-        let arrow_array: Box<dyn arrow2::array::Array> =
-            Box::new(PseudoArrowArray::new(new_values.into(), new_validity));
-
-        DataArray::<PythonType>::new(self.field().clone().into(), arrow_array)
-    }
-}
-
 impl FixedSizeListArray {
     pub fn take<I>(&self, idx: &DataArray<I>) -> DaftResult<Self>
     where
         I: DaftIntegerType,
         <I as DaftNumericType>::Native: arrow2::types::Index,
     {
+        check_take_bounds(idx, self.len())?;
         let mut growable = FixedSizeListArray::make_growable(
             self.name(),
             self.data_type(),
@@ -157,18 +155,7 @@ impl FixedSizeListArray {
             idx.data().null_count() > 0,
             idx.len(),
         );
-
-        for i in idx {
-            match i {
-                None => {
-                    growable.add_nulls(1);
-                }
-                Some(i) => {
-                    growable.extend(0, i.to_usize(), 1);
-                }
-            }
-        }
-
+        extend_with_indices(&mut growable, idx);
         Ok(growable.build()?.downcast::<FixedSizeListArray>()?.clone())
     }
 }
@@ -179,6 +166,7 @@ impl ListArray {
         I: DaftIntegerType,
         <I as DaftNumericType>::Native: arrow2::types::Index,
     {
+        check_take_bounds(idx, self.len())?;
         let child_capacity = idx
             .as_arrow()
             .iter()
@@ -198,18 +186,7 @@ impl ListArray {
             idx.len(),
             child_capacity,
         );
-
-        for i in idx {
-            match i {
-                None => {
-                    growable.add_nulls(1);
-                }
-                Some(i) => {
-                    growable.extend(0, i.to_usize(), 1);
-                }
-            }
-        }
-
+        extend_with_indices(&mut growable, idx);
         Ok(growable.build()?.downcast::<ListArray>()?.clone())
     }
 }
@@ -220,6 +197,7 @@ impl StructArray {
         I: DaftIntegerType,
         <I as DaftNumericType>::Native: arrow2::types::Index,
     {
+        check_take_bounds(idx, self.len())?;
         let idx_as_u64 = idx.cast(&DataType::UInt64)?;
         let taken_validity = self.validity().map(|v| {
             arrow2::bitmap::Bitmap::from_iter(idx.into_iter().map(|i| match i {
@@ -237,3 +215,69 @@ impl StructArray {
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::{DaftError, DaftResult};
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{BooleanArray, Int64Array, UInt64Array, Utf8Array},
+    };
+
+    fn indices(values: Vec<Option<u64>>) -> UInt64Array {
+        UInt64Array::from_iter("idx", values.into_iter())
+    }
+
+    #[test]
+    fn take_reversed_with_null_indices() -> DaftResult<()> {
+        let strings = Utf8Array::from((
+            "s",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("a"),
+                None,
+                Some("c"),
+                Some("d"),
+            ])),
+        ));
+        let taken = strings.take(&indices(vec![Some(3), Some(2), None, Some(1), Some(0)]))?;
+        assert_eq!(taken.name(), "s");
+        assert_eq!(
+            taken.as_arrow().iter().collect::<Vec<_>>(),
+            [Some("d"), Some("c"), None, None, Some("a")]
+        );
+
+        let bools = BooleanArray::from(("b", [true, false, true].as_slice()));
+        let taken = bools.take(&indices(vec![Some(1), Some(2), Some(0), Some(1), None]))?;
+        assert_eq!(
+            taken.as_arrow().iter().collect::<Vec<_>>(),
+            [Some(false), Some(true), Some(true), Some(false), None]
+        );
+
+        let ints = Int64Array::from(("i", vec![10, 20, 30]));
+        let taken = ints.take(&indices(vec![Some(2), None, Some(0)]))?;
+        assert_eq!(
+            taken
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            [Some(30), None, Some(10)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn take_out_of_bounds() {
+        let ints = Int64Array::from(("i", vec![10, 20, 30]));
+        assert!(matches!(
+            ints.take(&indices(vec![Some(0), Some(3)])),
+            Err(DaftError::ValueError(_))
+        ));
+        let strings = Utf8Array::from(("s", ["a"].as_slice()));
+        assert!(matches!(
+            strings.take(&indices(vec![None, Some(1)])),
+            Err(DaftError::ValueError(_))
+        ));
+    }
+}
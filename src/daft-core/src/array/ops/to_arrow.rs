@@ -0,0 +1,109 @@
+use crate::{
+    array::{DataArray, FixedSizeListArray, ListArray, StructArray},
+    datatypes::{
+        logical::{LogicalArrayImpl, MapArray},
+        DaftLogicalType, DaftPhysicalType,
+    },
+};
+
+/// Arrays that implement [`ToArrow`] can be converted into a Box<dyn arrow2::array::Array>, as the inverse of
+/// [`FromArrow`](super::from_arrow::FromArrow). Logical arrays are converted from their physical representation,
+/// labelled with the arrow2 datatype of their logical type.
+pub trait ToArrow {
+    fn to_arrow(&self) -> Box<dyn arrow2::array::Array>;
+}
+
+impl<T: DaftPhysicalType> ToArrow for DataArray<T> {
+    fn to_arrow(&self) -> Box<dyn arrow2::array::Array> {
+        self.data().to_boxed()
+    }
+}
+
+macro_rules! impl_to_arrow_with_inherent_method {
+    ($array_type:ty) => {
+        impl ToArrow for $array_type {
+            fn to_arrow(&self) -> Box<dyn arrow2::array::Array> {
+                // Inherent methods take precedence over trait methods, so this doesn't recurse
+                <$array_type>::to_arrow(self)
+            }
+        }
+    };
+}
+
+impl_to_arrow_with_inherent_method!(ListArray);
+impl_to_arrow_with_inherent_method!(FixedSizeListArray);
+impl_to_arrow_with_inherent_method!(StructArray);
+impl_to_arrow_with_inherent_method!(MapArray);
+
+impl<L: DaftLogicalType> ToArrow for LogicalArrayImpl<L, DataArray<L::PhysicalType>> {
+    fn to_arrow(&self) -> Box<dyn arrow2::array::Array> {
+        LogicalArrayImpl::<L, DataArray<L::PhysicalType>>::to_arrow(self)
+    }
+}
+
+impl<L: DaftLogicalType> ToArrow for LogicalArrayImpl<L, FixedSizeListArray> {
+    fn to_arrow(&self) -> Box<dyn arrow2::array::Array> {
+        LogicalArrayImpl::<L, FixedSizeListArray>::to_arrow(self)
+    }
+}
+
+impl<L: DaftLogicalType> ToArrow for LogicalArrayImpl<L, StructArray> {
+    fn to_arrow(&self) -> Box<dyn arrow2::array::Array> {
+        LogicalArrayImpl::<L, StructArray>::to_arrow(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow2::array::{Int64Array, ListArray as ArrowListArray, Utf8Array as ArrowUtf8Array};
+    use common_error::DaftResult;
+
+    use crate::{
+        array::{
+            ops::{from_arrow::FromArrow, to_arrow::ToArrow},
+            ListArray,
+        },
+        datatypes::{
+            logical::TimestampArray, Field, Int64Array as DaftInt64Array, TimeUnit, Utf8Array,
+        },
+        DataType,
+    };
+
+    #[test]
+    fn test_to_arrow_round_trip() -> DaftResult<()> {
+        let ints = Int64Array::from(vec![Some(0), None, Some(2)]).boxed();
+        let arr =
+            DaftInt64Array::from_arrow(Arc::new(Field::new("foo", DataType::Int64)), ints.clone())?;
+        assert_eq!(ToArrow::to_arrow(&arr), ints);
+
+        let strings = ArrowUtf8Array::<i64>::from(vec![Some("a"), None, Some("ccc")]).boxed();
+        let arr =
+            Utf8Array::from_arrow(Arc::new(Field::new("foo", DataType::Utf8)), strings.clone())?;
+        assert_eq!(ToArrow::to_arrow(&arr), strings);
+
+        // Logical arrays are lowered to their physical values, but keep their logical arrow2 datatype
+        let timestamp_dtype = DataType::Timestamp(TimeUnit::Microseconds, None);
+        let timestamps = Int64Array::from(vec![Some(0), None, Some(1_000_000)])
+            .to(timestamp_dtype.to_arrow()?)
+            .boxed();
+        let arr = TimestampArray::from_arrow(
+            Arc::new(Field::new("foo", timestamp_dtype)),
+            timestamps.clone(),
+        )?;
+        assert_eq!(ToArrow::to_arrow(&arr), timestamps);
+
+        let list_dtype = DataType::List(Box::new(DataType::Int64));
+        let lists = ArrowListArray::<i64>::new(
+            list_dtype.to_arrow()?,
+            arrow2::offset::OffsetsBuffer::try_from(vec![0i64, 2, 2, 3])?,
+            ints,
+            Some(arrow2::bitmap::Bitmap::from(&[true, false, true])),
+        )
+        .boxed();
+        let arr = ListArray::from_arrow(Arc::new(Field::new("foo", list_dtype)), lists.clone())?;
+        assert_eq!(ToArrow::to_arrow(&arr), lists);
+        Ok(())
+    }
+}
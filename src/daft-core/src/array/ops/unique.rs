@@ -0,0 +1,106 @@
+use std::hash::Hash;
+
+use common_error::DaftResult;
+
+use crate::{
+    array::DataArray,
+    datatypes::{
+        BinaryArray, BooleanArray, DaftIntegerType, DaftNumericType, Float32Array, Float64Array,
+        UInt64Array, Utf8Array,
+    },
+};
+
+use super::IntoGroups;
+
+/// Index of the first row of each distinct value of `array`, in the order those values are first seen
+fn first_seen_indices(array: &impl IntoGroups) -> DaftResult<UInt64Array> {
+    let (mut first_indices, _) = array.make_groups()?;
+    first_indices.sort_unstable();
+    Ok(UInt64Array::from(("", first_indices)))
+}
+
+impl<T> DataArray<T>
+where
+    T: DaftIntegerType,
+    <T as DaftNumericType>::Native: Ord + Hash + Eq,
+{
+    /// Distinct values of this array in the order they are first seen, with at most one null
+    pub fn unique(&self) -> DaftResult<Self> {
+        self.take(&first_seen_indices(self)?)
+    }
+}
+
+macro_rules! impl_unique {
+    ($ArrayT:ty) => {
+        impl $ArrayT {
+            /// Distinct values of this array in the order they are first seen, with at most one null
+            pub fn unique(&self) -> DaftResult<Self> {
+                self.take(&first_seen_indices(self)?)
+            }
+        }
+    };
+}
+
+// Floats are deduplicated by their bits, with all NaNs as a single value
+impl_unique!(Float32Array);
+impl_unique!(Float64Array);
+impl_unique!(Utf8Array);
+impl_unique!(BinaryArray);
+impl_unique!(BooleanArray);
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{Float64Array, Int64Array, Utf8Array},
+    };
+
+    #[test]
+    fn unique_keeps_first_seen_order() -> DaftResult<()> {
+        let ints = Int64Array::from_iter(
+            "i",
+            vec![Some(3), None, Some(1), Some(3), None, Some(2), Some(1)].into_iter(),
+        );
+        let unique = ints.unique()?;
+        assert_eq!(unique.name(), "i");
+        assert_eq!(
+            unique.into_iter().map(|v| v.copied()).collect::<Vec<_>>(),
+            [Some(3), None, Some(1), Some(2)]
+        );
+
+        let strings = Utf8Array::from(("s", ["b", "a", "b", "c", "a"].as_slice()));
+        assert_eq!(
+            strings.unique()?.as_arrow().iter().collect::<Vec<_>>(),
+            [Some("b"), Some("a"), Some("c")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unique_floats() -> DaftResult<()> {
+        let floats = Float64Array::from_iter(
+            "f",
+            vec![Some(1.5), Some(f64::NAN), None, Some(1.5), Some(f64::NAN)].into_iter(),
+        );
+        let unique = floats.unique()?.as_arrow().iter().collect::<Vec<_>>();
+        assert_eq!(unique.len(), 3);
+        assert_eq!(unique[0], Some(&1.5));
+        assert!(unique[1].unwrap().is_nan());
+        assert_eq!(unique[2], None);
+        Ok(())
+    }
+
+    #[test]
+    fn unique_all_null() -> DaftResult<()> {
+        let nulls = Int64Array::from_iter("i", vec![None, None, None].into_iter());
+        let unique = nulls.unique()?;
+        assert_eq!(unique.len(), 1);
+        assert_eq!(unique.null_count(), 1);
+        assert!(Int64Array::from(("i", Vec::<i64>::new()))
+            .unique()?
+            .is_empty());
+        Ok(())
+    }
+}
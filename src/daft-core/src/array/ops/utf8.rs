@@ -3,12 +3,17 @@ use std::borrow::Cow;
 use crate::{
     array::{DataArray, ListArray},
     datatypes::{
-        BooleanArray, DaftIntegerType, DaftNumericType, DaftPhysicalType, Field, Int64Array,
-        UInt64Array, Utf8Array,
+        logical::{DateArray, TimestampArray},
+        BooleanArray, DaftIntegerType, DaftNumericType, DaftPhysicalType, Field, Int32Array,
+        Int64Array, TimeUnit, UInt64Array, Utf8Array,
     },
     DataType, Series,
 };
 use arrow2::{self, array::Array};
+use chrono::{
+    format::{Item, StrftimeItems},
+    DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc,
+};
 
 use common_error::{DaftError, DaftResult};
 use num_traits::NumCast;
@@ -268,6 +273,8 @@ fn replace_on_literal<'a>(
 }
 
 impl Utf8Array {
+    /// Whether each string ends with the literal (not regex) `pattern`, which is either a single pattern for all rows or
+    /// one per row. An empty pattern matches every string, and null strings or patterns give null.
     pub fn endswith(&self, pattern: &Utf8Array) -> DaftResult<BooleanArray> {
         self.binary_broadcasted_compare(
             pattern,
@@ -276,6 +283,8 @@ impl Utf8Array {
         )
     }
 
+    /// Whether each string starts with the literal (not regex) `pattern`, which is either a single pattern for all rows or
+    /// one per row. An empty pattern matches every string, and null strings or patterns give null.
     pub fn startswith(&self, pattern: &Utf8Array) -> DaftResult<BooleanArray> {
         self.binary_broadcasted_compare(
             pattern,
@@ -284,6 +293,8 @@ impl Utf8Array {
         )
     }
 
+    /// Whether each string contains the literal (not regex) `pattern`, which is either a single pattern for all rows or
+    /// one per row. An empty pattern matches every string, and null strings or patterns give null.
     pub fn contains(&self, pattern: &Utf8Array) -> DaftResult<BooleanArray> {
         self.binary_broadcasted_compare(
             pattern,
@@ -347,8 +358,9 @@ impl Utf8Array {
         let self_iter = create_broadcasted_str_iter(self, expected_size);
         match (regex, pattern.len()) {
             (true, 1) => {
-                let regex = regex::Regex::new(pattern.get(0).unwrap());
-                let regex_iter = std::iter::repeat(Some(regex)).take(expected_size);
+                // Compile the pattern once, so that an invalid regex errors even if no row needs it
+                let regex = regex::Regex::new(pattern.get(0).unwrap())?;
+                let regex_iter = std::iter::repeat(Some(Ok(regex))).take(expected_size);
                 split_array_on_regex(
                     self_iter,
                     regex_iter,
@@ -400,6 +412,41 @@ impl Utf8Array {
         Ok(result)
     }
 
+    /// Splits each string on every occurrence of the literal `delimiter` into a list of substrings. Consecutive,
+    /// leading and trailing delimiters produce empty strings in the list when `keep_empty` is set, and are otherwise
+    /// dropped along with any other empty substrings. Null strings give null lists.
+    pub fn split_literal(&self, delimiter: &str, keep_empty: bool) -> DaftResult<ListArray> {
+        if delimiter.is_empty() {
+            return Err(DaftError::ValueError(
+                "Error in split_literal: delimiter must not be empty".to_string(),
+            ));
+        }
+
+        let self_arrow = self.as_arrow();
+        let mut splits = arrow2::array::MutableUtf8Array::with_capacity(self.len());
+        let mut offsets = arrow2::offset::Offsets::with_capacity(self.len());
+        for val in self_arrow.iter() {
+            let mut num_splits = 0i64;
+            if let Some(val) = val {
+                for split in val.split(delimiter) {
+                    if keep_empty || !split.is_empty() {
+                        splits.push(Some(split));
+                        num_splits += 1;
+                    }
+                }
+            }
+            offsets.try_push(num_splits)?;
+        }
+        let splits: arrow2::array::Utf8Array<i64> = splits.into();
+        let flat_child = Series::try_from(("splits", splits.to_boxed()))?;
+        Ok(ListArray::new(
+            Field::new(self.name(), DataType::List(Box::new(DataType::Utf8))),
+            flat_child,
+            offsets.into(),
+            self_arrow.validity().cloned(),
+        ))
+    }
+
     pub fn extract(&self, pattern: &Utf8Array, index: usize) -> DaftResult<Utf8Array> {
         let (is_full_null, expected_size) = parse_inputs(self, &[pattern])
             .map_err(|e| DaftError::ValueError(format!("Error in extract: {e}")))?;
@@ -417,8 +464,9 @@ impl Utf8Array {
         let self_iter = create_broadcasted_str_iter(self, expected_size);
         let result = match pattern.len() {
             1 => {
-                let regex = regex::Regex::new(pattern.get(0).unwrap());
-                let regex_iter = std::iter::repeat(Some(regex)).take(expected_size);
+                // Compile the pattern once, so that an invalid regex errors even if no row needs it
+                let regex = regex::Regex::new(pattern.get(0).unwrap())?;
+                let regex_iter = std::iter::repeat(Some(Ok(regex))).take(expected_size);
                 regex_extract_first_match(self_iter, regex_iter, index, self.name())?
             }
             _ => {
@@ -453,8 +501,9 @@ impl Utf8Array {
         let self_iter = create_broadcasted_str_iter(self, expected_size);
         let result = match pattern.len() {
             1 => {
-                let regex = regex::Regex::new(pattern.get(0).unwrap());
-                let regex_iter = std::iter::repeat(Some(regex)).take(expected_size);
+                // Compile the pattern once, so that an invalid regex errors even if no row needs it
+                let regex = regex::Regex::new(pattern.get(0).unwrap())?;
+                let regex_iter = std::iter::repeat(Some(Ok(regex))).take(expected_size);
                 regex_extract_all_matches(self_iter, regex_iter, index, expected_size, self.name())?
             }
             _ => {
@@ -469,6 +518,33 @@ impl Utf8Array {
         Ok(result)
     }
 
+    /// Whether each string matches the regular expression `pattern`, which is compiled once for all rows
+    pub fn regex_match(&self, pattern: &str) -> DaftResult<BooleanArray> {
+        let re = regex::Regex::new(pattern)?;
+        let arrow_result: arrow2::array::BooleanArray = self
+            .as_arrow()
+            .into_iter()
+            .map(|self_v| Some(re.is_match(self_v?)))
+            .collect();
+        Ok(BooleanArray::from((self.name(), arrow_result)))
+    }
+
+    /// Capture group `group` of the first match of the regular expression `pattern` in each string, where group 0 is
+    /// the whole match. Rows without a match (or where the group did not participate in the match) are null.
+    pub fn regex_extract(&self, pattern: &str, group: usize) -> DaftResult<Utf8Array> {
+        let re = regex::Regex::new(pattern)?;
+        if group >= re.captures_len() {
+            return Err(DaftError::ValueError(format!(
+                "Error in regex_extract: pattern {pattern:?} has no capture group {group}"
+            )));
+        }
+        let regex_iter = std::iter::repeat(Some(Ok(re))).take(self.len());
+        regex_extract_first_match(self.as_arrow().iter(), regex_iter, group, self.name())
+    }
+
+    /// Replaces all occurrences of `pattern` in each string with `replacement`, where each is either a single value
+    /// for all rows or one per row. When `regex` is set, `pattern` is a regular expression, and `replacement` can
+    /// refer to its capture groups with `$1` or `${name}`. Null strings, patterns or replacements give null.
     pub fn replace(
         &self,
         pattern: &Utf8Array,
@@ -493,8 +569,9 @@ impl Utf8Array {
 
         let result = match (regex, pattern.len()) {
             (true, 1) => {
-                let regex = regex::Regex::new(pattern.get(0).unwrap());
-                let regex_iter = std::iter::repeat(Some(regex)).take(expected_size);
+                // Compile the pattern once, so that an invalid regex errors even if no row needs it
+                let regex = regex::Regex::new(pattern.get(0).unwrap())?;
+                let regex_iter = std::iter::repeat(Some(Ok(regex))).take(expected_size);
                 regex_replace(self_iter, regex_iter, replacement_iter, self.name())?
             }
             (true, _) => {
@@ -526,6 +603,20 @@ impl Utf8Array {
         Ok(UInt64Array::from((self.name(), Box::new(arrow_result))))
     }
 
+    /// Number of Unicode characters in each string, unlike [`Utf8Array::length`] which counts bytes
+    pub fn length_chars(&self) -> DaftResult<UInt64Array> {
+        let self_arrow = self.as_arrow();
+        let arrow_result = self_arrow
+            .iter()
+            .map(|val| {
+                let v = val?;
+                Some(v.chars().count() as u64)
+            })
+            .collect::<arrow2::array::UInt64Array>()
+            .with_validity(self_arrow.validity().cloned());
+        Ok(UInt64Array::from((self.name(), Box::new(arrow_result))))
+    }
+
     pub fn lower(&self) -> DaftResult<Utf8Array> {
         self.unary_broadcasted_op(|val| val.to_lowercase().into())
     }
@@ -542,6 +633,11 @@ impl Utf8Array {
         self.unary_broadcasted_op(|val| val.trim_end().into())
     }
 
+    /// Removes leading and trailing whitespace, as defined by the Unicode `White_Space` property
+    pub fn strip(&self) -> DaftResult<Utf8Array> {
+        self.unary_broadcasted_op(|val| val.trim().into())
+    }
+
     pub fn reverse(&self) -> DaftResult<Utf8Array> {
         self.unary_broadcasted_op(|val| val.chars().rev().collect::<String>().into())
     }
@@ -724,6 +820,147 @@ impl Utf8Array {
         Ok(result)
     }
 
+    /// Substring of each string starting at the character index `start` and holding up to `length` characters,
+    /// or the rest of the string if `length` is None. Starts past the end of a string give an empty string.
+    pub fn substr(&self, start: usize, length: Option<usize>) -> DaftResult<Utf8Array> {
+        /// Byte offset of the character at index `nchars`, or the length of `val` if it has fewer characters
+        fn char_offset(val: &str, nchars: usize) -> usize {
+            val.char_indices().nth(nchars).map_or(val.len(), |(i, _)| i)
+        }
+
+        self.unary_broadcasted_op(|val| {
+            let rest = &val[char_offset(val, start)..];
+            match length {
+                Some(length) => rest[..char_offset(rest, length)].into(),
+                None => rest.into(),
+            }
+        })
+    }
+
+    /// Parses each string as a date with the chrono format string `format`, e.g. `"%Y-%m-%d"`. Strings which don't
+    /// match the format are null, or an error if `strict` is set.
+    pub fn to_date(&self, format: &str, strict: bool) -> DaftResult<DateArray> {
+        let epoch_date = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        let physical = self.parse_temporal("date", format, strict, |val| {
+            NaiveDate::parse_from_str(val, format)
+                .ok()
+                .map(|date| (date - epoch_date).num_days() as i32)
+        })?;
+        Ok(DateArray::new(
+            Field::new(self.name(), DataType::Date),
+            Int32Array::from((self.name(), Box::new(physical))),
+        ))
+    }
+
+    /// Parses each string as a timestamp with the chrono format string `format`, e.g. `"%Y-%m-%d %H:%M:%S"`, giving
+    /// a microsecond timestamp in the timezone `tz`. Strings holding an offset (parsed with `%z`) are converted to
+    /// that timezone, while strings without one are taken as local times in `tz`, or as naive timestamps if `tz` is
+    /// None. Strings which don't match the format, or local times which don't exist in `tz`, are null, or an error if
+    /// `strict` is set.
+    pub fn to_timestamp(
+        &self,
+        format: &str,
+        tz: Option<String>,
+        strict: bool,
+    ) -> DaftResult<TimestampArray> {
+        /// Microseconds since the epoch of `val`, which is taken as a local time in `tz` unless it holds an offset
+        fn parse<Tz: TimeZone>(val: &str, format: &str, tz: &Tz) -> Option<i64> {
+            match DateTime::parse_from_str(val, format) {
+                Ok(datetime) => Some(datetime.timestamp_micros()),
+                Err(_) => tz
+                    .from_local_datetime(&NaiveDateTime::parse_from_str(val, format).ok()?)
+                    .single()
+                    .map(|datetime| datetime.timestamp_micros()),
+            }
+        }
+
+        let physical = match &tz {
+            None => {
+                self.parse_temporal("timestamp", format, strict, |val| parse(val, format, &Utc))?
+            }
+            Some(timezone) => {
+                if let Ok(offset) = arrow2::temporal_conversions::parse_offset(timezone) {
+                    self.parse_temporal("timestamp", format, strict, |val| {
+                        parse(val, format, &offset)
+                    })?
+                } else if let Ok(tz) = arrow2::temporal_conversions::parse_offset_tz(timezone) {
+                    self.parse_temporal("timestamp", format, strict, |val| parse(val, format, &tz))?
+                } else {
+                    return Err(DaftError::ValueError(format!(
+                        "Error in to_timestamp: cannot parse timezone {timezone:?}"
+                    )));
+                }
+            }
+        };
+        Ok(TimestampArray::new(
+            Field::new(self.name(), DataType::Timestamp(TimeUnit::Microseconds, tz)),
+            Int64Array::from((self.name(), Box::new(physical))),
+        ))
+    }
+
+    /// Parses each string into the physical value of a temporal type with `parse`, which returns None for strings that
+    /// don't match `format`. An invalid `format` is always an error, while unparseable strings are only an error if
+    /// `strict` is set, and are null otherwise.
+    fn parse_temporal<T: arrow2::types::NativeType>(
+        &self,
+        kind: &str,
+        format: &str,
+        strict: bool,
+        parse: impl Fn(&str) -> Option<T>,
+    ) -> DaftResult<arrow2::array::PrimitiveArray<T>> {
+        if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+            return Err(DaftError::ValueError(format!(
+                "Error in to_{kind}: invalid format string {format:?}"
+            )));
+        }
+        self.as_arrow()
+            .iter()
+            .map(|val| match val {
+                Some(val) => match parse(val) {
+                    Some(parsed) => Ok(Some(parsed)),
+                    None if strict => Err(DaftError::ValueError(format!(
+                        "Error in to_{kind}: cannot parse {val:?} with format {format:?}"
+                    ))),
+                    None => Ok(None),
+                },
+                None => Ok(None),
+            })
+            .collect()
+    }
+
+    /// Pads each string on the left with `fill` up to `width` characters. Strings longer than `width` are left
+    /// untouched, or cut down to their first `width` characters if `truncate` is set.
+    pub fn lpad(&self, width: usize, fill: char, truncate: bool) -> DaftResult<Utf8Array> {
+        self.pad(width, fill, truncate, true)
+    }
+
+    /// Pads each string on the right with `fill` up to `width` characters, see [`Utf8Array::lpad`].
+    pub fn rpad(&self, width: usize, fill: char, truncate: bool) -> DaftResult<Utf8Array> {
+        self.pad(width, fill, truncate, false)
+    }
+
+    fn pad(&self, width: usize, fill: char, truncate: bool, left: bool) -> DaftResult<Utf8Array> {
+        self.unary_broadcasted_op(|val| {
+            let nchars = val.chars().count();
+            if nchars >= width {
+                return match truncate && nchars > width {
+                    true => val.chars().take(width).collect::<String>().into(),
+                    false => val.into(),
+                };
+            }
+            let padding = std::iter::repeat(fill).take(width - nchars);
+            let mut res = String::with_capacity(val.len() + (width - nchars) * fill.len_utf8());
+            if left {
+                res.extend(padding);
+                res.push_str(val);
+            } else {
+                res.push_str(val);
+                res.extend(padding);
+            }
+            res.into()
+        })
+    }
+
     fn binary_broadcasted_compare<ScalarKernel>(
         &self,
         other: &Self,
@@ -826,4 +1063,515 @@ mod tests {
         assert!(result.as_arrow().value(2));
         Ok(())
     }
+
+    #[test]
+    fn check_length_chars() -> DaftResult<()> {
+        let data = Utf8Array::from((
+            "data",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("abc"),
+                Some("café"),
+                None,
+                Some("👍🏽!"),
+                Some(""),
+            ])),
+        ));
+        let chars = data.length_chars()?;
+        assert_eq!(chars.name(), "data");
+        assert_eq!(
+            chars
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(3), Some(4), None, Some(3), Some(0)]
+        );
+        // Multi-byte characters count once, unlike in the byte length
+        assert_eq!(
+            data.length()?
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(3), Some(5), None, Some(9), Some(0)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn check_substr() -> DaftResult<()> {
+        let data = Utf8Array::from((
+            "data",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("hello"),
+                Some("héllo wörld"),
+                None,
+                Some("日本語"),
+                Some(""),
+            ])),
+        ));
+        let substr = |start, length| -> DaftResult<Vec<Option<String>>> {
+            let result = data.substr(start, length)?;
+            assert_eq!(result.name(), "data");
+            Ok(result
+                .as_arrow()
+                .iter()
+                .map(|v| v.map(|v| v.to_string()))
+                .collect())
+        };
+        let strings = |values: &[Option<&str>]| -> Vec<Option<String>> {
+            values.iter().map(|v| v.map(|v| v.to_string())).collect()
+        };
+
+        assert_eq!(
+            substr(1, Some(3))?,
+            strings(&[Some("ell"), Some("éll"), None, Some("本語"), Some("")])
+        );
+        assert_eq!(
+            substr(2, None)?,
+            strings(&[Some("llo"), Some("llo wörld"), None, Some("語"), Some("")])
+        );
+        // Starts and lengths past the end are clamped to the end of each string
+        assert_eq!(
+            substr(3, Some(100))?,
+            strings(&[Some("lo"), Some("lo wörld"), None, Some(""), Some("")])
+        );
+        assert_eq!(
+            substr(100, Some(2))?,
+            strings(&[Some(""), Some(""), None, Some(""), Some("")])
+        );
+        assert_eq!(
+            substr(0, Some(0))?,
+            strings(&[Some(""), Some(""), None, Some(""), Some("")])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn check_regex_match_and_extract() -> DaftResult<()> {
+        let data = Utf8Array::from((
+            "data",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("key=value"),
+                Some("no match"),
+                None,
+                Some("a=1, b=2"),
+            ])),
+        ));
+        let pattern = r"(\w+)=(\w+)";
+
+        let matched = data.regex_match(pattern)?;
+        assert_eq!(matched.name(), "data");
+        assert_eq!(
+            matched.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some(true), Some(false), None, Some(true)]
+        );
+
+        let extract = |group| -> DaftResult<Vec<Option<String>>> {
+            let result = data.regex_extract(pattern, group)?;
+            assert_eq!(result.name(), "data");
+            Ok(result
+                .as_arrow()
+                .iter()
+                .map(|v| v.map(|v| v.to_string()))
+                .collect())
+        };
+        assert_eq!(
+            extract(0)?,
+            vec![
+                Some("key=value".to_string()),
+                None,
+                None,
+                Some("a=1".to_string())
+            ]
+        );
+        assert_eq!(
+            extract(2)?,
+            vec![Some("value".to_string()), None, None, Some("1".to_string())]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn check_regex_invalid_pattern() {
+        let data = Utf8Array::from((
+            "data",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![Some("abc")])),
+        ));
+        assert!(matches!(
+            data.regex_match("(unclosed"),
+            Err(DaftError::ValueError(_))
+        ));
+        assert!(matches!(
+            data.regex_extract("(unclosed", 0),
+            Err(DaftError::ValueError(_))
+        ));
+        // Capture groups past the last group of the pattern are also rejected up front
+        assert!(matches!(
+            data.regex_extract("(a)b", 2),
+            Err(DaftError::ValueError(_))
+        ));
+    }
+
+    #[test]
+    fn check_split_literal() -> DaftResult<()> {
+        let data = Utf8Array::from((
+            "data",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("a,b,c"),
+                Some("a,,b,"),
+                None,
+                Some(""),
+                Some(",,"),
+            ])),
+        ));
+        let split = |keep_empty| -> DaftResult<Vec<Option<Vec<String>>>> {
+            let result = data.split_literal(",", keep_empty)?;
+            assert_eq!(result.name(), "data");
+            assert_eq!(result.len(), data.len());
+            Ok(result
+                .into_iter()
+                .map(|row| {
+                    row.map(|row| {
+                        row.utf8()
+                            .unwrap()
+                            .as_arrow()
+                            .iter()
+                            .map(|v| v.unwrap().to_string())
+                            .collect()
+                    })
+                })
+                .collect())
+        };
+        let lists = |rows: Vec<Option<Vec<&str>>>| -> Vec<Option<Vec<String>>> {
+            rows.into_iter()
+                .map(|row| row.map(|row| row.into_iter().map(|v| v.to_string()).collect()))
+                .collect()
+        };
+
+        // Consecutive and trailing delimiters produce empty strings, as does the empty string itself
+        assert_eq!(
+            split(true)?,
+            lists(vec![
+                Some(vec!["a", "b", "c"]),
+                Some(vec!["a", "", "b", ""]),
+                None,
+                Some(vec![""]),
+                Some(vec!["", "", ""]),
+            ])
+        );
+        assert_eq!(
+            split(false)?,
+            lists(vec![
+                Some(vec!["a", "b", "c"]),
+                Some(vec!["a", "b"]),
+                None,
+                Some(vec![]),
+                Some(vec![]),
+            ])
+        );
+        assert!(matches!(
+            data.split_literal("", true),
+            Err(DaftError::ValueError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn check_to_date() -> DaftResult<()> {
+        let data = Utf8Array::from((
+            "data",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("1970-01-02"),
+                None,
+                Some("2000-02-29"),
+                Some("not a date"),
+            ])),
+        ));
+        let dates = data.to_date("%Y-%m-%d", false)?;
+        assert_eq!(dates.name(), "data");
+        assert_eq!(*dates.data_type(), DataType::Date);
+        assert_eq!(
+            dates
+                .physical
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(1), None, Some(11016), None]
+        );
+        assert!(matches!(
+            data.to_date("%Y-%m-%d", true),
+            Err(DaftError::ValueError(_))
+        ));
+        assert!(matches!(
+            data.to_date("%Y-%m-%Q", false),
+            Err(DaftError::ValueError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn check_to_timestamp() -> DaftResult<()> {
+        let data = Utf8Array::from((
+            "data",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("1970-01-01 00:00:01"),
+                None,
+                Some("1970-01-01 01:00:00 +0100"),
+                Some("1970-01-01"),
+            ])),
+        ));
+        let micros = |array: &TimestampArray| {
+            array
+                .physical
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>()
+        };
+
+        let naive = data.to_timestamp("%Y-%m-%d %H:%M:%S", None, false)?;
+        assert_eq!(
+            *naive.data_type(),
+            DataType::Timestamp(TimeUnit::Microseconds, None)
+        );
+        assert_eq!(micros(&naive), vec![Some(1_000_000), None, None, None]);
+
+        // Local times are taken in the timezone, while explicit offsets are respected
+        let with_offset = data.to_timestamp("%Y-%m-%d %H:%M:%S %z", None, false)?;
+        assert_eq!(micros(&with_offset), vec![None, None, Some(0), None]);
+        let local = data.to_timestamp("%Y-%m-%d %H:%M:%S", Some("+02:00".to_string()), false)?;
+        assert_eq!(
+            *local.data_type(),
+            DataType::Timestamp(TimeUnit::Microseconds, Some("+02:00".to_string()))
+        );
+        assert_eq!(
+            micros(&local),
+            vec![Some(1_000_000 - 7_200_000_000), None, None, None]
+        );
+
+        assert!(matches!(
+            data.to_timestamp("%Y-%m-%d %H:%M:%S", None, true),
+            Err(DaftError::ValueError(_))
+        ));
+        assert!(matches!(
+            data.to_timestamp("%Y-%m-%d %Q", None, false),
+            Err(DaftError::ValueError(_))
+        ));
+        assert!(matches!(
+            data.to_timestamp("%Y-%m-%d", Some("Not/A_Timezone".to_string()), false),
+            Err(DaftError::ValueError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn check_case_and_whitespace_normalization() -> DaftResult<()> {
+        let data = Utf8Array::from((
+            "data",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("  Café ÉCOLE\t"),
+                None,
+                Some(" \t\n\u{3000}"),
+                Some("ǅungla"),
+            ])),
+        ));
+        let values = |result: Utf8Array| -> Vec<Option<String>> {
+            assert_eq!(result.name(), "data");
+            result
+                .as_arrow()
+                .iter()
+                .map(|v| v.map(|v| v.to_string()))
+                .collect()
+        };
+        let strings = |values: &[Option<&str>]| -> Vec<Option<String>> {
+            values.iter().map(|v| v.map(|v| v.to_string())).collect()
+        };
+
+        assert_eq!(
+            values(data.lower()?),
+            strings(&[
+                Some("  café école\t"),
+                None,
+                Some(" \t\n\u{3000}"),
+                Some("ǆungla")
+            ])
+        );
+        assert_eq!(
+            values(data.upper()?),
+            strings(&[
+                Some("  CAFÉ ÉCOLE\t"),
+                None,
+                Some(" \t\n\u{3000}"),
+                Some("ǄUNGLA")
+            ])
+        );
+        // All-whitespace strings, including non-ASCII whitespace, strip down to empty strings
+        assert_eq!(
+            values(data.strip()?),
+            strings(&[Some("Café ÉCOLE"), None, Some(""), Some("ǅungla")])
+        );
+        assert_eq!(
+            values(data.lstrip()?),
+            strings(&[Some("Café ÉCOLE\t"), None, Some(""), Some("ǅungla")])
+        );
+        assert_eq!(
+            values(data.rstrip()?),
+            strings(&[Some("  Café ÉCOLE"), None, Some(""), Some("ǅungla")])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn check_literal_predicates_with_empty_patterns_and_nulls() -> DaftResult<()> {
+        let data = Utf8Array::from((
+            "data",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("foo.bar"),
+                None,
+                Some(""),
+                Some("bar"),
+            ])),
+        ));
+        let check = |pattern: &str| -> DaftResult<[Vec<Option<bool>>; 3]> {
+            let pattern = Utf8Array::from(("pattern", [pattern].as_slice()));
+            let values = |result: BooleanArray| result.as_arrow().iter().collect::<Vec<_>>();
+            Ok([
+                values(data.contains(&pattern)?),
+                values(data.startswith(&pattern)?),
+                values(data.endswith(&pattern)?),
+            ])
+        };
+
+        let all = vec![Some(true), None, Some(true), Some(true)];
+        assert_eq!(check("")?, [all.clone(), all.clone(), all]);
+        // "." is matched literally rather than as a regex wildcard
+        assert_eq!(
+            check(".")?,
+            [
+                vec![Some(true), None, Some(false), Some(false)],
+                vec![Some(false), None, Some(false), Some(false)],
+                vec![Some(false), None, Some(false), Some(false)],
+            ]
+        );
+        assert_eq!(
+            check("bar")?,
+            [
+                vec![Some(true), None, Some(false), Some(true)],
+                vec![Some(false), None, Some(false), Some(true)],
+                vec![Some(true), None, Some(false), Some(true)],
+            ]
+        );
+
+        let null_pattern = Utf8Array::from((
+            "pattern",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("foo"),
+                Some("foo"),
+                None,
+                Some("b"),
+            ])),
+        ));
+        assert_eq!(
+            data.startswith(&null_pattern)?
+                .as_arrow()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(true), None, None, Some(true)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn check_replace_literal_and_regex() -> DaftResult<()> {
+        let data = Utf8Array::from((
+            "data",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("2023-01-15 and 2024-12-31"),
+                None,
+                Some("a.b.c"),
+            ])),
+        ));
+        let replace =
+            |pattern: &str, replacement: &str, regex| -> DaftResult<Vec<Option<String>>> {
+                let result = data.replace(
+                    &Utf8Array::from(("pattern", [pattern].as_slice())),
+                    &Utf8Array::from(("replacement", [replacement].as_slice())),
+                    regex,
+                )?;
+                assert_eq!(result.name(), "data");
+                Ok(result
+                    .as_arrow()
+                    .iter()
+                    .map(|v| v.map(|v| v.to_string()))
+                    .collect())
+            };
+
+        // Literal mode replaces every occurrence, without treating "." as a wildcard
+        assert_eq!(
+            replace(".", "/", false)?,
+            vec![
+                Some("2023-01-15 and 2024-12-31".to_string()),
+                None,
+                Some("a/b/c".to_string())
+            ]
+        );
+        assert_eq!(
+            replace(r"(\d{4})-(\d{2})-(\d{2})", "$3/$2/$1", true)?,
+            vec![
+                Some("15/01/2023 and 31/12/2024".to_string()),
+                None,
+                Some("a.b.c".to_string())
+            ]
+        );
+        assert!(matches!(
+            replace("(unclosed", "", true),
+            Err(DaftError::ValueError(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn check_pad() -> DaftResult<()> {
+        let data = Utf8Array::from((
+            "data",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("ab"),
+                None,
+                Some("日本"),
+                Some("toolong"),
+            ])),
+        ));
+        let values = |result: Utf8Array| -> Vec<Option<String>> {
+            assert_eq!(result.name(), "data");
+            result
+                .as_arrow()
+                .iter()
+                .map(|v| v.map(|v| v.to_string()))
+                .collect()
+        };
+        let strings = |values: &[Option<&str>]| -> Vec<Option<String>> {
+            values.iter().map(|v| v.map(|v| v.to_string())).collect()
+        };
+
+        // Widths count characters, so multi-byte strings and fills pad to the same width
+        assert_eq!(
+            values(data.lpad(4, '·', false)?),
+            strings(&[Some("··ab"), None, Some("··日本"), Some("toolong")])
+        );
+        assert_eq!(
+            values(data.rpad(4, '語', false)?),
+            strings(&[Some("ab語語"), None, Some("日本語語"), Some("toolong")])
+        );
+        assert_eq!(
+            values(data.lpad(4, ' ', true)?),
+            strings(&[Some("  ab"), None, Some("  日本"), Some("tool")])
+        );
+        assert_eq!(
+            values(data.rpad(1, ' ', true)?),
+            strings(&[Some("a"), None, Some("日"), Some("t")])
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,215 @@
+use std::{cmp::Ordering, collections::HashMap, hash::Hash};
+
+use common_error::DaftResult;
+
+use crate::{
+    array::{DataArray, StructArray},
+    datatypes::{
+        BinaryArray, BooleanArray, DaftNumericType, DataType, Field, UInt64Array, Utf8Array,
+    },
+    series::IntoSeries,
+};
+
+use super::{as_arrow::AsArrow, count_distinct::native_key};
+
+/// Index of the first row of each distinct value and the number of rows holding it, sorted descending by count with
+/// ties broken by `compare` on the first rows, and a null entry last. `get` returns the key of the value at an
+/// index, where None is a null, which is only counted as an entry of its own when `count_nulls` is set.
+fn value_count_indices<K: Hash + Eq>(
+    len: usize,
+    count_nulls: bool,
+    get: impl Fn(usize) -> Option<K>,
+    compare: impl Fn(usize, usize) -> Ordering,
+) -> (Vec<u64>, Vec<u64>) {
+    let mut counts: HashMap<Option<K>, (u64, u64)> = HashMap::new();
+    for idx in 0..len {
+        let key = get(idx);
+        if key.is_some() || count_nulls {
+            counts.entry(key).or_insert((idx as u64, 0)).1 += 1;
+        }
+    }
+    let mut entries = counts
+        .into_iter()
+        .map(|(key, (first, count))| (key.is_some(), first, count))
+        .collect::<Vec<_>>();
+    entries.sort_by(|(valid_a, first_a, count_a), (valid_b, first_b, count_b)| {
+        count_b
+            .cmp(count_a)
+            .then(valid_b.cmp(valid_a))
+            .then_with(|| match valid_a {
+                true => compare(*first_a as usize, *first_b as usize),
+                false => Ordering::Equal,
+            })
+    });
+    entries
+        .into_iter()
+        .map(|(_, first, count)| (first, count))
+        .unzip()
+}
+
+/// Struct array of a `value` and a `count` child, where `values` holds the value of each entry
+fn value_counts_array<A: IntoSeries>(
+    name: &str,
+    dtype: &DataType,
+    values: A,
+    counts: Vec<u64>,
+) -> StructArray {
+    let value_field = Field::new("value", dtype.clone());
+    let count_field = Field::new("count", DataType::UInt64);
+    StructArray::new(
+        Field::new(
+            name,
+            DataType::Struct(vec![value_field, count_field.clone()]),
+        ),
+        vec![
+            values.into_series().rename("value"),
+            UInt64Array::from((count_field.name.as_str(), counts)).into_series(),
+        ],
+        None,
+    )
+}
+
+impl<T> DataArray<T>
+where
+    T: DaftNumericType,
+    DataArray<T>: IntoSeries,
+{
+    /// Each distinct value of this array with the number of times it occurs, as a struct of a `value` and a `count`,
+    /// sorted descending by count with ties broken by ascending value. Nulls are counted as a value of their own
+    /// when `count_nulls` is set, and are otherwise excluded.
+    pub fn value_counts(&self, count_nulls: bool) -> DaftResult<StructArray> {
+        let arrow_array = self.as_arrow();
+        let values = arrow_array.values().as_slice();
+        #[allow(clippy::eq_op)]
+        let is_nan = |v: &T::Native| v != v;
+        let (indices, counts) = value_count_indices(
+            self.len(),
+            count_nulls,
+            |i| arrow_array.get(i).as_ref().map(native_key),
+            |a, b| {
+                let (l, r) = (&values[a], &values[b]);
+                l.partial_cmp(r)
+                    .unwrap_or_else(|| is_nan(l).cmp(&is_nan(r)))
+            },
+        );
+        let values = self.take(&UInt64Array::from(("", indices)))?;
+        Ok(value_counts_array(
+            self.name(),
+            self.data_type(),
+            values,
+            counts,
+        ))
+    }
+}
+
+macro_rules! impl_value_counts {
+    ($ArrayT:ty) => {
+        impl $ArrayT {
+            /// Each distinct value of this array with the number of times it occurs, as a struct of a `value` and a
+            /// `count`, sorted descending by count with ties broken by ascending value. Nulls are counted as a value
+            /// of their own when `count_nulls` is set, and are otherwise excluded.
+            pub fn value_counts(&self, count_nulls: bool) -> DaftResult<StructArray> {
+                let arrow_array = self.as_arrow();
+                let (indices, counts) = value_count_indices(
+                    self.len(),
+                    count_nulls,
+                    |i| arrow_array.get(i),
+                    |a, b| arrow_array.value(a).cmp(&arrow_array.value(b)),
+                );
+                let values = self.take(&UInt64Array::from(("", indices)))?;
+                Ok(value_counts_array(
+                    self.name(),
+                    self.data_type(),
+                    values,
+                    counts,
+                ))
+            }
+        }
+    };
+}
+
+impl_value_counts!(Utf8Array);
+impl_value_counts!(BinaryArray);
+impl_value_counts!(BooleanArray);
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+
+    use crate::{
+        array::StructArray,
+        datatypes::{DataType, Field, Int64Array, Utf8Array},
+    };
+
+    fn entries(value_counts: &StructArray) -> DaftResult<Vec<(String, u64)>> {
+        let (values, counts) = (&value_counts.children[0], &value_counts.children[1]);
+        (0..value_counts.len())
+            .map(|i| Ok((values.str_value(i)?, counts.u64()?.get(i).unwrap())))
+            .collect()
+    }
+
+    #[test]
+    fn value_counts_sorted_by_count_then_value() -> DaftResult<()> {
+        let ints = Int64Array::from_iter(
+            "i",
+            vec![
+                Some(3),
+                Some(1),
+                None,
+                Some(2),
+                Some(3),
+                Some(1),
+                None,
+                Some(3),
+                Some(5),
+            ]
+            .into_iter(),
+        );
+
+        let value_counts = ints.value_counts(false)?;
+        assert_eq!(value_counts.name(), "i");
+        assert_eq!(
+            *value_counts.data_type(),
+            DataType::Struct(vec![
+                Field::new("value", DataType::Int64),
+                Field::new("count", DataType::UInt64),
+            ])
+        );
+        assert_eq!(
+            entries(&value_counts)?,
+            [
+                ("3".to_string(), 3),
+                ("1".to_string(), 2),
+                ("2".to_string(), 1),
+                ("5".to_string(), 1)
+            ]
+        );
+
+        // Nulls tie with 1, but are placed after it
+        assert_eq!(
+            entries(&ints.value_counts(true)?)?,
+            [
+                ("3".to_string(), 3),
+                ("1".to_string(), 2),
+                ("None".to_string(), 2),
+                ("2".to_string(), 1),
+                ("5".to_string(), 1)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn value_counts_strings() -> DaftResult<()> {
+        let strings = Utf8Array::from(("s", ["b", "a", "b", "c", "a"].as_slice()));
+        assert_eq!(
+            entries(&strings.value_counts(true)?)?,
+            [
+                ("a".to_string(), 2),
+                ("b".to_string(), 2),
+                ("c".to_string(), 1)
+            ]
+        );
+        Ok(())
+    }
+}
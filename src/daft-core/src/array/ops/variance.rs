@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use arrow2;
+
+use crate::{array::DataArray, datatypes::*};
+
+use common_error::DaftResult;
+
+use super::DaftVarianceAggable;
+
+use super::as_arrow::AsArrow;
+
+use crate::array::ops::GroupIndices;
+
+/// Variance of `values` with a denominator of `N - ddof`, computed in a single pass with Welford's algorithm so that
+/// large inputs don't lose precision. Returns None if there are no more than `ddof` values.
+fn welford_variance(values: impl Iterator<Item = f64>, ddof: u64) -> Option<f64> {
+    let (mut count, mut mean, mut m2) = (0u64, 0.0, 0.0);
+    for value in values {
+        count += 1;
+        let delta = value - mean;
+        mean += delta / count as f64;
+        m2 += delta * (value - mean);
+    }
+    if count <= ddof {
+        None
+    } else {
+        Some(m2 / (count - ddof) as f64)
+    }
+}
+
+impl DaftVarianceAggable for &DataArray<Float64Type> {
+    type Output = DaftResult<DataArray<Float64Type>>;
+
+    fn variance(&self, ddof: u64) -> Self::Output {
+        let result = welford_variance(self.as_arrow().iter().flatten().copied(), ddof);
+        let arrow_array = Box::new(arrow2::array::PrimitiveArray::from([result]));
+
+        DataArray::new(
+            Arc::new(Field::new(self.field.name.clone(), DataType::Float64)),
+            arrow_array,
+        )
+    }
+
+    fn grouped_variance(&self, groups: &GroupIndices, ddof: u64) -> Self::Output {
+        use arrow2::array::PrimitiveArray;
+        let arrow_array = self.as_arrow();
+        let variance_per_group = groups
+            .iter()
+            .map(|g| welford_variance(g.iter().filter_map(|i| arrow_array.get(*i as usize)), ddof));
+        let variance_array = Box::new(PrimitiveArray::from_trusted_len_iter(variance_per_group));
+        Ok(DataArray::from((self.field.name.as_ref(), variance_array)))
+    }
+}
@@ -17,6 +17,14 @@ pub fn try_sum_supertype(dtype: &DataType) -> DaftResult<DataType> {
     }
 }
 
+/// Get the data type that the product of a column of the given data type should be casted to,
+/// which widens integers in the same way as sums.
+pub fn try_product_supertype(dtype: &DataType) -> DaftResult<DataType> {
+    try_sum_supertype(dtype).map_err(|_| {
+        DaftError::TypeError(format!("Invalid argument to product supertype: {}", dtype))
+    })
+}
+
 /// Get the data type that the mean of a column of the given data type should be casted to.
 pub fn try_mean_supertype(dtype: &DataType) -> DaftResult<DataType> {
     use DataType::*;
@@ -2,7 +2,10 @@ use std::ops::{Add, Div, Mul, Rem, Sub};
 
 use common_error::{DaftError, DaftResult};
 
-use crate::{impl_binary_trait_by_reference, utils::supertype::try_get_supertype};
+use crate::{
+    array::ops::MAX_DECIMAL128_PRECISION, impl_binary_trait_by_reference,
+    utils::supertype::try_get_supertype,
+};
 
 use super::DataType;
 
@@ -49,6 +52,12 @@ impl DataType {
                     let pt = intermediate_type.to_physical();
                     Ok((Boolean, Some(intermediate_type), pt))
                 }
+                (Decimal128(..), Decimal128(..)) => {
+                    // Decimals of differing scales are compared once rescaled to a common scale
+                    let intermediate_type = decimal128_sum_type(self, other)?;
+                    let pt = intermediate_type.to_physical();
+                    Ok((Boolean, Some(intermediate_type), pt))
+                }
                 (Timestamp(..), Date) | (Date, Timestamp(..)) => {
                     let intermediate_type = Date;
                     let pt = intermediate_type.to_physical();
@@ -93,6 +102,10 @@ impl Add for &DataType {
                 | (du @ Duration(..), ts @ Timestamp(..)) => Err(DaftError::TypeError(
                     format!("Cannot add due to differing precision: {}, {}. Please explicitly cast to the precision you wish to add in.", ts, du)
                 )),
+                (Duration(l_unit), Duration(r_unit)) if l_unit == r_unit => Ok(Duration(*l_unit)),
+                (l @ Duration(..), r @ Duration(..)) => Err(DaftError::TypeError(
+                    format!("Cannot add due to differing precision: {}, {}. Please explicitly cast to the precision you wish to add in.", l, r)
+                )),
                 (Null, other) | (other, Null) => {
                     match other {
                         // Condition is for backwards compatibility. TODO: remove
@@ -119,6 +132,7 @@ impl Add for &DataType {
                 }
                 (Boolean, other) | (other, Boolean)
                     if other.is_numeric() => Ok(other.clone()),
+                (Decimal128(..), Decimal128(..)) => decimal128_sum_type(self, other),
                 _ => Err(DaftError::TypeError(
                     format!("Cannot add types: {}, {}", self, other)
                 ))
@@ -141,6 +155,19 @@ impl Sub for &DataType {
                 (ts @ Timestamp(..), du @ Duration(..)) => Err(DaftError::TypeError(
                     format!("Cannot subtract due to differing precision: {}, {}. Please explicitly cast to the precision you wish to add in.", ts, du)
                 )),
+                (Timestamp(l_unit, l_tz), Timestamp(r_unit, r_tz))
+                    if l_unit == r_unit && l_tz == r_tz => Ok(Duration(*l_unit)),
+                (l @ Timestamp(l_unit, _), r @ Timestamp(r_unit, _)) if l_unit != r_unit => Err(DaftError::TypeError(
+                    format!("Cannot subtract due to differing precision: {}, {}. Please explicitly cast to the precision you wish to subtract in.", l, r)
+                )),
+                (l @ Timestamp(..), r @ Timestamp(..)) => Err(DaftError::TypeError(
+                    format!("Cannot subtract due to differing timezones: {}, {}. Please explicitly cast to the timezone you wish to subtract in.", l, r)
+                )),
+                (Duration(l_unit), Duration(r_unit)) if l_unit == r_unit => Ok(Duration(*l_unit)),
+                (l @ Duration(..), r @ Duration(..)) => Err(DaftError::TypeError(
+                    format!("Cannot subtract due to differing precision: {}, {}. Please explicitly cast to the precision you wish to subtract in.", l, r)
+                )),
+                (Decimal128(..), Decimal128(..)) => decimal128_sum_type(self, other),
                 _ => Err(DaftError::TypeError(
                     format!("Cannot subtract types: {}, {}", self, other)
                 ))
@@ -174,6 +201,20 @@ impl Mul for &DataType {
         try_numeric_supertype(self, other).or(match (self, other) {
             #[cfg(feature = "python")]
             (Python, _) | (_, Python) => Ok(Python),
+            (Decimal128(lp, ls), Decimal128(rp, rs)) => {
+                // The scale of a product is the sum of the scales, and it has at most as many digits as both inputs
+                let scale = ls + rs;
+                if scale > MAX_DECIMAL128_PRECISION {
+                    return Err(DaftError::TypeError(format!(
+                        "Cannot multiply types: {}, {}, as the product would have a scale of {scale}, which exceeds the maximum precision of {MAX_DECIMAL128_PRECISION}",
+                        self, other
+                    )));
+                }
+                Ok(Decimal128(
+                    (lp + rp).min(MAX_DECIMAL128_PRECISION),
+                    scale,
+                ))
+            }
             _ => Err(DaftError::TypeError(format!(
                 "Cannot multiply types: {}, {}",
                 self, other
@@ -198,6 +239,25 @@ impl Rem for &DataType {
     }
 }
 
+/// Type of the sum or difference of two decimals, which keeps the larger scale and has enough integer digits for
+/// both inputs, plus one for a carry
+fn decimal128_sum_type(l: &DataType, r: &DataType) -> DaftResult<DataType> {
+    match (l, r) {
+        (DataType::Decimal128(lp, ls), DataType::Decimal128(rp, rs)) => {
+            let scale = *ls.max(rs);
+            let integer_digits = (lp - ls).max(rp - rs) + 1;
+            Ok(DataType::Decimal128(
+                (integer_digits + scale).min(MAX_DECIMAL128_PRECISION),
+                scale,
+            ))
+        }
+        _ => Err(DaftError::TypeError(format!(
+            "Expected two decimal types, got: {}, {}",
+            l, r
+        ))),
+    }
+}
+
 impl_binary_trait_by_reference!(DataType, Add, add);
 impl_binary_trait_by_reference!(DataType, Sub, sub);
 impl_binary_trait_by_reference!(DataType, Mul, mul);
@@ -1,8 +1,10 @@
 use std::fmt::{Display, Formatter, Result};
 
-use arrow2::datatypes::DataType as ArrowType;
+use arrow2::datatypes::{DataType as ArrowType, IntervalUnit as ArrowIntervalUnit};
 
-use crate::datatypes::{field::Field, image_mode::ImageMode, time_unit::TimeUnit};
+use crate::datatypes::{
+    field::Field, image_mode::ImageMode, interval_unit::IntervalUnit, time_unit::TimeUnit,
+};
 
 use common_error::{DaftError, DaftResult};
 
@@ -66,6 +68,10 @@ pub enum DataType {
     Time(TimeUnit),
     /// Measure of elapsed time. This elapsed time is a physical duration (i.e. 1s as defined in S.I.)
     Duration(TimeUnit),
+    /// Calendar interval measured in [`IntervalUnit`], which unlike a [`DataType::Duration`] may span a varying
+    /// amount of elapsed time. Physically an [`i64`]: a number of months for [`IntervalUnit::YearMonth`], or days in
+    /// the high 32 bits and milliseconds in the low 32 bits for [`IntervalUnit::DayTime`].
+    Interval(IntervalUnit),
     /// Opaque binary data of variable length whose offsets are represented as [`i64`].
     Binary,
     /// A variable-length UTF-8 encoded string whose offsets are represented as [`i64`].
@@ -145,6 +151,7 @@ impl DataType {
             DataType::Date => Ok(ArrowType::Date32),
             DataType::Time(unit) => Ok(ArrowType::Time64(unit.to_arrow())),
             DataType::Duration(unit) => Ok(ArrowType::Duration(unit.to_arrow())),
+            DataType::Interval(unit) => Ok(ArrowType::Interval(unit.to_arrow())),
             DataType::Binary => Ok(ArrowType::LargeBinary),
             DataType::Utf8 => Ok(ArrowType::LargeUtf8),
             DataType::FixedSizeList(child_dtype, size) => Ok(ArrowType::FixedSizeList(
@@ -206,7 +213,7 @@ impl DataType {
         match self {
             Decimal128(..) => Int128,
             Date => Int32,
-            Duration(_) | Timestamp(..) | Time(_) => Int64,
+            Duration(_) | Interval(_) | Timestamp(..) | Time(_) => Int64,
             List(child_dtype) => List(Box::new(child_dtype.to_physical())),
             FixedSizeList(child_dtype, size) => {
                 FixedSizeList(Box::new(child_dtype.to_physical()), *size)
@@ -396,6 +403,7 @@ impl DataType {
                 | DataType::Time(..)
                 | DataType::Timestamp(..)
                 | DataType::Duration(..)
+                | DataType::Interval(..)
                 | DataType::Embedding(..)
                 | DataType::Image(..)
                 | DataType::FixedShapeImage(..)
@@ -458,6 +466,12 @@ impl From<&ArrowType> for DataType {
                 DataType::Time(timeunit.into())
             }
             ArrowType::Duration(timeunit) => DataType::Duration(timeunit.into()),
+            ArrowType::Interval(ArrowIntervalUnit::YearMonth) => {
+                DataType::Interval(IntervalUnit::YearMonth)
+            }
+            ArrowType::Interval(ArrowIntervalUnit::DayTime) => {
+                DataType::Interval(IntervalUnit::DayTime)
+            }
             ArrowType::Binary | ArrowType::LargeBinary | ArrowType::FixedSizeBinary(_) => {
                 DataType::Binary
             }
@@ -0,0 +1,41 @@
+use std::fmt::{Display, Formatter};
+
+use arrow2::{datatypes::IntervalUnit as ArrowIntervalUnit, types::days_ms};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub enum IntervalUnit {
+    /// A number of months.
+    YearMonth,
+    /// A number of days and a number of milliseconds.
+    DayTime,
+}
+
+impl IntervalUnit {
+    #![allow(clippy::wrong_self_convention)]
+    pub fn to_arrow(&self) -> ArrowIntervalUnit {
+        match self {
+            IntervalUnit::YearMonth => ArrowIntervalUnit::YearMonth,
+            IntervalUnit::DayTime => ArrowIntervalUnit::DayTime,
+        }
+    }
+}
+
+impl Display for IntervalUnit {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        // Leverage Debug trait implementation, which will already return the enum variant as a string.
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Packs a day-time interval into the [`i64`] that physically backs it, with the days in the high 32 bits and the
+/// milliseconds in the low 32 bits.
+pub(crate) fn pack_days_ms(value: days_ms) -> i64 {
+    ((value.days() as i64) << 32) | (value.milliseconds() as u32 as i64)
+}
+
+/// Inverse of [`pack_days_ms`].
+pub(crate) fn unpack_days_ms(value: i64) -> days_ms {
+    days_ms::new((value >> 32) as i32, value as i32)
+}
@@ -8,9 +8,9 @@ use crate::{
 use common_error::DaftResult;
 
 use super::{
-    DaftArrayType, DaftDataType, DataArray, DataType, Decimal128Type, DurationType, EmbeddingType,
-    FixedShapeImageType, FixedShapeTensorType, FixedSizeListArray, ImageType, MapType, TensorType,
-    TimeType, TimestampType,
+    unpack_days_ms, DaftArrayType, DaftDataType, DataArray, DataType, Decimal128Type, DurationType,
+    EmbeddingType, FixedShapeImageType, FixedShapeTensorType, FixedSizeListArray, ImageType,
+    IntervalType, IntervalUnit, MapType, TensorType, TimeType, TimestampType,
 };
 
 /// A LogicalArray is a wrapper on top of some underlying array, applying the semantic meaning of its
@@ -109,6 +109,22 @@ impl<L: DaftLogicalType> LogicalArrayImpl<L, DataArray<L::PhysicalType>> {
                         .to_boxed()
                 })
             }
+            // Intervals don't share a physical type with arrow2, so convert their values.
+            Interval(unit) => {
+                use arrow2::{array::Array, compute::arity::unary};
+                let physical = physical_arrow_array
+                    .as_any()
+                    .downcast_ref::<arrow2::array::PrimitiveArray<i64>>()
+                    .unwrap();
+                match unit {
+                    IntervalUnit::YearMonth => {
+                        unary(physical, |v| v as i32, arrow_logical_type).to_boxed()
+                    }
+                    IntervalUnit::DayTime => {
+                        unary(physical, unpack_days_ms, arrow_logical_type).to_boxed()
+                    }
+                }
+            }
             // Otherwise, use arrow cast to make sure the result arrow2 array is of the correct type.
             _ => arrow2::compute::cast::cast(
                 physical_arrow_array,
@@ -167,6 +183,7 @@ pub type Decimal128Array = LogicalArray<Decimal128Type>;
 pub type DateArray = LogicalArray<DateType>;
 pub type TimeArray = LogicalArray<TimeType>;
 pub type DurationArray = LogicalArray<DurationType>;
+pub type IntervalArray = LogicalArray<IntervalType>;
 pub type ImageArray = LogicalArray<ImageType>;
 pub type TimestampArray = LogicalArray<TimestampType>;
 pub type TensorArray = LogicalArray<TensorType>;
@@ -4,12 +4,13 @@ mod dtype;
 mod field;
 mod image_format;
 mod image_mode;
+mod interval_unit;
 mod matching;
 mod time_unit;
 
 pub use crate::array::{DataArray, FixedSizeListArray};
 use crate::array::{ListArray, StructArray};
-pub use agg_ops::{try_mean_supertype, try_sum_supertype};
+pub use agg_ops::{try_mean_supertype, try_product_supertype, try_sum_supertype};
 use arrow2::{
     compute::comparison::Simd8,
     types::{simd::Simd, NativeType},
@@ -21,6 +22,8 @@ pub use field::FieldID;
 pub use field::FieldRef;
 pub use image_format::ImageFormat;
 pub use image_mode::ImageMode;
+pub use interval_unit::IntervalUnit;
+pub(crate) use interval_unit::{pack_days_ms, unpack_days_ms};
 use num_traits::{Bounded, Float, FromPrimitive, Num, NumCast, ToPrimitive, Zero};
 use serde::Serialize;
 use std::ops::{Add, Div, Mul, Rem, Sub};
@@ -198,6 +201,7 @@ impl_daft_logical_data_array_datatype!(TimestampType, Unknown, Int64Type);
 impl_daft_logical_data_array_datatype!(DateType, Date, Int32Type);
 impl_daft_logical_data_array_datatype!(TimeType, Unknown, Int64Type);
 impl_daft_logical_data_array_datatype!(DurationType, Unknown, Int64Type);
+impl_daft_logical_data_array_datatype!(IntervalType, Unknown, Int64Type);
 impl_daft_logical_data_array_datatype!(ImageType, Unknown, StructType);
 impl_daft_logical_data_array_datatype!(TensorType, Unknown, StructType);
 impl_daft_logical_fixed_size_list_datatype!(EmbeddingType, Unknown);
@@ -9,25 +9,47 @@ use arrow2::{
 use xxhash_rust::const_xxh3;
 use xxhash_rust::xxh3::{xxh3_64, xxh3_64_with_seed};
 
+/// Hash of every null when hashing without a seed. Valid values never hash to it, see [`valid_hash`].
+pub const NULL_HASH: u64 = u64::MAX;
+
+/// Bytes hashed in place of a null when hashing with a seed, so that nulls still mix in the hash of the columns
+/// chained before them. It is longer than any primitive and not valid UTF-8, so only a binary value can match it.
+pub const NULL_MARKER: &[u8] = b"\xffdaft-null-marker";
+
+/// Moves a valid value's hash off [`NULL_HASH`] so that nulls can't collide with any valid value.
+#[inline]
+const fn valid_hash(hash: u64) -> u64 {
+    if hash == NULL_HASH {
+        NULL_HASH - 1
+    } else {
+        hash
+    }
+}
+
+/// Hash of a null under `seed`.
+#[inline]
+fn null_hash_with_seed(seed: u64) -> u64 {
+    valid_hash(xxh3_64_with_seed(NULL_MARKER, seed))
+}
+
 fn hash_primitive<T: NativeType>(
     array: &PrimitiveArray<T>,
     seed: Option<&PrimitiveArray<u64>>,
 ) -> PrimitiveArray<u64> {
-    const NULL_HASH: u64 = const_xxh3::xxh3_64(b"");
     let hashes = if let Some(seed) = seed {
         array
             .iter()
             .zip(seed.values_iter())
             .map(|(v, s)| match v {
-                Some(v) => xxh3_64_with_seed(v.to_le_bytes().as_ref(), *s),
-                None => NULL_HASH,
+                Some(v) => valid_hash(xxh3_64_with_seed(v.to_le_bytes().as_ref(), *s)),
+                None => null_hash_with_seed(*s),
             })
             .collect::<Vec<_>>()
     } else {
         array
             .iter()
             .map(|v| match v {
-                Some(v) => xxh3_64(v.to_le_bytes().as_ref()),
+                Some(v) => valid_hash(xxh3_64(v.to_le_bytes().as_ref())),
                 None => NULL_HASH,
             })
             .collect::<Vec<_>>()
@@ -36,19 +58,17 @@ fn hash_primitive<T: NativeType>(
 }
 
 fn hash_boolean(array: &BooleanArray, seed: Option<&PrimitiveArray<u64>>) -> PrimitiveArray<u64> {
-    const NULL_HASH: u64 = const_xxh3::xxh3_64(b"");
-
-    const FALSE_HASH: u64 = const_xxh3::xxh3_64(b"0");
-    const TRUE_HASH: u64 = const_xxh3::xxh3_64(b"1");
+    const FALSE_HASH: u64 = valid_hash(const_xxh3::xxh3_64(b"0"));
+    const TRUE_HASH: u64 = valid_hash(const_xxh3::xxh3_64(b"1"));
 
     let hashes = if let Some(seed) = seed {
         array
             .iter()
             .zip(seed.values_iter())
             .map(|(v, s)| match v {
-                Some(true) => xxh3_64_with_seed(b"1", *s),
-                Some(false) => xxh3_64_with_seed(b"0", *s),
-                None => NULL_HASH,
+                Some(true) => valid_hash(xxh3_64_with_seed(b"1", *s)),
+                Some(false) => valid_hash(xxh3_64_with_seed(b"0", *s)),
+                None => null_hash_with_seed(*s),
             })
             .collect::<Vec<_>>()
     } else {
@@ -65,14 +85,12 @@ fn hash_boolean(array: &BooleanArray, seed: Option<&PrimitiveArray<u64>>) -> Pri
 }
 
 fn hash_null(array: &NullArray, seed: Option<&PrimitiveArray<u64>>) -> PrimitiveArray<u64> {
-    const NULL_HASH: u64 = const_xxh3::xxh3_64(b"");
-
     let hashes = if let Some(seed) = seed {
         seed.values_iter()
-            .map(|s| xxh3_64_with_seed(b"", *s))
+            .map(|s| null_hash_with_seed(*s))
             .collect::<Vec<_>>()
     } else {
-        (0..array.len()).map(|_| NULL_HASH).collect::<Vec<_>>()
+        vec![NULL_HASH; array.len()]
     };
     PrimitiveArray::<u64>::new(DataType::UInt64, hashes.into(), None)
 }
@@ -83,12 +101,20 @@ fn hash_binary<O: Offset>(
 ) -> PrimitiveArray<u64> {
     let hashes = if let Some(seed) = seed {
         array
-            .values_iter()
+            .iter()
             .zip(seed.values_iter())
-            .map(|(v, s)| xxh3_64_with_seed(v, *s))
+            .map(|(v, s)| {
+                v.map_or_else(
+                    || null_hash_with_seed(*s),
+                    |v| valid_hash(xxh3_64_with_seed(v, *s)),
+                )
+            })
             .collect::<Vec<_>>()
     } else {
-        array.values_iter().map(xxh3_64).collect::<Vec<_>>()
+        array
+            .iter()
+            .map(|v| v.map_or(NULL_HASH, |v| valid_hash(xxh3_64(v))))
+            .collect::<Vec<_>>()
     };
     PrimitiveArray::<u64>::new(DataType::UInt64, hashes.into(), None)
 }
@@ -99,14 +125,19 @@ fn hash_utf8<O: Offset>(
 ) -> PrimitiveArray<u64> {
     let hashes = if let Some(seed) = seed {
         array
-            .values_iter()
+            .iter()
             .zip(seed.values_iter())
-            .map(|(v, s)| xxh3_64_with_seed(v.as_bytes(), *s))
+            .map(|(v, s)| {
+                v.map_or_else(
+                    || null_hash_with_seed(*s),
+                    |v| valid_hash(xxh3_64_with_seed(v.as_bytes(), *s)),
+                )
+            })
             .collect::<Vec<_>>()
     } else {
         array
-            .values_iter()
-            .map(|v| xxh3_64(v.as_bytes()))
+            .iter()
+            .map(|v| v.map_or(NULL_HASH, |v| valid_hash(xxh3_64(v.as_bytes()))))
             .collect::<Vec<_>>()
     };
     PrimitiveArray::<u64>::new(DataType::UInt64, hashes.into(), None)
@@ -139,6 +170,10 @@ macro_rules! with_match_hashing_primitive_type {(
     }
 })}
 
+/// Hashes each row of `array` with xxh3 over the little-endian bytes of its value, so hashes are the same across
+/// runs and platforms. When given, `seed` holds the seed to use for each row, which lets hashes of several columns be
+/// chained together. Without a seed, nulls hash to [`NULL_HASH`], which no valid value hashes to. With a seed, nulls
+/// hash like [`NULL_MARKER`] under that seed, so that rows differing only before a null column still hash apart.
 pub fn hash(array: &dyn Array, seed: Option<&PrimitiveArray<u64>>) -> Result<PrimitiveArray<u64>> {
     if let Some(s) = seed {
         if s.len() != array.len() {
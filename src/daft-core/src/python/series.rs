@@ -442,6 +442,15 @@ impl PySeries {
         Ok(self.series.fill_null(&fill_value.series)?.into())
     }
 
+    pub fn python_apply(&self, func: PyObject, skip_nulls: bool) -> PyResult<Self> {
+        Ok(self
+            .series
+            .python()?
+            .apply(func, skip_nulls)?
+            .into_series()
+            .into())
+    }
+
     pub fn _debug_bincode_serialize(&self, py: Python) -> PyResult<PyObject> {
         let values = bincode::serialize(&self.series).unwrap();
         Ok(PyBytes::new(py, &values).to_object(py))
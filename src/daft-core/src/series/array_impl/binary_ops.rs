@@ -17,7 +17,7 @@ use crate::{
 
 use crate::datatypes::logical::{
     DateArray, DurationArray, EmbeddingArray, FixedShapeImageArray, FixedShapeTensorArray,
-    ImageArray, TensorArray, TimeArray, TimestampArray,
+    ImageArray, IntervalArray, TensorArray, TimeArray, TimestampArray,
 };
 use crate::datatypes::{
     BinaryArray, BooleanArray, ExtensionArray, Float32Array, Float64Array, Int16Array, Int32Array,
@@ -96,6 +96,20 @@ macro_rules! py_numeric_binary_op {
     }};
 }
 
+macro_rules! decimal_binary_op {
+    ($self:expr, $rhs:expr, $op:ident, $symbol:expr, $pyop:expr) => {{
+        let output_type = ($self.data_type().$op($rhs.data_type()))?;
+        let lhs = $self.0.clone().into_series();
+        use DataType::*;
+        match $rhs.data_type() {
+            #[cfg(feature = "python")]
+            Python => Ok(py_binary_op!(lhs, $rhs, $pyop)),
+            Decimal128(..) => Ok($self.0.$op($rhs.decimal128()?, &output_type)?.into_series()),
+            _ => binary_op_unimplemented!(lhs, $symbol, $rhs, output_type),
+        }
+    }};
+}
+
 macro_rules! physical_logic_op {
     ($self:expr, $rhs:expr, $op:ident, $pyop:expr) => {{
         let output_type = ($self.data_type().logical_op($rhs.data_type()))?;
@@ -231,16 +245,27 @@ impl SeriesBinaryOps for ArrayWrapper<ListArray> {}
 impl SeriesBinaryOps for ArrayWrapper<StructArray> {}
 impl SeriesBinaryOps for ArrayWrapper<MapArray> {}
 impl SeriesBinaryOps for ArrayWrapper<ExtensionArray> {}
-impl SeriesBinaryOps for ArrayWrapper<Decimal128Array> {}
+impl SeriesBinaryOps for ArrayWrapper<Decimal128Array> {
+    fn add(&self, rhs: &Series) -> DaftResult<Series> {
+        decimal_binary_op!(self, rhs, add, "+", "add")
+    }
+    fn sub(&self, rhs: &Series) -> DaftResult<Series> {
+        decimal_binary_op!(self, rhs, sub, "-", "sub")
+    }
+    fn mul(&self, rhs: &Series) -> DaftResult<Series> {
+        decimal_binary_op!(self, rhs, mul, "*", "mul")
+    }
+}
 impl SeriesBinaryOps for ArrayWrapper<DateArray> {}
 impl SeriesBinaryOps for ArrayWrapper<TimeArray> {}
+impl SeriesBinaryOps for ArrayWrapper<IntervalArray> {}
 impl SeriesBinaryOps for ArrayWrapper<DurationArray> {
     fn add(&self, rhs: &Series) -> DaftResult<Series> {
         use DataType::*;
         let output_type = (self.data_type() + rhs.data_type())?;
         let lhs = self.0.clone().into_series();
         match rhs.data_type() {
-            Timestamp(..) => {
+            Timestamp(..) | Duration(..) => {
                 let lhs = lhs.as_physical()?;
                 let rhs = rhs.as_physical()?;
                 let physical_result = lhs.add(rhs)?;
@@ -249,6 +274,20 @@ impl SeriesBinaryOps for ArrayWrapper<DurationArray> {
             _ => binary_op_unimplemented!(lhs, "+", rhs, output_type),
         }
     }
+    fn sub(&self, rhs: &Series) -> DaftResult<Series> {
+        use DataType::*;
+        let output_type = (self.data_type() - rhs.data_type())?;
+        let lhs = self.0.clone().into_series();
+        match rhs.data_type() {
+            Duration(..) => {
+                let lhs = lhs.as_physical()?;
+                let rhs = rhs.as_physical()?;
+                let physical_result = lhs.sub(rhs)?;
+                physical_result.cast(&output_type)
+            }
+            _ => binary_op_unimplemented!(lhs, "-", rhs, output_type),
+        }
+    }
 }
 impl SeriesBinaryOps for ArrayWrapper<TimestampArray> {
     fn add(&self, rhs: &Series) -> DaftResult<Series> {
@@ -270,7 +309,8 @@ impl SeriesBinaryOps for ArrayWrapper<TimestampArray> {
         let output_type = (self.data_type() - rhs.data_type())?;
         let lhs = self.0.clone().into_series();
         match rhs.data_type() {
-            Duration(..) => {
+            // Subtracting timestamps gives the duration between them, in the same unit
+            Duration(..) | Timestamp(..) => {
                 let lhs = lhs.as_physical()?;
                 let rhs = rhs.as_physical()?;
                 let physical_result = lhs.sub(rhs)?;
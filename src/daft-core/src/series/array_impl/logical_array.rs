@@ -1,7 +1,7 @@
 use crate::datatypes::logical::{
     DateArray, Decimal128Array, DurationArray, EmbeddingArray, FixedShapeImageArray,
-    FixedShapeTensorArray, ImageArray, LogicalArray, MapArray, TensorArray, TimeArray,
-    TimestampArray,
+    FixedShapeTensorArray, ImageArray, IntervalArray, LogicalArray, MapArray, TensorArray,
+    TimeArray, TimestampArray,
 };
 use crate::datatypes::{BooleanArray, DaftLogicalType, Field};
 
@@ -230,6 +230,7 @@ impl_series_like_for_logical_array!(Decimal128Array);
 impl_series_like_for_logical_array!(DateArray);
 impl_series_like_for_logical_array!(TimeArray);
 impl_series_like_for_logical_array!(DurationArray);
+impl_series_like_for_logical_array!(IntervalArray);
 impl_series_like_for_logical_array!(TimestampArray);
 impl_series_like_for_logical_array!(ImageArray);
 impl_series_like_for_logical_array!(TensorArray);
@@ -1,7 +1,10 @@
 use crate::array::ListArray;
 use crate::count_mode::CountMode;
 use crate::series::IntoSeries;
-use crate::{array::ops::GroupIndices, series::Series, with_match_physical_daft_types};
+use crate::{
+    array::ops::GroupIndices, series::Series, with_match_integer_daft_types,
+    with_match_physical_daft_types,
+};
 use arrow2::array::PrimitiveArray;
 use common_error::{DaftError, DaftResult};
 
@@ -19,6 +22,46 @@ impl Series {
         })
     }
 
+    pub fn count_distinct(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftCountDistinctAggable;
+        use crate::datatypes::DataType::*;
+
+        let s = self.as_physical()?;
+        macro_rules! count_distinct {
+            ($arr:ty) => {{
+                let array = s.downcast::<$arr>()?;
+                match groups {
+                    Some(groups) => Ok(DaftCountDistinctAggable::grouped_count_distinct(
+                        &array, groups,
+                    )?
+                    .into_series()),
+                    None => Ok(DaftCountDistinctAggable::count_distinct(&array)?.into_series()),
+                }
+            }};
+        }
+        match s.data_type() {
+            Null => count_distinct!(NullArray),
+            Boolean => count_distinct!(BooleanArray),
+            Int8 => count_distinct!(Int8Array),
+            Int16 => count_distinct!(Int16Array),
+            Int32 => count_distinct!(Int32Array),
+            Int64 => count_distinct!(Int64Array),
+            Int128 => count_distinct!(Int128Array),
+            UInt8 => count_distinct!(UInt8Array),
+            UInt16 => count_distinct!(UInt16Array),
+            UInt32 => count_distinct!(UInt32Array),
+            UInt64 => count_distinct!(UInt64Array),
+            Float32 => count_distinct!(Float32Array),
+            Float64 => count_distinct!(Float64Array),
+            Binary => count_distinct!(BinaryArray),
+            Utf8 => count_distinct!(Utf8Array),
+            other => Err(DaftError::TypeError(format!(
+                "Count distinct is not implemented for type {}",
+                other
+            ))),
+        }
+    }
+
     pub fn sum(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
         use crate::array::ops::DaftSumAggable;
         use crate::datatypes::DataType::*;
@@ -68,6 +111,61 @@ impl Series {
         }
     }
 
+    pub fn product(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftProductAggable;
+        use crate::datatypes::DataType::*;
+
+        match self.data_type() {
+            // intX -> int64, in line with sum
+            Int8 | Int16 | Int32 | Int64 => {
+                let casted = self.cast(&Int64)?;
+                match groups {
+                    Some(groups) => {
+                        Ok(DaftProductAggable::grouped_product(&casted.i64()?, groups)?
+                            .into_series())
+                    }
+                    None => Ok(DaftProductAggable::product(&casted.i64()?)?.into_series()),
+                }
+            }
+            // uintX -> uint64, in line with sum
+            UInt8 | UInt16 | UInt32 | UInt64 => {
+                let casted = self.cast(&UInt64)?;
+                match groups {
+                    Some(groups) => {
+                        Ok(DaftProductAggable::grouped_product(&casted.u64()?, groups)?
+                            .into_series())
+                    }
+                    None => Ok(DaftProductAggable::product(&casted.u64()?)?.into_series()),
+                }
+            }
+            // floatX -> floatX
+            Float32 => match groups {
+                Some(groups) => Ok(DaftProductAggable::grouped_product(
+                    &self.downcast::<Float32Array>()?,
+                    groups,
+                )?
+                .into_series()),
+                None => Ok(
+                    DaftProductAggable::product(&self.downcast::<Float32Array>()?)?.into_series(),
+                ),
+            },
+            Float64 => match groups {
+                Some(groups) => Ok(DaftProductAggable::grouped_product(
+                    &self.downcast::<Float64Array>()?,
+                    groups,
+                )?
+                .into_series()),
+                None => Ok(
+                    DaftProductAggable::product(&self.downcast::<Float64Array>()?)?.into_series(),
+                ),
+            },
+            other => Err(DaftError::TypeError(format!(
+                "Numeric product is not implemented for type {}",
+                other
+            ))),
+        }
+    }
+
     pub fn mean(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
         use crate::array::ops::DaftMeanAggable;
         use crate::datatypes::DataType::*;
@@ -90,6 +188,127 @@ impl Series {
         }
     }
 
+    pub fn median(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftMedianAggable;
+        use crate::datatypes::DataType::*;
+
+        // Upcast all numeric types to float64 and use f64 median kernel.
+        match self.data_type() {
+            Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 | Float32 | Float64 => {
+                let casted = self.cast(&Float64)?;
+                match groups {
+                    Some(groups) => Ok(
+                        DaftMedianAggable::grouped_median(&casted.f64()?, groups)?.into_series()
+                    ),
+                    None => Ok(DaftMedianAggable::median(&casted.f64()?)?.into_series()),
+                }
+            }
+            other => Err(DaftError::TypeError(format!(
+                "Numeric median is not implemented for type {}",
+                other
+            ))),
+        }
+    }
+
+    pub fn mode(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftModeAggable;
+        use crate::datatypes::DataType::*;
+
+        macro_rules! mode {
+            ($arr:ty) => {{
+                let array = self.downcast::<$arr>()?;
+                match groups {
+                    Some(groups) => {
+                        Ok(DaftModeAggable::grouped_mode(&array, groups)?.into_series())
+                    }
+                    None => Ok(DaftModeAggable::mode(&array)?.into_series()),
+                }
+            }};
+        }
+        match self.data_type() {
+            Null => mode!(NullArray),
+            Boolean => mode!(BooleanArray),
+            Int8 => mode!(Int8Array),
+            Int16 => mode!(Int16Array),
+            Int32 => mode!(Int32Array),
+            Int64 => mode!(Int64Array),
+            UInt8 => mode!(UInt8Array),
+            UInt16 => mode!(UInt16Array),
+            UInt32 => mode!(UInt32Array),
+            UInt64 => mode!(UInt64Array),
+            Float32 => mode!(Float32Array),
+            Float64 => mode!(Float64Array),
+            Binary => mode!(BinaryArray),
+            Utf8 => mode!(Utf8Array),
+            other => Err(DaftError::TypeError(format!(
+                "Mode is not implemented for type {}",
+                other
+            ))),
+        }
+    }
+
+    pub fn quantile(&self, groups: Option<&GroupIndices>, q: f64) -> DaftResult<Series> {
+        use crate::array::ops::DaftQuantileAggable;
+        use crate::datatypes::DataType::*;
+
+        if !(0.0..=1.0).contains(&q) {
+            return Err(DaftError::ValueError(format!(
+                "Quantile must be between 0 and 1, got {}",
+                q
+            )));
+        }
+
+        // Upcast all numeric types to float64 and use f64 quantile kernel.
+        match self.data_type() {
+            Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 | Float32 | Float64 => {
+                let casted = self.cast(&Float64)?;
+                match groups {
+                    Some(groups) => {
+                        Ok(
+                            DaftQuantileAggable::grouped_quantile(&casted.f64()?, groups, q)?
+                                .into_series(),
+                        )
+                    }
+                    None => Ok(DaftQuantileAggable::quantile(&casted.f64()?, q)?.into_series()),
+                }
+            }
+            other => Err(DaftError::TypeError(format!(
+                "Numeric quantile is not implemented for type {}",
+                other
+            ))),
+        }
+    }
+
+    pub fn variance(&self, groups: Option<&GroupIndices>, ddof: u64) -> DaftResult<Series> {
+        use crate::array::ops::DaftVarianceAggable;
+        use crate::datatypes::DataType::*;
+
+        // Upcast all numeric types to float64 and use f64 variance kernel.
+        match self.data_type() {
+            Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 | Float32 | Float64 => {
+                let casted = self.cast(&Float64)?;
+                match groups {
+                    Some(groups) => {
+                        Ok(
+                            DaftVarianceAggable::grouped_variance(&casted.f64()?, groups, ddof)?
+                                .into_series(),
+                        )
+                    }
+                    None => Ok(DaftVarianceAggable::variance(&casted.f64()?, ddof)?.into_series()),
+                }
+            }
+            other => Err(DaftError::TypeError(format!(
+                "Numeric variance is not implemented for type {}",
+                other
+            ))),
+        }
+    }
+
+    pub fn stddev(&self, groups: Option<&GroupIndices>, ddof: u64) -> DaftResult<Series> {
+        let variance = self.variance(groups, ddof)?;
+        Ok(variance.f64()?.apply(f64::sqrt)?.into_series())
+    }
+
     pub fn min(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
         self.inner.min(groups)
     }
@@ -98,6 +317,116 @@ impl Series {
         self.inner.max(groups)
     }
 
+    pub fn bit_and(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        self.bitwise_agg(groups, "bitwise and", |s, groups| {
+            use crate::array::ops::DaftBitwiseAggable;
+            with_match_integer_daft_types!(s.data_type(), |$T| {
+                let array = s.downcast::<<$T as DaftDataType>::ArrayType>()?;
+                match groups {
+                    Some(groups) => Ok(DaftBitwiseAggable::grouped_bit_and(&array, groups)?.into_series()),
+                    None => Ok(DaftBitwiseAggable::bit_and(&array)?.into_series()),
+                }
+            })
+        })
+    }
+
+    pub fn bit_or(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        self.bitwise_agg(groups, "bitwise or", |s, groups| {
+            use crate::array::ops::DaftBitwiseAggable;
+            with_match_integer_daft_types!(s.data_type(), |$T| {
+                let array = s.downcast::<<$T as DaftDataType>::ArrayType>()?;
+                match groups {
+                    Some(groups) => Ok(DaftBitwiseAggable::grouped_bit_or(&array, groups)?.into_series()),
+                    None => Ok(DaftBitwiseAggable::bit_or(&array)?.into_series()),
+                }
+            })
+        })
+    }
+
+    pub fn bit_xor(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        self.bitwise_agg(groups, "bitwise xor", |s, groups| {
+            use crate::array::ops::DaftBitwiseAggable;
+            with_match_integer_daft_types!(s.data_type(), |$T| {
+                let array = s.downcast::<<$T as DaftDataType>::ArrayType>()?;
+                match groups {
+                    Some(groups) => Ok(DaftBitwiseAggable::grouped_bit_xor(&array, groups)?.into_series()),
+                    None => Ok(DaftBitwiseAggable::bit_xor(&array)?.into_series()),
+                }
+            })
+        })
+    }
+
+    /// Checks that the series holds integers before running the bitwise aggregation `agg` on it
+    fn bitwise_agg(
+        &self,
+        groups: Option<&GroupIndices>,
+        name: &str,
+        agg: impl Fn(&Series, Option<&GroupIndices>) -> DaftResult<Series>,
+    ) -> DaftResult<Series> {
+        use crate::datatypes::DataType::*;
+
+        match self.data_type() {
+            Int8 | Int16 | Int32 | Int64 | UInt8 | UInt16 | UInt32 | UInt64 => agg(self, groups),
+            other => Err(DaftError::TypeError(format!(
+                "Aggregation {} is only implemented for integer types, got {}",
+                name, other
+            ))),
+        }
+    }
+
+    pub fn any(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftBoolAggable;
+        let array = self.bool_agg_input("any")?;
+        let array = array.bool()?;
+        match groups {
+            Some(groups) => Ok(DaftBoolAggable::grouped_any(&array, groups)?.into_series()),
+            None => Ok(DaftBoolAggable::any(&array)?.into_series()),
+        }
+    }
+
+    pub fn all(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::DaftBoolAggable;
+        let array = self.bool_agg_input("all")?;
+        let array = array.bool()?;
+        match groups {
+            Some(groups) => Ok(DaftBoolAggable::grouped_all(&array, groups)?.into_series()),
+            None => Ok(DaftBoolAggable::all(&array)?.into_series()),
+        }
+    }
+
+    pub fn string_agg(&self, groups: Option<&GroupIndices>, separator: &str) -> DaftResult<Series> {
+        use crate::array::ops::DaftStringAggable;
+        let array = match self.data_type() {
+            DataType::Utf8 => self.clone(),
+            DataType::Null => self.cast(&DataType::Utf8)?,
+            other => {
+                return Err(DaftError::TypeError(format!(
+                    "Aggregation string_agg is only implemented for string types, got {}",
+                    other
+                )))
+            }
+        };
+        let array = array.utf8()?;
+        match groups {
+            Some(groups) => {
+                Ok(DaftStringAggable::grouped_string_agg(&array, groups, separator)?.into_series())
+            }
+            None => Ok(DaftStringAggable::string_agg(&array, separator)?.into_series()),
+        }
+    }
+
+    /// Checks that the series holds booleans (or only nulls) before running the boolean aggregation `name` on it
+    fn bool_agg_input(&self, name: &str) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Boolean => Ok(self.clone()),
+            DataType::Null => self.cast(&DataType::Boolean),
+            other => Err(DaftError::TypeError(format!(
+                "Aggregation {} is only implemented for boolean types, got {}",
+                name, other
+            ))),
+        }
+    }
+
     pub fn any_value(
         &self,
         groups: Option<&GroupIndices>,
@@ -134,10 +463,119 @@ impl Series {
         )?)
     }
 
+    /// First non-null value of each group in the order of the input
+    pub fn first(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        self.take_first_valid(groups, false)
+    }
+
+    /// Last non-null value of each group in the order of the input
+    pub fn last(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        self.take_first_valid(groups, true)
+    }
+
+    fn take_first_valid(
+        &self,
+        groups: Option<&GroupIndices>,
+        from_end: bool,
+    ) -> DaftResult<Series> {
+        let validity = self.validity();
+        let is_valid = |i: u64| validity.map_or(true, |v| v.get_bit(i as usize));
+        let indices = if self.data_type().is_null() {
+            Box::new(PrimitiveArray::new_null(
+                arrow2::datatypes::DataType::UInt64,
+                groups.map_or(1, |groups| groups.len()),
+            ))
+        } else {
+            match groups {
+                Some(groups) => Box::new(PrimitiveArray::from_trusted_len_iter(groups.iter().map(
+                    |g| {
+                        if from_end {
+                            g.iter().rev().find(|i| is_valid(**i)).copied()
+                        } else {
+                            g.iter().find(|i| is_valid(**i)).copied()
+                        }
+                    },
+                ))),
+                None => {
+                    let mut all = 0..self.len() as u64;
+                    let idx = if from_end {
+                        all.rev().find(|i| is_valid(*i))
+                    } else {
+                        all.find(|i| is_valid(*i))
+                    };
+                    Box::new(PrimitiveArray::from([idx]))
+                }
+            }
+        };
+
+        self.take(&Series::from_arrow(
+            Field::new("", DataType::UInt64).into(),
+            indices,
+        )?)
+    }
+
     pub fn agg_list(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
         self.inner.agg_list(groups)
     }
 
+    /// Like `agg_list`, but each group's list only holds the first occurrence of each distinct non-null value,
+    /// in the order of the input
+    pub fn agg_set(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
+        use crate::array::ops::IntoGroups;
+        use crate::datatypes::DataType::*;
+
+        let s = self.as_physical()?;
+        if !matches!(
+            s.data_type(),
+            Null | Boolean
+                | Int8
+                | Int16
+                | Int32
+                | Int64
+                | Int128
+                | UInt8
+                | UInt16
+                | UInt32
+                | UInt64
+                | Float32
+                | Float64
+                | Utf8
+                | Binary
+        ) {
+            return Err(DaftError::TypeError(format!(
+                "Set aggregation is not implemented for type {}",
+                self.data_type()
+            )));
+        }
+
+        // Id of each row's value, so that duplicates can be found by comparing ids
+        let mut value_ids = vec![0; self.len()];
+        if !s.data_type().is_null() {
+            for (value_id, rows) in s.make_groups()?.1.iter().enumerate() {
+                for row in rows {
+                    value_ids[*row as usize] = value_id;
+                }
+            }
+        }
+        let validity = self.validity();
+        let is_valid = |i: u64| {
+            !self.data_type().is_null() && validity.map_or(true, |v| v.get_bit(i as usize))
+        };
+        let distinct_rows = |rows: &mut dyn Iterator<Item = u64>| {
+            let mut seen = std::collections::HashSet::new();
+            rows.filter(|i| is_valid(*i) && seen.insert(value_ids[*i as usize]))
+                .collect::<Vec<_>>()
+        };
+        let distinct_groups: GroupIndices = match groups {
+            Some(groups) => groups
+                .iter()
+                .map(|g| distinct_rows(&mut g.iter().copied()))
+                .collect(),
+            None => vec![distinct_rows(&mut (0..self.len() as u64))],
+        };
+        self.agg_list(Some(&distinct_groups))
+    }
+
     pub fn agg_concat(&self, groups: Option<&GroupIndices>) -> DaftResult<Series> {
         use crate::array::ops::DaftConcatAggable;
         match self.data_type() {
@@ -30,10 +30,24 @@ impl_arithmetic_for_series!(Rem, rem);
 
 #[cfg(test)]
 mod tests {
-    use crate::array::ops::full::FullNull;
-    use crate::datatypes::{DataType, Float64Array, Int64Array, Utf8Array};
-    use crate::series::IntoSeries;
-    use common_error::DaftResult;
+    use crate::array::ops::{as_arrow::AsArrow, full::FullNull};
+    use crate::datatypes::{DataType, Float64Array, Int64Array, TimeUnit, Utf8Array};
+    use crate::series::{IntoSeries, Series};
+    use common_error::{DaftError, DaftResult};
+
+    fn temporal(name: &str, values: Vec<i64>, dtype: DataType) -> DaftResult<Series> {
+        Int64Array::from((name, values)).into_series().cast(&dtype)
+    }
+
+    fn physical_values(series: &Series) -> DaftResult<Vec<i64>> {
+        Ok(series
+            .as_physical()?
+            .i64()?
+            .as_arrow()
+            .values_iter()
+            .copied()
+            .collect())
+    }
 
     #[test]
     fn add_int_and_int() -> DaftResult<()> {
@@ -102,4 +116,47 @@ mod tests {
         assert_eq!(*c?.data_type(), DataType::Utf8);
         Ok(())
     }
+    #[test]
+    fn sub_timestamp_and_timestamp() -> DaftResult<()> {
+        let ts = DataType::Timestamp(TimeUnit::Milliseconds, None);
+        let a = temporal("a", vec![5_000, 1_000, 0], ts.clone())?;
+        let b = temporal("b", vec![2_000, 1_000, 3_000], ts)?;
+        let c = (&a - &b)?;
+        assert_eq!(*c.data_type(), DataType::Duration(TimeUnit::Milliseconds));
+        assert_eq!(physical_values(&c)?, vec![3_000, 0, -3_000]);
+        Ok(())
+    }
+    #[test]
+    fn add_timestamp_and_duration() -> DaftResult<()> {
+        let ts = DataType::Timestamp(TimeUnit::Microseconds, Some("UTC".to_string()));
+        let a = temporal("a", vec![1_000_000, 0], ts.clone())?;
+        let b = temporal(
+            "b",
+            vec![500, -1],
+            DataType::Duration(TimeUnit::Microseconds),
+        )?;
+        let c = (&a + &b)?;
+        assert_eq!(*c.data_type(), ts);
+        assert_eq!(physical_values(&c)?, vec![1_000_500, -1]);
+        // Subtracting the duration again gives back the timestamps
+        assert_eq!(physical_values(&(&c - &b)?)?, vec![1_000_000, 0]);
+        Ok(())
+    }
+    #[test]
+    fn temporal_arithmetic_with_differing_units() -> DaftResult<()> {
+        let a = temporal(
+            "a",
+            vec![1],
+            DataType::Timestamp(TimeUnit::Milliseconds, None),
+        )?;
+        let b = temporal(
+            "b",
+            vec![1],
+            DataType::Timestamp(TimeUnit::Nanoseconds, None),
+        )?;
+        let d = temporal("d", vec![1], DataType::Duration(TimeUnit::Nanoseconds))?;
+        assert!(matches!(&a - &b, Err(DaftError::TypeError(_))));
+        assert!(matches!(&a + &d, Err(DaftError::TypeError(_))));
+        Ok(())
+    }
 }
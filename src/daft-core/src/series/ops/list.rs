@@ -69,6 +69,28 @@ impl Series {
         }
     }
 
+    pub fn list_count_distinct(&self) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::List(_) => self.list()?.count_distinct(),
+            DataType::FixedSizeList(..) => self.fixed_size_list()?.count_distinct(),
+            dt => Err(DaftError::TypeError(format!(
+                "Count distinct not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
+    pub fn list_distinct(&self) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::List(_) => self.list()?.distinct(),
+            DataType::FixedSizeList(..) => self.fixed_size_list()?.distinct(),
+            dt => Err(DaftError::TypeError(format!(
+                "Distinct not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
     pub fn list_sum(&self) -> DaftResult<Series> {
         match self.data_type() {
             DataType::List(_) => self.list()?.sum(),
@@ -91,6 +113,61 @@ impl Series {
         }
     }
 
+    pub fn list_median(&self) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::List(_) => self.list()?.median(),
+            DataType::FixedSizeList(..) => self.fixed_size_list()?.median(),
+            dt => Err(DaftError::TypeError(format!(
+                "Median not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
+    pub fn list_mode(&self) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::List(_) => self.list()?.mode(),
+            DataType::FixedSizeList(..) => self.fixed_size_list()?.mode(),
+            dt => Err(DaftError::TypeError(format!(
+                "Mode not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
+    pub fn list_quantile(&self, q: f64) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::List(_) => self.list()?.quantile(q),
+            DataType::FixedSizeList(..) => self.fixed_size_list()?.quantile(q),
+            dt => Err(DaftError::TypeError(format!(
+                "Quantile not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
+    pub fn list_stddev(&self, ddof: u64) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::List(_) => self.list()?.stddev(ddof),
+            DataType::FixedSizeList(..) => self.fixed_size_list()?.stddev(ddof),
+            dt => Err(DaftError::TypeError(format!(
+                "Stddev not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
+    pub fn list_variance(&self, ddof: u64) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::List(_) => self.list()?.variance(ddof),
+            DataType::FixedSizeList(..) => self.fixed_size_list()?.variance(ddof),
+            dt => Err(DaftError::TypeError(format!(
+                "Variance not implemented for {}",
+                dt
+            ))),
+        }
+    }
+
     pub fn list_min(&self) -> DaftResult<Series> {
         match self.data_type() {
             DataType::List(_) => self.list()?.min(),
@@ -25,6 +25,7 @@ pub mod json;
 pub mod len;
 pub mod list;
 pub mod not;
+pub mod negate;
 pub mod null;
 pub mod partitioning;
 pub mod round;
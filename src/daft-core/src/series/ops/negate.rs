@@ -0,0 +1,50 @@
+use crate::datatypes::DataType;
+use crate::series::Series;
+use common_error::DaftError;
+use common_error::DaftResult;
+
+impl Series {
+    pub fn negate(&self) -> DaftResult<Series> {
+        use crate::series::array_impl::IntoSeries;
+
+        use DataType::*;
+        match self.data_type() {
+            Int8 => Ok(self.i8().unwrap().negate()?.into_series()),
+            Int16 => Ok(self.i16().unwrap().negate()?.into_series()),
+            Int32 => Ok(self.i32().unwrap().negate()?.into_series()),
+            Int64 => Ok(self.i64().unwrap().negate()?.into_series()),
+            Float32 => Ok(self.f32().unwrap().negate()?.into_series()),
+            Float64 => Ok(self.f64().unwrap().negate()?.into_series()),
+            dt => Err(DaftError::TypeError(format!(
+                "negate not implemented for {}",
+                dt
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::{DaftError, DaftResult};
+
+    use crate::{
+        array::ops::as_arrow::AsArrow,
+        datatypes::{Int8Array, UInt8Array},
+        series::IntoSeries,
+    };
+
+    #[test]
+    fn negate_series() -> DaftResult<()> {
+        let negated = Int8Array::from(("i", vec![-1, 0, 2]))
+            .into_series()
+            .negate()?;
+        assert_eq!(negated.i8()?.as_arrow().values().as_slice(), [1, 0, -2]);
+        Ok(())
+    }
+
+    #[test]
+    fn negate_unsigned_series_errors() {
+        let unsigned = UInt8Array::from(("u", vec![1, 2])).into_series();
+        assert!(matches!(unsigned.negate(), Err(DaftError::TypeError(_))));
+    }
+}
@@ -138,6 +138,16 @@ impl Series {
         }
     }
 
+    pub fn utf8_strip(&self) -> DaftResult<Series> {
+        match self.data_type() {
+            DataType::Utf8 => Ok(self.utf8()?.strip()?.into_series()),
+            DataType::Null => Ok(self.clone()),
+            dt => Err(DaftError::TypeError(format!(
+                "Strip not implemented for type {dt}"
+            ))),
+        }
+    }
+
     pub fn utf8_reverse(&self) -> DaftResult<Series> {
         match self.data_type() {
             DataType::Utf8 => Ok(self.utf8()?.reverse()?.into_series()),
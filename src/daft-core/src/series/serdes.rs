@@ -10,7 +10,8 @@ use crate::{
     },
     datatypes::logical::{
         DateArray, Decimal128Array, DurationArray, EmbeddingArray, FixedShapeImageArray,
-        FixedShapeTensorArray, ImageArray, MapArray, TensorArray, TimeArray, TimestampArray,
+        FixedShapeTensorArray, ImageArray, IntervalArray, MapArray, TensorArray, TimeArray,
+        TimestampArray,
     },
     with_match_daft_types, DataType, IntoSeries, Series,
 };
@@ -254,6 +255,17 @@ impl<'d> serde::Deserialize<'d> for Series {
                             .into_series(),
                         )
                     }
+                    Interval(..) => {
+                        type PType = <<IntervalType as DaftLogicalType>::PhysicalType as DaftDataType>::ArrayType;
+                        let physical = map.next_value::<Series>()?;
+                        Ok(
+                            IntervalArray::new(
+                                field,
+                                physical.downcast::<PType>().unwrap().clone(),
+                            )
+                            .into_series(),
+                        )
+                    }
                     Embedding(..) => {
                         type PType = <<EmbeddingType as DaftLogicalType>::PhysicalType as DaftDataType>::ArrayType;
                         let physical = map.next_value::<Series>()?;
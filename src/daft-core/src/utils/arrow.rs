@@ -176,10 +176,59 @@ pub fn arrow_bitmap_and_helper(
     l_bitmap: Option<&arrow2::bitmap::Bitmap>,
     r_bitmap: Option<&arrow2::bitmap::Bitmap>,
 ) -> Option<arrow2::bitmap::Bitmap> {
-    match (l_bitmap, r_bitmap) {
-        (None, None) => None,
-        (Some(l), None) => Some(l.clone()),
-        (None, Some(r)) => Some(r.clone()),
-        (Some(l), Some(r)) => Some(arrow2::bitmap::and(l, r)),
+    combine_validities(&[l_bitmap, r_bitmap])
+}
+
+/// Validity of the result of an operation over several arrays of the same length, which is null wherever any of
+/// the inputs is null. Gives `None`, meaning all valid, if none of the inputs have a validity.
+pub fn combine_validities(
+    validities: &[Option<&arrow2::bitmap::Bitmap>],
+) -> Option<arrow2::bitmap::Bitmap> {
+    validities
+        .iter()
+        .flatten()
+        .fold(None, |combined, validity| match combined {
+            None => Some((*validity).clone()),
+            Some(combined) => Some(arrow2::bitmap::and(&combined, validity)),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow2::bitmap::Bitmap;
+
+    use super::combine_validities;
+
+    #[test]
+    fn combine_two_validities() {
+        let l = Bitmap::from([true, false, true, true]);
+        let r = Bitmap::from([true, true, false, true]);
+        assert_eq!(
+            combine_validities(&[Some(&l), Some(&r)]),
+            Some(Bitmap::from([true, false, false, true]))
+        );
+        assert_eq!(combine_validities(&[Some(&l), None]), Some(l.clone()));
+        assert_eq!(combine_validities(&[None, Some(&r)]), Some(r));
+    }
+
+    #[test]
+    fn combine_three_validities() {
+        let a = Bitmap::from([true, false, true, true]);
+        let b = Bitmap::from([true, true, false, true]);
+        let c = Bitmap::from([false, true, true, true]);
+        assert_eq!(
+            combine_validities(&[Some(&a), Some(&b), Some(&c)]),
+            Some(Bitmap::from([false, false, false, true]))
+        );
+        assert_eq!(
+            combine_validities(&[Some(&a), None, Some(&c)]),
+            Some(Bitmap::from([false, false, true, true]))
+        );
+    }
+
+    #[test]
+    fn combine_all_valid() {
+        assert_eq!(combine_validities(&[None, None, None]), None);
+        assert_eq!(combine_validities(&[]), None);
     }
 }
@@ -1,8 +1,10 @@
 use daft_core::{
     count_mode::CountMode,
-    datatypes::{try_mean_supertype, try_sum_supertype, DataType, Field, FieldID},
+    datatypes::{
+        try_mean_supertype, try_product_supertype, try_sum_supertype, DataType, Field, FieldID,
+    },
     schema::Schema,
-    utils::supertype::try_get_supertype,
+    utils::{hashable_float_wrapper::FloatWrapper, supertype::try_get_supertype},
 };
 
 use crate::{
@@ -55,12 +57,28 @@ pub enum Expr {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum AggExpr {
     Count(ExprRef, CountMode),
+    CountDistinct(ExprRef),
     Sum(ExprRef),
+    Product(ExprRef),
     Mean(ExprRef),
+    Median(ExprRef),
+    Mode(ExprRef),
+    Quantile(ExprRef, FloatWrapper<f64>),
+    Stddev(ExprRef, u64),
+    Variance(ExprRef, u64),
     Min(ExprRef),
     Max(ExprRef),
     AnyValue(ExprRef, bool),
+    First(ExprRef),
+    Last(ExprRef),
+    BitAnd(ExprRef),
+    BitOr(ExprRef),
+    BitXor(ExprRef),
+    Any(ExprRef),
+    All(ExprRef),
+    StringAgg(ExprRef, String),
     List(ExprRef),
+    Set(ExprRef),
     Concat(ExprRef),
     MapGroups {
         func: FunctionExpr,
@@ -85,12 +103,28 @@ impl AggExpr {
         use AggExpr::*;
         match self {
             Count(expr, ..)
+            | CountDistinct(expr)
             | Sum(expr)
+            | Product(expr)
             | Mean(expr)
+            | Median(expr)
+            | Mode(expr)
+            | Quantile(expr, _)
+            | Stddev(expr, _)
+            | Variance(expr, _)
             | Min(expr)
             | Max(expr)
             | AnyValue(expr, _)
+            | First(expr)
+            | Last(expr)
+            | BitAnd(expr)
+            | BitOr(expr)
+            | BitXor(expr)
+            | Any(expr)
+            | All(expr)
+            | StringAgg(expr, _)
             | List(expr)
+            | Set(expr)
             | Concat(expr) => expr.name(),
             MapGroups { func: _, inputs } => inputs.first().unwrap().name(),
         }
@@ -103,14 +137,42 @@ impl AggExpr {
                 let child_id = expr.semantic_id(schema);
                 FieldID::new(format!("{child_id}.local_count({mode})"))
             }
+            CountDistinct(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_count_distinct()"))
+            }
             Sum(expr) => {
                 let child_id = expr.semantic_id(schema);
                 FieldID::new(format!("{child_id}.local_sum()"))
             }
+            Product(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_product()"))
+            }
             Mean(expr) => {
                 let child_id = expr.semantic_id(schema);
                 FieldID::new(format!("{child_id}.local_mean()"))
             }
+            Median(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_median()"))
+            }
+            Mode(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_mode()"))
+            }
+            Quantile(expr, q) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_quantile({})", q.0))
+            }
+            Stddev(expr, ddof) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_stddev(ddof={ddof})"))
+            }
+            Variance(expr, ddof) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_variance(ddof={ddof})"))
+            }
             Min(expr) => {
                 let child_id = expr.semantic_id(schema);
                 FieldID::new(format!("{child_id}.local_min()"))
@@ -125,10 +187,48 @@ impl AggExpr {
                     "{child_id}.local_any_value(ignore_nulls={ignore_nulls})"
                 ))
             }
+            First(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_first()"))
+            }
+            Last(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_last()"))
+            }
+            BitAnd(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_bit_and()"))
+            }
+            BitOr(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_bit_or()"))
+            }
+            BitXor(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_bit_xor()"))
+            }
+            Any(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_any()"))
+            }
+            All(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_all()"))
+            }
+            StringAgg(expr, separator) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!(
+                    "{child_id}.local_string_agg(separator={separator:?})"
+                ))
+            }
             List(expr) => {
                 let child_id = expr.semantic_id(schema);
                 FieldID::new(format!("{child_id}.local_list()"))
             }
+            Set(expr) => {
+                let child_id = expr.semantic_id(schema);
+                FieldID::new(format!("{child_id}.local_set()"))
+            }
             Concat(expr) => {
                 let child_id = expr.semantic_id(schema);
                 FieldID::new(format!("{child_id}.local_concat()"))
@@ -141,12 +241,28 @@ impl AggExpr {
         use AggExpr::*;
         match self {
             Count(expr, ..)
+            | CountDistinct(expr)
             | Sum(expr)
+            | Product(expr)
             | Mean(expr)
+            | Median(expr)
+            | Mode(expr)
+            | Quantile(expr, _)
+            | Stddev(expr, _)
+            | Variance(expr, _)
             | Min(expr)
             | Max(expr)
             | AnyValue(expr, _)
+            | First(expr)
+            | Last(expr)
+            | BitAnd(expr)
+            | BitOr(expr)
+            | BitXor(expr)
+            | Any(expr)
+            | All(expr)
+            | StringAgg(expr, _)
             | List(expr)
+            | Set(expr)
             | Concat(expr) => vec![expr.clone()],
             MapGroups { func: _, inputs } => inputs.iter().map(|e| e.clone().into()).collect(),
         }
@@ -155,7 +271,7 @@ impl AggExpr {
     pub fn to_field(&self, schema: &Schema) -> DaftResult<Field> {
         use AggExpr::*;
         match self {
-            Count(expr, ..) => {
+            Count(expr, ..) | CountDistinct(expr) => {
                 let field = expr.to_field(schema)?;
                 Ok(Field::new(field.name.as_str(), DataType::UInt64))
             }
@@ -166,18 +282,68 @@ impl AggExpr {
                     try_sum_supertype(&field.dtype)?,
                 ))
             }
-            Mean(expr) => {
+            Product(expr) => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(
+                    field.name.as_str(),
+                    try_product_supertype(&field.dtype)?,
+                ))
+            }
+            Mean(expr) | Median(expr) | Stddev(expr, _) | Variance(expr, _) => {
+                let field = expr.to_field(schema)?;
+                Ok(Field::new(
+                    field.name.as_str(),
+                    try_mean_supertype(&field.dtype)?,
+                ))
+            }
+            Quantile(expr, q) => {
+                if !(0.0..=1.0).contains(&q.0) {
+                    return Err(DaftError::ValueError(format!(
+                        "Quantile must be between 0 and 1, got {}",
+                        q.0
+                    )));
+                }
                 let field = expr.to_field(schema)?;
                 Ok(Field::new(
                     field.name.as_str(),
                     try_mean_supertype(&field.dtype)?,
                 ))
             }
-            Min(expr) | Max(expr) | AnyValue(expr, _) => {
+            Min(expr) | Max(expr) | Mode(expr) | AnyValue(expr, _) | First(expr) | Last(expr) => {
                 let field = expr.to_field(schema)?;
                 Ok(Field::new(field.name.as_str(), field.dtype))
             }
-            List(expr) => expr.to_field(schema)?.to_list_field(),
+            BitAnd(expr) | BitOr(expr) | BitXor(expr) => {
+                let field = expr.to_field(schema)?;
+                if !field.dtype.is_integer() {
+                    return Err(DaftError::TypeError(format!(
+                        "Bitwise aggregations are only supported on integer types, got dtype {} for column \"{}\"",
+                        field.dtype, field.name
+                    )));
+                }
+                Ok(field)
+            }
+            Any(expr) | All(expr) => {
+                let field = expr.to_field(schema)?;
+                if !matches!(field.dtype, DataType::Boolean | DataType::Null) {
+                    return Err(DaftError::TypeError(format!(
+                        "Boolean aggregations are only supported on boolean types, got dtype {} for column \"{}\"",
+                        field.dtype, field.name
+                    )));
+                }
+                Ok(Field::new(field.name.as_str(), DataType::Boolean))
+            }
+            StringAgg(expr, _) => {
+                let field = expr.to_field(schema)?;
+                if !matches!(field.dtype, DataType::Utf8 | DataType::Null) {
+                    return Err(DaftError::TypeError(format!(
+                        "String aggregation is only supported on string types, got dtype {} for column \"{}\"",
+                        field.dtype, field.name
+                    )));
+                }
+                Ok(Field::new(field.name.as_str(), DataType::Utf8))
+            }
+            List(expr) | Set(expr) => expr.to_field(schema)?.to_list_field(),
             Concat(expr) => {
                 let field = expr.to_field(schema)?;
                 match field.dtype {
@@ -200,11 +366,23 @@ impl AggExpr {
         use AggExpr::*;
         match name {
             "count" => Ok(Count(child.clone().into(), CountMode::Valid)),
+            "count_distinct" => Ok(CountDistinct(child.clone().into())),
             "sum" => Ok(Sum(child.clone().into())),
+            "product" => Ok(Product(child.clone().into())),
             "mean" => Ok(Mean(child.clone().into())),
+            "median" => Ok(Median(child.clone().into())),
+            "mode" => Ok(Mode(child.clone().into())),
             "min" => Ok(Min(child.clone().into())),
             "max" => Ok(Max(child.clone().into())),
+            "first" => Ok(First(child.clone().into())),
+            "last" => Ok(Last(child.clone().into())),
+            "bit_and" => Ok(BitAnd(child.clone().into())),
+            "bit_or" => Ok(BitOr(child.clone().into())),
+            "bit_xor" => Ok(BitXor(child.clone().into())),
+            "any" => Ok(Any(child.clone().into())),
+            "all" => Ok(All(child.clone().into())),
             "list" => Ok(List(child.clone().into())),
+            "set" => Ok(Set(child.clone().into())),
             _ => Err(DaftError::ValueError(format!(
                 "{} not a valid aggregation name",
                 name
@@ -240,14 +418,42 @@ impl Expr {
         Expr::Agg(AggExpr::Count(self.clone().into(), mode))
     }
 
+    pub fn count_distinct(&self) -> Self {
+        Expr::Agg(AggExpr::CountDistinct(self.clone().into()))
+    }
+
     pub fn sum(&self) -> Self {
         Expr::Agg(AggExpr::Sum(self.clone().into()))
     }
 
+    pub fn product(&self) -> Self {
+        Expr::Agg(AggExpr::Product(self.clone().into()))
+    }
+
     pub fn mean(&self) -> Self {
         Expr::Agg(AggExpr::Mean(self.clone().into()))
     }
 
+    pub fn median(&self) -> Self {
+        Expr::Agg(AggExpr::Median(self.clone().into()))
+    }
+
+    pub fn mode(&self) -> Self {
+        Expr::Agg(AggExpr::Mode(self.clone().into()))
+    }
+
+    pub fn quantile(&self, q: f64) -> Self {
+        Expr::Agg(AggExpr::Quantile(self.clone().into(), FloatWrapper(q)))
+    }
+
+    pub fn stddev(&self, ddof: u64) -> Self {
+        Expr::Agg(AggExpr::Stddev(self.clone().into(), ddof))
+    }
+
+    pub fn variance(&self, ddof: u64) -> Self {
+        Expr::Agg(AggExpr::Variance(self.clone().into(), ddof))
+    }
+
     pub fn min(&self) -> Self {
         Expr::Agg(AggExpr::Min(self.clone().into()))
     }
@@ -260,10 +466,49 @@ impl Expr {
         Expr::Agg(AggExpr::AnyValue(self.clone().into(), ignore_nulls))
     }
 
+    pub fn first(&self) -> Self {
+        Expr::Agg(AggExpr::First(self.clone().into()))
+    }
+
+    pub fn last(&self) -> Self {
+        Expr::Agg(AggExpr::Last(self.clone().into()))
+    }
+
+    pub fn bit_and(&self) -> Self {
+        Expr::Agg(AggExpr::BitAnd(self.clone().into()))
+    }
+
+    pub fn bit_or(&self) -> Self {
+        Expr::Agg(AggExpr::BitOr(self.clone().into()))
+    }
+
+    pub fn bit_xor(&self) -> Self {
+        Expr::Agg(AggExpr::BitXor(self.clone().into()))
+    }
+
+    pub fn any(&self) -> Self {
+        Expr::Agg(AggExpr::Any(self.clone().into()))
+    }
+
+    pub fn all(&self) -> Self {
+        Expr::Agg(AggExpr::All(self.clone().into()))
+    }
+
+    pub fn string_agg(&self, separator: &str) -> Self {
+        Expr::Agg(AggExpr::StringAgg(
+            self.clone().into(),
+            separator.to_string(),
+        ))
+    }
+
     pub fn agg_list(&self) -> Self {
         Expr::Agg(AggExpr::List(self.clone().into()))
     }
 
+    pub fn agg_set(&self) -> Self {
+        Expr::Agg(AggExpr::Set(self.clone().into()))
+    }
+
     pub fn agg_concat(&self) -> Self {
         Expr::Agg(AggExpr::Concat(self.clone().into()))
     }
@@ -682,14 +927,30 @@ impl Display for AggExpr {
         use AggExpr::*;
         match self {
             Count(expr, mode) => write!(f, "count({expr}, {mode})"),
+            CountDistinct(expr) => write!(f, "count_distinct({expr})"),
             Sum(expr) => write!(f, "sum({expr})"),
+            Product(expr) => write!(f, "product({expr})"),
             Mean(expr) => write!(f, "mean({expr})"),
+            Median(expr) => write!(f, "median({expr})"),
+            Mode(expr) => write!(f, "mode({expr})"),
+            Quantile(expr, q) => write!(f, "quantile({expr}, {})", q.0),
+            Stddev(expr, ddof) => write!(f, "stddev({expr}, ddof={ddof})"),
+            Variance(expr, ddof) => write!(f, "variance({expr}, ddof={ddof})"),
             Min(expr) => write!(f, "min({expr})"),
             Max(expr) => write!(f, "max({expr})"),
             AnyValue(expr, ignore_nulls) => {
                 write!(f, "any_value({expr}, ignore_nulls={ignore_nulls})")
             }
+            First(expr) => write!(f, "first({expr})"),
+            Last(expr) => write!(f, "last({expr})"),
+            BitAnd(expr) => write!(f, "bit_and({expr})"),
+            BitOr(expr) => write!(f, "bit_or({expr})"),
+            BitXor(expr) => write!(f, "bit_xor({expr})"),
+            Any(expr) => write!(f, "any({expr})"),
+            All(expr) => write!(f, "all({expr})"),
+            StringAgg(expr, separator) => write!(f, "string_agg({expr}, separator={separator:?})"),
             List(expr) => write!(f, "list({expr})"),
+            Set(expr) => write!(f, "set({expr})"),
             Concat(expr) => write!(f, "list({expr})"),
             MapGroups { func, inputs } => function_display(f, func, inputs),
         }
@@ -1,22 +1,36 @@
 mod count;
+mod count_distinct;
+mod distinct;
 mod explode;
 mod get;
 mod join;
 mod max;
 mod mean;
+mod median;
 mod min;
+mod mode;
+mod quantile;
+mod stddev;
 mod sum;
+mod variance;
 
 use count::CountEvaluator;
-use daft_core::CountMode;
+use count_distinct::CountDistinctEvaluator;
+use daft_core::{utils::hashable_float_wrapper::FloatWrapper, CountMode};
+use distinct::DistinctEvaluator;
 use explode::ExplodeEvaluator;
 use get::GetEvaluator;
 use join::JoinEvaluator;
 use max::MaxEvaluator;
 use mean::MeanEvaluator;
+use median::MedianEvaluator;
 use min::MinEvaluator;
+use mode::ModeEvaluator;
+use quantile::QuantileEvaluator;
 use serde::{Deserialize, Serialize};
+use stddev::StddevEvaluator;
 use sum::SumEvaluator;
+use variance::VarianceEvaluator;
 
 use crate::Expr;
 
@@ -27,9 +41,16 @@ pub enum ListExpr {
     Explode,
     Join,
     Count(CountMode),
+    CountDistinct,
+    Distinct,
     Get,
     Sum,
     Mean,
+    Median,
+    Mode,
+    Quantile(FloatWrapper<f64>),
+    Stddev(u64),
+    Variance(u64),
     Min,
     Max,
 }
@@ -42,9 +63,16 @@ impl ListExpr {
             Explode => &ExplodeEvaluator {},
             Join => &JoinEvaluator {},
             Count(_) => &CountEvaluator {},
+            CountDistinct => &CountDistinctEvaluator {},
+            Distinct => &DistinctEvaluator {},
             Get => &GetEvaluator {},
             Sum => &SumEvaluator {},
             Mean => &MeanEvaluator {},
+            Median => &MedianEvaluator {},
+            Mode => &ModeEvaluator {},
+            Quantile(_) => &QuantileEvaluator {},
+            Stddev(_) => &StddevEvaluator {},
+            Variance(_) => &VarianceEvaluator {},
             Min => &MinEvaluator {},
             Max => &MaxEvaluator {},
         }
@@ -72,6 +100,20 @@ pub fn count(input: &Expr, mode: CountMode) -> Expr {
     }
 }
 
+pub fn count_distinct(input: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::List(ListExpr::CountDistinct),
+        inputs: vec![input.clone()],
+    }
+}
+
+pub fn distinct(input: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::List(ListExpr::Distinct),
+        inputs: vec![input.clone()],
+    }
+}
+
 pub fn get(input: &Expr, idx: &Expr, default: &Expr) -> Expr {
     Expr::Function {
         func: super::FunctionExpr::List(ListExpr::Get),
@@ -93,6 +135,41 @@ pub fn mean(input: &Expr) -> Expr {
     }
 }
 
+pub fn median(input: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::List(ListExpr::Median),
+        inputs: vec![input.clone()],
+    }
+}
+
+pub fn mode(input: &Expr) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::List(ListExpr::Mode),
+        inputs: vec![input.clone()],
+    }
+}
+
+pub fn quantile(input: &Expr, q: f64) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::List(ListExpr::Quantile(FloatWrapper(q))),
+        inputs: vec![input.clone()],
+    }
+}
+
+pub fn stddev(input: &Expr, ddof: u64) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::List(ListExpr::Stddev(ddof)),
+        inputs: vec![input.clone()],
+    }
+}
+
+pub fn variance(input: &Expr, ddof: u64) -> Expr {
+    Expr::Function {
+        func: super::FunctionExpr::List(ListExpr::Variance(ddof)),
+        inputs: vec![input.clone()],
+    }
+}
+
 pub fn min(input: &Expr) -> Expr {
     Expr::Function {
         func: super::FunctionExpr::List(ListExpr::Min),
@@ -0,0 +1,54 @@
+use crate::{functions::FunctionExpr, Expr};
+use daft_core::{
+    datatypes::{try_mean_supertype, Field},
+    schema::Schema,
+    series::Series,
+};
+
+use common_error::{DaftError, DaftResult};
+
+use super::{super::FunctionEvaluator, ListExpr};
+
+pub(super) struct QuantileEvaluator {}
+
+impl FunctionEvaluator for QuantileEvaluator {
+    fn fn_name(&self) -> &'static str {
+        "quantile"
+    }
+
+    fn to_field(&self, inputs: &[Expr], schema: &Schema, _: &Expr) -> DaftResult<Field> {
+        match inputs {
+            [input] => {
+                let inner_field = input.to_field(schema)?.to_exploded_field()?;
+                Ok(Field::new(
+                    inner_field.name.as_str(),
+                    try_mean_supertype(&inner_field.dtype)?,
+                ))
+            }
+            _ => Err(DaftError::SchemaMismatch(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+
+    fn evaluate(&self, inputs: &[Series], expr: &Expr) -> DaftResult<Series> {
+        match inputs {
+            [input] => {
+                let q = match expr {
+                    Expr::Function {
+                        func: FunctionExpr::List(ListExpr::Quantile(q)),
+                        inputs: _,
+                    } => q.0,
+                    _ => panic!("Expected List Quantile Expr, got {expr}"),
+                };
+
+                Ok(input.list_quantile(q)?)
+            }
+            _ => Err(DaftError::ValueError(format!(
+                "Expected 1 input arg, got {}",
+                inputs.len()
+            ))),
+        }
+    }
+}
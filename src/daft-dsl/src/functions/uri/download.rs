@@ -1,5 +1,5 @@
 use daft_core::datatypes::{DataType, Field};
-use daft_io::url_download;
+use daft_io::{url_download, UrlDownloadOptions};
 
 use crate::functions::FunctionExpr;
 use crate::{functions::FunctionEvaluator, Expr};
@@ -23,9 +23,11 @@ impl FunctionEvaluator for DownloadEvaluator {
                 let field = input.to_field(schema)?;
 
                 match &field.dtype {
-                    DataType::Utf8 => Ok(Field::new(field.name, DataType::Binary)),
+                    DataType::Utf8 | DataType::Binary => {
+                        Ok(Field::new(field.name, DataType::Binary))
+                    }
                     _ => Err(DaftError::TypeError(format!(
-                        "Download can only download uris from Utf8Array, got {}",
+                        "Download can only download uris from Utf8Array or BinaryArray, got {}",
                         field
                     ))),
                 }
@@ -38,33 +40,39 @@ impl FunctionEvaluator for DownloadEvaluator {
     }
 
     fn evaluate(&self, inputs: &[Series], expr: &Expr) -> DaftResult<Series> {
-        let (max_connections, raise_error_on_failure, multi_thread, config) = match expr {
-            Expr::Function {
-                func:
-                    FunctionExpr::Uri(UriExpr::Download {
-                        max_connections,
-                        raise_error_on_failure,
-                        multi_thread,
-                        config,
-                    }),
-                inputs: _,
-            } => (
-                max_connections,
-                raise_error_on_failure,
-                multi_thread,
-                config,
-            ),
-            _ => panic!("Expected Url Download Expr, got {expr}"),
-        };
+        let (max_connections, raise_error_on_failure, multi_thread, max_size_bytes, config) =
+            match expr {
+                Expr::Function {
+                    func:
+                        FunctionExpr::Uri(UriExpr::Download {
+                            max_connections,
+                            raise_error_on_failure,
+                            multi_thread,
+                            max_size_bytes,
+                            config,
+                        }),
+                    inputs: _,
+                } => (
+                    max_connections,
+                    raise_error_on_failure,
+                    multi_thread,
+                    max_size_bytes,
+                    config,
+                ),
+                _ => panic!("Expected Url Download Expr, got {expr}"),
+            };
 
         match inputs {
             [input] => url_download(
                 input,
-                *max_connections,
-                *raise_error_on_failure,
-                *multi_thread,
-                config.clone(),
-                None,
+                &UrlDownloadOptions {
+                    max_connections: *max_connections,
+                    raise_error_on_failure: *raise_error_on_failure,
+                    multi_thread: *multi_thread,
+                    max_size_bytes: *max_size_bytes,
+                    config: config.clone(),
+                    ..Default::default()
+                },
             ),
             _ => Err(DaftError::ValueError(format!(
                 "Expected 1 input arg, got {}",
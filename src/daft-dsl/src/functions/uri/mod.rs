@@ -14,9 +14,11 @@ use common_io_config::IOConfig;
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum UriExpr {
     Download {
-        max_connections: usize,
+        /// None picks a default from the available parallelism when the download runs
+        max_connections: Option<usize>,
         raise_error_on_failure: bool,
         multi_thread: bool,
+        max_size_bytes: Option<usize>,
         config: Arc<IOConfig>,
     },
 }
@@ -33,9 +35,10 @@ impl UriExpr {
 
 pub fn download(
     input: &Expr,
-    max_connections: usize,
+    max_connections: Option<usize>,
     raise_error_on_failure: bool,
     multi_thread: bool,
+    max_size_bytes: Option<usize>,
     config: Option<IOConfig>,
 ) -> Expr {
     Expr::Function {
@@ -43,6 +46,7 @@ pub fn download(
             max_connections,
             raise_error_on_failure,
             multi_thread,
+            max_size_bytes,
             config: config.unwrap_or_default().into(),
         }),
         inputs: vec![input.clone()],
@@ -233,14 +233,42 @@ impl PyExpr {
         Ok(self.expr.count(mode).into())
     }
 
+    pub fn count_distinct(&self) -> PyResult<Self> {
+        Ok(self.expr.count_distinct().into())
+    }
+
     pub fn sum(&self) -> PyResult<Self> {
         Ok(self.expr.sum().into())
     }
 
+    pub fn product(&self) -> PyResult<Self> {
+        Ok(self.expr.product().into())
+    }
+
     pub fn mean(&self) -> PyResult<Self> {
         Ok(self.expr.mean().into())
     }
 
+    pub fn median(&self) -> PyResult<Self> {
+        Ok(self.expr.median().into())
+    }
+
+    pub fn mode(&self) -> PyResult<Self> {
+        Ok(self.expr.mode().into())
+    }
+
+    pub fn quantile(&self, q: f64) -> PyResult<Self> {
+        Ok(self.expr.quantile(q).into())
+    }
+
+    pub fn stddev(&self, ddof: u64) -> PyResult<Self> {
+        Ok(self.expr.stddev(ddof).into())
+    }
+
+    pub fn variance(&self, ddof: u64) -> PyResult<Self> {
+        Ok(self.expr.variance(ddof).into())
+    }
+
     pub fn min(&self) -> PyResult<Self> {
         Ok(self.expr.min().into())
     }
@@ -253,10 +281,46 @@ impl PyExpr {
         Ok(self.expr.any_value(ignore_nulls).into())
     }
 
+    pub fn first(&self) -> PyResult<Self> {
+        Ok(self.expr.first().into())
+    }
+
+    pub fn last(&self) -> PyResult<Self> {
+        Ok(self.expr.last().into())
+    }
+
+    pub fn bit_and(&self) -> PyResult<Self> {
+        Ok(self.expr.bit_and().into())
+    }
+
+    pub fn bit_or(&self) -> PyResult<Self> {
+        Ok(self.expr.bit_or().into())
+    }
+
+    pub fn bit_xor(&self) -> PyResult<Self> {
+        Ok(self.expr.bit_xor().into())
+    }
+
+    pub fn any(&self) -> PyResult<Self> {
+        Ok(self.expr.any().into())
+    }
+
+    pub fn all(&self) -> PyResult<Self> {
+        Ok(self.expr.all().into())
+    }
+
+    pub fn string_agg(&self, separator: &str) -> PyResult<Self> {
+        Ok(self.expr.string_agg(separator).into())
+    }
+
     pub fn agg_list(&self) -> PyResult<Self> {
         Ok(self.expr.agg_list().into())
     }
 
+    pub fn agg_set(&self) -> PyResult<Self> {
+        Ok(self.expr.agg_set().into())
+    }
+
     pub fn agg_concat(&self) -> PyResult<Self> {
         Ok(self.expr.agg_concat().into())
     }
@@ -593,12 +657,15 @@ impl PyExpr {
 
     pub fn url_download(
         &self,
-        max_connections: i64,
+        max_connections: Option<i64>,
         raise_error_on_failure: bool,
         multi_thread: bool,
+        max_size_bytes: Option<usize>,
         config: PyIOConfig,
     ) -> PyResult<Self> {
-        if max_connections <= 0 {
+        if let Some(max_connections) = max_connections
+            && max_connections <= 0
+        {
             return Err(PyValueError::new_err(format!(
                 "max_connections must be positive and non_zero: {max_connections}"
             )));
@@ -606,9 +673,10 @@ impl PyExpr {
         use crate::functions::uri::download;
         Ok(download(
             &self.expr,
-            max_connections as usize,
+            max_connections.map(|max_connections| max_connections as usize),
             raise_error_on_failure,
             multi_thread,
+            max_size_bytes,
             Some(config.config),
         )
         .into())
@@ -17,12 +17,28 @@ impl TreeNode for Expr {
                 use crate::AggExpr::*;
                 match agg_expr {
                     Count(expr, ..)
+                    | CountDistinct(expr)
                     | Sum(expr)
+                    | Product(expr)
                     | Mean(expr)
+                    | Median(expr)
+                    | Mode(expr)
+                    | Quantile(expr, _)
+                    | Stddev(expr, _)
+                    | Variance(expr, _)
                     | Min(expr)
                     | Max(expr)
                     | AnyValue(expr, _)
+                    | First(expr)
+                    | Last(expr)
+                    | BitAnd(expr)
+                    | BitOr(expr)
+                    | BitXor(expr)
+                    | Any(expr)
+                    | All(expr)
+                    | StringAgg(expr, _)
                     | List(expr)
+                    | Set(expr)
                     | Concat(expr) => vec![expr.as_ref()],
                     MapGroups { func: _, inputs } => inputs.iter().collect::<Vec<_>>(),
                 }
@@ -63,14 +79,32 @@ impl TreeNode for Expr {
                 use crate::AggExpr::*;
                 match agg_expr {
                     Count(expr, mode) => transform(expr.as_ref().clone())?.count(mode),
+                    CountDistinct(expr) => transform(expr.as_ref().clone())?.count_distinct(),
                     Sum(expr) => transform(expr.as_ref().clone())?.sum(),
+                    Product(expr) => transform(expr.as_ref().clone())?.product(),
                     Mean(expr) => transform(expr.as_ref().clone())?.mean(),
+                    Median(expr) => transform(expr.as_ref().clone())?.median(),
+                    Mode(expr) => transform(expr.as_ref().clone())?.mode(),
+                    Quantile(expr, q) => transform(expr.as_ref().clone())?.quantile(q.0),
+                    Stddev(expr, ddof) => transform(expr.as_ref().clone())?.stddev(ddof),
+                    Variance(expr, ddof) => transform(expr.as_ref().clone())?.variance(ddof),
                     Min(expr) => transform(expr.as_ref().clone())?.min(),
                     Max(expr) => transform(expr.as_ref().clone())?.max(),
                     AnyValue(expr, ignore_nulls) => {
                         transform(expr.as_ref().clone())?.any_value(ignore_nulls)
                     }
+                    First(expr) => transform(expr.as_ref().clone())?.first(),
+                    Last(expr) => transform(expr.as_ref().clone())?.last(),
+                    BitAnd(expr) => transform(expr.as_ref().clone())?.bit_and(),
+                    BitOr(expr) => transform(expr.as_ref().clone())?.bit_or(),
+                    BitXor(expr) => transform(expr.as_ref().clone())?.bit_xor(),
+                    Any(expr) => transform(expr.as_ref().clone())?.any(),
+                    All(expr) => transform(expr.as_ref().clone())?.all(),
+                    StringAgg(expr, separator) => {
+                        transform(expr.as_ref().clone())?.string_agg(&separator)
+                    }
                     List(expr) => transform(expr.as_ref().clone())?.agg_list(),
+                    Set(expr) => transform(expr.as_ref().clone())?.agg_set(),
                     Concat(expr) => transform(expr.as_ref().clone())?.agg_concat(),
                     MapGroups { func, inputs } => Expr::Agg(MapGroups {
                         func,
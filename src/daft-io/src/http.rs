@@ -1,16 +1,31 @@
-use std::{num::ParseIntError, ops::Range, string::FromUtf8Error, sync::Arc};
+use std::{
+    collections::HashMap,
+    num::ParseIntError,
+    ops::Range,
+    string::FromUtf8Error,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
 use async_trait::async_trait;
+use bytes::Bytes;
+use common_io_config::HttpConfig;
 use futures::{stream::BoxStream, TryStreamExt};
 
 use lazy_static::lazy_static;
+use rand::Rng;
 use regex::Regex;
-use reqwest::header::{CONTENT_LENGTH, RANGE};
+use reqwest::header::{
+    HeaderMap, HeaderName, HeaderValue, CONTENT_LENGTH, CONTENT_TYPE, IF_MODIFIED_SINCE,
+    IF_NONE_MATCH, RANGE,
+};
 use snafu::{IntoError, ResultExt, Snafu};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 use url::Position;
 
 use crate::{
-    object_io::{FileMetadata, FileType, LSResult},
+    get_io_pool_num_threads,
+    object_io::{FileMetadata, FileType, GetConditions, LSResult, UploadMethod},
     stats::IOStatsRef,
     stream_utils::io_stats_on_bytestream,
 };
@@ -74,6 +89,15 @@ enum Error {
         "Unable to parse data as Integer while reading header for file: {path}. {source}"
     ))]
     UnableToParseInteger { path: String, source: ParseIntError },
+
+    #[snafu(display("Invalid HTTP header \"{}\": {}", name, msg))]
+    InvalidHeader { name: String, msg: String },
+
+    #[snafu(display("Invalid {} proxy: {}", scheme, msg))]
+    InvalidProxy { scheme: &'static str, msg: String },
+
+    #[snafu(display("Unable to grab semaphore. {}", source))]
+    UnableToGrabSemaphore { source: tokio::sync::AcquireError },
 }
 
 /// Finds and retrieves FileMetadata from HTML text
@@ -136,23 +160,156 @@ fn _get_file_metadata_from_html(path: &str, text: &str) -> super::Result<Vec<Fil
 
 pub(crate) struct HttpSource {
     client: reqwest::Client,
+    config: HttpConfig,
+    /// Semaphores bounding the number of concurrent requests to each host (keyed on the URL's host
+    /// and port), which are only used when `max_connections_per_host` is set
+    host_semaphores: Mutex<HashMap<String, Arc<Semaphore>>>,
+}
+
+/// Builds the [`HeaderMap`] of custom headers to attach to every request made with this config
+pub(crate) fn headers_from_config(config: &HttpConfig) -> super::Result<HeaderMap> {
+    let mut headers = HeaderMap::with_capacity(config.headers.len());
+    for (name, value) in config.headers.iter() {
+        let header_name =
+            HeaderName::from_bytes(name.as_bytes()).map_err(|err| Error::InvalidHeader {
+                name: name.clone(),
+                msg: err.to_string(),
+            })?;
+        let header_value = HeaderValue::from_str(value).map_err(|err| Error::InvalidHeader {
+            name: name.clone(),
+            msg: err.to_string(),
+        })?;
+        headers.append(header_name, header_value);
+    }
+    Ok(headers)
+}
+
+/// Builds the proxies that requests made with this config are sent through, which bypass the hosts in
+/// `no_proxy`. Credentials embedded in a proxy URL are sent to that proxy as basic auth.
+pub(crate) fn proxies_from_config(config: &HttpConfig) -> super::Result<Vec<reqwest::Proxy>> {
+    let no_proxy = reqwest::NoProxy::from_string(&config.no_proxy.join(","));
+    let mut proxies = vec![];
+    if let Some(http_proxy) = &config.http_proxy {
+        let proxy = reqwest::Proxy::http(http_proxy).map_err(|err| Error::InvalidProxy {
+            scheme: "HTTP",
+            msg: err.to_string(),
+        })?;
+        proxies.push(proxy.no_proxy(no_proxy.clone()));
+    }
+    if let Some(https_proxy) = &config.https_proxy {
+        let proxy = reqwest::Proxy::https(https_proxy).map_err(|err| Error::InvalidProxy {
+            scheme: "HTTPS",
+            msg: err.to_string(),
+        })?;
+        proxies.push(proxy.no_proxy(no_proxy));
+    }
+    Ok(proxies)
+}
+
+/// Checks that `config` can be used to build a client, so that invalid configs surface as a single error
+/// before any request is made
+pub(crate) fn validate_config(config: &HttpConfig) -> super::Result<()> {
+    headers_from_config(config)?;
+    proxies_from_config(config)?;
+    if config.max_connections_per_host == Some(0) {
+        return Err(super::Error::InvalidArgument {
+            msg: "max_connections_per_host must be non-zero".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Extracts the HTTP status code of the response that caused this error, if any
+pub(crate) fn status_code_of_error(error: &super::Error) -> Option<u16> {
+    match error {
+        super::Error::HttpStatus { status, .. }
+        | super::Error::ContentTypeNotAllowed { status, .. } => Some(*status),
+        super::Error::NotFound { source, .. } | super::Error::UnableToOpenFile { source, .. } => {
+            source
+                .downcast_ref::<reqwest::Error>()
+                .and_then(|err| err.status())
+                .map(|status| status.as_u16())
+        }
+        _ => None,
+    }
+}
+
+/// Whether a response with this status code indicates a transient failure that is worth retrying
+fn is_retryable_status(status: reqwest::StatusCode) -> bool {
+    matches!(status.as_u16(), 429 | 502 | 503 | 504)
+}
+
+/// Whether an error that occurred before receiving a response is worth retrying
+fn is_retryable_error(error: &reqwest::Error) -> bool {
+    error.is_connect() || error.is_timeout()
+}
+
+/// Whether the outcome of a read-only request is worth retrying
+fn is_retryable_result(result: &reqwest::Result<reqwest::Response>) -> bool {
+    match result {
+        Ok(response) => is_retryable_status(response.status()),
+        Err(err) => is_retryable_error(err),
+    }
+}
+
+/// Whether the outcome of an upload is worth retrying. Uploads aren't idempotent, so they are only retried if the
+/// connection could not be established at all, since any later failure (a timeout or an error status code) may
+/// come after the server already acted on the upload.
+fn is_retryable_upload_result(result: &reqwest::Result<reqwest::Response>) -> bool {
+    matches!(result, Err(err) if err.is_connect())
+}
+
+/// Computes the jittered exponential backoff to wait before the retry following the `attempt`-th try
+fn retry_backoff(initial_backoff_ms: u64, attempt: u32) -> Duration {
+    let backoff_ms = initial_backoff_ms.saturating_mul(1u64 << (attempt - 1).min(16));
+    let jitter = rand::thread_rng().gen_range(0.5..=1.0);
+    Duration::from_millis((backoff_ms as f64 * jitter) as u64)
 }
 
 impl From<Error> for super::Error {
     fn from(error: Error) -> Self {
         use Error::*;
         match error {
+            // Only timeouts while establishing the connection are connect timeouts. Other timeouts, such as the
+            // whole request taking longer than `timeout_ms` after the server accepted the connection, are read
+            // timeouts.
+            UnableToConnect { path, source } if source.is_connect() && source.is_timeout() => {
+                super::Error::ConnectTimeout {
+                    path,
+                    source: source.into(),
+                }
+            }
+            UnableToConnect { path, source } | UnableToReadBytes { path, source }
+                if source.is_timeout() =>
+            {
+                super::Error::ReadTimeout {
+                    path,
+                    source: source.into(),
+                }
+            }
+            UnableToConnect { path, source } => super::Error::UnableToConnect {
+                path,
+                source: source.into(),
+            },
             UnableToOpenFile { path, source } => match source.status().map(|v| v.as_u16()) {
                 Some(404) | Some(410) => super::Error::NotFound {
                     path,
                     source: source.into(),
                 },
-                None | Some(_) => super::Error::UnableToOpenFile {
+                Some(status) => super::Error::HttpStatus {
+                    path,
+                    status,
+                    source: source.into(),
+                },
+                None => super::Error::UnableToOpenFile {
                     path,
                     source: source.into(),
                 },
             },
             UnableToDetermineSize { path } => super::Error::UnableToDetermineSize { path },
+            InvalidHeader { .. } | InvalidProxy { .. } => super::Error::InvalidArgument {
+                msg: error.to_string(),
+            },
             _ => super::Error::Generic {
                 store: super::SourceType::Http,
                 source: error.into(),
@@ -162,15 +319,86 @@ impl From<Error> for super::Error {
 }
 
 impl HttpSource {
-    pub async fn get_client() -> super::Result<Arc<Self>> {
+    pub async fn get_client(config: &HttpConfig) -> super::Result<Arc<Self>> {
+        // A single client (and therefore a single connection pool) is shared by every request made
+        // through this source, so size the pool to keep all concurrent connections to a host alive.
+        let pool_max_idle_per_host = (config.max_connections_per_io_thread as usize)
+            * get_io_pool_num_threads()
+                .await
+                .expect("Should be running in tokio pool");
+        let mut builder = reqwest::ClientBuilder::default()
+            .pool_max_idle_per_host(pool_max_idle_per_host)
+            .default_headers(headers_from_config(config)?)
+            .gzip(config.decompress)
+            .deflate(config.decompress)
+            .brotli(config.decompress);
+        // Explicitly configured proxies replace the ones reqwest would otherwise pick up from the environment
+        for proxy in proxies_from_config(config)? {
+            builder = builder.proxy(proxy);
+        }
+        if let Some(max_redirects) = config.max_redirects {
+            builder = builder.redirect(match max_redirects {
+                0 => reqwest::redirect::Policy::none(),
+                n => reqwest::redirect::Policy::limited(n as usize),
+            });
+        }
+        if let Some(connect_timeout_ms) = config.connect_timeout_ms {
+            builder = builder.connect_timeout(Duration::from_millis(connect_timeout_ms));
+        }
+        if let Some(timeout_ms) = config.timeout_ms {
+            builder = builder.timeout(Duration::from_millis(timeout_ms));
+        }
         Ok(HttpSource {
-            client: reqwest::ClientBuilder::default()
-                .pool_max_idle_per_host(70)
-                .build()
-                .context(UnableToCreateClientSnafu)?,
+            client: builder.build().context(UnableToCreateClientSnafu)?,
+            config: config.clone(),
+            host_semaphores: Mutex::new(HashMap::new()),
         }
         .into())
     }
+
+    /// Waits for a free slot for a request to the host of `uri` when `max_connections_per_host` is set,
+    /// returning a permit that holds the slot until it is dropped
+    async fn acquire_host_permit(&self, uri: &str) -> super::Result<Option<OwnedSemaphorePermit>> {
+        let Some(max_connections_per_host) = self.config.max_connections_per_host else {
+            return Ok(None);
+        };
+        let url = url::Url::parse(uri).with_context(|_| InvalidUrlSnafu { path: uri })?;
+        let host = url[Position::BeforeHost..Position::AfterPort].to_string();
+        let semaphore = self
+            .host_semaphores
+            .lock()
+            .unwrap()
+            .entry(host)
+            .or_insert_with(|| Arc::new(Semaphore::new(max_connections_per_host as usize)))
+            .clone();
+        let permit = semaphore
+            .acquire_owned()
+            .await
+            .context(UnableToGrabSemaphoreSnafu)?;
+        Ok(Some(permit))
+    }
+
+    /// Sends the request produced by `make_request`, retrying up to `num_tries` times in total with a
+    /// jittered exponential backoff while `is_retryable` holds for the outcome, see [`is_retryable_result`]
+    /// and [`is_retryable_upload_result`]. Any other outcome is returned immediately.
+    async fn send_with_retries<F>(
+        &self,
+        make_request: F,
+        is_retryable: fn(&reqwest::Result<reqwest::Response>) -> bool,
+    ) -> reqwest::Result<reqwest::Response>
+    where
+        F: Fn() -> reqwest::RequestBuilder,
+    {
+        let mut attempt = 1;
+        loop {
+            let result = make_request().send().await;
+            if !is_retryable(&result) || attempt >= self.config.num_tries {
+                return result;
+            }
+            tokio::time::sleep(retry_backoff(self.config.retry_initial_backoff_ms, attempt)).await;
+            attempt += 1;
+        }
+    }
 }
 
 #[async_trait]
@@ -181,17 +409,47 @@ impl ObjectSource for HttpSource {
         range: Option<Range<usize>>,
         io_stats: Option<IOStatsRef>,
     ) -> super::Result<GetResult> {
-        let request = self.client.get(uri);
-        let request = match range {
-            None => request,
-            Some(range) => request.header(
-                RANGE,
-                format!("bytes={}-{}", range.start, range.end.saturating_sub(1)),
-            ),
+        Ok(self.get_with_status(uri, range, io_stats).await?.0)
+    }
+
+    async fn get_with_status(
+        &self,
+        uri: &str,
+        range: Option<Range<usize>>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<(GetResult, Option<u16>)> {
+        self.get_if_changed(uri, range, &GetConditions::default(), io_stats)
+            .await
+    }
+
+    async fn get_if_changed(
+        &self,
+        uri: &str,
+        range: Option<Range<usize>>,
+        conditions: &GetConditions,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<(GetResult, Option<u16>)> {
+        let make_request = || {
+            let mut request = self.client.get(uri);
+            if let Some(range) = &range {
+                request = request.header(
+                    RANGE,
+                    format!("bytes={}-{}", range.start, range.end.saturating_sub(1)),
+                );
+            }
+            if let Some(etag) = &conditions.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &conditions.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+            request
         };
 
-        let response = request
-            .send()
+        // The permit is held until the body has been fully read, by passing it along with the stream
+        let permit = self.acquire_host_permit(uri).await?;
+        let response = self
+            .send_with_retries(make_request, is_retryable_result)
             .await
             .context(UnableToConnectSnafu::<String> { path: uri.into() })?;
         let response = response
@@ -200,6 +458,19 @@ impl ObjectSource for HttpSource {
         if let Some(is) = io_stats.as_ref() {
             is.mark_get_requests(1)
         }
+        let status = response.status().as_u16();
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok());
+        // Dropping the response here closes the connection without reading the body
+        if status != 304 && !conditions.allows_content_type(content_type) {
+            return Err(super::Error::ContentTypeNotAllowed {
+                path: uri.into(),
+                content_type: content_type.map(|v| v.to_string()),
+                status,
+            });
+        }
         let size_bytes = response.content_length().map(|s| s as usize);
         let stream = response.bytes_stream();
         let owned_string = uri.to_owned();
@@ -210,17 +481,15 @@ impl ObjectSource for HttpSource {
             .into_error(e)
             .into()
         });
-        Ok(GetResult::Stream(
-            io_stats_on_bytestream(stream, io_stats),
-            size_bytes,
-            None,
+        Ok((
+            GetResult::Stream(io_stats_on_bytestream(stream, io_stats), size_bytes, permit),
+            Some(status),
         ))
     }
 
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize> {
-        let request = self.client.head(uri);
-        let response = request
-            .send()
+        let response = self
+            .send_with_retries(|| self.client.head(uri), is_retryable_result)
             .await
             .context(UnableToConnectSnafu::<String> { path: uri.into() })?;
         let response = response
@@ -246,6 +515,32 @@ impl ObjectSource for HttpSource {
         }
     }
 
+    async fn put(
+        &self,
+        uri: &str,
+        data: Bytes,
+        method: UploadMethod,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<u16>> {
+        let method = match method {
+            UploadMethod::Put => reqwest::Method::PUT,
+            UploadMethod::Post => reqwest::Method::POST,
+        };
+        let _permit = self.acquire_host_permit(uri).await?;
+        let response = self
+            .send_with_retries(
+                || self.client.request(method.clone(), uri).body(data.clone()),
+                is_retryable_upload_result,
+            )
+            .await
+            .context(UnableToConnectSnafu::<String> { path: uri.into() })?;
+        if let Some(is) = io_stats.as_ref() {
+            is.mark_put_requests(1);
+            is.mark_bytes_uploaded(data.len());
+        }
+        Ok(Some(response.status().as_u16()))
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
@@ -275,9 +570,8 @@ impl ObjectSource for HttpSource {
             unimplemented!("Prefix-listing is not implemented for HTTP listing");
         }
 
-        let request = self.client.get(path);
-        let response = request
-            .send()
+        let response = self
+            .send_with_retries(|| self.client.get(path), is_retryable_result)
             .await
             .context(UnableToConnectSnafu::<String> { path: path.into() })?
             .error_for_status()
@@ -326,6 +620,8 @@ impl ObjectSource for HttpSource {
 #[cfg(test)]
 mod tests {
 
+    use common_io_config::HttpConfig;
+
     use crate::object_io::ObjectSource;
     use crate::HttpSource;
     use crate::Result;
@@ -335,7 +631,7 @@ mod tests {
         let parquet_file_path = "https://daft-public-data.s3.us-west-2.amazonaws.com/test_fixtures/parquet_small/0dad4c3f-da0d-49db-90d8-98684571391b-0.parquet";
         let parquet_expected_md5 = "929674747af64a98aceaa6d895863bd3";
 
-        let client = HttpSource::get_client().await?;
+        let client = HttpSource::get_client(&HttpConfig::default()).await?;
         let parquet_file = client.get(parquet_file_path, None, None).await?;
         let bytes = parquet_file.bytes().await?;
         let all_bytes = bytes.as_ref();
@@ -5,8 +5,11 @@ mod azure_blob;
 mod google_cloud;
 mod http;
 mod local;
+#[cfg(test)]
+mod mock_http_server;
 mod object_io;
 mod object_store_glob;
+mod rate_limiter;
 mod s3_like;
 mod stats;
 mod stream_utils;
@@ -16,17 +19,29 @@ use lazy_static::lazy_static;
 #[cfg(feature = "python")]
 pub mod python;
 
-pub use common_io_config::{AzureConfig, IOConfig, S3Config};
+pub use common_io_config::{AzureConfig, HttpConfig, IOConfig, S3Config};
 pub use object_io::FileMetadata;
+pub use object_io::GetConditions;
 pub use object_io::GetResult;
+pub use object_io::UploadMethod;
 #[cfg(feature = "python")]
 pub use python::register_modules;
 pub use stats::{IOStatsContext, IOStatsRef};
-use tokio::runtime::RuntimeFlavor;
+use tokio::{io::AsyncWriteExt, runtime::RuntimeFlavor};
 
-use std::{borrow::Cow, collections::HashMap, hash::Hash, ops::Range, sync::Arc};
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    hash::Hash,
+    ops::Range,
+    sync::{
+        atomic::{self, AtomicUsize},
+        Arc,
+    },
+};
 
 use futures::{stream::BoxStream, StreamExt, TryStreamExt};
+use tokio_util::sync::CancellationToken;
 
 use snafu::Snafu;
 use url::ParseError;
@@ -34,12 +49,13 @@ use url::ParseError;
 use snafu::prelude::*;
 
 use daft_core::{
-    array::ops::as_arrow::AsArrow,
-    datatypes::{BinaryArray, Utf8Array},
+    array::{ops::as_arrow::AsArrow, StructArray},
+    datatypes::{BinaryArray, BooleanArray, Field, UInt16Array, Utf8Array},
     DataType, IntoSeries, Series,
 };
 
 use common_error::{DaftError, DaftResult};
+use rate_limiter::RateLimiter;
 use s3_like::S3LikeSource;
 
 use self::{http::HttpSource, local::LocalSource, object_io::ObjectSource};
@@ -51,6 +67,22 @@ pub enum Error {
     #[snafu(display("Object at location {} not found\nDetails:\n{}", path, source))]
     NotFound { path: String, source: DynError },
 
+    #[snafu(display("Timed out connecting to {}: {}", path, source))]
+    ConnectTimeout { path: String, source: DynError },
+
+    #[snafu(display("Timed out reading from {}: {}", path, source))]
+    ReadTimeout { path: String, source: DynError },
+
+    #[snafu(display("Unable to connect to {}: {}", path, source))]
+    UnableToConnect { path: String, source: DynError },
+
+    #[snafu(display("Request to {} failed with status code {}: {}", path, status, source))]
+    HttpStatus {
+        path: String,
+        status: u16,
+        source: DynError,
+    },
+
     #[snafu(display("Invalid Argument: {:?}", msg))]
     InvalidArgument { msg: String },
 
@@ -63,12 +95,21 @@ pub enum Error {
         source: std::io::Error,
     },
 
+    #[snafu(display("Unable to write data to file {}: {}", path, source))]
+    UnableToWriteToFile {
+        path: String,
+        source: std::io::Error,
+    },
+
     #[snafu(display("Unable to convert URL \"{}\" to path", path))]
     InvalidUrl {
         path: String,
         source: url::ParseError,
     },
 
+    #[snafu(display("Invalid URL \"{}\": {}", path, reason))]
+    MalformedUrl { path: String, reason: String },
+
     #[snafu(display("Not a File: \"{}\"", path))]
     NotAFile { path: String },
 
@@ -88,9 +129,35 @@ pub enum Error {
         source: DynError,
     },
 
+    #[snafu(display("Object exceeds the maximum size of {} bytes", max_size_bytes))]
+    MaxSizeExceeded { max_size_bytes: usize },
+
     #[snafu(display("Source not yet implemented: {}", store))]
     NotImplementedSource { store: String },
 
+    #[snafu(display("Method not yet implemented for this source: {}", method))]
+    NotImplementedMethod { method: String },
+
+    #[snafu(display(
+        "Server ignored the requested byte range for {} and responded with the full object",
+        path
+    ))]
+    RangeIgnored { path: String },
+
+    #[snafu(display(
+        "Content type {:?} of {} is not one of the allowed content types",
+        content_type,
+        path
+    ))]
+    ContentTypeNotAllowed {
+        path: String,
+        content_type: Option<String>,
+        status: u16,
+    },
+
+    #[snafu(display("Unable to upload to {}: received status code {}", path, status))]
+    UploadFailed { path: String, status: u16 },
+
     #[snafu(display("Unhandled Error for path: {}\nDetails:\n{}", path, msg))]
     Unhandled { path: String, msg: String },
 
@@ -112,6 +179,18 @@ impl From<Error> for DaftError {
         use Error::*;
         match err {
             NotFound { path, source } => DaftError::FileNotFound { path, source },
+            ConnectTimeout { path, source } => DaftError::ConnectTimeout { path, source },
+            ReadTimeout { path, source } => DaftError::ReadTimeout { path, source },
+            UnableToConnect { path, source } => DaftError::ConnectionError { path, source },
+            HttpStatus {
+                path,
+                status,
+                source,
+            } => DaftError::HttpStatus {
+                path,
+                status,
+                source,
+            },
             _ => DaftError::External(err.into()),
         }
     }
@@ -153,7 +232,9 @@ impl IOClient {
 
         let new_source = match source_type {
             SourceType::File => LocalSource::get_client().await? as Arc<dyn ObjectSource>,
-            SourceType::Http => HttpSource::get_client().await? as Arc<dyn ObjectSource>,
+            SourceType::Http => {
+                HttpSource::get_client(&self.config.http).await? as Arc<dyn ObjectSource>
+            }
             SourceType::S3 => {
                 S3LikeSource::get_client(&self.config.s3).await? as Arc<dyn ObjectSource>
             }
@@ -209,26 +290,125 @@ impl IOClient {
         source.get_size(path.as_ref(), io_stats).await
     }
 
+    pub async fn single_url_get_with_status(
+        &self,
+        input: String,
+        range: Option<Range<usize>>,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<(GetResult, Option<u16>)> {
+        let (scheme, path) = parse_url(&input)?;
+        let source = self.get_source(&scheme).await?;
+        source.get_with_status(path.as_ref(), range, io_stats).await
+    }
+
+    pub async fn single_url_get_if_changed(
+        &self,
+        input: String,
+        range: Option<Range<usize>>,
+        conditions: &GetConditions,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<(GetResult, Option<u16>)> {
+        let (scheme, path) = parse_url(&input)?;
+        let source = self.get_source(&scheme).await?;
+        source
+            .get_if_changed(path.as_ref(), range, conditions, io_stats)
+            .await
+    }
+
+    /// Downloads the bytes at `input`, also returning the status code of the response if one was received and the
+    /// error if the download failed. Downloads of more than `max_size_bytes` are aborted and treated as failures.
+    ///
+    /// If a `range` is given, only those bytes of the object are requested. HTTP servers that ignore the range and
+    /// respond with the full object (`200 OK` rather than `206 Partial Content`) are treated as failures, unless
+    /// `truncate_ignored_range` is set, in which case the requested range is sliced out of the full response.
+    ///
+    /// If `conditions` are given, the request is conditional on the object having changed since the copy they
+    /// describe, and a `304 Not Modified` response marks the row as unchanged rather than holding empty bytes.
+    /// Objects whose content type isn't allowed by `conditions` are null without being read, rather than a failure.
+    #[allow(clippy::too_many_arguments)]
     async fn single_url_download(
         &self,
         index: usize,
         input: Option<String>,
+        range: Option<Range<usize>>,
+        truncate_ignored_range: bool,
+        conditions: Option<GetConditions>,
         raise_error_on_failure: bool,
+        max_size_bytes: Option<usize>,
         io_stats: Option<IOStatsRef>,
-    ) -> Result<Option<bytes::Bytes>> {
-        let value = if let Some(input) = input {
-            let response = self.single_url_get(input, None, io_stats).await;
-            let res = match response {
-                Ok(res) => res.bytes().await,
-                Err(err) => Err(err),
+    ) -> Result<UrlDownloadResult> {
+        let (value, status) = if let Some(input) = input {
+            // An empty range has no bytes to download, and can't be expressed as a Range header
+            if range.as_ref().is_some_and(|range| range.is_empty()) {
+                return Ok(UrlDownloadResult {
+                    bytes: Some(bytes::Bytes::new()),
+                    ..Default::default()
+                });
+            }
+            let response = match &conditions {
+                Some(conditions) => {
+                    self.single_url_get_if_changed(
+                        input.clone(),
+                        range.clone(),
+                        conditions,
+                        io_stats,
+                    )
+                    .await
+                }
+                None => {
+                    self.single_url_get_with_status(input.clone(), range.clone(), io_stats)
+                        .await
+                }
+            };
+            let (res, status) = match response {
+                // The object hasn't changed since the copy described by `conditions`, so there are no bytes to read
+                Ok((_, Some(304))) if conditions.is_some() => (Ok(None), Some(304)),
+                Ok((res, status)) => {
+                    let res = res.bytes_with_max_size(max_size_bytes).await;
+                    let res = match (range, status) {
+                        (Some(range), Some(200)) => res.and_then(|bytes| {
+                            ensure!(truncate_ignored_range, RangeIgnoredSnafu { path: input });
+                            let end = range.end.min(bytes.len());
+                            Ok(bytes.slice(range.start.min(end)..end))
+                        }),
+                        _ => res,
+                    };
+                    (res.map(Some), status)
+                }
+                Err(Error::ContentTypeNotAllowed {
+                    path,
+                    content_type,
+                    status,
+                }) => {
+                    log::debug!(
+                        "Skipping url_download at index {index}: content type {content_type:?} of {path} is not allowed"
+                    );
+                    return Ok(UrlDownloadResult {
+                        status: Some(status),
+                        ..Default::default()
+                    });
+                }
+                Err(err) => {
+                    let status = http::status_code_of_error(&err);
+                    (Err(err), status)
+                }
             };
-            Some(res)
+            (Some(res), status)
         } else {
-            None
+            (None, None)
         };
 
         match value {
-            Some(Ok(bytes)) => Ok(Some(bytes)),
+            Some(Ok(Some(bytes))) => Ok(UrlDownloadResult {
+                bytes: Some(bytes),
+                status,
+                ..Default::default()
+            }),
+            Some(Ok(None)) => Ok(UrlDownloadResult {
+                status,
+                unchanged: true,
+                ..Default::default()
+            }),
             Some(Err(err)) => match raise_error_on_failure {
                 true => Err(err),
                 false => {
@@ -236,12 +416,178 @@ impl IOClient {
                         "Error occurred during url_download at index: {index} {} (falling back to Null)",
                         err
                     );
+                    Ok(UrlDownloadResult {
+                        status,
+                        error: Some(err.to_string()),
+                        ..Default::default()
+                    })
+                }
+            },
+            None => Ok(UrlDownloadResult::default()),
+        }
+    }
+
+    /// Streams the bytes at `input` into a local file at `output_path`, returning `output_path` once it has been fully
+    /// written. Partially written files are removed if the download fails, including when it exceeds
+    /// `max_size_bytes`. Rows where either the URL or the output path is null are skipped without issuing a request,
+    /// as are objects whose content type isn't allowed by `conditions`.
+    #[allow(clippy::too_many_arguments)]
+    async fn single_url_download_to_file(
+        &self,
+        index: usize,
+        input: Option<String>,
+        output_path: Option<String>,
+        conditions: Option<GetConditions>,
+        raise_error_on_failure: bool,
+        max_size_bytes: Option<usize>,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<Option<String>> {
+        let (Some(input), Some(output_path)) = (input, output_path) else {
+            return Ok(None);
+        };
+        let response = match &conditions {
+            Some(conditions) => self
+                .single_url_get_if_changed(input, None, conditions, io_stats)
+                .await
+                .map(|(response, _)| response),
+            None => self.single_url_get(input, None, io_stats).await,
+        };
+        let result = match response {
+            Ok(response) => {
+                let result = write_to_file(response, &output_path, max_size_bytes).await;
+                if result.is_err() {
+                    let _ = tokio::fs::remove_file(&output_path).await;
+                }
+                result
+            }
+            Err(Error::ContentTypeNotAllowed {
+                path, content_type, ..
+            }) => {
+                log::debug!(
+                    "Skipping url_download_to_files at index {index}: content type {content_type:?} of {path} is not allowed"
+                );
+                return Ok(None);
+            }
+            Err(err) => Err(err),
+        };
+
+        match result {
+            Ok(()) => Ok(Some(output_path)),
+            Err(err) => match raise_error_on_failure {
+                true => Err(err),
+                false => {
+                    log::warn!(
+                        "Error occurred during url_download_to_files at index: {index} {} (falling back to Null)",
+                        err
+                    );
                     Ok(None)
                 }
             },
-            None => Ok(None),
         }
     }
+
+    /// Uploads `data` to `input`, returning the status code of the response if one was received. Rows where either
+    /// the URL or the data is null are skipped without issuing a request.
+    async fn single_url_upload(
+        &self,
+        index: usize,
+        input: Option<String>,
+        data: Option<bytes::Bytes>,
+        method: UploadMethod,
+        raise_error_on_failure: bool,
+        io_stats: Option<IOStatsRef>,
+    ) -> Result<Option<u16>> {
+        let (Some(input), Some(data)) = (input, data) else {
+            return Ok(None);
+        };
+        let response = async {
+            let (scheme, path) = parse_url(&input)?;
+            let source = self.get_source(&scheme).await?;
+            source.put(path.as_ref(), data, method, io_stats).await
+        }
+        .await;
+
+        let err = match response {
+            Ok(Some(status)) if !(200..300).contains(&status) => Error::UploadFailed {
+                path: input,
+                status,
+            },
+            Ok(status) => return Ok(status),
+            Err(err) => err,
+        };
+        let status = match &err {
+            Error::UploadFailed { status, .. } => Some(*status),
+            _ => None,
+        };
+        match raise_error_on_failure {
+            true => Err(err),
+            false => {
+                log::warn!(
+                    "Error occurred during url_upload at index: {index} {} (falling back to Null)",
+                    err
+                );
+                Ok(status)
+            }
+        }
+    }
+}
+
+/// Writes the contents of `response` into a new local file at `path`, streaming it rather than collecting it in memory.
+/// Fails with [`Error::MaxSizeExceeded`] as soon as more than `max_size_bytes` bytes have been written.
+async fn write_to_file(
+    response: GetResult,
+    path: &str,
+    max_size_bytes: Option<usize>,
+) -> Result<()> {
+    let check_size = |size: usize| match max_size_bytes {
+        Some(max_size_bytes) if size > max_size_bytes => {
+            Err(Error::MaxSizeExceeded { max_size_bytes })
+        }
+        _ => Ok(()),
+    };
+    let mut file = tokio::fs::File::create(path)
+        .await
+        .context(UnableToWriteToFileSnafu { path })?;
+    match response {
+        GetResult::File(local_file) => {
+            let source_path = local_file.path.to_string_lossy();
+            let mut source =
+                tokio::fs::File::open(&local_file.path)
+                    .await
+                    .context(UnableToReadBytesSnafu {
+                        path: source_path.as_ref(),
+                    })?;
+            let size = source
+                .metadata()
+                .await
+                .context(UnableToReadBytesSnafu {
+                    path: source_path.as_ref(),
+                })?
+                .len();
+            check_size(size as usize)?;
+            tokio::io::copy(&mut source, &mut file)
+                .await
+                .context(UnableToWriteToFileSnafu { path })?;
+        }
+        GetResult::Stream(mut stream, size, _permit) => {
+            // Bail out before reading any of the body if the size hint already exceeds the limit
+            if let Some(size) = size {
+                check_size(size)?;
+            }
+            let mut written = 0;
+            while let Some(bytes) = stream.next().await {
+                let bytes = bytes?;
+                written += bytes.len();
+                check_size(written)?;
+                file.write_all(&bytes)
+                    .await
+                    .context(UnableToWriteToFileSnafu { path })?;
+            }
+        }
+    }
+    file.flush()
+        .await
+        .context(UnableToWriteToFileSnafu { path })
 }
 
 #[derive(Debug, Hash, PartialEq, std::cmp::Eq, Clone, Copy)]
@@ -292,6 +638,26 @@ pub fn parse_url(input: &str) -> Result<(SourceType, Cow<'_, str>)> {
         _ => Err(Error::NotImplementedSource { store: scheme }),
     }
 }
+
+/// Checks that `input` is a well-formed URL for one of the supported sources, without issuing any I/O for it
+fn validate_url(input: &str) -> Result<()> {
+    let malformed = |reason: String| Error::MalformedUrl {
+        path: input.to_string(),
+        reason,
+    };
+    if input.trim().is_empty() {
+        return Err(malformed("URL is empty".to_string()));
+    }
+    if input.starts_with("://") {
+        return Err(malformed("URL is missing a scheme".to_string()));
+    }
+    match parse_url(input) {
+        Ok(_) => Ok(()),
+        Err(Error::InvalidUrl { source, .. }) => Err(malformed(source.to_string())),
+        Err(err) => Err(err),
+    }
+}
+
 type CacheKey = (bool, Arc<IOConfig>);
 lazy_static! {
     static ref NUM_CPUS: usize = std::thread::available_parallelism().unwrap().get();
@@ -391,23 +757,57 @@ pub async fn get_io_pool_num_threads() -> Option<usize> {
     }
 }
 
-pub fn _url_download(
-    array: &Utf8Array,
+/// Runs the IO operation produced by `make_task` for every row index in `rows` concurrently on the IO runtime,
+/// with at most `max_connections` operations in flight (per thread when `multi_thread` is set). Results are
+/// returned in the order of `rows`, regardless of the order in which the operations complete. If `progress` is provided, it
+/// is incremented as each row's operation completes successfully. If `rate_limiter` is provided, each operation
+/// waits for a token from it before starting.
+fn run_per_row<T, F, Fut>(
+    rows: impl IntoIterator<Item = usize>,
     max_connections: usize,
-    raise_error_on_failure: bool,
     multi_thread: bool,
     config: Arc<IOConfig>,
-    io_stats: Option<IOStatsRef>,
-) -> DaftResult<BinaryArray> {
-    let urls = array.as_arrow().iter();
-    let name = array.name();
-    ensure!(
-        max_connections > 0,
-        InvalidArgumentSnafu {
-            msg: "max_connections for url_download must be non-zero".to_owned()
-        }
-    );
+    progress: Option<Arc<AtomicUsize>>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    make_task: F,
+) -> DaftResult<Vec<T>>
+where
+    T: Send + 'static,
+    F: Fn(Arc<IOClient>, usize) -> Fut,
+    Fut: std::future::Future<Output = Result<T>> + Send + 'static,
+{
+    let results = run_per_row_cancellable(
+        rows,
+        max_connections,
+        multi_thread,
+        config,
+        progress,
+        rate_limiter,
+        None,
+        make_task,
+    )?;
+    // Without a cancellation token, the operation of every row is always run
+    Ok(results.into_iter().map(Option::unwrap).collect())
+}
 
+/// Same as [`run_per_row`], but once `cancellation` is cancelled no further operations are started, while those
+/// already in flight run to completion. The result of each row whose operation was never started is `None`.
+#[allow(clippy::too_many_arguments)]
+fn run_per_row_cancellable<T, F, Fut>(
+    rows: impl IntoIterator<Item = usize>,
+    max_connections: usize,
+    multi_thread: bool,
+    config: Arc<IOConfig>,
+    progress: Option<Arc<AtomicUsize>>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    cancellation: Option<CancellationToken>,
+    make_task: F,
+) -> DaftResult<Vec<Option<T>>>
+where
+    T: Send + 'static,
+    F: Fn(Arc<IOClient>, usize) -> Fut,
+    Fut: std::future::Future<Output = Result<T>> + Send + 'static,
+{
     let runtime_handle = get_runtime(multi_thread)?;
     let _rt_guard = runtime_handle.enter();
     let max_connections = match multi_thread {
@@ -416,41 +816,316 @@ pub fn _url_download(
     };
     let io_client = get_io_client(multi_thread, config)?;
 
-    let fetches = futures::stream::iter(urls.enumerate().map(|(i, url)| {
-        let owned_url = url.map(|s| s.to_string());
-        let owned_client = io_client.clone();
-        let owned_io_stats = io_stats.clone();
-        tokio::spawn(async move {
-            (
-                i,
-                owned_client
-                    .single_url_download(i, owned_url, raise_error_on_failure, owned_io_stats)
-                    .await,
-            )
+    let rows = rows.into_iter().collect::<Vec<_>>();
+    let num_rows = rows.len();
+    let cancellation = cancellation.unwrap_or_default();
+    // Rows are only pulled from the source stream as connection slots free up, so ending it on cancellation stops
+    // new operations from being spawned while `buffer_unordered` still drains the ones in flight
+    let tasks = futures::stream::iter(rows.into_iter().enumerate())
+        .take_until(cancellation.cancelled_owned())
+        .map(|(position, i)| {
+            let task = make_task(io_client.clone(), i);
+            let rate_limiter = rate_limiter.clone();
+            tokio::spawn(async move {
+                // Waiting tasks only occupy one of the `max_connections` slots, so tasks that already hold a token
+                // always make progress
+                if let Some(rate_limiter) = rate_limiter {
+                    rate_limiter.acquire().await;
+                }
+                (position, task.await)
+            })
         })
-    }))
-    .buffer_unordered(max_connections)
-    .then(async move |r| match r {
-        Ok((i, Ok(v))) => Ok((i, v)),
-        Ok((_i, Err(error))) => Err(error),
-        Err(error) => Err(Error::JoinError { source: error }),
-    });
-
-    let collect_future = fetches.try_collect::<Vec<_>>();
-    let mut results = runtime_handle.block_on(collect_future)?;
-
-    results.sort_by_key(|k| k.0);
-    let mut offsets: Vec<i64> = Vec::with_capacity(results.len() + 1);
-    offsets.push(0);
-    let mut valid = Vec::with_capacity(results.len());
-    valid.reserve(results.len());
+        .buffer_unordered(max_connections)
+        .inspect(move |r| {
+            if let (Some(progress), Ok((_, Ok(_)))) = (&progress, r) {
+                progress.fetch_add(1, atomic::Ordering::Relaxed);
+            }
+        })
+        .then(async move |r| match r {
+            Ok((i, Ok(v))) => Ok((i, v)),
+            Ok((_i, Err(error))) => Err(error),
+            Err(error) => Err(Error::JoinError { source: error }),
+        });
+
+    let collect_future = tasks.try_collect::<Vec<_>>();
+    let completed = runtime_handle.block_on(collect_future)?;
+
+    let mut results = (0..num_rows).map(|_| None).collect::<Vec<_>>();
+    for (position, v) in completed {
+        results[position] = Some(v);
+    }
+    Ok(results)
+}
+
+/// Outcome of downloading a single row in `url_download`
+#[derive(Default)]
+struct UrlDownloadResult {
+    /// Downloaded bytes, which are missing for null URLs and failed downloads
+    bytes: Option<bytes::Bytes>,
+    /// Status code of the response, if one was received
+    status: Option<u16>,
+    /// Formatted error, if the download failed
+    error: Option<String>,
+    /// Whether the object is unchanged since the copy described by the row's conditions, in which case there are no
+    /// bytes
+    unchanged: bool,
+    /// Whether the download was never started because the batch was cancelled, in which case there are no bytes
+    cancelled: bool,
+}
+
+/// Options shared by the `url_download` family of functions
+#[derive(Clone)]
+pub struct UrlDownloadOptions {
+    /// Maximum number of requests in flight at once, per thread when `multi_thread` is set. None picks a default from
+    /// the available parallelism, see [`default_max_connections`].
+    pub max_connections: Option<usize>,
+    /// Whether a failed download raises an error, rather than yielding a null for its row
+    pub raise_error_on_failure: bool,
+    /// Whether to run the downloads on the multi-threaded IO runtime
+    pub multi_thread: bool,
+    /// Downloads of more than this many bytes are aborted and treated as failures
+    pub max_size_bytes: Option<usize>,
+    /// Requests are started at no more than this rate overall, in addition to there being no more than
+    /// `max_connections` requests in flight at once
+    pub max_requests_per_second: Option<f64>,
+    /// Number of requests that may start at once after an idle period before `max_requests_per_second` applies.
+    /// Defaults to 1, which spreads requests out evenly.
+    pub max_requests_burst: Option<u32>,
+    /// Rows whose HTTP response has a `Content-Type` matching none of these (see
+    /// [`GetConditions::allows_content_type`]) are null, and their bodies are never read
+    pub allowed_content_types: Option<Vec<String>>,
+    pub config: Arc<IOConfig>,
+    pub io_stats: Option<IOStatsRef>,
+    /// Incremented as each row completes, so that callers can report progress from another thread while the download
+    /// is running. Rows complete in a nondeterministic order (the order in which their downloads finish rather than
+    /// input order), but the counter reaches the number of rows exactly once every row has completed.
+    pub progress: Option<Arc<AtomicUsize>>,
+    /// Once cancelled, no further downloads are started, while those already in flight finish. Rows whose download
+    /// was never started are null.
+    pub cancellation: Option<CancellationToken>,
+}
 
-    let cap_needed: usize = results
+impl Default for UrlDownloadOptions {
+    fn default() -> Self {
+        UrlDownloadOptions {
+            max_connections: None,
+            raise_error_on_failure: true,
+            multi_thread: true,
+            max_size_bytes: None,
+            max_requests_per_second: None,
+            max_requests_burst: None,
+            allowed_content_types: None,
+            config: Arc::new(IOConfig::default()),
+            io_stats: None,
+            progress: None,
+            cancellation: None,
+        }
+    }
+}
+
+/// Checks the options that `url_download` and `url_upload` have in common
+fn validate_connection_options(
+    function_name: &str,
+    max_connections: usize,
+    config: &IOConfig,
+) -> DaftResult<()> {
+    ensure!(
+        max_connections > 0,
+        InvalidArgumentSnafu {
+            msg: format!("max_connections for {function_name} must be non-zero")
+        }
+    );
+    http::validate_config(&config.http).map_err(|err| DaftError::ValueError(err.to_string()))?;
+    Ok(())
+}
+
+impl UrlDownloadOptions {
+    fn max_connections(&self) -> usize {
+        self.max_connections
+            .unwrap_or_else(|| default_max_connections(self.multi_thread))
+    }
+
+    /// Checks the options up-front so that invalid ones surface as a single error before any request fires, returning
+    /// the rate limiter that requests must wait on, if any
+    fn validate(&self) -> DaftResult<Option<Arc<RateLimiter>>> {
+        validate_connection_options("url_download", self.max_connections(), &self.config)?;
+
+        Ok(self
+            .max_requests_per_second
+            .map(|rate| RateLimiter::try_new(rate, self.max_requests_burst.unwrap_or(1)))
+            .transpose()
+            .map_err(|err| {
+                DaftError::ValueError(format!("Invalid rate limit for url_download: {err}"))
+            })?
+            .map(Arc::new))
+    }
+
+    /// Combines the `conditions` of a row with the allowed content types, which apply to every row
+    fn row_conditions(&self, conditions: Option<&GetConditions>) -> Option<GetConditions> {
+        match &self.allowed_content_types {
+            None => conditions.cloned(),
+            Some(allowed_content_types) => Some(GetConditions {
+                allowed_content_types: Some(allowed_content_types.clone()),
+                ..conditions.cloned().unwrap_or_default()
+            }),
+        }
+    }
+}
+
+/// Options for [`_url_upload`]. The fields it has in common with [`UrlDownloadOptions`] mean the same as there.
+#[derive(Clone)]
+pub struct UrlUploadOptions {
+    pub method: UploadMethod,
+    pub max_connections: Option<usize>,
+    /// Whether a row with an unsuccessful status code, or without any response, raises an error rather than
+    /// yielding its status code or a null
+    pub raise_error_on_failure: bool,
+    pub multi_thread: bool,
+    pub config: Arc<IOConfig>,
+    pub io_stats: Option<IOStatsRef>,
+}
+
+impl Default for UrlUploadOptions {
+    fn default() -> Self {
+        UrlUploadOptions {
+            method: UploadMethod::Put,
+            max_connections: None,
+            raise_error_on_failure: true,
+            multi_thread: true,
+            config: Arc::new(IOConfig::default()),
+            io_stats: None,
+        }
+    }
+}
+
+impl UrlUploadOptions {
+    fn max_connections(&self) -> usize {
+        self.max_connections
+            .unwrap_or_else(|| default_max_connections(self.multi_thread))
+    }
+}
+
+/// Downloads every URL in `array` concurrently, returning the result of each row in the same order as `array`. If `ranges` are given, only the range of bytes in the same row is downloaded
+/// for each URL (or the whole object, for rows with no range). If `conditions` are given, each URL is only downloaded
+/// if it has changed since the copy described by the conditions in the same row.
+fn _url_download_results(
+    array: &Utf8Array,
+    ranges: Option<&[Option<Range<usize>>]>,
+    truncate_ignored_ranges: bool,
+    conditions: Option<&[GetConditions]>,
+    options: &UrlDownloadOptions,
+) -> DaftResult<Vec<UrlDownloadResult>> {
+    let rate_limiter = options.validate()?;
+    let raise_error_on_failure = options.raise_error_on_failure;
+    let max_size_bytes = options.max_size_bytes;
+
+    if let Some(ranges) = ranges
+        && ranges.len() != array.len()
+    {
+        return Err(DaftError::ValueError(format!(
+            "url_download_range expects as many ranges as URLs, but got {} ranges and {} URLs",
+            ranges.len(),
+            array.len()
+        )));
+    }
+    if let Some(conditions) = conditions
+        && conditions.len() != array.len()
+    {
+        return Err(DaftError::ValueError(format!(
+            "url_download_if_changed expects as many conditions as URLs, but got {} conditions and {} URLs",
+            conditions.len(),
+            array.len()
+        )));
+    }
+
+    // Validate every URL up-front so that malformed ones fail before any request fires, rather than part-way through
+    let urls = array.as_arrow();
+    let url_errors = urls
         .iter()
-        .filter_map(|f| f.1.as_ref().map(|f| f.len()))
-        .sum();
+        .map(|url| url.and_then(|url| validate_url(url).err()))
+        .collect::<Vec<_>>();
+    if raise_error_on_failure
+        && let Some((i, err)) = url_errors
+            .iter()
+            .enumerate()
+            .find_map(|(i, err)| err.as_ref().map(|err| (i, err)))
+    {
+        return Err(DaftError::ValueError(format!(
+            "url_download failed at index {i}: {err}"
+        )));
+    }
+    for (i, err) in url_errors.iter().enumerate() {
+        if let Some(err) = err {
+            log::warn!("Skipping url_download at index {i}: {err} (falling back to Null)");
+        }
+    }
+
+    // Rows with invalid URLs are never dispatched, but still count towards progress
+    let valid_rows = (0..array.len())
+        .filter(|i| url_errors[*i].is_none())
+        .collect::<Vec<_>>();
+    if let Some(progress) = &options.progress {
+        progress.fetch_add(array.len() - valid_rows.len(), atomic::Ordering::Relaxed);
+    }
+
+    let mut downloads = run_per_row_cancellable(
+        valid_rows,
+        options.max_connections(),
+        options.multi_thread,
+        options.config.clone(),
+        options.progress.clone(),
+        rate_limiter,
+        options.cancellation.clone(),
+        |io_client, i| {
+            let owned_url = urls.get(i).map(|s| s.to_string());
+            let owned_range = ranges.and_then(|ranges| ranges[i].clone());
+            let owned_conditions =
+                options.row_conditions(conditions.map(|conditions| &conditions[i]));
+            let owned_io_stats = options.io_stats.clone();
+            async move {
+                io_client
+                    .single_url_download(
+                        i,
+                        owned_url,
+                        owned_range,
+                        truncate_ignored_ranges,
+                        owned_conditions,
+                        raise_error_on_failure,
+                        max_size_bytes,
+                        owned_io_stats,
+                    )
+                    .await
+            }
+        },
+    )?
+    .into_iter();
+    Ok(url_errors
+        .into_iter()
+        .map(|err| match err {
+            None => downloads.next().unwrap().unwrap_or(UrlDownloadResult {
+                cancelled: true,
+                ..Default::default()
+            }),
+            Some(err) => UrlDownloadResult {
+                error: Some(err.to_string()),
+                ..Default::default()
+            },
+        })
+        .collect())
+}
+
+/// Assembles the downloaded bytes of each row into a [`BinaryArray`], with a null for each row that has no bytes
+fn binary_array_from_downloads<'a>(
+    name: &str,
+    downloads: impl ExactSizeIterator<Item = Option<&'a bytes::Bytes>> + Clone,
+) -> DaftResult<BinaryArray> {
+    let mut offsets: Vec<i64> = Vec::with_capacity(downloads.len() + 1);
+    offsets.push(0);
+    let mut valid = arrow2::bitmap::MutableBitmap::with_capacity(downloads.len());
+
+    let cap_needed: usize = downloads.clone().flatten().map(|b| b.len()).sum();
     let mut data = Vec::with_capacity(cap_needed);
-    for (_, b) in results.into_iter() {
+    for b in downloads {
         match b {
             Some(b) => {
                 data.extend(b.as_ref());
@@ -464,32 +1139,2151 @@ pub fn _url_download(
         }
     }
     Ok(BinaryArray::try_from((name, data, offsets))?
-        .with_validity_slice(valid.as_slice())
+        .with_validity_bitmap(valid.into())
         .unwrap())
 }
 
+/// Downloads every URL in `array` concurrently into a [`BinaryArray`], with a null for each null URL and for each
+/// failed download when `raise_error_on_failure` is false. See [`UrlDownloadOptions`] for how the downloads are run.
+///
+/// Each row is fetched from the [`ObjectSource`] for its URL's scheme, so a single array can mix e.g. `http(s)://`,
+/// `s3://`, `gs://` and local paths, with each source configured by the matching section of `config`.
+pub fn _url_download(array: &Utf8Array, options: &UrlDownloadOptions) -> DaftResult<BinaryArray> {
+    let results = _url_download_results(array, None, false, None, options)?;
+    binary_array_from_downloads(array.name(), results.iter().map(|r| r.bytes.as_ref()))
+}
+
+/// Same as [`_url_download`], but for URLs given as the UTF-8 bytes in each row of a [`BinaryArray`]. Rows which are
+/// not valid UTF-8 raise an error naming the row when `raise_error_on_failure` is set, and are otherwise null without
+/// issuing a request.
+pub fn _url_download_binary(
+    array: &BinaryArray,
+    options: &UrlDownloadOptions,
+) -> DaftResult<BinaryArray> {
+    let mut urls = Vec::with_capacity(array.len());
+    for (i, url) in array.as_arrow().iter().enumerate() {
+        let url = match url.map(std::str::from_utf8) {
+            Some(Ok(url)) => Some(url),
+            Some(Err(err)) if options.raise_error_on_failure => {
+                return Err(DaftError::ValueError(format!(
+                    "url_download failed at index {i}: URL is not valid UTF-8: {err}"
+                )))
+            }
+            Some(Err(err)) => {
+                log::warn!(
+                    "Skipping url_download at index {i}: URL is not valid UTF-8: {err} (falling back to Null)"
+                );
+                None
+            }
+            None => None,
+        };
+        urls.push(url);
+    }
+    _url_download(
+        &Utf8Array::from_iter(array.name(), urls.into_iter()),
+        options,
+    )
+}
+
+/// Same as [`_url_download`], but also returns whether any row was skipped because `cancellation` was cancelled
+/// before its download started.
+pub fn _url_download_cancellable(
+    array: &Utf8Array,
+    options: &UrlDownloadOptions,
+) -> DaftResult<(BinaryArray, bool)> {
+    let results = _url_download_results(array, None, false, None, options)?;
+    let cancelled = results.iter().any(|r| r.cancelled);
+    let data = binary_array_from_downloads(array.name(), results.iter().map(|r| r.bytes.as_ref()))?;
+    Ok((data, cancelled))
+}
+
+/// Iterator over the chunks of downloaded bytes returned by [`_url_download_stream`], where each call to `next`
+/// blocks until every download in the next chunk has completed. Iteration stops after the first error.
+pub struct UrlDownloadStream {
+    runtime: Arc<tokio::runtime::Runtime>,
+    chunks: BoxStream<'static, DaftResult<BinaryArray>>,
+    failed: bool,
+    /// Number of input rows, and how many of them the chunks yielded so far hold
+    num_rows: usize,
+    num_rows_yielded: usize,
+}
+
+impl Iterator for UrlDownloadStream {
+    type Item = DaftResult<BinaryArray>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let chunk = match self.runtime.block_on(self.chunks.next()) {
+            Some(Ok(chunk)) => {
+                self.num_rows_yielded += chunk.len();
+                Some(Ok(chunk))
+            }
+            // The chunks only end early when the stream was cancelled, which must not pass for a complete download
+            None if self.num_rows_yielded < self.num_rows => {
+                Some(Err(DaftError::ComputeError(format!(
+                    "url_download_stream was cancelled after downloading {} of {} rows",
+                    self.num_rows_yielded, self.num_rows
+                ))))
+            }
+            chunk => chunk,
+        };
+        self.failed = matches!(chunk, Some(Err(_)));
+        chunk
+    }
+}
+
+/// Same as [`_url_download`], but rather than collecting every download into one array, returns an iterator over
+/// [`BinaryArray`] chunks of up to `batch_size` consecutive rows, so that the downloads of early rows can be
+/// processed before the rest have finished. Chunks are yielded in input order as soon as all of their downloads have
+/// completed, and downloads keep running ahead into later chunks with up to `max_connections` requests in flight.
+/// Once `cancellation` is cancelled, rows whose download hasn't started are never yielded, and the stream ends with
+/// an error after the chunks of the rows that were downloaded.
+pub fn _url_download_stream(
+    array: &Utf8Array,
+    batch_size: usize,
+    options: &UrlDownloadOptions,
+) -> DaftResult<UrlDownloadStream> {
+    let rate_limiter = options.validate()?;
+    ensure!(
+        batch_size > 0,
+        InvalidArgumentSnafu {
+            msg: "batch_size for url_download_stream must be non-zero".to_owned()
+        }
+    );
+    let raise_error_on_failure = options.raise_error_on_failure;
+    let max_size_bytes = options.max_size_bytes;
+
+    // As in `_url_download_results`, malformed URLs fail before any request fires, or are otherwise skipped
+    let urls = array.as_arrow();
+    let mut rows = Vec::with_capacity(array.len());
+    for (i, url) in urls.iter().enumerate() {
+        match url.map(validate_url) {
+            Some(Err(err)) if raise_error_on_failure => {
+                return Err(DaftError::ValueError(format!(
+                    "url_download failed at index {i}: {err}"
+                )));
+            }
+            Some(Err(err)) => {
+                log::warn!("Skipping url_download at index {i}: {err} (falling back to Null)");
+                rows.push(None);
+            }
+            _ => rows.push(url.map(|url| url.to_string())),
+        }
+    }
+
+    let runtime = get_runtime(options.multi_thread)?;
+    let max_connections = match options.multi_thread {
+        false => options.max_connections(),
+        true => options.max_connections() * usize::from(std::thread::available_parallelism()?),
+    };
+    let io_client = get_io_client(options.multi_thread, options.config.clone())?;
+    let name = array.name().to_string();
+    let num_rows = rows.len();
+    let conditions = options.row_conditions(None);
+    let io_stats = options.io_stats.clone();
+    let progress = options.progress.clone();
+    let cancellation = options.cancellation.clone().unwrap_or_default();
+
+    // `buffered` yields results in input order while still keeping `max_connections` downloads in flight
+    let chunks = futures::stream::iter(rows.into_iter().enumerate())
+        .take_until(cancellation.cancelled_owned())
+        .map(move |(i, url)| {
+            let io_client = io_client.clone();
+            let conditions = conditions.clone();
+            let io_stats = io_stats.clone();
+            let rate_limiter = rate_limiter.clone();
+            tokio::spawn(async move {
+                if let Some(rate_limiter) = rate_limiter {
+                    rate_limiter.acquire().await;
+                }
+                io_client
+                    .single_url_download(
+                        i,
+                        url,
+                        None,
+                        false,
+                        conditions,
+                        raise_error_on_failure,
+                        max_size_bytes,
+                        io_stats,
+                    )
+                    .await
+            })
+        })
+        .buffered(max_connections)
+        .map(|r| match r {
+            Ok(r) => r,
+            Err(error) => Err(Error::JoinError { source: error }),
+        })
+        .inspect(move |r| {
+            if let (Some(progress), Ok(_)) = (&progress, r) {
+                progress.fetch_add(1, atomic::Ordering::Relaxed);
+            }
+        })
+        .chunks(batch_size)
+        .map(move |results| {
+            let results = results.into_iter().collect::<Result<Vec<_>>>()?;
+            binary_array_from_downloads(&name, results.iter().map(|r| r.bytes.as_ref()))
+        })
+        .boxed();
+    Ok(UrlDownloadStream {
+        runtime,
+        chunks,
+        failed: false,
+        num_rows,
+        num_rows_yielded: 0,
+    })
+}
+
+/// Same as [`_url_download`], but returns a [`StructArray`] with a `data` field holding the downloaded bytes and a
+/// `status` field holding the status code of each row's response. The status is null for rows where no response
+/// was received at all (e.g. DNS or connection failures) and for sources that have no notion of status codes (such
+/// as local files or object stores).
+pub fn _url_download_with_status(
+    array: &Utf8Array,
+    options: &UrlDownloadOptions,
+) -> DaftResult<StructArray> {
+    let results = _url_download_results(array, None, false, None, options)?;
+    let data = binary_array_from_downloads("data", results.iter().map(|r| r.bytes.as_ref()))?;
+    let status = UInt16Array::from_iter("status", results.iter().map(|r| r.status));
+    let data = data.into_series();
+    let status = status.into_series();
+    let dtype = DataType::Struct(vec![data.field().clone(), status.field().clone()]);
+    Ok(StructArray::new(
+        Field::new(array.name(), dtype),
+        vec![data, status],
+        None,
+    ))
+}
+
+/// Same as [`_url_download`], but returns a [`StructArray`] with a `data` field holding the downloaded bytes and an
+/// `error` field holding the formatted error of each failed row, so that failures can be inspected rather than only
+/// logged. The error is null for successful rows and for null URLs. Errors are only recorded when
+/// `raise_error_on_failure` is false, since otherwise the first failure is raised instead.
+pub fn _url_download_with_errors(
+    array: &Utf8Array,
+    options: &UrlDownloadOptions,
+) -> DaftResult<StructArray> {
+    let results = _url_download_results(array, None, false, None, options)?;
+    let data = binary_array_from_downloads("data", results.iter().map(|r| r.bytes.as_ref()))?;
+    let error = Utf8Array::from_iter(
+        "error",
+        results
+            .iter()
+            .map(|r| r.error.as_deref())
+            .collect::<Vec<_>>()
+            .into_iter(),
+    );
+    let data = data.into_series();
+    let error = error.into_series();
+    let dtype = DataType::Struct(vec![data.field().clone(), error.field().clone()]);
+    Ok(StructArray::new(
+        Field::new(array.name(), dtype),
+        vec![data, error],
+        None,
+    ))
+}
+
+/// Same as [`_url_download`], but only downloads each URL if it has changed since a previously downloaded copy, as
+/// described by the ETag and Last-Modified values in the same row of `etags` and `last_modified`. HTTP(S) URLs with
+/// either value are fetched with `If-None-Match`/`If-Modified-Since` headers. Returns a [`StructArray`] with a `data`
+/// field holding the downloaded bytes and an `unchanged` field which is true for rows whose server responded with
+/// `304 Not Modified`, in which case `data` is null. Rows without prior values are downloaded unconditionally.
+pub fn _url_download_if_changed(
+    array: &Utf8Array,
+    etags: Option<&Utf8Array>,
+    last_modified: Option<&Utf8Array>,
+    options: &UrlDownloadOptions,
+) -> DaftResult<StructArray> {
+    for (name, values) in [("etags", etags), ("last_modified", last_modified)] {
+        if let Some(values) = values
+            && values.len() != array.len()
+        {
+            return Err(DaftError::ValueError(format!(
+                "url_download_if_changed expects as many {name} as URLs, but got {} {name} and {} URLs",
+                values.len(),
+                array.len()
+            )));
+        }
+    }
+    let value_at = |values: Option<&Utf8Array>, i: usize| {
+        values.and_then(|values| values.get(i).map(|v| v.to_string()))
+    };
+    let conditions = (0..array.len())
+        .map(|i| GetConditions {
+            etag: value_at(etags, i),
+            last_modified: value_at(last_modified, i),
+            ..Default::default()
+        })
+        .collect::<Vec<_>>();
+
+    let results = _url_download_results(array, None, false, Some(&conditions), options)?;
+    let data = binary_array_from_downloads("data", results.iter().map(|r| r.bytes.as_ref()))?;
+    let unchanged = BooleanArray::from((
+        "unchanged",
+        results
+            .iter()
+            .map(|r| r.unchanged)
+            .collect::<Vec<_>>()
+            .as_slice(),
+    ));
+    let data = data.into_series();
+    let unchanged = unchanged.into_series();
+    let dtype = DataType::Struct(vec![data.field().clone(), unchanged.field().clone()]);
+    Ok(StructArray::new(
+        Field::new(array.name(), dtype),
+        vec![data, unchanged],
+        None,
+    ))
+}
+
+/// Same as [`_url_download`], but only downloads the range of bytes in the same row of `ranges` for each URL, or the
+/// whole object for rows with no range. HTTP(S) URLs are fetched with a `Range` header, and servers that respond
+/// with `206 Partial Content` yield exactly the requested range. Servers that ignore the header and respond with the
+/// full object are treated as failures, unless `truncate_ignored_ranges` is set, in which case the requested range
+/// is sliced out of the full response. Rows with an empty range yield an empty body without any request.
+pub fn _url_download_range(
+    array: &Utf8Array,
+    ranges: &[Option<Range<usize>>],
+    truncate_ignored_ranges: bool,
+    options: &UrlDownloadOptions,
+) -> DaftResult<BinaryArray> {
+    let results =
+        _url_download_results(array, Some(ranges), truncate_ignored_ranges, None, options)?;
+    binary_array_from_downloads(array.name(), results.iter().map(|r| r.bytes.as_ref()))
+}
+
+/// Downloads every URL in `array` concurrently, streaming each body into the local file at the path in the same row
+/// of `output_paths` rather than holding it in memory. Returns a [`Utf8Array`] of the written paths in the same order,
+/// with a null for rows with a null URL or null output path, for rows that were never started because the batch was
+/// cancelled, and for failed downloads when `raise_error_on_failure` is false. Partially written files of failed
+/// downloads are removed.
+pub fn _url_download_to_files(
+    array: &Utf8Array,
+    output_paths: &Utf8Array,
+    options: &UrlDownloadOptions,
+) -> DaftResult<Utf8Array> {
+    let rate_limiter = options.validate()?;
+    if array.len() != output_paths.len() {
+        return Err(DaftError::ValueError(format!(
+            "url_download_to_files expects as many URLs as output paths, but got {} URLs and {} output paths",
+            array.len(),
+            output_paths.len()
+        )));
+    }
+    let raise_error_on_failure = options.raise_error_on_failure;
+    let max_size_bytes = options.max_size_bytes;
+
+    let urls = array.as_arrow();
+    let paths = output_paths.as_arrow();
+    let results = run_per_row_cancellable(
+        0..array.len(),
+        options.max_connections(),
+        options.multi_thread,
+        options.config.clone(),
+        options.progress.clone(),
+        rate_limiter,
+        options.cancellation.clone(),
+        |io_client, i| {
+            let owned_url = urls.get(i).map(|s| s.to_string());
+            let owned_path = paths.get(i).map(|s| s.to_string());
+            let owned_conditions = options.row_conditions(None);
+            let owned_io_stats = options.io_stats.clone();
+            async move {
+                io_client
+                    .single_url_download_to_file(
+                        i,
+                        owned_url,
+                        owned_path,
+                        owned_conditions,
+                        raise_error_on_failure,
+                        max_size_bytes,
+                        owned_io_stats,
+                    )
+                    .await
+            }
+        },
+    )?;
+    Ok(Utf8Array::from_iter(
+        array.name(),
+        results.into_iter().map(Option::flatten),
+    ))
+}
+
+/// Uploads each row of `data` to the URL in the same row of `array` concurrently, returning the status code of each
+/// row's response in the same order. Rows with a null URL or null data are skipped and yield a null status, as do
+/// rows where no response was received at all when `raise_error_on_failure` is false. Rows with an unsuccessful
+/// status code keep that status code, or raise an error when `raise_error_on_failure` is set.
+pub fn _url_upload(
+    array: &Utf8Array,
+    data: &BinaryArray,
+    options: &UrlUploadOptions,
+) -> DaftResult<UInt16Array> {
+    // Validate the options up-front so that they surface as a single error before any request fires
+    validate_connection_options("url_upload", options.max_connections(), &options.config)?;
+    if array.len() != data.len() {
+        return Err(DaftError::ValueError(format!(
+            "url_upload expects as many URLs as payloads, but got {} URLs and {} payloads",
+            array.len(),
+            data.len()
+        )));
+    }
+
+    let method = options.method;
+    let raise_error_on_failure = options.raise_error_on_failure;
+    let urls = array.as_arrow();
+    let payloads = data.as_arrow();
+    let results = run_per_row(
+        0..array.len(),
+        options.max_connections(),
+        options.multi_thread,
+        options.config.clone(),
+        None,
+        None,
+        |io_client, i| {
+            let owned_url = urls.get(i).map(|s| s.to_string());
+            let owned_data = payloads.get(i).map(bytes::Bytes::copy_from_slice);
+            let owned_io_stats = options.io_stats.clone();
+            async move {
+                io_client
+                    .single_url_upload(
+                        i,
+                        owned_url,
+                        owned_data,
+                        method,
+                        raise_error_on_failure,
+                        owned_io_stats,
+                    )
+                    .await
+            }
+        },
+    )?;
+    Ok(UInt16Array::from_iter(array.name(), results.into_iter()))
+}
+
 type DynError = Box<dyn std::error::Error + Send + Sync + 'static>;
 
-pub fn url_download(
-    series: &Series,
-    max_connections: usize,
-    raise_error_on_failure: bool,
-    multi_thread: bool,
-    config: Arc<IOConfig>,
-    io_stats: Option<IOStatsRef>,
+/// Downloads are network-bound rather than CPU-bound, so without an explicit `max_connections` this many requests are
+/// kept in flight for each thread of available parallelism
+const DEFAULT_MAX_CONNECTIONS_PER_THREAD: usize = 8;
+
+/// Default `max_connections` for `url_download`, which keeps [`DEFAULT_MAX_CONNECTIONS_PER_THREAD`] requests in flight
+/// for each thread of available parallelism (or a single thread if it can't be determined). In multi-threaded mode
+/// the limit already applies per thread, so it isn't scaled by the parallelism again.
+fn default_max_connections(multi_thread: bool) -> usize {
+    let parallelism = match multi_thread {
+        true => 1,
+        false => std::thread::available_parallelism().map_or(1, usize::from),
+    };
+    DEFAULT_MAX_CONNECTIONS_PER_THREAD * parallelism
+}
+
+/// See [`_url_download`] and [`_url_download_binary`]
+pub fn url_download(series: &Series, options: &UrlDownloadOptions) -> DaftResult<Series> {
+    match series.data_type() {
+        DataType::Utf8 => Ok(_url_download(series.utf8()?, options)?.into_series()),
+        DataType::Binary => Ok(_url_download_binary(series.binary()?, options)?.into_series()),
+        dt => Err(DaftError::TypeError(format!(
+            "url download not implemented for type {dt}"
+        ))),
+    }
+}
+
+pub fn url_download_with_status(
+    series: &Series,
+    options: &UrlDownloadOptions,
+) -> DaftResult<Series> {
+    match series.data_type() {
+        DataType::Utf8 => Ok(_url_download_with_status(series.utf8()?, options)?.into_series()),
+        dt => Err(DaftError::TypeError(format!(
+            "url download not implemented for type {dt}"
+        ))),
+    }
+}
+
+pub fn url_download_cancellable(
+    series: &Series,
+    options: &UrlDownloadOptions,
+) -> DaftResult<(Series, bool)> {
+    match series.data_type() {
+        DataType::Utf8 => {
+            let (data, cancelled) = _url_download_cancellable(series.utf8()?, options)?;
+            Ok((data.into_series(), cancelled))
+        }
+        dt => Err(DaftError::TypeError(format!(
+            "url download not implemented for type {dt}"
+        ))),
+    }
+}
+
+pub fn url_download_stream(
+    series: &Series,
+    batch_size: usize,
+    options: &UrlDownloadOptions,
+) -> DaftResult<UrlDownloadStream> {
+    match series.data_type() {
+        DataType::Utf8 => _url_download_stream(series.utf8()?, batch_size, options),
+        dt => Err(DaftError::TypeError(format!(
+            "url download not implemented for type {dt}"
+        ))),
+    }
+}
+
+pub fn url_download_if_changed(
+    series: &Series,
+    etags: Option<&Series>,
+    last_modified: Option<&Series>,
+    options: &UrlDownloadOptions,
 ) -> DaftResult<Series> {
     match series.data_type() {
-        DataType::Utf8 => Ok(_url_download(
+        DataType::Utf8 => Ok(_url_download_if_changed(
             series.utf8()?,
-            max_connections,
-            raise_error_on_failure,
-            multi_thread,
-            config,
-            io_stats,
+            etags.map(|s| s.utf8()).transpose()?,
+            last_modified.map(|s| s.utf8()).transpose()?,
+            options,
+        )?
+        .into_series()),
+        dt => Err(DaftError::TypeError(format!(
+            "url download not implemented for type {dt}"
+        ))),
+    }
+}
+
+pub fn url_download_with_errors(
+    series: &Series,
+    options: &UrlDownloadOptions,
+) -> DaftResult<Series> {
+    match series.data_type() {
+        DataType::Utf8 => Ok(_url_download_with_errors(series.utf8()?, options)?.into_series()),
+        dt => Err(DaftError::TypeError(format!(
+            "url download not implemented for type {dt}"
+        ))),
+    }
+}
+
+pub fn url_download_to_files(
+    series: &Series,
+    output_paths: &Series,
+    options: &UrlDownloadOptions,
+) -> DaftResult<Series> {
+    match (series.data_type(), output_paths.data_type()) {
+        (DataType::Utf8, DataType::Utf8) => Ok(_url_download_to_files(
+            series.utf8()?,
+            output_paths.utf8()?,
+            options,
         )?
         .into_series()),
+        (url_dt, path_dt) => Err(DaftError::TypeError(format!(
+            "url download to files not implemented for URLs of type {url_dt} and output paths of type {path_dt}"
+        ))),
+    }
+}
+
+/// See [`_url_download_range`]
+pub fn url_download_range(
+    series: &Series,
+    ranges: &[Option<Range<usize>>],
+    truncate_ignored_ranges: bool,
+    options: &UrlDownloadOptions,
+) -> DaftResult<Series> {
+    match series.data_type() {
+        DataType::Utf8 => {
+            Ok(
+                _url_download_range(series.utf8()?, ranges, truncate_ignored_ranges, options)?
+                    .into_series(),
+            )
+        }
         dt => Err(DaftError::TypeError(format!(
             "url download not implemented for type {dt}"
         ))),
     }
 }
+
+pub fn url_upload(
+    series: &Series,
+    data: &Series,
+    options: &UrlUploadOptions,
+) -> DaftResult<Series> {
+    match (series.data_type(), data.data_type()) {
+        (DataType::Utf8, DataType::Binary) => {
+            Ok(_url_upload(series.utf8()?, data.binary()?, options)?.into_series())
+        }
+        (url_dt, data_dt) => Err(DaftError::TypeError(format!(
+            "url upload not implemented for URLs of type {url_dt} and data of type {data_dt}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    };
+
+    use common_error::{DaftError, DaftResult};
+    use daft_core::{
+        array::{ops::as_arrow::AsArrow, StructArray},
+        datatypes::{BinaryArray, Utf8Array},
+        IntoSeries,
+    };
+
+    use crate::{
+        mock_http_server::{spawn_mock_http_server, MockResponse},
+        HttpConfig, IOConfig, IOStatsContext, UploadMethod, UrlDownloadOptions, UrlUploadOptions,
+    };
+
+    /// Spawns a server that responds to `GET /<status>` with that status code and a body of `<status>`
+    fn spawn_status_server() -> String {
+        spawn_mock_http_server(|request| {
+            let status = request.path.trim_start_matches('/').parse().unwrap_or(404);
+            MockResponse::new(status, status.to_string())
+        })
+    }
+
+    #[test]
+    fn test_url_download_with_status() -> DaftResult<()> {
+        let base_url = spawn_status_server();
+        let urls = Utf8Array::from_iter(
+            "urls",
+            vec![
+                Some(format!("{base_url}/200")),
+                Some(format!("{base_url}/404")),
+                Some(format!("{base_url}/500")),
+                None,
+                // Nothing listens on port 1, so no response is ever received
+                Some("http://127.0.0.1:1/unreachable".to_string()),
+            ]
+            .into_iter(),
+        );
+
+        let result = crate::_url_download_with_status(
+            &urls,
+            &UrlDownloadOptions {
+                max_connections: Some(4),
+                raise_error_on_failure: false,
+                multi_thread: false,
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(result.name(), "urls");
+        assert_eq!(result.len(), 5);
+
+        let data = result.children[0].binary()?;
+        assert_eq!(
+            data.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some(b"200".as_slice()), None, None, None, None]
+        );
+        let status = result.children[1].u16()?;
+        assert_eq!(
+            status
+                .as_arrow()
+                .iter()
+                .map(|s| s.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(200), Some(404), Some(500), None, None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_download_with_status_matches_url_download() -> DaftResult<()> {
+        let base_url = spawn_status_server();
+        let urls = (0..10)
+            .map(|i| Some(format!("{base_url}/{}", if i % 3 == 0 { 404 } else { 200 })))
+            .collect::<Vec<_>>();
+        let urls = Utf8Array::from_iter("urls", urls.into_iter());
+        let config = Arc::new(IOConfig::default());
+
+        let downloaded = crate::_url_download(
+            &urls,
+            &UrlDownloadOptions {
+                max_connections: Some(4),
+                raise_error_on_failure: false,
+                multi_thread: false,
+                config: config.clone(),
+                ..Default::default()
+            },
+        )?;
+        let with_status = crate::_url_download_with_status(
+            &urls,
+            &UrlDownloadOptions {
+                max_connections: Some(4),
+                raise_error_on_failure: false,
+                multi_thread: false,
+                config,
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(
+            downloaded.as_arrow(),
+            with_status.children[0].binary()?.as_arrow()
+        );
+        Ok(())
+    }
+
+    /// Spawns a server that responds to `GET /<size>` with a body of `<size>` bytes. Under `/lying/`, the server
+    /// instead claims a `Content-Length` of 1 GiB without sending it, and under `/chunked/` it streams the body in
+    /// chunks without any `Content-Length` at all.
+    fn spawn_sized_server() -> String {
+        spawn_mock_http_server(|request| {
+            let (response_kind, size) = request
+                .path
+                .trim_start_matches('/')
+                .rsplit_once('/')
+                .unwrap_or(("", request.path.trim_start_matches('/')));
+            let size: usize = size.parse().unwrap();
+            let response = MockResponse::new(200, vec![b'a'; size]);
+            match response_kind {
+                "lying" => response.with_header("Content-Length", (1usize << 30).to_string()),
+                "chunked" => response.with_header("Transfer-Encoding", "chunked"),
+                _ => response,
+            }
+        })
+    }
+
+    #[test]
+    fn test_url_download_max_size_bytes() -> DaftResult<()> {
+        let base_url = spawn_sized_server();
+        let urls = Utf8Array::from_iter(
+            "urls",
+            vec![
+                Some(format!("{base_url}/100")),
+                Some(format!("{base_url}/101")),
+                Some(format!("{base_url}/chunked/100")),
+                Some(format!("{base_url}/chunked/100000")),
+            ]
+            .into_iter(),
+        );
+
+        let result = crate::_url_download(
+            &urls,
+            &UrlDownloadOptions {
+                max_connections: Some(4),
+                raise_error_on_failure: false,
+                multi_thread: false,
+                max_size_bytes: Some(100),
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(
+            result
+                .as_arrow()
+                .iter()
+                .map(|b| b.map(|b| b.len()))
+                .collect::<Vec<_>>(),
+            vec![Some(100), None, Some(100), None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_download_max_size_bytes_content_length_short_circuit() -> DaftResult<()> {
+        let base_url = spawn_sized_server();
+        let urls = Utf8Array::from_iter(
+            "urls",
+            vec![Some(format!("{base_url}/lying/10"))].into_iter(),
+        );
+        let config = Arc::new(IOConfig::default());
+
+        // The claimed Content-Length is over the limit, so the download fails without waiting on the missing body
+        let result = crate::_url_download(
+            &urls,
+            &UrlDownloadOptions {
+                max_connections: Some(1),
+                multi_thread: false,
+                max_size_bytes: Some(100),
+                config: config.clone(),
+                ..Default::default()
+            },
+        );
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("exceeds the maximum size of 100 bytes"));
+
+        let result = crate::_url_download(
+            &urls,
+            &UrlDownloadOptions {
+                max_connections: Some(1),
+                raise_error_on_failure: false,
+                multi_thread: false,
+                max_size_bytes: Some(100),
+                config,
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(result.as_arrow().iter().collect::<Vec<_>>(), vec![None]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_download_mixed_http_and_local() -> DaftResult<()> {
+        let base_url = spawn_status_server();
+        let dir = tempfile::tempdir().unwrap();
+        let local_path = dir.path().join("local.txt");
+        std::fs::write(&local_path, b"local").unwrap();
+        let local_path = local_path.to_string_lossy().to_string();
+        let missing_path = dir.path().join("missing.txt").to_string_lossy().to_string();
+
+        let urls = Utf8Array::from_iter(
+            "urls",
+            vec![
+                Some(format!("{base_url}/200")),
+                Some(format!("file://{local_path}")),
+                Some(local_path.clone()),
+                Some(format!("file://{missing_path}")),
+                Some(format!("{base_url}/404")),
+            ]
+            .into_iter(),
+        );
+        let config = Arc::new(IOConfig::default());
+
+        let result = crate::_url_download(
+            &urls,
+            &UrlDownloadOptions {
+                max_connections: Some(4),
+                raise_error_on_failure: false,
+                multi_thread: false,
+                config: config.clone(),
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(
+            result.as_arrow().iter().collect::<Vec<_>>(),
+            vec![
+                Some(b"200".as_slice()),
+                Some(b"local".as_slice()),
+                Some(b"local".as_slice()),
+                None,
+                None
+            ]
+        );
+
+        // Failures reading local files are raised just like failed HTTP requests
+        let with_missing = Utf8Array::from_iter(
+            "urls",
+            vec![
+                Some(format!("{base_url}/200")),
+                Some(format!("file://{missing_path}")),
+            ]
+            .into_iter(),
+        );
+        let result = crate::_url_download(
+            &with_missing,
+            &UrlDownloadOptions {
+                max_connections: Some(4),
+                multi_thread: false,
+                config,
+                ..Default::default()
+            },
+        );
+        assert!(matches!(result, Err(DaftError::FileNotFound { .. })));
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_upload() -> DaftResult<()> {
+        let received = Arc::new(Mutex::new(vec![]));
+        let base_url = {
+            let received = received.clone();
+            spawn_mock_http_server(move |request| {
+                received.lock().unwrap().push((
+                    request.method.clone(),
+                    request.path.clone(),
+                    request.body.clone(),
+                ));
+                match request.path.as_str() {
+                    "/fail" => MockResponse::new(500, "failed"),
+                    _ => MockResponse::new(201, "created"),
+                }
+            })
+        };
+
+        let urls = Utf8Array::from_iter(
+            "urls",
+            vec![
+                Some(format!("{base_url}/0")),
+                Some(format!("{base_url}/1")),
+                None,
+                Some(format!("{base_url}/3")),
+                Some(format!("{base_url}/fail")),
+            ]
+            .into_iter(),
+        );
+        let data = BinaryArray::from_iter(
+            "data",
+            vec![
+                Some(b"zero".as_slice()),
+                Some(b"one".as_slice()),
+                Some(b"two".as_slice()),
+                None,
+                Some(b"four".as_slice()),
+            ]
+            .into_iter(),
+        );
+        let io_stats = IOStatsContext::new("test_url_upload");
+        let result = crate::_url_upload(
+            &urls,
+            &data,
+            &UrlUploadOptions {
+                method: UploadMethod::Put,
+                max_connections: Some(4),
+                raise_error_on_failure: false,
+                multi_thread: false,
+                io_stats: Some(io_stats.clone()),
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(result.name(), "urls");
+        assert_eq!(
+            result
+                .as_arrow()
+                .iter()
+                .map(|s| s.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(201), Some(201), None, None, Some(500)]
+        );
+
+        // Rows with a null URL or null data never reach the server
+        let mut received = std::mem::take(&mut *received.lock().unwrap());
+        received.sort();
+        assert_eq!(
+            received,
+            vec![
+                ("PUT".to_string(), "/0".to_string(), b"zero".to_vec()),
+                ("PUT".to_string(), "/1".to_string(), b"one".to_vec()),
+                ("PUT".to_string(), "/fail".to_string(), b"four".to_vec()),
+            ]
+        );
+        // Uploads that received a response are recorded, whatever their status code
+        assert_eq!(io_stats.load_put_requests(), 3);
+        assert_eq!(io_stats.load_bytes_uploaded(), 11);
+
+        let result = crate::_url_upload(
+            &urls,
+            &data,
+            &UrlUploadOptions {
+                method: UploadMethod::Post,
+                max_connections: Some(4),
+                multi_thread: false,
+                ..Default::default()
+            },
+        );
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("received status code 500"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_upload_is_not_retried_after_a_response() -> DaftResult<()> {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let base_url = {
+            let requests = requests.clone();
+            spawn_mock_http_server(move |_| {
+                requests.fetch_add(1, Ordering::SeqCst);
+                MockResponse::new(503, "unavailable")
+            })
+        };
+        let config = Arc::new(IOConfig {
+            http: HttpConfig {
+                num_tries: 3,
+                retry_initial_backoff_ms: 1,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        let urls = Utf8Array::from_iter("urls", vec![Some(format!("{base_url}/0"))].into_iter());
+
+        // Downloads are retried on a 503, while the server may already have acted on an upload that got one
+        crate::_url_download(
+            &urls,
+            &UrlDownloadOptions {
+                raise_error_on_failure: false,
+                multi_thread: false,
+                config: config.clone(),
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(requests.swap(0, Ordering::SeqCst), 3);
+
+        let data = BinaryArray::from_iter("data", vec![Some(b"zero".as_slice())].into_iter());
+        let result = crate::_url_upload(
+            &urls,
+            &data,
+            &UrlUploadOptions {
+                raise_error_on_failure: false,
+                multi_thread: false,
+                config,
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(result.as_arrow().get(0), Some(503));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_download_max_connections_per_host() -> DaftResult<()> {
+        /// Spawns a server that records the maximum number of requests it has been serving at once
+        fn spawn_concurrency_tracking_server() -> (String, Arc<AtomicUsize>) {
+            let in_flight = Arc::new(AtomicUsize::new(0));
+            let max_in_flight = Arc::new(AtomicUsize::new(0));
+            let base_url = {
+                let max_in_flight = max_in_flight.clone();
+                spawn_mock_http_server(move |request| {
+                    let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_in_flight.fetch_max(current, Ordering::SeqCst);
+                    std::thread::sleep(std::time::Duration::from_millis(20));
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    MockResponse::new(200, request.path.clone())
+                })
+            };
+            (base_url, max_in_flight)
+        }
+
+        let max_connections_per_host = 3;
+        let (first_url, first_max_in_flight) = spawn_concurrency_tracking_server();
+        let (second_url, second_max_in_flight) = spawn_concurrency_tracking_server();
+        let paths = (0..40).map(|i| format!("/{i}")).collect::<Vec<_>>();
+        let urls = paths
+            .iter()
+            .enumerate()
+            .map(|(i, path)| {
+                let base_url = if i % 2 == 0 { &first_url } else { &second_url };
+                Some(format!("{base_url}{path}"))
+            })
+            .collect::<Vec<_>>();
+        let urls = Utf8Array::from_iter("urls", urls.into_iter());
+
+        let mut config = IOConfig::default();
+        config.http.max_connections_per_host = Some(max_connections_per_host);
+        let result = crate::_url_download(
+            &urls,
+            &UrlDownloadOptions {
+                max_connections: Some(16),
+                multi_thread: false,
+                config: Arc::new(config),
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(
+            result.as_arrow().iter().collect::<Vec<_>>(),
+            paths
+                .iter()
+                .map(|path| Some(path.as_bytes()))
+                .collect::<Vec<_>>()
+        );
+        assert!(first_max_in_flight.load(Ordering::SeqCst) <= max_connections_per_host as usize);
+        assert!(second_max_in_flight.load(Ordering::SeqCst) <= max_connections_per_host as usize);
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_download_zero_max_connections_per_host() {
+        let urls = Utf8Array::from_iter("urls", vec![Some("http://127.0.0.1:1/")].into_iter());
+        let mut config = IOConfig::default();
+        config.http.max_connections_per_host = Some(0);
+        let result = crate::_url_download(
+            &urls,
+            &UrlDownloadOptions {
+                max_connections: Some(1),
+                raise_error_on_failure: false,
+                multi_thread: false,
+                config: Arc::new(config),
+                ..Default::default()
+            },
+        );
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("max_connections_per_host must be non-zero"));
+    }
+
+    #[test]
+    fn test_url_download_progress() -> DaftResult<()> {
+        let base_url = spawn_status_server();
+        let urls = (0..100)
+            .map(|i| match i % 10 {
+                0 => None,
+                1 => Some(format!("{base_url}/404")),
+                _ => Some(format!("{base_url}/200")),
+            })
+            .collect::<Vec<_>>();
+        let urls = Utf8Array::from_iter("urls", urls.into_iter());
+        let progress = Arc::new(AtomicUsize::new(0));
+
+        crate::_url_download(
+            &urls,
+            &UrlDownloadOptions {
+                max_connections: Some(8),
+                raise_error_on_failure: false,
+                multi_thread: false,
+                progress: Some(progress.clone()),
+                ..Default::default()
+            },
+        )?;
+        // Null URLs and failed downloads count as completed rows too
+        assert_eq!(progress.load(Ordering::SeqCst), urls.len());
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_download_max_redirects() -> DaftResult<()> {
+        // Redirects `/<n>` to `/<n - 1>` until reaching `/0`
+        let base_url = spawn_mock_http_server(|request| {
+            let remaining: usize = request.path.trim_start_matches('/').parse().unwrap();
+            match remaining {
+                0 => MockResponse::new(200, "done"),
+                n => MockResponse::new(302, "redirecting")
+                    .with_header("Location", format!("/{}", n - 1)),
+            }
+        });
+        let urls = Utf8Array::from_iter(
+            "urls",
+            vec![Some(format!("{base_url}/2")), Some(format!("{base_url}/3"))].into_iter(),
+        );
+        let download_with_max_redirects = |max_redirects| {
+            let mut config = IOConfig::default();
+            config.http.max_redirects = max_redirects;
+            crate::_url_download_with_status(
+                &urls,
+                &UrlDownloadOptions {
+                    max_connections: Some(2),
+                    raise_error_on_failure: false,
+                    multi_thread: false,
+                    config: Arc::new(config),
+                    ..Default::default()
+                },
+            )
+        };
+        let downloads_and_statuses = |result: StructArray| -> DaftResult<_> {
+            let data = result.children[0].binary()?.as_arrow().clone();
+            let status = result.children[1].u16()?.as_arrow().clone();
+            Ok((
+                data.iter()
+                    .map(|b| b.map(|b| b.to_vec()))
+                    .collect::<Vec<_>>(),
+                status.iter().map(|s| s.copied()).collect::<Vec<_>>(),
+            ))
+        };
+
+        // Redirects are followed by default
+        assert_eq!(
+            downloads_and_statuses(download_with_max_redirects(None)?)?,
+            (
+                vec![Some(b"done".to_vec()), Some(b"done".to_vec())],
+                vec![Some(200), Some(200)]
+            )
+        );
+
+        // With redirects disabled, the 302 is the terminal response
+        assert_eq!(
+            downloads_and_statuses(download_with_max_redirects(Some(0))?)?,
+            (
+                vec![Some(b"redirecting".to_vec()), Some(b"redirecting".to_vec())],
+                vec![Some(302), Some(302)]
+            )
+        );
+
+        // Requests that need more redirects than allowed fail
+        assert_eq!(
+            downloads_and_statuses(download_with_max_redirects(Some(2))?)?,
+            (vec![Some(b"done".to_vec()), None], vec![Some(200), None])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_download_range() -> DaftResult<()> {
+        // Serves the bytes "0123456789", honoring Range headers under `/ranged` but not under `/full`
+        let base_url = spawn_mock_http_server(|request| {
+            let body = b"0123456789";
+            match (request.path.as_str(), request.range) {
+                ("/ranged", Some((start, end))) => {
+                    MockResponse::new(206, &body[start..=end.min(body.len() - 1)]).with_header(
+                        "Content-Range",
+                        format!("bytes {start}-{end}/{}", body.len()),
+                    )
+                }
+                _ => MockResponse::new(200, body.as_slice()),
+            }
+        });
+        let urls = Utf8Array::from_iter(
+            "urls",
+            vec![
+                Some(format!("{base_url}/ranged")),
+                Some(format!("{base_url}/ranged")),
+                Some(format!("{base_url}/full")),
+                Some(format!("{base_url}/full")),
+            ]
+            .into_iter(),
+        );
+        let ranges = vec![Some(2..5), None, Some(2..5), None];
+        let config = Arc::new(IOConfig::default());
+
+        let expected_ranged = vec![Some(b"234".as_slice()), Some(b"0123456789".as_slice())];
+        let result = crate::_url_download_range(
+            &urls,
+            &ranges,
+            false,
+            &UrlDownloadOptions {
+                max_connections: Some(4),
+                raise_error_on_failure: false,
+                multi_thread: false,
+                config: config.clone(),
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(
+            result.as_arrow().iter().collect::<Vec<_>>(),
+            [
+                expected_ranged.clone(),
+                vec![None, Some(b"0123456789".as_slice())]
+            ]
+            .concat()
+        );
+
+        let result = crate::_url_download_range(
+            &urls,
+            &ranges,
+            true,
+            &UrlDownloadOptions {
+                max_connections: Some(4),
+                raise_error_on_failure: false,
+                multi_thread: false,
+                config: config.clone(),
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(
+            result.as_arrow().iter().collect::<Vec<_>>(),
+            [
+                expected_ranged,
+                vec![Some(b"234".as_slice()), Some(b"0123456789".as_slice())]
+            ]
+            .concat()
+        );
+
+        let result = crate::_url_download_range(
+            &urls,
+            &ranges,
+            false,
+            &UrlDownloadOptions {
+                max_connections: Some(4),
+                multi_thread: false,
+                config,
+                ..Default::default()
+            },
+        );
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("ignored the requested byte range"));
+        Ok(())
+    }
+
+    /// Test that empty ranges yield empty bodies without sending a request
+    #[test]
+    fn test_url_download_empty_range() -> DaftResult<()> {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let base_url = {
+            let requests = requests.clone();
+            spawn_mock_http_server(move |request| {
+                requests.fetch_add(1, Ordering::SeqCst);
+                MockResponse::new(200, request.path.clone())
+            })
+        };
+        let urls = Utf8Array::from_iter(
+            "urls",
+            vec![
+                Some(format!("{base_url}/a")),
+                Some(format!("{base_url}/b")),
+                None,
+            ]
+            .into_iter(),
+        );
+        let result = crate::_url_download_range(
+            &urls,
+            &[Some(3..3), Some(0..0), Some(0..0)],
+            false,
+            &UrlDownloadOptions {
+                max_connections: Some(4),
+                multi_thread: false,
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(
+            result.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some(b"".as_slice()), Some(b"".as_slice()), None]
+        );
+        assert_eq!(requests.load(Ordering::SeqCst), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_download_with_errors() -> DaftResult<()> {
+        let base_url = spawn_status_server();
+        let urls = Utf8Array::from_iter(
+            "urls",
+            vec![
+                Some(format!("{base_url}/200")),
+                Some(format!("{base_url}/404")),
+                None,
+            ]
+            .into_iter(),
+        );
+
+        let result = crate::_url_download_with_errors(
+            &urls,
+            &UrlDownloadOptions {
+                max_connections: Some(4),
+                raise_error_on_failure: false,
+                multi_thread: false,
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(result.name(), "urls");
+        let data = result.children[0].binary()?;
+        assert_eq!(
+            data.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some(b"200".as_slice()), None, None]
+        );
+        let errors = result.children[1]
+            .utf8()?
+            .as_arrow()
+            .iter()
+            .collect::<Vec<_>>();
+        assert!(errors[0].is_none());
+        assert!(errors[1].is_some_and(|err| err.contains("/404") && err.contains("not found")));
+        assert!(errors[2].is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_download_to_files() -> DaftResult<()> {
+        let base_url = spawn_status_server();
+        let dir = tempfile::tempdir().unwrap();
+        let local_path = dir.path().join("local.txt");
+        std::fs::write(&local_path, b"local").unwrap();
+        let output_path = |name: &str| dir.path().join(name).to_string_lossy().to_string();
+
+        let urls = Utf8Array::from_iter(
+            "urls",
+            vec![
+                Some(format!("{base_url}/200")),
+                Some(local_path.to_string_lossy().to_string()),
+                Some(format!("{base_url}/404")),
+                None,
+                Some(format!("{base_url}/200")),
+            ]
+            .into_iter(),
+        );
+        let output_paths = Utf8Array::from_iter(
+            "paths",
+            vec![
+                Some(output_path("0")),
+                Some(output_path("1")),
+                Some(output_path("2")),
+                Some(output_path("3")),
+                None,
+            ]
+            .into_iter(),
+        );
+
+        let result = crate::_url_download_to_files(
+            &urls,
+            &output_paths,
+            &UrlDownloadOptions {
+                max_connections: Some(4),
+                raise_error_on_failure: false,
+                multi_thread: false,
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(result.name(), "urls");
+        assert_eq!(
+            result.as_arrow().iter().collect::<Vec<_>>(),
+            vec![
+                Some(output_path("0").as_str()),
+                Some(output_path("1").as_str()),
+                None,
+                None,
+                None
+            ]
+        );
+        assert_eq!(std::fs::read(output_path("0")).unwrap(), b"200");
+        assert_eq!(std::fs::read(output_path("1")).unwrap(), b"local");
+        // Failed downloads leave no partial files behind, and skipped rows never create one
+        assert!(!std::path::Path::new(&output_path("2")).exists());
+        assert!(!std::path::Path::new(&output_path("3")).exists());
+
+        // Downloads over the size limit are failures too, whether streamed or copied from a local file
+        let oversized_paths = Utf8Array::from_iter(
+            "paths",
+            (0..urls.len())
+                .map(|i| Some(output_path(&format!("oversized-{i}"))))
+                .collect::<Vec<_>>()
+                .into_iter(),
+        );
+        let result = crate::_url_download_to_files(
+            &urls,
+            &oversized_paths,
+            &UrlDownloadOptions {
+                max_connections: Some(4),
+                raise_error_on_failure: false,
+                multi_thread: false,
+                max_size_bytes: Some(2),
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(result.as_arrow().iter().flatten().count(), 0);
+        assert!(!std::path::Path::new(&output_path("oversized-0")).exists());
+        assert!(!std::path::Path::new(&output_path("oversized-1")).exists());
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_download_max_requests_per_second() -> DaftResult<()> {
+        let base_url = spawn_status_server();
+        let urls = (0..100)
+            .map(|_| Some(format!("{base_url}/200")))
+            .collect::<Vec<_>>();
+        let urls = Utf8Array::from_iter("urls", urls.into_iter());
+
+        let start = std::time::Instant::now();
+        let result = crate::_url_download(
+            &urls,
+            &UrlDownloadOptions {
+                max_connections: Some(32),
+                multi_thread: false,
+                max_requests_per_second: Some(50.0),
+                ..Default::default()
+            },
+        )?;
+        let elapsed = start.elapsed().as_secs_f64();
+        assert_eq!(result.len(), 100);
+        // The first request starts immediately and each of the other 99 waits 20ms for its token.
+        // Only the lower bound is checked, since a loaded machine can always be slower.
+        assert!(elapsed >= 1.98, "took {elapsed}s");
+
+        // With a burst of 50, the first 50 requests start at once and only the other 50 wait for their tokens
+        let start = std::time::Instant::now();
+        crate::_url_download(
+            &urls,
+            &UrlDownloadOptions {
+                max_connections: Some(32),
+                multi_thread: false,
+                max_requests_per_second: Some(50.0),
+                max_requests_burst: Some(50),
+                ..Default::default()
+            },
+        )?;
+        let elapsed = start.elapsed().as_secs_f64();
+        assert!(elapsed >= 1.0, "took {elapsed}s");
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_download_invalid_max_requests_per_second() {
+        let urls = Utf8Array::from_iter("urls", vec![Some("http://127.0.0.1:1/")].into_iter());
+        for rate in [
+            0.0,
+            -1.0,
+            f64::NAN,
+            f64::INFINITY,
+            f64::MIN_POSITIVE / 2.0,
+            1e-300,
+        ] {
+            let result = crate::_url_download(
+                &urls,
+                &UrlDownloadOptions {
+                    max_connections: Some(1),
+                    raise_error_on_failure: false,
+                    multi_thread: false,
+                    max_requests_per_second: Some(rate),
+                    ..Default::default()
+                },
+            );
+            assert!(result.is_err(), "rate {rate} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_url_download_error_variants() {
+        let base_url = spawn_mock_http_server(|request| match request.path.as_str() {
+            "/slow" => {
+                std::thread::sleep(std::time::Duration::from_millis(500));
+                MockResponse::new(200, "slow")
+            }
+            path => MockResponse::new(path.trim_start_matches('/').parse().unwrap(), "status"),
+        });
+        let mut http_config = crate::HttpConfig::default();
+        http_config.timeout_ms = Some(100);
+        let config = Arc::new(IOConfig {
+            http: http_config,
+            ..Default::default()
+        });
+        let download_error = |url: String| {
+            let urls = Utf8Array::from_iter("urls", vec![Some(url)].into_iter());
+            crate::_url_download(
+                &urls,
+                &UrlDownloadOptions {
+                    max_connections: Some(1),
+                    multi_thread: false,
+                    config: config.clone(),
+                    ..Default::default()
+                },
+            )
+            .unwrap_err()
+        };
+
+        assert!(matches!(
+            download_error(format!("{base_url}/404")),
+            DaftError::FileNotFound { .. }
+        ));
+        assert!(matches!(
+            download_error(format!("{base_url}/503")),
+            DaftError::HttpStatus { status: 503, .. }
+        ));
+        // Nothing listens on port 1, so connections are refused
+        assert!(matches!(
+            download_error("http://127.0.0.1:1/".to_string()),
+            DaftError::ConnectionError { .. }
+        ));
+        assert!(matches!(
+            download_error(format!("{base_url}/slow")),
+            DaftError::ReadTimeout { .. }
+        ));
+    }
+
+    #[test]
+    fn test_url_download_invalid_urls() -> DaftResult<()> {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let server_requests = requests.clone();
+        let base_url = spawn_mock_http_server(move |request| {
+            server_requests.fetch_add(1, Ordering::SeqCst);
+            MockResponse::new(200, request.path.clone())
+        });
+        let invalid_urls = [
+            "",
+            "://example.com/data",
+            "http://exa mple.com/data",
+            "http://",
+        ];
+
+        for (n, invalid_url) in invalid_urls.iter().enumerate() {
+            let urls = Utf8Array::from_iter(
+                "urls",
+                vec![
+                    Some(format!("{base_url}/0")),
+                    None,
+                    Some(invalid_url.to_string()),
+                ]
+                .into_iter(),
+            );
+            let download = |raise_error_on_failure| {
+                crate::_url_download_with_errors(
+                    &urls,
+                    &UrlDownloadOptions {
+                        max_connections: Some(4),
+                        raise_error_on_failure,
+                        multi_thread: false,
+                        ..Default::default()
+                    },
+                )
+            };
+
+            // Invalid URLs are rejected before any request is issued, naming the offending row
+            let err = download(true).unwrap_err();
+            assert!(
+                matches!(&err, DaftError::ValueError(msg) if msg.contains("index 2")),
+                "{err}"
+            );
+            assert_eq!(requests.load(Ordering::SeqCst), n);
+
+            // Otherwise only the valid rows issue requests, and invalid ones are null with an error
+            let result = download(false)?;
+            assert_eq!(requests.load(Ordering::SeqCst), n + 1);
+            let data = result.children[0].binary()?;
+            assert_eq!(
+                data.as_arrow().iter().collect::<Vec<_>>(),
+                vec![Some(b"/0".as_slice()), None, None]
+            );
+            let errors = result.children[1]
+                .utf8()?
+                .as_arrow()
+                .iter()
+                .collect::<Vec<_>>();
+            assert!(errors[0].is_none());
+            assert!(errors[1].is_none());
+            assert!(errors[2].is_some_and(|err| err.contains("Invalid URL")));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_download_binary_urls() -> DaftResult<()> {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let server_requests = requests.clone();
+        let base_url = spawn_mock_http_server(move |request| {
+            server_requests.fetch_add(1, Ordering::SeqCst);
+            MockResponse::new(200, request.path.clone())
+        });
+        let valid_urls = [format!("{base_url}/0"), format!("{base_url}/3")];
+        let urls = BinaryArray::from_iter(
+            "urls",
+            vec![
+                Some(valid_urls[0].as_bytes()),
+                None,
+                Some(b"http://\xff\xfe/invalid".as_slice()),
+                Some(valid_urls[1].as_bytes()),
+            ]
+            .into_iter(),
+        );
+        let download = |raise_error_on_failure| {
+            crate::_url_download_binary(
+                &urls,
+                &UrlDownloadOptions {
+                    max_connections: Some(4),
+                    raise_error_on_failure,
+                    multi_thread: false,
+                    ..Default::default()
+                },
+            )
+        };
+
+        // Invalid UTF-8 is rejected before any request is issued, naming the offending row
+        let err = download(true).unwrap_err();
+        assert!(
+            matches!(&err, DaftError::ValueError(msg) if msg.contains("index 2") && msg.contains("UTF-8")),
+            "{err}"
+        );
+        assert_eq!(requests.load(Ordering::SeqCst), 0);
+
+        // Otherwise the rest of the batch is still downloaded, and invalid rows are null
+        let result = download(false)?;
+        assert_eq!(result.name(), "urls");
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+        assert_eq!(
+            result.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some(b"/0".as_slice()), None, None, Some(b"/3".as_slice())]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_download_through_proxy() -> DaftResult<()> {
+        let proxy_requests = Arc::new(Mutex::new(vec![]));
+        let recorded_requests = proxy_requests.clone();
+        let proxy_url = spawn_mock_http_server(move |request| {
+            recorded_requests.lock().unwrap().push((
+                request.path.clone(),
+                request.header("proxy-authorization").map(str::to_string),
+            ));
+            MockResponse::new(200, "proxied")
+        });
+        let direct_url = spawn_mock_http_server(|_| MockResponse::new(200, "direct"));
+
+        // Requests to example.invalid go through the proxy, while 127.0.0.1 is exempted by no_proxy
+        let mut http_config = crate::HttpConfig::default();
+        http_config.http_proxy = Some(proxy_url.replace("http://", "http://user:pass@"));
+        http_config.no_proxy = vec!["127.0.0.1".to_string()];
+        let urls = Utf8Array::from_iter(
+            "urls",
+            vec![
+                Some("http://example.invalid/data".to_string()),
+                Some(format!("{direct_url}/data")),
+            ]
+            .into_iter(),
+        );
+        let result = crate::_url_download(
+            &urls,
+            &UrlDownloadOptions {
+                max_connections: Some(4),
+                multi_thread: false,
+                config: Arc::new(IOConfig {
+                    http: http_config,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(
+            result.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some(b"proxied".as_slice()), Some(b"direct".as_slice())]
+        );
+
+        // Credentials in the proxy URL are sent to the proxy as basic auth (base64 of "user:pass")
+        assert_eq!(
+            *proxy_requests.lock().unwrap(),
+            vec![(
+                "http://example.invalid/data".to_string(),
+                Some("Basic dXNlcjpwYXNz".to_string())
+            )]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_binary_array_from_downloads_validity() -> DaftResult<()> {
+        let downloads = [
+            Some(bytes::Bytes::from_static(b"a")),
+            None,
+            Some(bytes::Bytes::from_static(b"")),
+            None,
+        ];
+        let array =
+            crate::binary_array_from_downloads("data", downloads.iter().map(|b| b.as_ref()))?;
+        assert_eq!(
+            array.validity(),
+            Some(&arrow2::bitmap::Bitmap::from([true, false, true, false]))
+        );
+        assert_eq!(
+            array.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some(b"a".as_slice()), None, Some(b"".as_slice()), None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_download_base64_round_trip() -> DaftResult<()> {
+        let body = vec![0x89, b'P', b'N', b'G', 0x00, 0xff, 0xfe];
+        let base_url = {
+            let body = body.clone();
+            spawn_mock_http_server(move |_| MockResponse::new(200, body.clone()))
+        };
+        let urls = Utf8Array::from_iter(
+            "urls",
+            vec![Some(format!("{base_url}/image.png")), None].into_iter(),
+        );
+        let downloaded = crate::_url_download(
+            &urls,
+            &UrlDownloadOptions {
+                max_connections: Some(4),
+                multi_thread: false,
+                ..Default::default()
+            },
+        )?;
+
+        let encoded = downloaded.to_base64(false)?;
+        assert_eq!(
+            encoded.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some("iVBORwD//g=="), None]
+        );
+        let decoded = encoded.from_base64(false, true)?;
+        assert_eq!(
+            decoded.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some(body.as_slice()), None]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_download_default_max_connections() -> DaftResult<()> {
+        assert!(crate::default_max_connections(false) > 0);
+        assert_eq!(
+            crate::default_max_connections(true),
+            crate::DEFAULT_MAX_CONNECTIONS_PER_THREAD
+        );
+
+        let base_url = spawn_status_server();
+        let urls = Utf8Array::from_iter(
+            "urls",
+            vec![Some(format!("{base_url}/200")); 20].into_iter(),
+        )
+        .into_series();
+        let download = |max_connections| {
+            crate::url_download(
+                &urls,
+                &UrlDownloadOptions {
+                    max_connections,
+                    multi_thread: false,
+                    ..Default::default()
+                },
+            )
+        };
+
+        let result = download(None)?;
+        assert_eq!(
+            result.binary()?.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some(b"200".as_slice()); 20]
+        );
+        // An explicit limit of zero is still rejected
+        assert!(matches!(
+            download(Some(0)),
+            Err(err) if err.to_string().contains("must be non-zero")
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_download_if_changed() -> DaftResult<()> {
+        const ETAG: &str = "\"v2\"";
+        const LAST_MODIFIED: &str = "Wed, 01 Jan 2025 00:00:00 GMT";
+        let base_url = spawn_mock_http_server(|request| {
+            if request.header("If-None-Match") == Some(ETAG)
+                || request.header("If-Modified-Since") == Some(LAST_MODIFIED)
+            {
+                MockResponse::new(304, "")
+            } else {
+                MockResponse::new(200, "new").with_header("ETag", ETAG)
+            }
+        });
+        let urls = Utf8Array::from_iter(
+            "urls",
+            vec![Some(format!("{base_url}/object")); 4]
+                .into_iter()
+                .chain(std::iter::once(None)),
+        );
+        let etags = Utf8Array::from_iter(
+            "etags",
+            vec![Some("\"v1\""), Some(ETAG), None, None, Some(ETAG)].into_iter(),
+        );
+        let last_modified = Utf8Array::from_iter(
+            "last_modified",
+            vec![None, None, Some(LAST_MODIFIED), None, None].into_iter(),
+        );
+
+        let result = crate::_url_download_if_changed(
+            &urls,
+            Some(&etags),
+            Some(&last_modified),
+            &UrlDownloadOptions {
+                max_connections: Some(4),
+                multi_thread: false,
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(result.name(), "urls");
+        assert_eq!(
+            result.children[0]
+                .binary()?
+                .as_arrow()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![
+                Some(b"new".as_slice()),
+                None,
+                None,
+                Some(b"new".as_slice()),
+                None
+            ]
+        );
+        assert_eq!(
+            result.children[1]
+                .bool()?
+                .as_arrow()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![
+                Some(false),
+                Some(true),
+                Some(true),
+                Some(false),
+                Some(false)
+            ]
+        );
+
+        // Conditions must line up with the URLs
+        assert!(matches!(
+            crate::_url_download_if_changed(
+                &urls,
+                Some(&etags.slice(0, 2)?),
+                None,
+                &UrlDownloadOptions {
+                    max_connections: Some(4),
+                    multi_thread: false,
+                    ..Default::default()
+                }
+            ),
+            Err(DaftError::ValueError(..))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_download_stream() -> DaftResult<()> {
+        let base_url =
+            spawn_mock_http_server(|request| MockResponse::new(200, request.path.clone()));
+        let urls = Utf8Array::from_iter(
+            "urls",
+            vec![
+                Some(format!("{base_url}/a")),
+                Some(format!("{base_url}/b")),
+                None,
+                Some(format!("{base_url}/c")),
+                Some(format!("{base_url}/d")),
+            ]
+            .into_iter(),
+        );
+        let chunks = crate::_url_download_stream(
+            &urls,
+            2,
+            &UrlDownloadOptions {
+                max_connections: Some(2),
+                multi_thread: false,
+                ..Default::default()
+            },
+        )?
+        .collect::<DaftResult<Vec<_>>>()?;
+
+        assert!(chunks.iter().all(|chunk| chunk.name() == "urls"));
+        assert_eq!(
+            chunks
+                .iter()
+                .map(|chunk| chunk.as_arrow().iter().collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            vec![
+                vec![Some(b"/a".as_slice()), Some(b"/b".as_slice())],
+                vec![None, Some(b"/c".as_slice())],
+                vec![Some(b"/d".as_slice())],
+            ]
+        );
+
+        assert!(matches!(
+            crate::_url_download_stream(&urls, 0, &UrlDownloadOptions {max_connections: Some(2), multi_thread: false, ..Default::default() }),
+            Err(err) if err.to_string().contains("must be non-zero")
+        ));
+        Ok(())
+    }
+
+    /// Test that each row is dispatched to the source for its URL's scheme, with `s3://` rows going to an
+    /// S3-compatible endpoint served by the mock server, and the results merged back in input order.
+    #[test]
+    fn test_url_download_dispatches_by_scheme() -> DaftResult<()> {
+        let s3_requests = Arc::new(Mutex::new(vec![]));
+        let s3_endpoint = {
+            let s3_requests = s3_requests.clone();
+            spawn_mock_http_server(move |request| {
+                s3_requests.lock().unwrap().push(request.path.clone());
+                MockResponse::new(200, "from s3")
+            })
+        };
+        let http_url = spawn_mock_http_server(|_| MockResponse::new(200, "from http"));
+        let config = Arc::new(IOConfig {
+            s3: crate::S3Config {
+                endpoint_url: Some(s3_endpoint),
+                region_name: Some("us-east-1".to_string()),
+                anonymous: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        let urls = Utf8Array::from_iter(
+            "urls",
+            vec![
+                Some("s3://bucket/a.bin".to_string()),
+                Some(format!("{http_url}/b.bin")),
+                None,
+                Some("s3://bucket/c.bin".to_string()),
+            ]
+            .into_iter(),
+        );
+
+        let result = crate::_url_download(
+            &urls,
+            &UrlDownloadOptions {
+                max_connections: Some(4),
+                multi_thread: false,
+                config,
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(
+            result.as_arrow().iter().collect::<Vec<_>>(),
+            vec![
+                Some(b"from s3".as_slice()),
+                Some(b"from http".as_slice()),
+                None,
+                Some(b"from s3".as_slice()),
+            ]
+        );
+        // S3 paths are requested path-style from the custom endpoint
+        let mut s3_requests = s3_requests.lock().unwrap().clone();
+        s3_requests.sort();
+        assert_eq!(s3_requests.len(), 2);
+        assert!(s3_requests[0].starts_with("/bucket/a.bin"));
+        assert!(s3_requests[1].starts_with("/bucket/c.bin"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_download_allowed_content_types() -> DaftResult<()> {
+        let base_url = spawn_mock_http_server(|request| {
+            let response = MockResponse::new(200, request.path.clone());
+            match request.path.as_str() {
+                "/png" => response.with_header("Content-Type", "image/png"),
+                "/jpeg" => response.with_header("Content-Type", "IMAGE/JPEG; charset=binary"),
+                "/json" => response.with_header("Content-Type", "application/json"),
+                _ => response,
+            }
+        });
+        let urls = Utf8Array::from_iter(
+            "urls",
+            ["png", "jpeg", "json", "untyped"]
+                .iter()
+                .map(|path| Some(format!("{base_url}/{path}")))
+                .collect::<Vec<_>>()
+                .into_iter(),
+        );
+        let download = |allowed_content_types: Option<Vec<&str>>| {
+            crate::_url_download(
+                &urls,
+                &UrlDownloadOptions {
+                    max_connections: Some(4),
+                    multi_thread: false,
+                    allowed_content_types: allowed_content_types
+                        .map(|types| types.into_iter().map(String::from).collect()),
+                    ..Default::default()
+                },
+            )
+            .map(|result| {
+                result
+                    .as_arrow()
+                    .iter()
+                    .map(|v| v.map(|v| v.to_vec()))
+                    .collect::<Vec<_>>()
+            })
+        };
+
+        // Disallowed content types are null rather than an error, even though failures are raised
+        assert_eq!(
+            download(Some(vec!["image/*"]))?,
+            vec![Some(b"/png".to_vec()), Some(b"/jpeg".to_vec()), None, None]
+        );
+        assert_eq!(
+            download(Some(vec!["image/png", "application/json"]))?,
+            vec![Some(b"/png".to_vec()), None, Some(b"/json".to_vec()), None]
+        );
+        assert_eq!(download(Some(vec!["*/*"]))?.iter().flatten().count(), 4);
+        assert_eq!(download(None)?.iter().flatten().count(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_url_download_cancellable() -> DaftResult<()> {
+        let cancellation = tokio_util::sync::CancellationToken::new();
+        // Cancel the batch while the second request is in flight
+        let base_url = {
+            let cancellation = cancellation.clone();
+            spawn_mock_http_server(move |request| {
+                if request.path == "/1" {
+                    cancellation.cancel();
+                }
+                MockResponse::new(200, request.path.clone())
+            })
+        };
+        let urls = Utf8Array::from_iter(
+            "urls",
+            (0..5)
+                .map(|i| Some(format!("{base_url}/{i}")))
+                .collect::<Vec<_>>()
+                .into_iter(),
+        );
+
+        // With a single connection, the requests are made one at a time in input order
+        let (result, cancelled) = crate::_url_download_cancellable(
+            &urls,
+            &UrlDownloadOptions {
+                max_connections: Some(1),
+                multi_thread: false,
+                cancellation: Some(cancellation),
+                ..Default::default()
+            },
+        )?;
+        assert!(cancelled);
+        assert_eq!(
+            result.as_arrow().iter().collect::<Vec<_>>(),
+            vec![
+                Some(b"/0".as_slice()),
+                Some(b"/1".as_slice()),
+                None,
+                None,
+                None
+            ]
+        );
+
+        let (result, cancelled) = crate::_url_download_cancellable(
+            &urls,
+            &UrlDownloadOptions {
+                max_connections: Some(1),
+                multi_thread: false,
+                cancellation: Some(tokio_util::sync::CancellationToken::new()),
+                ..Default::default()
+            },
+        )?;
+        assert!(!cancelled);
+        assert_eq!(result.as_arrow().iter().flatten().count(), 5);
+        Ok(())
+    }
+
+    /// Test that a cancelled stream yields the chunks downloaded before the cancellation and then an error, rather
+    /// than ending as if every row had been downloaded
+    #[test]
+    fn test_url_download_stream_cancelled() -> DaftResult<()> {
+        let cancellation = tokio_util::sync::CancellationToken::new();
+        let base_url = {
+            let cancellation = cancellation.clone();
+            spawn_mock_http_server(move |request| {
+                if request.path == "/1" {
+                    cancellation.cancel();
+                }
+                MockResponse::new(200, request.path.clone())
+            })
+        };
+        let urls = Utf8Array::from_iter(
+            "urls",
+            (0..5)
+                .map(|i| Some(format!("{base_url}/{i}")))
+                .collect::<Vec<_>>()
+                .into_iter(),
+        );
+        let options = UrlDownloadOptions {
+            max_connections: Some(1),
+            multi_thread: false,
+            cancellation: Some(cancellation),
+            ..Default::default()
+        };
+
+        // With a single connection, the requests are made one at a time in input order
+        let mut chunks = crate::_url_download_stream(&urls, 2, &options)?;
+        assert_eq!(
+            chunks
+                .next()
+                .unwrap()?
+                .as_arrow()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![Some(b"/0".as_slice()), Some(b"/1".as_slice())]
+        );
+        assert!(matches!(
+            chunks.next(),
+            Some(Err(DaftError::ComputeError(msg))) if msg.contains("cancelled after downloading 2 of 5 rows")
+        ));
+        assert!(chunks.next().is_none());
+
+        // Every collected stream either holds all of the rows or fails
+        let result =
+            crate::_url_download_stream(&urls, 2, &options)?.collect::<DaftResult<Vec<_>>>();
+        assert!(result.is_err());
+        let result = crate::_url_download_stream(
+            &urls,
+            2,
+            &UrlDownloadOptions {
+                cancellation: Some(tokio_util::sync::CancellationToken::new()),
+                ..options
+            },
+        )?
+        .collect::<DaftResult<Vec<_>>>()?;
+        assert_eq!(
+            result.iter().map(|chunk| chunk.len()).sum::<usize>(),
+            urls.len()
+        );
+        Ok(())
+    }
+}
@@ -302,7 +302,10 @@ impl ObjectSource for LocalSource {
     }
 }
 
-pub(crate) async fn collect_file(local_file: LocalFile) -> Result<Bytes> {
+pub(crate) async fn collect_file(
+    local_file: LocalFile,
+    max_size_bytes: Option<usize>,
+) -> Result<Bytes> {
     let path = &local_file.path;
     let mut file = tokio::fs::File::open(path)
         .await
@@ -312,15 +315,8 @@ pub(crate) async fn collect_file(local_file: LocalFile) -> Result<Bytes> {
 
     let mut buf = vec![];
 
-    match local_file.range {
-        None => {
-            let _ = file
-                .read_to_end(&mut buf)
-                .await
-                .context(UnableToReadBytesSnafu {
-                    path: path.to_string_lossy(),
-                })?;
-        }
+    let mut file = match local_file.range {
+        None => file.take(u64::MAX),
         Some(range) => {
             let length = range.end - range.start;
             file.seek(SeekFrom::Start(range.start as u64))
@@ -330,12 +326,22 @@ pub(crate) async fn collect_file(local_file: LocalFile) -> Result<Bytes> {
                 })?;
             buf.reserve(length);
             file.take(length as u64)
-                .read_to_end(&mut buf)
-                .await
-                .context(UnableToReadBytesSnafu {
-                    path: path.to_string_lossy(),
-                })?;
         }
+    };
+    // Read at most one byte past the limit, which is enough to tell whether the limit was exceeded
+    if let Some(max_size_bytes) = max_size_bytes {
+        file.set_limit(file.limit().min(max_size_bytes as u64 + 1));
+    }
+    let _ = file
+        .read_to_end(&mut buf)
+        .await
+        .context(UnableToReadBytesSnafu {
+            path: path.to_string_lossy(),
+        })?;
+    if let Some(max_size_bytes) = max_size_bytes
+        && buf.len() > max_size_bytes
+    {
+        return Err(super::Error::MaxSizeExceeded { max_size_bytes });
     }
     Ok(Bytes::from(buf))
 }
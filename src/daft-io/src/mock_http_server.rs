@@ -0,0 +1,147 @@
+//! A minimal blocking HTTP/1.1 server for exercising the HTTP code paths in tests without network access
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::Arc,
+};
+
+pub(crate) struct MockRequest {
+    pub method: String,
+    pub path: String,
+    /// Inclusive `(start, end)` offsets of the request's `Range: bytes=<start>-<end>` header, if any
+    pub range: Option<(usize, usize)>,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl MockRequest {
+    /// Value of the request's first header named `name`, ignoring case
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+pub(crate) struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl MockResponse {
+    pub fn new(status: u16, body: impl Into<Vec<u8>>) -> Self {
+        MockResponse {
+            status,
+            headers: vec![],
+            body: body.into(),
+        }
+    }
+
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    fn has_header(&self, name: &str) -> bool {
+        self.headers
+            .iter()
+            .any(|(k, _)| k.eq_ignore_ascii_case(name))
+    }
+}
+
+/// Size of the chunks that bodies are split into when a response sets `Transfer-Encoding: chunked`
+const CHUNK_SIZE: usize = 1024;
+
+fn read_request<R: BufRead>(reader: &mut R) -> Option<MockRequest> {
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).ok()?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next()?.to_string();
+    let path = parts.next()?.to_string();
+
+    let mut content_length = 0;
+    let mut range = None;
+    let mut headers = vec![];
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        let Some((name, value)) = line.split_once(':') else {
+            continue;
+        };
+        let (name, value) = (name.trim(), value.trim());
+        if name.eq_ignore_ascii_case("content-length") {
+            content_length = value.parse::<usize>().ok()?;
+        } else if name.eq_ignore_ascii_case("range") {
+            let (start, end) = value.strip_prefix("bytes=")?.split_once('-')?;
+            range = Some((start.parse().ok()?, end.parse().ok()?));
+        }
+        headers.push((name.to_string(), value.to_string()));
+    }
+
+    let mut body = vec![0u8; content_length];
+    reader.read_exact(&mut body).ok()?;
+
+    Some(MockRequest {
+        method,
+        path,
+        range,
+        headers,
+        body,
+    })
+}
+
+/// Spawns a server on a background thread which answers every request with `handler`, returning the base URL
+/// (e.g. `http://127.0.0.1:12345`) that the server is listening on. Each connection is served on its own thread and
+/// is closed after a single response. Responses get a `Content-Length` matching their body unless they set their own
+/// `Content-Length` or set `Transfer-Encoding: chunked`, in which case the body is sent in chunked encoding.
+pub(crate) fn spawn_mock_http_server<F>(handler: F) -> String
+where
+    F: Fn(&MockRequest) -> MockResponse + Send + Sync + 'static,
+{
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let handler = Arc::new(handler);
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else {
+                continue;
+            };
+            let handler = handler.clone();
+            std::thread::spawn(move || {
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let Some(request) = read_request(&mut reader) else {
+                    return;
+                };
+                let response = handler(&request);
+                let mut head = format!("HTTP/1.1 {} Mock\r\n", response.status);
+                let chunked = response.has_header("transfer-encoding");
+                if !chunked && !response.has_header("content-length") {
+                    head.push_str(&format!("Content-Length: {}\r\n", response.body.len()));
+                }
+                for (name, value) in response.headers.iter() {
+                    head.push_str(&format!("{name}: {value}\r\n"));
+                }
+                head.push_str("Connection: close\r\n\r\n");
+                let _ = stream.write_all(head.as_bytes());
+                if chunked {
+                    for chunk in response.body.chunks(CHUNK_SIZE) {
+                        let _ = stream.write_all(format!("{:x}\r\n", chunk.len()).as_bytes());
+                        let _ = stream.write_all(chunk);
+                        let _ = stream.write_all(b"\r\n");
+                    }
+                    let _ = stream.write_all(b"0\r\n\r\n");
+                } else {
+                    let _ = stream.write_all(&response.body);
+                }
+            });
+        }
+    });
+    format!("http://{addr}")
+}
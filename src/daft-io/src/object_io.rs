@@ -21,22 +21,41 @@ pub enum GetResult {
     ),
 }
 
-async fn collect_bytes<S>(mut stream: S, size_hint: Option<usize>) -> super::Result<Bytes>
+async fn collect_bytes<S>(
+    mut stream: S,
+    size_hint: Option<usize>,
+    max_size_bytes: Option<usize>,
+) -> super::Result<Bytes>
 where
     S: Stream<Item = super::Result<Bytes>> + Send + Unpin,
 {
+    let check_size = |size: usize| match max_size_bytes {
+        Some(max_size_bytes) if size > max_size_bytes => {
+            Err(super::Error::MaxSizeExceeded { max_size_bytes })
+        }
+        _ => Ok(()),
+    };
+    // Bail out before reading any of the body if the size hint already exceeds the limit
+    if let Some(size_hint) = size_hint {
+        check_size(size_hint)?;
+    }
+
     let first = stream.next().await.transpose()?.unwrap_or_default();
+    check_size(first.len())?;
     // Avoid copying if single response
     match stream.next().await.transpose()? {
         None => Ok(first),
         Some(second) => {
+            check_size(first.len() + second.len())?;
             let size_hint = size_hint.unwrap_or_else(|| first.len() + second.len());
 
             let mut buf = Vec::with_capacity(size_hint);
             buf.extend_from_slice(&first);
             buf.extend_from_slice(&second);
             while let Some(maybe_bytes) = stream.next().await {
-                buf.extend_from_slice(&maybe_bytes?);
+                let bytes = maybe_bytes?;
+                check_size(buf.len() + bytes.len())?;
+                buf.extend_from_slice(&bytes);
             }
 
             Ok(buf.into())
@@ -46,10 +65,16 @@ where
 
 impl GetResult {
     pub async fn bytes(self) -> super::Result<Bytes> {
+        self.bytes_with_max_size(None).await
+    }
+
+    /// Same as [`GetResult::bytes`], but fails with [`super::Error::MaxSizeExceeded`] as soon as more than
+    /// `max_size_bytes` bytes have been read, without reading the remainder of the object
+    pub async fn bytes_with_max_size(self, max_size_bytes: Option<usize>) -> super::Result<Bytes> {
         use GetResult::*;
         match self {
-            File(f) => collect_file(f).await,
-            Stream(stream, size, _permit) => collect_bytes(stream, size).await,
+            File(f) => collect_file(f, max_size_bytes).await,
+            Stream(stream, size, _permit) => collect_bytes(stream, size, max_size_bytes).await,
         }
     }
 }
@@ -91,6 +116,56 @@ pub struct LSResult {
     pub continuation_token: Option<String>,
 }
 
+/// Conditions under which [`ObjectSource::get_if_changed`] reads an object: only if it has changed since a previously
+/// downloaded copy, as described by that copy's validators, and only if its content type is allowed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GetConditions {
+    /// `ETag` of the previous copy, sent as `If-None-Match`
+    pub etag: Option<String>,
+    /// `Last-Modified` of the previous copy, sent as `If-Modified-Since`
+    pub last_modified: Option<String>,
+    /// Media types that the object's `Content-Type` must match, such as `image/png`, or `image/*` for any subtype
+    pub allowed_content_types: Option<Vec<String>>,
+}
+
+impl GetConditions {
+    /// Whether `content_type` matches the allowed content types, ignoring case and any parameters such as
+    /// `; charset=utf-8`. A missing content type only matches if every content type is allowed.
+    pub fn allows_content_type(&self, content_type: Option<&str>) -> bool {
+        let Some(allowed) = &self.allowed_content_types else {
+            return true;
+        };
+        let media_type = content_type.map(|content_type| {
+            content_type
+                .split(';')
+                .next()
+                .unwrap_or_default()
+                .trim()
+                .to_ascii_lowercase()
+        });
+        allowed.iter().any(|pattern| {
+            let pattern = pattern.trim().to_ascii_lowercase();
+            match (pattern.as_str(), &media_type) {
+                ("*" | "*/*", _) => true,
+                (_, None) => false,
+                (pattern, Some(media_type)) => match pattern.strip_suffix("/*") {
+                    Some(main_type) => media_type
+                        .split_once('/')
+                        .is_some_and(|(media_main_type, _)| media_main_type == main_type),
+                    None => pattern == media_type,
+                },
+            }
+        })
+    }
+}
+
+/// HTTP method used to upload data with [`ObjectSource::put`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UploadMethod {
+    Put,
+    Post,
+}
+
 use async_stream::stream;
 
 #[async_trait]
@@ -101,6 +176,32 @@ pub(crate) trait ObjectSource: Sync + Send {
         range: Option<Range<usize>>,
         io_stats: Option<IOStatsRef>,
     ) -> super::Result<GetResult>;
+
+    /// Same as [`ObjectSource::get`], but also returns the status code of the response for sources
+    /// that communicate over HTTP. Other sources have no notion of status codes and return `None`.
+    async fn get_with_status(
+        &self,
+        uri: &str,
+        range: Option<Range<usize>>,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<(GetResult, Option<u16>)> {
+        Ok((self.get(uri, range, io_stats).await?, None))
+    }
+
+    /// Same as [`ObjectSource::get_with_status`], but sources that communicate over HTTP make the request
+    /// conditional on `conditions`, so that the response is an empty `304 Not Modified` if the object hasn't changed,
+    /// and fail with [`super::Error::ContentTypeNotAllowed`] without reading the body if its content type isn't
+    /// allowed. Other sources have no notion of conditional requests or content types and always return the object.
+    async fn get_if_changed(
+        &self,
+        uri: &str,
+        range: Option<Range<usize>>,
+        _conditions: &GetConditions,
+        io_stats: Option<IOStatsRef>,
+    ) -> super::Result<(GetResult, Option<u16>)> {
+        self.get_with_status(uri, range, io_stats).await
+    }
+
     async fn get_range(
         &self,
         uri: &str,
@@ -111,6 +212,21 @@ pub(crate) trait ObjectSource: Sync + Send {
     }
     async fn get_size(&self, uri: &str, io_stats: Option<IOStatsRef>) -> super::Result<usize>;
 
+    /// Uploads `data` to `uri` with `method`, returning the status code of the response for sources that
+    /// communicate over HTTP. Unsuccessful status codes are returned rather than treated as errors, so that
+    /// callers can decide how to handle them.
+    async fn put(
+        &self,
+        _uri: &str,
+        _data: Bytes,
+        _method: UploadMethod,
+        _io_stats: Option<IOStatsRef>,
+    ) -> super::Result<Option<u16>> {
+        Err(super::Error::NotImplementedMethod {
+            method: "put".to_string(),
+        })
+    }
+
     async fn glob(
         self: Arc<Self>,
         glob_path: &str,
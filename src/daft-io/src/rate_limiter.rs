@@ -0,0 +1,112 @@
+use std::time::Duration;
+
+use snafu::ensure;
+use tokio::{sync::Mutex, time::Instant};
+
+use crate::{InvalidArgumentSnafu, Result};
+
+/// Bounds on the rates a [`RateLimiter`] accepts, so that the wait for a token always fits in a [`Duration`]
+const MIN_REQUESTS_PER_SECOND: f64 = 1e-6;
+const MAX_REQUESTS_PER_SECOND: f64 = 1e9;
+
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+/// Token bucket limiting the rate at which operations start. The bucket holds up to `burst` tokens, starts full,
+/// and refills at `requests_per_second` tokens per second. Each operation takes one token, and callers that find
+/// the bucket empty wait in line for the next one.
+pub(crate) struct RateLimiter {
+    requests_per_second: f64,
+    burst: u32,
+    bucket: Mutex<Bucket>,
+}
+
+impl RateLimiter {
+    pub fn try_new(requests_per_second: f64, burst: u32) -> Result<Self> {
+        ensure!(
+            (MIN_REQUESTS_PER_SECOND..=MAX_REQUESTS_PER_SECOND).contains(&requests_per_second),
+            InvalidArgumentSnafu {
+                msg: format!(
+                    "requests per second must be between {MIN_REQUESTS_PER_SECOND} and {MAX_REQUESTS_PER_SECOND}, got {requests_per_second}"
+                )
+            }
+        );
+        ensure!(
+            burst > 0,
+            InvalidArgumentSnafu {
+                msg: "rate limiter burst must be non-zero".to_owned()
+            }
+        );
+        Ok(RateLimiter {
+            requests_per_second,
+            burst,
+            bucket: Mutex::new(Bucket {
+                tokens: burst as f64,
+                refilled_at: Instant::now(),
+            }),
+        })
+    }
+
+    /// Waits until a token is available and takes it
+    pub async fn acquire(&self) {
+        // The lock is held while waiting, so that waiters take tokens in the order they arrived
+        let mut bucket = self.bucket.lock().await;
+        loop {
+            let now = Instant::now();
+            let refilled = (now - bucket.refilled_at).as_secs_f64() * self.requests_per_second;
+            bucket.tokens = (bucket.tokens + refilled).min(self.burst as f64);
+            bucket.refilled_at = now;
+            if bucket.tokens >= 1.0 {
+                bucket.tokens -= 1.0;
+                return;
+            }
+            let wait = (1.0 - bucket.tokens) / self.requests_per_second;
+            tokio::time::sleep(Duration::from_secs_f64(wait)).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tokio::time::Instant;
+
+    use super::RateLimiter;
+
+    #[test]
+    fn test_rate_limiter_rejects_invalid_rates() {
+        for rate in [
+            0.0,
+            -1.0,
+            f64::NAN,
+            f64::INFINITY,
+            f64::MIN_POSITIVE / 2.0,
+            1e-300,
+            1e12,
+        ] {
+            assert!(
+                RateLimiter::try_new(rate, 1).is_err(),
+                "rate {rate} should be rejected"
+            );
+        }
+        assert!(RateLimiter::try_new(1.0, 0).is_err());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_rate_limiter_burst() {
+        let limiter = RateLimiter::try_new(10.0, 5).unwrap();
+        let start = Instant::now();
+        // The full bucket lets the first 5 through at once, then the next 2 wait 100ms each
+        for _ in 0..5 {
+            limiter.acquire().await;
+        }
+        assert_eq!(start.elapsed(), Duration::ZERO);
+        for _ in 0..2 {
+            limiter.acquire().await;
+        }
+        assert_eq!(start.elapsed(), Duration::from_millis(200));
+    }
+}
@@ -1,7 +1,7 @@
 use common_error::DaftResult;
 use daft_dsl::Expr;
 use daft_io::IOStatsContext;
-use daft_table::Table;
+use daft_table::{StreamingAggregate, Table};
 
 use crate::micropartition::MicroPartition;
 
@@ -9,6 +9,31 @@ impl MicroPartition {
     pub fn agg(&self, to_agg: &[Expr], group_by: &[Expr]) -> DaftResult<Self> {
         let io_stats = IOStatsContext::new("MicroPartition::agg");
 
+        // Aggregate the tables of this partition one batch at a time, so that they never need to be concatenated.
+        // Aggregations which can't be merged across batches (such as grouped UDFs) fall back to concatenating.
+        let tables = self.tables_or_read(io_stats.clone())?;
+        if tables.len() > 1
+            && let Some(aggregations) = to_agg
+                .iter()
+                .map(|e| match e {
+                    Expr::Agg(agg_expr) => Some(agg_expr.clone()),
+                    _ => None,
+                })
+                .collect::<Option<Vec<_>>>()
+            && let Ok(mut aggregate) =
+                StreamingAggregate::try_new(&aggregations, group_by, None, &self.schema)
+        {
+            for table in tables.iter() {
+                aggregate.update(table)?;
+            }
+            let agged = aggregate.finalize()?;
+            return Ok(MicroPartition::new_loaded(
+                agged.schema.clone(),
+                vec![agged].into(),
+                None,
+            ));
+        }
+
         let tables = self.concat_or_get(io_stats)?;
 
         match tables.as_slice() {
@@ -33,3 +58,65 @@ impl MicroPartition {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::{datatypes::Int64Array, series::IntoSeries};
+    use daft_dsl::{col, AggExpr, Expr};
+    use daft_io::IOStatsContext;
+    use daft_table::Table;
+
+    use crate::micropartition::MicroPartition;
+
+    /// Test that aggregating a partition of several tables batch by batch matches aggregating their concatenation.
+    #[test]
+    fn test_agg_over_multiple_tables() -> DaftResult<()> {
+        let batch = |keys: Vec<i64>, values: Vec<i64>| {
+            Table::from_columns(vec![
+                Int64Array::from(("k", keys)).into_series(),
+                Int64Array::from(("v", values)).into_series(),
+            ])
+        };
+        let tables = vec![
+            batch(vec![1, 2, 1], vec![10, 20, 30])?,
+            batch(vec![2, 3], vec![40, 50])?,
+        ];
+        let to_agg = [
+            Expr::Agg(AggExpr::Sum(col("v").alias("sum").into())),
+            Expr::Agg(AggExpr::Mean(col("v").alias("mean").into())),
+            Expr::Agg(AggExpr::AnyValue(col("v").alias("any").into(), false)),
+        ];
+        let group_by = [col("k")];
+
+        let partition =
+            MicroPartition::new_loaded(tables[0].schema.clone(), tables.clone().into(), None);
+        let streamed = partition.agg(&to_agg, &group_by)?;
+        let expected =
+            Table::concat(&tables.iter().collect::<Vec<_>>())?.agg(&to_agg, &group_by)?;
+
+        let streamed = streamed.concat_or_get(IOStatsContext::new("test"))?;
+        let [streamed] = streamed.as_slice() else {
+            panic!("Expected the aggregation to produce a single table");
+        };
+        assert_eq!(streamed.schema, expected.schema);
+        let sorted_values = |table: &Table, name: &str| -> DaftResult<Vec<String>> {
+            let table = table.sort(&[col("k")], &[false])?;
+            let column = table.get_column(name)?;
+            (0..column.len()).map(|i| column.str_value(i)).collect()
+        };
+        for name in ["k", "sum", "mean"] {
+            assert_eq!(
+                sorted_values(streamed, name)?,
+                sorted_values(&expected, name)?
+            );
+        }
+        // Any value of each group may be taken, as long as it comes from that group
+        let any_values = sorted_values(streamed, "any")?;
+        assert_eq!(any_values.len(), 3);
+        assert!(["10", "30"].contains(&any_values[0].as_str()));
+        assert!(["20", "40"].contains(&any_values[1].as_str()));
+        assert_eq!(any_values[2], "50");
+        Ok(())
+    }
+}
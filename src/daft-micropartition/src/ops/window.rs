@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use common_error::DaftResult;
+use daft_dsl::Expr;
+use daft_io::IOStatsContext;
+use daft_table::{Table, WindowFunction};
+
+use crate::micropartition::MicroPartition;
+
+impl MicroPartition {
+    pub fn window(
+        &self,
+        window_functions: &[WindowFunction],
+        partition_by: &[Expr],
+        order_by: &[Expr],
+        descending: &[bool],
+    ) -> DaftResult<Self> {
+        let io_stats = IOStatsContext::new("MicroPartition::window");
+
+        let tables = self.concat_or_get(io_stats)?;
+        let windowed = match tables.as_slice() {
+            [] => Table::empty(Some(self.schema.clone()))?.window(
+                window_functions,
+                partition_by,
+                order_by,
+                descending,
+            )?,
+            [single] => single.window(window_functions, partition_by, order_by, descending)?,
+            _ => unreachable!(),
+        };
+        Ok(Self::new_loaded(
+            windowed.schema.clone(),
+            Arc::new(vec![windowed]),
+            None,
+        ))
+    }
+}
@@ -16,7 +16,10 @@ use daft_json::{JsonConvertOptions, JsonParseOptions, JsonReadOptions};
 use daft_parquet::read::ParquetSchemaInferenceOptions;
 use daft_scan::{python::pylib::PyScanTask, storage_config::PyStorageConfig, ScanTask};
 use daft_stats::TableStatistics;
-use daft_table::python::PyTable;
+use daft_table::{
+    python::{PyTable, PyWindowFunction},
+    WindowFunction,
+};
 use pyo3::{exceptions::PyValueError, prelude::*, types::PyBytes, Python};
 
 use crate::micropartition::{MicroPartition, TableState};
@@ -431,6 +434,32 @@ impl PyMicroPartition {
         })
     }
 
+    pub fn window(
+        &self,
+        py: Python,
+        window_functions: Vec<PyWindowFunction>,
+        partition_by: Vec<PyExpr>,
+        order_by: Vec<PyExpr>,
+        descending: Vec<bool>,
+    ) -> PyResult<Self> {
+        let window_functions: Vec<WindowFunction> =
+            window_functions.into_iter().map(|f| f.into()).collect();
+        let partition_by: Vec<daft_dsl::Expr> =
+            partition_by.into_iter().map(|e| e.into()).collect();
+        let order_by: Vec<daft_dsl::Expr> = order_by.into_iter().map(|e| e.into()).collect();
+        py.allow_threads(|| {
+            Ok(self
+                .inner
+                .window(
+                    window_functions.as_slice(),
+                    partition_by.as_slice(),
+                    order_by.as_slice(),
+                    descending.as_slice(),
+                )?
+                .into())
+        })
+    }
+
     #[staticmethod]
     pub fn read_json(
         py: Python,
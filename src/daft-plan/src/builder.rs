@@ -10,7 +10,7 @@ use crate::{
     planner::plan,
     sink_info::{OutputFileInfo, SinkInfo},
     source_info::SourceInfo,
-    JoinStrategy, JoinType, PhysicalPlanScheduler, ResourceRequest,
+    JoinStrategy, JoinType, NullKeyBehavior, PhysicalPlanScheduler, ResourceRequest,
 };
 use common_error::{DaftError, DaftResult};
 use common_io_config::IOConfig;
@@ -18,6 +18,7 @@ use daft_core::schema::Schema;
 use daft_core::schema::SchemaRef;
 use daft_dsl::Expr;
 use daft_scan::{file_format::FileFormat, Pushdowns, ScanExternalInfo, ScanOperatorRef};
+use daft_table::WindowFunction;
 
 #[cfg(feature = "python")]
 use {
@@ -27,6 +28,7 @@ use {
     daft_core::python::schema::PySchema,
     daft_dsl::python::PyExpr,
     daft_scan::python::pylib::ScanOperatorHandle,
+    daft_table::python::PyWindowFunction,
     pyo3::prelude::*,
 };
 
@@ -80,12 +82,28 @@ fn extract_agg_expr(expr: &Expr) -> DaftResult<daft_dsl::AggExpr> {
             // reorder expressions so that alias goes before agg
             match agg_expr {
                 Count(e, count_mode) => Count(Alias(e, name.clone()).into(), count_mode),
+                CountDistinct(e) => CountDistinct(Alias(e, name.clone()).into()),
                 Sum(e) => Sum(Alias(e, name.clone()).into()),
+                Product(e) => Product(Alias(e, name.clone()).into()),
                 Mean(e) => Mean(Alias(e, name.clone()).into()),
+                Median(e) => Median(Alias(e, name.clone()).into()),
+                Mode(e) => Mode(Alias(e, name.clone()).into()),
+                Quantile(e, q) => Quantile(Alias(e, name.clone()).into(), q),
+                Stddev(e, ddof) => Stddev(Alias(e, name.clone()).into(), ddof),
+                Variance(e, ddof) => Variance(Alias(e, name.clone()).into(), ddof),
                 Min(e) => Min(Alias(e, name.clone()).into()),
                 Max(e) => Max(Alias(e, name.clone()).into()),
                 AnyValue(e, ignore_nulls) => AnyValue(Alias(e, name.clone()).into(), ignore_nulls),
+                First(e) => First(Alias(e, name.clone()).into()),
+                Last(e) => Last(Alias(e, name.clone()).into()),
+                BitAnd(e) => BitAnd(Alias(e, name.clone()).into()),
+                BitOr(e) => BitOr(Alias(e, name.clone()).into()),
+                BitXor(e) => BitXor(Alias(e, name.clone()).into()),
+                Any(e) => Any(Alias(e, name.clone()).into()),
+                All(e) => All(Alias(e, name.clone()).into()),
+                StringAgg(e, separator) => StringAgg(Alias(e, name.clone()).into(), separator),
                 List(e) => List(Alias(e, name.clone()).into()),
+                Set(e) => Set(Alias(e, name.clone()).into()),
                 Concat(e) => Concat(Alias(e, name.clone()).into()),
                 MapGroups { func, inputs } => MapGroups {
                     func,
@@ -109,9 +127,30 @@ fn extract_and_check_agg_expr(expr: &Expr) -> DaftResult<daft_dsl::AggExpr> {
 
     let agg_expr = extract_agg_expr(expr)?;
     let has_nested_agg = match &agg_expr {
-        Count(e, _) | Sum(e) | Mean(e) | Min(e) | Max(e) | AnyValue(e, _) | List(e) | Concat(e) => {
-            check_for_agg(e)
-        }
+        Count(e, _)
+        | CountDistinct(e)
+        | Sum(e)
+        | Product(e)
+        | Mean(e)
+        | Median(e)
+        | Mode(e)
+        | Quantile(e, _)
+        | Stddev(e, _)
+        | Variance(e, _)
+        | Min(e)
+        | Max(e)
+        | AnyValue(e, _)
+        | First(e)
+        | Last(e)
+        | BitAnd(e)
+        | BitOr(e)
+        | BitXor(e)
+        | Any(e)
+        | All(e)
+        | StringAgg(e, _)
+        | List(e)
+        | Set(e)
+        | Concat(e) => check_for_agg(e),
         MapGroups { inputs, .. } => inputs.iter().any(check_for_agg),
     };
 
@@ -294,14 +333,26 @@ impl LogicalPlanBuilder {
         Ok(logical_plan.into())
     }
 
-    pub fn aggregate(&self, agg_exprs: Vec<Expr>, groupby_exprs: Vec<Expr>) -> DaftResult<Self> {
+    pub fn aggregate(
+        &self,
+        agg_exprs: Vec<Expr>,
+        groupby_exprs: Vec<Expr>,
+        null_keys: NullKeyBehavior,
+        having: Option<Expr>,
+    ) -> DaftResult<Self> {
         let agg_exprs = agg_exprs
             .iter()
             .map(extract_and_check_agg_expr)
             .collect::<DaftResult<Vec<daft_dsl::AggExpr>>>()?;
 
-        let logical_plan: LogicalPlan =
-            logical_ops::Aggregate::try_new(self.plan.clone(), agg_exprs, groupby_exprs)?.into();
+        let logical_plan: LogicalPlan = logical_ops::Aggregate::try_new(
+            self.plan.clone(),
+            agg_exprs,
+            groupby_exprs,
+            null_keys,
+            having,
+        )?
+        .into();
         Ok(logical_plan.into())
     }
 
@@ -347,6 +398,32 @@ impl LogicalPlanBuilder {
         Ok(logical_plan.into())
     }
 
+    pub fn window(
+        &self,
+        window_functions: Vec<WindowFunction>,
+        partition_by: Vec<Expr>,
+        order_by: Vec<Expr>,
+        descending: Vec<bool>,
+    ) -> DaftResult<Self> {
+        for expr in partition_by.iter().chain(order_by.iter()) {
+            if check_for_agg(expr) {
+                return Err(DaftError::ValueError(format!(
+                    "Aggregation expressions are not currently supported in window partitioning or ordering: {expr}"
+                )));
+            }
+        }
+
+        let logical_plan: LogicalPlan = logical_ops::Window::try_new(
+            self.plan.clone(),
+            window_functions,
+            partition_by,
+            order_by,
+            descending,
+        )?
+        .into();
+        Ok(logical_plan.into())
+    }
+
     pub fn table_write(
         &self,
         root_dir: &str,
@@ -545,7 +622,13 @@ impl PyLogicalPlanBuilder {
             .into())
     }
 
-    pub fn aggregate(&self, agg_exprs: Vec<PyExpr>, groupby_exprs: Vec<PyExpr>) -> PyResult<Self> {
+    pub fn aggregate(
+        &self,
+        agg_exprs: Vec<PyExpr>,
+        groupby_exprs: Vec<PyExpr>,
+        null_keys: NullKeyBehavior,
+        having: Option<PyExpr>,
+    ) -> PyResult<Self> {
         let agg_exprs = agg_exprs
             .iter()
             .map(|expr| expr.clone().into())
@@ -554,7 +637,15 @@ impl PyLogicalPlanBuilder {
             .iter()
             .map(|expr| expr.clone().into())
             .collect::<Vec<Expr>>();
-        Ok(self.builder.aggregate(agg_exprs, groupby_exprs)?.into())
+        Ok(self
+            .builder
+            .aggregate(
+                agg_exprs,
+                groupby_exprs,
+                null_keys,
+                having.map(|expr| expr.into()),
+            )?
+            .into())
     }
 
     pub fn join(
@@ -590,6 +681,31 @@ impl PyLogicalPlanBuilder {
             .into())
     }
 
+    pub fn window(
+        &self,
+        window_functions: Vec<PyWindowFunction>,
+        partition_by: Vec<PyExpr>,
+        order_by: Vec<PyExpr>,
+        descending: Vec<bool>,
+    ) -> PyResult<Self> {
+        let window_functions = window_functions
+            .into_iter()
+            .map(|f| f.into())
+            .collect::<Vec<WindowFunction>>();
+        let partition_by = partition_by
+            .iter()
+            .map(|expr| expr.clone().into())
+            .collect::<Vec<Expr>>();
+        let order_by = order_by
+            .iter()
+            .map(|expr| expr.clone().into())
+            .collect::<Vec<Expr>>();
+        Ok(self
+            .builder
+            .window(window_functions, partition_by, order_by, descending)?
+            .into())
+    }
+
     pub fn table_write(
         &self,
         root_dir: &str,
@@ -7,6 +7,7 @@ mod display;
 mod join;
 mod logical_ops;
 mod logical_plan;
+mod null_keys;
 mod optimization;
 mod partitioning;
 mod physical_ops;
@@ -23,6 +24,7 @@ pub use builder::{LogicalPlanBuilder, PyLogicalPlanBuilder};
 use daft_scan::file_format::FileFormat;
 pub use join::{JoinStrategy, JoinType};
 pub use logical_plan::LogicalPlan;
+pub use null_keys::NullKeyBehavior;
 pub use partitioning::ClusteringSpec;
 pub use physical_plan::PhysicalPlanScheduler;
 pub use resource_request::ResourceRequest;
@@ -51,6 +53,7 @@ pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
     parent.add_class::<DatabaseSourceConfig>()?;
     parent.add_class::<JoinType>()?;
     parent.add_class::<JoinStrategy>()?;
+    parent.add_class::<NullKeyBehavior>()?;
     parent.add_class::<PhysicalPlanScheduler>()?;
     parent.add_class::<ResourceRequest>()?;
     parent.add_class::<FileInfos>()?;
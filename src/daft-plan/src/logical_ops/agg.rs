@@ -1,13 +1,20 @@
 use std::sync::Arc;
 
+use common_error::DaftError;
+use common_treenode::TreeNode;
+use indexmap::{IndexMap, IndexSet};
 use itertools::Itertools;
 use snafu::ResultExt;
 
-use daft_core::schema::{Schema, SchemaRef};
-use daft_dsl::{AggExpr, Expr};
+use daft_core::{
+    schema::{Schema, SchemaRef},
+    DataType,
+};
+use daft_dsl::{col, optimization, AggExpr, Expr};
 
+use crate::logical_ops::Project;
 use crate::logical_plan::{self, CreationSnafu};
-use crate::LogicalPlan;
+use crate::{LogicalPlan, NullKeyBehavior};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Aggregate {
@@ -20,6 +27,12 @@ pub struct Aggregate {
     /// Grouping to apply.
     pub groupby: Vec<Expr>,
 
+    /// How rows with null group keys are grouped.
+    pub null_keys: NullKeyBehavior,
+
+    /// Predicate on the group keys and aggregation outputs which each output group must satisfy.
+    pub having: Option<Expr>,
+
     pub output_schema: SchemaRef,
 }
 
@@ -28,7 +41,12 @@ impl Aggregate {
         input: Arc<LogicalPlan>,
         aggregations: Vec<AggExpr>,
         groupby: Vec<Expr>,
+        null_keys: NullKeyBehavior,
+        having: Option<Expr>,
     ) -> logical_plan::Result<Self> {
+        // Compute any inputs shared by several aggregations once, in a child projection.
+        let (input, aggregations) = Self::try_factor_shared_inputs(input, aggregations, &groupby)?;
+
         let output_schema = {
             let upstream_schema = input.schema();
             let fields = groupby
@@ -39,15 +57,128 @@ impl Aggregate {
                 .context(CreationSnafu)?;
             Schema::new(fields).context(CreationSnafu)?.into()
         };
+        if let Some(having) = &having {
+            Self::check_having(having, &output_schema).context(CreationSnafu)?;
+        }
 
         Ok(Self {
             aggregations,
             groupby,
+            null_keys,
+            having,
             output_schema,
             input,
         })
     }
 
+    /// Checks that `having` is a Boolean predicate which only references columns of the aggregated output.
+    fn check_having(having: &Expr, output_schema: &Schema) -> common_error::DaftResult<()> {
+        let output_names = output_schema.names();
+        if let Some(colname) = optimization::get_required_columns(having)
+            .into_iter()
+            .find(|colname| !output_names.contains(colname))
+        {
+            return Err(DaftError::ValueError(format!(
+                "HAVING predicate {having} may only reference group keys and aggregation outputs, but references: {colname}"
+            )));
+        }
+        let field = having.to_field(output_schema)?;
+        if !matches!(field.dtype, DataType::Boolean) {
+            return Err(DaftError::ValueError(format!(
+                "Expected HAVING predicate {having} to resolve to type Boolean, but received: {}",
+                field.dtype
+            )));
+        }
+        Ok(())
+    }
+
+    fn try_factor_shared_inputs(
+        input: Arc<LogicalPlan>,
+        aggregations: Vec<AggExpr>,
+        groupby: &[Expr],
+    ) -> logical_plan::Result<(Arc<LogicalPlan>, Vec<AggExpr>)> {
+        // Given construction parameters for an aggregation,
+        // find the input expressions which are computed by more than one aggregation,
+        // e.g. sum(a+b) and mean(a+b) both compute a+b.
+        // Returns a new set of aggregation parameters
+        // (a maybe new input node, and a maybe new list of aggregations),
+        // where the new input projects each shared input once under its semantic ID
+        // and the aggregations select that column instead of recomputing the input.
+        let upstream_schema = input.schema();
+
+        let mut seen_inputs = IndexSet::new();
+        let mut shared_inputs = IndexMap::new();
+        for agg_expr in aggregations.iter() {
+            // Grouped UDFs take several inputs, so are left as they are
+            if matches!(agg_expr, AggExpr::MapGroups { .. }) {
+                continue;
+            }
+            let child = &agg_expr.children()[0];
+            if !optimization::requires_computation(child) {
+                continue;
+            }
+            let child_id = child.semantic_id(&upstream_schema);
+            if !seen_inputs.insert(child_id.clone()) {
+                shared_inputs.insert(child_id, child.clone());
+            }
+        }
+        if shared_inputs.is_empty() {
+            return Ok((input, aggregations));
+        }
+
+        let aggregations = aggregations
+            .into_iter()
+            .map(|agg_expr| {
+                if matches!(agg_expr, AggExpr::MapGroups { .. }) {
+                    return Ok(agg_expr);
+                }
+                let child = agg_expr.children()[0].clone();
+                let child_id = child.semantic_id(&upstream_schema);
+                if !shared_inputs.contains_key(&child_id) {
+                    return Ok(agg_expr);
+                }
+                // Keep the input's name, since it is also the name of the aggregation's output
+                let new_child = Expr::Column(child_id.id).alias(child.name()?);
+                match Expr::Agg(agg_expr).map_children(|_| Ok(new_child.clone()))? {
+                    Expr::Agg(agg_expr) => Ok(agg_expr),
+                    _ => unreachable!(),
+                }
+            })
+            .collect::<common_error::DaftResult<Vec<_>>>()
+            .context(CreationSnafu)?;
+
+        let shared_colnames = shared_inputs
+            .keys()
+            .map(|id| id.id.to_string())
+            .collect::<IndexSet<_>>();
+        let child_projection = shared_inputs
+            .iter()
+            .map(|(id, child)| {
+                let child = match child.as_ref() {
+                    Expr::Alias(inner, _) => inner.clone(),
+                    _ => child.clone(),
+                };
+                Expr::Alias(child, id.id.clone())
+            })
+            .chain(
+                aggregations
+                    .iter()
+                    .flat_map(|agg_expr| {
+                        optimization::get_required_columns(&Expr::Agg(agg_expr.clone()))
+                    })
+                    .chain(groupby.iter().flat_map(optimization::get_required_columns))
+                    .filter(|colname| !shared_colnames.contains(colname))
+                    .collect::<IndexSet<_>>()
+                    .into_iter()
+                    .map(col),
+            )
+            .collect::<Vec<_>>();
+
+        let plan: LogicalPlan =
+            Project::try_new(input, child_projection, Default::default())?.into();
+        Ok((plan.into(), aggregations))
+    }
+
     pub(crate) fn schema(&self) -> SchemaRef {
         let source_schema = self.input.schema();
 
@@ -75,8 +206,105 @@ impl Aggregate {
                 "Group by = {}",
                 self.groupby.iter().map(|e| e.to_string()).join(", ")
             ));
+            if self.null_keys != NullKeyBehavior::GroupTogether {
+                res.push(format!("Null keys = {}", self.null_keys));
+            }
+        }
+        if let Some(having) = &self.having {
+            res.push(format!("Having = {having}"));
         }
         res.push(format!("Output schema = {}", self.schema().short_string()));
         res
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::{count_mode::CountMode, datatypes::Field, DataType};
+    use daft_dsl::{binary_op, col, lit, AggExpr, Operator};
+
+    use crate::{
+        logical_ops::Aggregate,
+        test::{dummy_scan_node, dummy_scan_operator},
+        LogicalPlan,
+    };
+
+    /// Test that an input shared by several aggregations is computed once in a child projection.
+    /// e.g.
+    /// sum(a+b as x), mean(a+b as y) by c
+    /// ->
+    /// 1. sum(ab as x), mean(ab as y) by c
+    /// 2. a+b as ab, c
+    #[test]
+    fn test_shared_input() -> DaftResult<()> {
+        let source = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+            Field::new("c", DataType::Utf8),
+        ]))
+        .build();
+        let ab = binary_op(Operator::Plus, &col("a"), &col("b"));
+        let aggregate = Aggregate::try_new(
+            source.clone(),
+            vec![
+                AggExpr::Sum(ab.alias("x").into()),
+                AggExpr::Mean(ab.alias("y").into()),
+            ],
+            vec![col("c")],
+            Default::default(),
+            None,
+        )?;
+
+        let ab_colname = ab.semantic_id(&source.schema()).id;
+        let ab_col = col(ab_colname.clone());
+        let expected_aggregations = vec![
+            AggExpr::Sum(ab_col.alias("x").into()),
+            AggExpr::Mean(ab_col.alias("y").into()),
+        ];
+        assert_eq!(aggregate.aggregations, expected_aggregations);
+
+        let LogicalPlan::Project(subprojection) = aggregate.input.as_ref() else {
+            panic!()
+        };
+        assert_eq!(
+            subprojection.projection,
+            vec![ab.alias(ab_colname), col("c")]
+        );
+        assert_eq!(
+            aggregate.output_schema.names(),
+            vec!["c".to_string(), "x".to_string(), "y".to_string()]
+        );
+
+        Ok(())
+    }
+
+    /// Test that a HAVING predicate may reference group keys and aggregation outputs, but not other input columns.
+    #[test]
+    fn test_having_references() -> DaftResult<()> {
+        let source = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+            Field::new("c", DataType::Utf8),
+        ]))
+        .build();
+        let try_having = |having| {
+            Aggregate::try_new(
+                source.clone(),
+                vec![AggExpr::Count(
+                    col("a").alias("count").into(),
+                    CountMode::Valid,
+                )],
+                vec![col("c")],
+                Default::default(),
+                Some(having),
+            )
+        };
+
+        let having = col("count").gt(&lit(10)).and(&col("c").not_null());
+        assert_eq!(try_having(having.clone())?.having, Some(having));
+        assert!(try_having(col("b").gt(&lit(10))).is_err());
+        assert!(try_having(col("count")).is_err());
+        Ok(())
+    }
+}
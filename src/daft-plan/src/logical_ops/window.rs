@@ -0,0 +1,135 @@
+use std::sync::Arc;
+
+use common_error::DaftError;
+use daft_core::schema::{Schema, SchemaRef};
+use daft_dsl::{optimization, Expr};
+use daft_table::WindowFunction;
+use itertools::Itertools;
+use snafu::ResultExt;
+
+use crate::logical_plan::{self, CreationSnafu};
+use crate::LogicalPlan;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Window {
+    // Upstream node.
+    pub input: Arc<LogicalPlan>,
+
+    /// Window functions to compute, each appending a column to the input.
+    pub window_functions: Vec<WindowFunction>,
+
+    /// Rows are windowed separately for each distinct value of these expressions.
+    pub partition_by: Vec<Expr>,
+
+    /// Ordering of the rows within each partition.
+    pub order_by: Vec<Expr>,
+    pub descending: Vec<bool>,
+
+    pub output_schema: SchemaRef,
+}
+
+impl Window {
+    pub(crate) fn try_new(
+        input: Arc<LogicalPlan>,
+        window_functions: Vec<WindowFunction>,
+        partition_by: Vec<Expr>,
+        order_by: Vec<Expr>,
+        descending: Vec<bool>,
+    ) -> logical_plan::Result<Self> {
+        if window_functions.is_empty() {
+            return Err(DaftError::ValueError(
+                "A window must be given at least one window function".to_string(),
+            ))
+            .context(CreationSnafu);
+        }
+        if order_by.len() != descending.len() {
+            return Err(DaftError::ValueError(format!(
+                "A window must be given one descending flag per order-by expression, got {} expressions and {} flags",
+                order_by.len(),
+                descending.len()
+            )))
+            .context(CreationSnafu);
+        }
+
+        let upstream_schema = input.schema();
+        // Check that the partition-by and order-by expressions resolve against the input.
+        partition_by
+            .iter()
+            .chain(order_by.iter())
+            .map(|e| e.to_field(&upstream_schema))
+            .collect::<common_error::DaftResult<Vec<_>>>()
+            .context(CreationSnafu)?;
+        let output_schema = {
+            let fields = upstream_schema
+                .fields
+                .values()
+                .cloned()
+                .map(Ok)
+                .chain(
+                    window_functions
+                        .iter()
+                        .map(|f| f.to_field(&upstream_schema)),
+                )
+                .collect::<common_error::DaftResult<Vec<_>>>()
+                .context(CreationSnafu)?;
+            Schema::new(fields).context(CreationSnafu)?.into()
+        };
+
+        Ok(Self {
+            input,
+            window_functions,
+            partition_by,
+            order_by,
+            descending,
+            output_schema,
+        })
+    }
+
+    /// Names of the input columns which the window functions, partitioning and ordering read.
+    pub fn required_columns(&self) -> Vec<String> {
+        self.window_functions
+            .iter()
+            .flat_map(|f| match f {
+                WindowFunction::Agg(agg_expr) => {
+                    optimization::get_required_columns(&Expr::Agg(agg_expr.clone()))
+                }
+                WindowFunction::RowNumber(_)
+                | WindowFunction::Rank(_)
+                | WindowFunction::DenseRank(_) => vec![],
+            })
+            .chain(
+                self.partition_by
+                    .iter()
+                    .chain(self.order_by.iter())
+                    .flat_map(optimization::get_required_columns),
+            )
+            .collect()
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!(
+            "Window: {}",
+            self.window_functions
+                .iter()
+                .map(|e| e.to_string())
+                .join(", ")
+        ));
+        if !self.partition_by.is_empty() {
+            res.push(format!(
+                "Partition by = {}",
+                self.partition_by.iter().map(|e| e.to_string()).join(", ")
+            ));
+        }
+        if !self.order_by.is_empty() {
+            let pairs = self
+                .order_by
+                .iter()
+                .zip(self.descending.iter())
+                .map(|(sb, d)| format!("({}, {})", sb, if *d { "descending" } else { "ascending" }))
+                .join(", ");
+            res.push(format!("Order by = {}", pairs));
+        }
+        res
+    }
+}
@@ -25,6 +25,7 @@ pub enum LogicalPlan {
     Sink(Sink),
     Sample(Sample),
     MonotonicallyIncreasingId(MonotonicallyIncreasingId),
+    Window(Window),
 }
 
 impl LogicalPlan {
@@ -50,6 +51,7 @@ impl LogicalPlan {
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { schema, .. }) => {
                 schema.clone()
             }
+            Self::Window(Window { output_schema, .. }) => output_schema.clone(),
         }
     }
 
@@ -116,6 +118,7 @@ impl LogicalPlan {
                     .collect();
                 vec![res]
             }
+            Self::Window(window) => vec![window.required_columns().into_iter().collect()],
             Self::Join(join) => {
                 let left = join.left_on.iter().flat_map(get_required_columns).collect();
                 let right = join
@@ -146,6 +149,7 @@ impl LogicalPlan {
             Self::Sink(Sink { input, .. }) => vec![input],
             Self::Sample(Sample { input, .. }) => vec![input],
             Self::MonotonicallyIncreasingId(MonotonicallyIncreasingId { input, .. }) => vec![input],
+            Self::Window(Window { input, .. }) => vec![input],
         }
     }
 
@@ -162,8 +166,9 @@ impl LogicalPlan {
                 Self::Sort(Sort { sort_by, descending, .. }) => Self::Sort(Sort::try_new(input.clone(), sort_by.clone(), descending.clone()).unwrap()),
                 Self::Repartition(Repartition {  repartition_spec: scheme_config, .. }) => Self::Repartition(Repartition::try_new(input.clone(), scheme_config.clone()).unwrap()),
                 Self::Distinct(_) => Self::Distinct(Distinct::new(input.clone())),
-                Self::Aggregate(Aggregate { aggregations, groupby, ..}) => Self::Aggregate(Aggregate::try_new(input.clone(), aggregations.clone(), groupby.clone()).unwrap()),
+                Self::Aggregate(Aggregate { aggregations, groupby, null_keys, having, ..}) => Self::Aggregate(Aggregate::try_new(input.clone(), aggregations.clone(), groupby.clone(), *null_keys, having.clone()).unwrap()),
                 Self::Sink(Sink { sink_info, .. }) => Self::Sink(Sink::try_new(input.clone(), sink_info.clone()).unwrap()),
+                Self::Window(Window { window_functions, partition_by, order_by, descending, .. }) => Self::Window(Window::try_new(input.clone(), window_functions.clone(), partition_by.clone(), order_by.clone(), descending.clone()).unwrap()),
                 _ => panic!("Logical op {} has two inputs, but got one", self),
             },
             [input1, input2] => match self {
@@ -207,6 +212,7 @@ impl LogicalPlan {
             Self::Sink(..) => "Sink",
             Self::Sample(..) => "Sample",
             Self::MonotonicallyIncreasingId(..) => "MonotonicallyIncreasingId",
+            Self::Window(..) => "Window",
         };
         name.to_string()
     }
@@ -229,6 +235,7 @@ impl LogicalPlan {
                 vec![format!("Sample: {fraction}", fraction = sample.fraction)]
             }
             Self::MonotonicallyIncreasingId(_) => vec!["MonotonicallyIncreasingId".to_string()],
+            Self::Window(window) => window.multiline_display(),
         }
     }
 
@@ -291,3 +298,4 @@ impl_from_data_struct_for_logical_plan!(Join);
 impl_from_data_struct_for_logical_plan!(Sink);
 impl_from_data_struct_for_logical_plan!(Sample);
 impl_from_data_struct_for_logical_plan!(MonotonicallyIncreasingId);
+impl_from_data_struct_for_logical_plan!(Window);
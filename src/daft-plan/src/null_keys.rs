@@ -0,0 +1,79 @@
+use std::{
+    fmt::{Display, Formatter, Result},
+    str::FromStr,
+};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::impl_bincode_py_state_serialization;
+#[cfg(feature = "python")]
+use pyo3::{
+    exceptions::PyValueError, pyclass, pymethods, types::PyBytes, PyObject, PyResult, PyTypeInfo,
+    Python, ToPyObject,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// How a grouped aggregation treats rows whose group keys are null.
+///
+/// | GroupTogether - Rows with null keys form their own group, like in SQL and pandas with `dropna=False`.
+/// | Drop          - Rows with any null key are dropped before aggregating.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Hash)]
+#[cfg_attr(feature = "python", pyclass(module = "daft.daft"))]
+pub enum NullKeyBehavior {
+    #[default]
+    GroupTogether,
+    Drop,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl NullKeyBehavior {
+    /// Create a NullKeyBehavior from its string representation.
+    ///
+    /// Args:
+    ///     null_keys: String representation of the null key behavior, e.g. "group_together" or "drop".
+    #[staticmethod]
+    pub fn from_null_key_behavior_str(null_keys: &str) -> PyResult<Self> {
+        Self::from_str(null_keys).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    pub fn __str__(&self) -> PyResult<String> {
+        Ok(self.to_string())
+    }
+}
+
+impl_bincode_py_state_serialization!(NullKeyBehavior);
+
+impl NullKeyBehavior {
+    pub fn iterator() -> std::slice::Iter<'static, NullKeyBehavior> {
+        use NullKeyBehavior::*;
+
+        static NULL_KEY_BEHAVIORS: [NullKeyBehavior; 2] = [GroupTogether, Drop];
+        NULL_KEY_BEHAVIORS.iter()
+    }
+}
+
+impl FromStr for NullKeyBehavior {
+    type Err = DaftError;
+
+    fn from_str(null_keys: &str) -> DaftResult<Self> {
+        use NullKeyBehavior::*;
+
+        match null_keys {
+            "group_together" => Ok(GroupTogether),
+            "drop" => Ok(Drop),
+            _ => Err(DaftError::TypeError(format!(
+                "Null key behavior {} is not supported; only the following behaviors are supported: {:?}",
+                null_keys,
+                NullKeyBehavior::iterator().as_slice()
+            ))),
+        }
+    }
+}
+
+impl Display for NullKeyBehavior {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        // Leverage Debug trait implementation, which will already return the enum variant as a string.
+        write!(f, "{:?}", self)
+    }
+}
@@ -3,7 +3,10 @@ use std::{collections::HashMap, sync::Arc};
 use common_error::DaftResult;
 
 use daft_core::schema::Schema;
-use daft_dsl::{optimization::replace_columns_with_expressions, Expr};
+use daft_dsl::{
+    optimization::{get_required_columns, replace_columns_with_expressions},
+    Expr,
+};
 use indexmap::IndexSet;
 
 use crate::{
@@ -211,14 +214,20 @@ impl PushDownProjection {
                 }
             }
             LogicalPlan::Aggregate(aggregate) => {
-                // Prune unnecessary columns from the child aggregate.
+                // Prune unnecessary columns from the child aggregate,
+                // keeping those which its HAVING predicate needs.
                 let required_columns = &plan.required_columns()[0];
+                let having_columns = aggregate
+                    .having
+                    .iter()
+                    .flat_map(get_required_columns)
+                    .collect::<IndexSet<_>>();
                 let pruned_aggregate_exprs = aggregate
                     .aggregations
                     .iter()
                     .filter_map(|e| {
-                        required_columns
-                            .contains(e.name().unwrap())
+                        let name = e.name().unwrap();
+                        (required_columns.contains(name) || having_columns.contains(name))
                             .then(|| e.clone())
                     })
                     .collect::<Vec<_>>();
@@ -228,6 +237,8 @@ impl PushDownProjection {
                         aggregate.input.clone(),
                         pruned_aggregate_exprs,
                         aggregate.groupby.clone(),
+                        aggregate.null_keys,
+                        aggregate.having.clone(),
                     )?
                     .into();
 
@@ -440,6 +451,11 @@ impl PushDownProjection {
                 // since Distinct implicitly requires all parent columns.
                 Ok(Transformed::No(plan))
             }
+            LogicalPlan::Window(_) => {
+                // Cannot push down past a Window,
+                // since the projection may select the columns the Window appends.
+                Ok(Transformed::No(plan))
+            }
             LogicalPlan::Sink(_) => {
                 panic!("Bad projection due to upstream sink node: {:?}", projection)
             }
@@ -671,7 +687,7 @@ mod tests {
         let group_by = vec![col("c")];
         let proj = vec![col("a")];
         let plan = dummy_scan_node(scan_op.clone())
-            .aggregate(agg, group_by.clone())?
+            .aggregate(agg, group_by.clone(), Default::default(), None)?
             .project(proj.clone(), Default::default())?
             .build();
 
@@ -681,7 +697,7 @@ mod tests {
             scan_op,
             Pushdowns::default().with_columns(Some(Arc::new(proj_pushdown))),
         )
-        .aggregate(new_agg, group_by)?
+        .aggregate(new_agg, group_by, Default::default(), None)?
         .project(proj, Default::default())?
         .build();
 
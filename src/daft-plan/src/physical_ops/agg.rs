@@ -1,7 +1,11 @@
+use common_error::DaftResult;
+use daft_core::schema::Schema;
 use daft_dsl::{AggExpr, Expr};
+use daft_table::split_partial_final;
 use itertools::Itertools;
 
-use crate::physical_plan::PhysicalPlanRef;
+use crate::physical_ops::{Filter, Project};
+use crate::physical_plan::{PhysicalPlan, PhysicalPlanRef};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -14,6 +18,9 @@ pub struct Aggregate {
 
     /// Grouping to apply.
     pub groupby: Vec<Expr>,
+
+    /// Predicate on the aggregated columns which each output group must satisfy.
+    pub having: Option<Expr>,
 }
 
 impl Aggregate {
@@ -21,14 +28,62 @@ impl Aggregate {
         input: PhysicalPlanRef,
         aggregations: Vec<AggExpr>,
         groupby: Vec<Expr>,
+        having: Option<Expr>,
     ) -> Self {
         Self {
             input,
             aggregations,
             groupby,
+            having,
         }
     }
 
+    /// Splits this aggregation into two phases for inputs with several partitions:
+    /// a partial aggregation which computes the partial state of each group in each partition
+    /// (e.g. a sum and a count for a mean), and a final aggregation which merges the partial states
+    /// of each group once `gather` has brought them into the same partition.
+    /// A projection then computes the outputs of this aggregation from the merged states,
+    /// which are filtered by the HAVING predicate if there is one.
+    pub fn into_partial_final(
+        self,
+        schema: &Schema,
+        gather: impl FnOnce(PhysicalPlanRef) -> PhysicalPlan,
+    ) -> DaftResult<PhysicalPlan> {
+        let (partial_aggs, final_aggs, final_exprs) =
+            split_partial_final(&self.aggregations, schema);
+        let final_exprs = self.groupby.iter().cloned().chain(final_exprs).collect();
+
+        let partial_agg: PhysicalPlanRef = if partial_aggs.is_empty() {
+            self.input
+        } else {
+            PhysicalPlan::Aggregate(Aggregate::new(
+                self.input,
+                partial_aggs,
+                self.groupby.clone(),
+                None,
+            ))
+            .into()
+        };
+        let final_agg = PhysicalPlan::Aggregate(Aggregate::new(
+            gather(partial_agg).into(),
+            final_aggs,
+            self.groupby,
+            None,
+        ));
+
+        let clustering_spec = final_agg.clustering_spec().clone();
+        let project = PhysicalPlan::Project(Project::try_new(
+            final_agg.into(),
+            final_exprs,
+            Default::default(),
+            clustering_spec,
+        )?);
+        Ok(match self.having {
+            Some(having) => PhysicalPlan::Filter(Filter::new(project.into(), having)),
+            None => project,
+        })
+    }
+
     pub fn multiline_display(&self) -> Vec<String> {
         let mut res = vec![];
         res.push(format!(
@@ -41,6 +96,9 @@ impl Aggregate {
                 self.groupby.iter().map(|e| e.to_string()).join(", ")
             ));
         }
+        if let Some(having) = &self.having {
+            res.push(format!("Having = {having}"));
+        }
         res
     }
 }
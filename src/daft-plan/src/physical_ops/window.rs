@@ -0,0 +1,71 @@
+use daft_dsl::Expr;
+use daft_table::WindowFunction;
+use itertools::Itertools;
+
+use crate::physical_plan::PhysicalPlanRef;
+use serde::{Deserialize, Serialize};
+
+/// Computes window functions over the rows of each partition, appending one column per function.
+/// Unlike `Aggregate`, every input row is kept: the output holds the rows of each partition in turn,
+/// sorted by the order-by expressions (or in input order if there are none).
+/// All rows of a partition must be in the same input partition, see [`daft_table::Table::window`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Window {
+    // Upstream node.
+    pub input: PhysicalPlanRef,
+
+    /// Window functions to compute.
+    pub window_functions: Vec<WindowFunction>,
+
+    /// Rows are windowed separately for each distinct value of these expressions.
+    pub partition_by: Vec<Expr>,
+
+    /// Ordering of the rows within each partition.
+    pub order_by: Vec<Expr>,
+    pub descending: Vec<bool>,
+}
+
+impl Window {
+    pub(crate) fn new(
+        input: PhysicalPlanRef,
+        window_functions: Vec<WindowFunction>,
+        partition_by: Vec<Expr>,
+        order_by: Vec<Expr>,
+        descending: Vec<bool>,
+    ) -> Self {
+        Self {
+            input,
+            window_functions,
+            partition_by,
+            order_by,
+            descending,
+        }
+    }
+
+    pub fn multiline_display(&self) -> Vec<String> {
+        let mut res = vec![];
+        res.push(format!(
+            "Window: {}",
+            self.window_functions
+                .iter()
+                .map(|e| e.to_string())
+                .join(", ")
+        ));
+        if !self.partition_by.is_empty() {
+            res.push(format!(
+                "Partition by = {}",
+                self.partition_by.iter().map(|e| e.to_string()).join(", ")
+            ));
+        }
+        if !self.order_by.is_empty() {
+            let pairs = self
+                .order_by
+                .iter()
+                .zip(self.descending.iter())
+                .map(|(sb, d)| format!("({}, {})", sb, if *d { "descending" } else { "ascending" }))
+                .join(", ");
+            res.push(format!("Order by = {}", pairs));
+        }
+        res
+    }
+}
@@ -7,6 +7,7 @@ use {
     daft_dsl::python::PyExpr,
     daft_dsl::Expr,
     daft_scan::{file_format::FileFormat, python::pylib::PyScanTask},
+    daft_table::python::PyWindowFunction,
     pyo3::{
         pyclass, pymethods, types::PyBytes, PyObject, PyRef, PyRefMut, PyResult, PyTypeInfo,
         Python, ToPyObject,
@@ -55,6 +56,7 @@ pub enum PhysicalPlan {
     FanoutByRange(FanoutByRange),
     ReduceMerge(ReduceMerge),
     Aggregate(Aggregate),
+    Window(Window),
     Concat(Concat),
     HashJoin(HashJoin),
     SortMergeJoin(SortMergeJoin),
@@ -137,6 +139,7 @@ impl PhysicalPlan {
             ))
             .into(),
             Self::ReduceMerge(ReduceMerge { input }) => input.clustering_spec(),
+            Self::Window(Window { input, .. }) => input.clustering_spec(),
             Self::Aggregate(Aggregate { input, groupby, .. }) => {
                 let input_clustering_spec = input.clustering_spec();
                 if input_clustering_spec.num_partitions() == 1 {
@@ -244,7 +247,8 @@ impl PhysicalPlan {
             | Self::Flatten(Flatten { input, .. })
             | Self::ReduceMerge(ReduceMerge { input, .. })
             | Self::Sort(Sort { input, .. })
-            | Self::Split(Split { input, .. }) => input.approximate_size_bytes(),
+            | Self::Split(Split { input, .. })
+            | Self::Window(Window { input, .. }) => input.approximate_size_bytes(),
             Self::Concat(Concat { input, other }) => {
                 input.approximate_size_bytes().and_then(|input_size| {
                     other
@@ -298,6 +302,7 @@ impl PhysicalPlan {
             Self::FanoutByRange(FanoutByRange { input, .. }) => vec![input],
             Self::ReduceMerge(ReduceMerge { input }) => vec![input],
             Self::Aggregate(Aggregate { input, .. }) => vec![input],
+            Self::Window(Window { input, .. }) => vec![input],
             Self::TabularWriteParquet(TabularWriteParquet { input, .. }) => vec![input],
             Self::TabularWriteCsv(TabularWriteCsv { input, .. }) => vec![input],
             Self::TabularWriteJson(TabularWriteJson { input, .. }) => vec![input],
@@ -337,7 +342,8 @@ impl PhysicalPlan {
                 Self::FanoutByHash(FanoutByHash { num_partitions, partition_by, .. }) => Self::FanoutByHash(FanoutByHash::new(input.clone(), *num_partitions, partition_by.clone())),
                 Self::FanoutByRange(FanoutByRange { num_partitions, sort_by, descending, .. }) => Self::FanoutByRange(FanoutByRange::new(input.clone(), *num_partitions, sort_by.clone(), descending.clone())),
                 Self::ReduceMerge(..) => Self::ReduceMerge(ReduceMerge::new(input.clone())),
-                Self::Aggregate(Aggregate { aggregations, groupby, ..}) => Self::Aggregate(Aggregate::new(input.clone(), aggregations.clone(), groupby.clone())),
+                Self::Aggregate(Aggregate { aggregations, groupby, having, ..}) => Self::Aggregate(Aggregate::new(input.clone(), aggregations.clone(), groupby.clone(), having.clone())),
+                Self::Window(Window { window_functions, partition_by, order_by, descending, .. }) => Self::Window(Window::new(input.clone(), window_functions.clone(), partition_by.clone(), order_by.clone(), descending.clone())),
                 Self::TabularWriteParquet(TabularWriteParquet { schema, file_info, .. }) => Self::TabularWriteParquet(TabularWriteParquet::new(schema.clone(), file_info.clone(), input.clone())),
                 Self::TabularWriteCsv(TabularWriteCsv { schema, file_info, .. }) => Self::TabularWriteCsv(TabularWriteCsv::new(schema.clone(), file_info.clone(), input.clone())),
                 Self::TabularWriteJson(TabularWriteJson { schema, file_info, .. }) => Self::TabularWriteJson(TabularWriteJson::new(schema.clone(), file_info.clone(), input.clone())),
@@ -386,6 +392,7 @@ impl PhysicalPlan {
             Self::FanoutByRange(..) => "FanoutByRange",
             Self::ReduceMerge(..) => "ReduceMerge",
             Self::Aggregate(..) => "Aggregate",
+            Self::Window(..) => "Window",
             Self::HashJoin(..) => "HashJoin",
             Self::BroadcastJoin(..) => "BroadcastJoin",
             Self::SortMergeJoin(..) => "SortMergeJoin",
@@ -420,6 +427,7 @@ impl PhysicalPlan {
             Self::FanoutByRange(fanout_by_range) => fanout_by_range.multiline_display(),
             Self::ReduceMerge(reduce_merge) => reduce_merge.multiline_display(),
             Self::Aggregate(aggregate) => aggregate.multiline_display(),
+            Self::Window(window) => window.multiline_display(),
             Self::HashJoin(hash_join) => hash_join.multiline_display(),
             Self::BroadcastJoin(broadcast_join) => broadcast_join.multiline_display(),
             Self::SortMergeJoin(sort_merge_join) => sort_merge_join.multiline_display(),
@@ -788,8 +796,8 @@ impl PhysicalPlan {
             PhysicalPlan::Aggregate(Aggregate {
                 aggregations,
                 groupby,
+                having,
                 input,
-                ..
             }) => {
                 let upstream_iter = input.to_partition_tasks(py, psets)?;
                 let aggs_as_pyexprs: Vec<PyExpr> = aggregations
@@ -800,10 +808,48 @@ impl PhysicalPlan {
                     .iter()
                     .map(|expr| PyExpr::from(expr.clone()))
                     .collect();
+                let having_as_pyexpr = having.as_ref().map(|expr| PyExpr::from(expr.clone()));
                 let py_iter = py
                     .import(pyo3::intern!(py, "daft.execution.rust_physical_plan_shim"))?
                     .getattr(pyo3::intern!(py, "local_aggregate"))?
-                    .call1((upstream_iter, aggs_as_pyexprs, groupbys_as_pyexprs))?;
+                    .call1((
+                        upstream_iter,
+                        aggs_as_pyexprs,
+                        groupbys_as_pyexprs,
+                        having_as_pyexpr,
+                    ))?;
+                Ok(py_iter.into())
+            }
+            PhysicalPlan::Window(Window {
+                input,
+                window_functions,
+                partition_by,
+                order_by,
+                descending,
+            }) => {
+                let upstream_iter = input.to_partition_tasks(py, psets)?;
+                let window_functions: Vec<PyWindowFunction> = window_functions
+                    .iter()
+                    .map(|f| PyWindowFunction::from(f.clone()))
+                    .collect();
+                let partition_by_pyexprs: Vec<PyExpr> = partition_by
+                    .iter()
+                    .map(|expr| PyExpr::from(expr.clone()))
+                    .collect();
+                let order_by_pyexprs: Vec<PyExpr> = order_by
+                    .iter()
+                    .map(|expr| PyExpr::from(expr.clone()))
+                    .collect();
+                let py_iter = py
+                    .import(pyo3::intern!(py, "daft.execution.rust_physical_plan_shim"))?
+                    .getattr(pyo3::intern!(py, "window"))?
+                    .call1((
+                        upstream_iter,
+                        window_functions,
+                        partition_by_pyexprs,
+                        order_by_pyexprs,
+                        descending.clone(),
+                    ))?;
                 Ok(py_iter.into())
             }
             PhysicalPlan::Coalesce(Coalesce {
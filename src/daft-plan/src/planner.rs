@@ -1,13 +1,9 @@
 use std::cmp::Ordering;
+use std::cmp::{max, min};
 use std::sync::Arc;
-use std::{
-    cmp::{max, min},
-    collections::HashMap,
-};
 
 use common_daft_config::DaftExecutionConfig;
 use common_error::DaftResult;
-use daft_core::count_mode::CountMode;
 use daft_core::DataType;
 use daft_dsl::Expr;
 use daft_scan::ScanExternalInfo;
@@ -17,7 +13,7 @@ use crate::logical_ops::{
     Explode as LogicalExplode, Filter as LogicalFilter, Join as LogicalJoin, Limit as LogicalLimit,
     MonotonicallyIncreasingId as LogicalMonotonicallyIncreasingId, Project as LogicalProject,
     Repartition as LogicalRepartition, Sample as LogicalSample, Sink as LogicalSink,
-    Sort as LogicalSort, Source,
+    Sort as LogicalSort, Source, Window as LogicalWindow,
 };
 use crate::logical_plan::LogicalPlan;
 use crate::partitioning::{
@@ -27,7 +23,7 @@ use crate::physical_plan::PhysicalPlan;
 use crate::sink_info::{OutputFileInfo, SinkInfo};
 use crate::source_info::SourceInfo;
 use crate::FileFormat;
-use crate::{physical_ops::*, JoinStrategy};
+use crate::{physical_ops::*, JoinStrategy, NullKeyBehavior};
 
 #[cfg(feature = "python")]
 use crate::physical_ops::InMemoryScan;
@@ -226,6 +222,7 @@ pub fn plan(logical_plan: &LogicalPlan, cfg: Arc<DaftExecutionConfig>) -> DaftRe
                 input_physical.into(),
                 vec![],
                 col_exprs.clone(),
+                None,
             ));
             let num_partitions = agg_op.clustering_spec().num_partitions();
             if num_partitions > 1 {
@@ -239,6 +236,7 @@ pub fn plan(logical_plan: &LogicalPlan, cfg: Arc<DaftExecutionConfig>) -> DaftRe
                     reduce_op.into(),
                     vec![],
                     col_exprs,
+                    None,
                 )))
             } else {
                 Ok(agg_op)
@@ -261,12 +259,21 @@ pub fn plan(logical_plan: &LogicalPlan, cfg: Arc<DaftExecutionConfig>) -> DaftRe
         LogicalPlan::Aggregate(LogicalAggregate {
             aggregations,
             groupby,
+            null_keys,
+            having,
             input,
             ..
         }) => {
-            use daft_dsl::AggExpr::{self, *};
-            use daft_dsl::Expr::Column;
-            let input_plan = plan(input, cfg.clone())?;
+            let mut input_plan = plan(input, cfg.clone())?;
+            if *null_keys == NullKeyBehavior::Drop && !groupby.is_empty() {
+                // Drop rows with null keys before they are hashed into groups
+                let predicate = groupby
+                    .iter()
+                    .map(|e| e.not_null())
+                    .reduce(|acc, e| acc.and(&e))
+                    .unwrap();
+                input_plan = PhysicalPlan::Filter(Filter::new(input_plan.into(), predicate));
+            }
 
             let num_input_partitions = input_plan.clustering_spec().num_partitions();
 
@@ -275,194 +282,20 @@ pub fn plan(logical_plan: &LogicalPlan, cfg: Arc<DaftExecutionConfig>) -> DaftRe
                     input_plan.into(),
                     aggregations.clone(),
                     groupby.clone(),
+                    having.clone(),
                 )),
-                _ => {
-                    let schema = logical_plan.schema();
-
-                    // Aggregations to apply in the first and second stages.
-                    // Semantic column name -> AggExpr
-                    let mut first_stage_aggs: HashMap<Arc<str>, AggExpr> = HashMap::new();
-                    let mut second_stage_aggs: HashMap<Arc<str>, AggExpr> = HashMap::new();
-                    // Project the aggregation results to their final output names
-                    let mut final_exprs: Vec<Expr> = groupby.clone();
-
-                    for agg_expr in aggregations {
-                        let output_name = agg_expr.name().unwrap();
-                        match agg_expr {
-                            Count(e, mode) => {
-                                let count_id = agg_expr.semantic_id(&schema).id;
-                                let sum_of_count_id =
-                                    Sum(Column(count_id.clone()).into()).semantic_id(&schema).id;
-                                first_stage_aggs.entry(count_id.clone()).or_insert(Count(
-                                    e.alias(count_id.clone()).clone().into(),
-                                    *mode,
-                                ));
-                                second_stage_aggs
-                                    .entry(sum_of_count_id.clone())
-                                    .or_insert(Sum(Column(count_id.clone())
-                                        .alias(sum_of_count_id.clone())
-                                        .into()));
-                                final_exprs
-                                    .push(Column(sum_of_count_id.clone()).alias(output_name));
-                            }
-                            Sum(e) => {
-                                let sum_id = agg_expr.semantic_id(&schema).id;
-                                let sum_of_sum_id =
-                                    Sum(Column(sum_id.clone()).into()).semantic_id(&schema).id;
-                                first_stage_aggs
-                                    .entry(sum_id.clone())
-                                    .or_insert(Sum(e.alias(sum_id.clone()).clone().into()));
-                                second_stage_aggs
-                                    .entry(sum_of_sum_id.clone())
-                                    .or_insert(Sum(Column(sum_id.clone())
-                                        .alias(sum_of_sum_id.clone())
-                                        .into()));
-                                final_exprs.push(Column(sum_of_sum_id.clone()).alias(output_name));
-                            }
-                            Mean(e) => {
-                                let sum_id = Sum(e.clone()).semantic_id(&schema).id;
-                                let count_id =
-                                    Count(e.clone(), CountMode::Valid).semantic_id(&schema).id;
-                                let sum_of_sum_id =
-                                    Sum(Column(sum_id.clone()).into()).semantic_id(&schema).id;
-                                let sum_of_count_id =
-                                    Sum(Column(count_id.clone()).into()).semantic_id(&schema).id;
-                                first_stage_aggs
-                                    .entry(sum_id.clone())
-                                    .or_insert(Sum(e.alias(sum_id.clone()).clone().into()));
-                                first_stage_aggs.entry(count_id.clone()).or_insert(Count(
-                                    e.alias(count_id.clone()).clone().into(),
-                                    CountMode::Valid,
-                                ));
-                                second_stage_aggs
-                                    .entry(sum_of_sum_id.clone())
-                                    .or_insert(Sum(Column(sum_id.clone())
-                                        .alias(sum_of_sum_id.clone())
-                                        .into()));
-                                second_stage_aggs
-                                    .entry(sum_of_count_id.clone())
-                                    .or_insert(Sum(Column(count_id.clone())
-                                        .alias(sum_of_count_id.clone())
-                                        .into()));
-                                final_exprs.push(
-                                    (Column(sum_of_sum_id.clone())
-                                        / Column(sum_of_count_id.clone()))
-                                    .alias(output_name),
-                                );
-                            }
-                            Min(e) => {
-                                let min_id = agg_expr.semantic_id(&schema).id;
-                                let min_of_min_id =
-                                    Min(Column(min_id.clone()).into()).semantic_id(&schema).id;
-                                first_stage_aggs
-                                    .entry(min_id.clone())
-                                    .or_insert(Min(e.alias(min_id.clone()).clone().into()));
-                                second_stage_aggs
-                                    .entry(min_of_min_id.clone())
-                                    .or_insert(Min(Column(min_id.clone())
-                                        .alias(min_of_min_id.clone())
-                                        .into()));
-                                final_exprs.push(Column(min_of_min_id.clone()).alias(output_name));
-                            }
-                            Max(e) => {
-                                let max_id = agg_expr.semantic_id(&schema).id;
-                                let max_of_max_id =
-                                    Max(Column(max_id.clone()).into()).semantic_id(&schema).id;
-                                first_stage_aggs
-                                    .entry(max_id.clone())
-                                    .or_insert(Max(e.alias(max_id.clone()).clone().into()));
-                                second_stage_aggs
-                                    .entry(max_of_max_id.clone())
-                                    .or_insert(Max(Column(max_id.clone())
-                                        .alias(max_of_max_id.clone())
-                                        .into()));
-                                final_exprs.push(Column(max_of_max_id.clone()).alias(output_name));
-                            }
-                            AnyValue(e, ignore_nulls) => {
-                                let any_id = agg_expr.semantic_id(&schema).id;
-                                let any_of_any_id =
-                                    AnyValue(Column(any_id.clone()).into(), *ignore_nulls)
-                                        .semantic_id(&schema)
-                                        .id;
-                                first_stage_aggs.entry(any_id.clone()).or_insert(AnyValue(
-                                    e.alias(any_id.clone()).clone().into(),
-                                    *ignore_nulls,
-                                ));
-                                second_stage_aggs
-                                    .entry(any_of_any_id.clone())
-                                    .or_insert(AnyValue(
-                                        Column(any_id.clone()).alias(any_of_any_id.clone()).into(),
-                                        *ignore_nulls,
-                                    ));
-                            }
-                            List(e) => {
-                                let list_id = agg_expr.semantic_id(&schema).id;
-                                let concat_of_list_id = Concat(Column(list_id.clone()).into())
-                                    .semantic_id(&schema)
-                                    .id;
-                                first_stage_aggs
-                                    .entry(list_id.clone())
-                                    .or_insert(List(e.alias(list_id.clone()).clone().into()));
-                                second_stage_aggs
-                                    .entry(concat_of_list_id.clone())
-                                    .or_insert(Concat(
-                                        Column(list_id.clone())
-                                            .alias(concat_of_list_id.clone())
-                                            .into(),
-                                    ));
-                                final_exprs
-                                    .push(Column(concat_of_list_id.clone()).alias(output_name));
-                            }
-                            Concat(e) => {
-                                let concat_id = agg_expr.semantic_id(&schema).id;
-                                let concat_of_concat_id = Concat(Column(concat_id.clone()).into())
-                                    .semantic_id(&schema)
-                                    .id;
-                                first_stage_aggs
-                                    .entry(concat_id.clone())
-                                    .or_insert(Concat(e.alias(concat_id.clone()).clone().into()));
-                                second_stage_aggs
-                                    .entry(concat_of_concat_id.clone())
-                                    .or_insert(Concat(
-                                        Column(concat_id.clone())
-                                            .alias(concat_of_concat_id.clone())
-                                            .into(),
-                                    ));
-                                final_exprs
-                                    .push(Column(concat_of_concat_id.clone()).alias(output_name));
-                            }
-                            MapGroups { func, inputs } => {
-                                let func_id = agg_expr.semantic_id(&schema).id;
-                                // No first stage aggregation for MapGroups, do all the work in the second stage.
-                                second_stage_aggs
-                                    .entry(func_id.clone())
-                                    .or_insert(MapGroups {
-                                        func: func.clone(),
-                                        inputs: inputs.to_vec(),
-                                    });
-                                final_exprs.push(Column(output_name.into()));
-                            }
-                        }
-                    }
-
-                    let first_stage_agg = if first_stage_aggs.is_empty() {
-                        input_plan
-                    } else {
-                        PhysicalPlan::Aggregate(Aggregate::new(
-                            input_plan.into(),
-                            first_stage_aggs.values().cloned().collect(),
-                            groupby.clone(),
-                        ))
-                    };
-                    let gather_plan = if groupby.is_empty() {
-                        PhysicalPlan::Coalesce(Coalesce::new(
-                            first_stage_agg.into(),
-                            num_input_partitions,
-                            1,
-                        ))
+                _ => Aggregate::new(
+                    input_plan.into(),
+                    aggregations.clone(),
+                    groupby.clone(),
+                    having.clone(),
+                )
+                .into_partial_final(&logical_plan.schema(), |partial_agg| {
+                    if groupby.is_empty() {
+                        PhysicalPlan::Coalesce(Coalesce::new(partial_agg, num_input_partitions, 1))
                     } else {
                         let split_op = PhysicalPlan::FanoutByHash(FanoutByHash::new(
-                            first_stage_agg.into(),
+                            partial_agg,
                             min(
                                 num_input_partitions,
                                 cfg.shuffle_aggregation_default_partitions,
@@ -470,22 +303,8 @@ pub fn plan(logical_plan: &LogicalPlan, cfg: Arc<DaftExecutionConfig>) -> DaftRe
                             groupby.clone(),
                         ));
                         PhysicalPlan::ReduceMerge(ReduceMerge::new(split_op.into()))
-                    };
-
-                    let second_stage_agg = PhysicalPlan::Aggregate(Aggregate::new(
-                        gather_plan.into(),
-                        second_stage_aggs.values().cloned().collect(),
-                        groupby.clone(),
-                    ));
-
-                    let clustering_spec = second_stage_agg.clustering_spec().clone();
-                    PhysicalPlan::Project(Project::try_new(
-                        second_stage_agg.into(),
-                        final_exprs,
-                        Default::default(),
-                        clustering_spec,
-                    )?)
-                }
+                    }
+                })?,
             };
 
             Ok(result_plan)
@@ -742,6 +561,41 @@ pub fn plan(logical_plan: &LogicalPlan, cfg: Arc<DaftExecutionConfig>) -> DaftRe
                 MonotonicallyIncreasingId::new(input_physical.into(), column_name),
             ))
         }
+        LogicalPlan::Window(LogicalWindow {
+            input,
+            window_functions,
+            partition_by,
+            order_by,
+            descending,
+            ..
+        }) => {
+            let input_physical = plan(input, cfg)?;
+            let num_input_partitions = input_physical.clustering_spec().num_partitions();
+            // Bring all rows of each window partition into the same partition.
+            let input_physical = if num_input_partitions == 1 {
+                input_physical
+            } else if partition_by.is_empty() {
+                PhysicalPlan::Coalesce(Coalesce::new(
+                    input_physical.into(),
+                    num_input_partitions,
+                    1,
+                ))
+            } else {
+                let split_op = PhysicalPlan::FanoutByHash(FanoutByHash::new(
+                    input_physical.into(),
+                    num_input_partitions,
+                    partition_by.clone(),
+                ));
+                PhysicalPlan::ReduceMerge(ReduceMerge::new(split_op.into()))
+            };
+            Ok(PhysicalPlan::Window(Window::new(
+                input_physical.into(),
+                window_functions.clone(),
+                partition_by.clone(),
+                order_by.clone(),
+                descending.clone(),
+            )))
+        }
     }
 }
 
@@ -749,7 +603,7 @@ pub fn plan(logical_plan: &LogicalPlan, cfg: Arc<DaftExecutionConfig>) -> DaftRe
 mod tests {
     use common_daft_config::DaftExecutionConfig;
     use common_error::DaftResult;
-    use daft_core::{datatypes::Field, DataType};
+    use daft_core::{count_mode::CountMode, datatypes::Field, DataType};
     use daft_dsl::{col, lit, AggExpr, Expr};
     use std::assert_matches::assert_matches;
     use std::sync::Arc;
@@ -757,6 +611,8 @@ mod tests {
     use crate::physical_plan::PhysicalPlan;
     use crate::planner::plan;
     use crate::test::{dummy_scan_node, dummy_scan_operator};
+    use crate::NullKeyBehavior;
+    use daft_table::WindowFunction;
 
     /// Tests that planner drops a simple Repartition (e.g. df.into_partitions()) the child already has the desired number of partitions.
     ///
@@ -841,6 +697,8 @@ mod tests {
         .aggregate(
             vec![Expr::Agg(AggExpr::Sum(col("a").into()))],
             vec![col("b")],
+            Default::default(),
+            None,
         )?
         .hash_repartition(Some(10), vec![col("b")])?
         .build();
@@ -849,4 +707,182 @@ mod tests {
         assert_matches!(physical_plan, PhysicalPlan::Project(_));
         Ok(())
     }
+
+    /// Tests that an aggregation over several partitions is split into a partial aggregation on each partition,
+    /// which computes a sum and a count for a mean, and a final aggregation merging them.
+    ///
+    /// Aggregation -> Project-Aggregation-ReduceMerge-FanoutByHash-Aggregation
+    #[test]
+    fn multi_partition_agg_split_into_partial_final() -> DaftResult<()> {
+        let cfg = DaftExecutionConfig::default().into();
+        let logical_plan = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]))
+        .hash_repartition(Some(10), vec![col("a")])?
+        .aggregate(
+            vec![Expr::Agg(AggExpr::Mean(col("a").into()))],
+            vec![col("b")],
+            Default::default(),
+            None,
+        )?
+        .build();
+        let physical_plan = plan(logical_plan.as_ref(), cfg)?;
+        let PhysicalPlan::Project(project) = physical_plan else {
+            panic!("Expected a projection, got {physical_plan:?}");
+        };
+        let PhysicalPlan::Aggregate(final_agg) = project.input.as_ref() else {
+            panic!("Expected the final aggregation");
+        };
+        assert_eq!(final_agg.aggregations.len(), 2);
+        assert_matches!(final_agg.input.as_ref(), PhysicalPlan::ReduceMerge(_));
+        let PhysicalPlan::ReduceMerge(reduce) = final_agg.input.as_ref() else {
+            unreachable!()
+        };
+        let PhysicalPlan::FanoutByHash(fanout) = reduce.input.as_ref() else {
+            panic!("Expected a fanout by the groupby");
+        };
+        let PhysicalPlan::Aggregate(partial_agg) = fanout.input.as_ref() else {
+            panic!("Expected the partial aggregation");
+        };
+        let mut partial_aggs = partial_agg
+            .aggregations
+            .iter()
+            .map(|agg_expr| match agg_expr {
+                AggExpr::Sum(_) => "sum",
+                AggExpr::Count(..) => "count",
+                other => panic!("Unexpected partial aggregation {other}"),
+            })
+            .collect::<Vec<_>>();
+        partial_aggs.sort();
+        assert_eq!(partial_aggs, vec!["count", "sum"]);
+        Ok(())
+    }
+
+    /// Tests that rows with null keys are filtered out before a grouped aggregation that drops null keys.
+    ///
+    /// Aggregation -> Aggregation-Filter
+    #[test]
+    fn agg_drop_null_keys_filters_keys() -> DaftResult<()> {
+        let cfg = DaftExecutionConfig::default().into();
+        let builder = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]));
+        let aggs = vec![Expr::Agg(AggExpr::Sum(col("a").into()))];
+
+        let logical_plan = builder
+            .aggregate(aggs.clone(), vec![col("b")], NullKeyBehavior::Drop, None)?
+            .build();
+        let PhysicalPlan::Aggregate(agg) = plan(logical_plan.as_ref(), cfg)? else {
+            panic!("Expected a single partition aggregation");
+        };
+        let PhysicalPlan::Filter(filter) = agg.input.as_ref() else {
+            panic!("Expected the null keys to be filtered out");
+        };
+        assert_eq!(filter.predicate, col("b").not_null());
+
+        let logical_plan = builder
+            .aggregate(aggs, vec![col("b")], NullKeyBehavior::GroupTogether, None)?
+            .build();
+        let PhysicalPlan::Aggregate(agg) = plan(logical_plan.as_ref(), Default::default())? else {
+            panic!("Expected a single partition aggregation");
+        };
+        assert!(!matches!(agg.input.as_ref(), PhysicalPlan::Filter(_)));
+        Ok(())
+    }
+
+    /// Tests that a HAVING predicate is fused into a single partition aggregation,
+    /// and filters the final outputs of an aggregation over several partitions.
+    ///
+    /// Aggregation -> Filter-Project-Aggregation-ReduceMerge-FanoutByHash-Aggregation
+    #[test]
+    fn agg_having_filters_final_outputs() -> DaftResult<()> {
+        let builder = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]));
+        let aggs = vec![Expr::Agg(AggExpr::Count(
+            col("a").alias("count").into(),
+            CountMode::Valid,
+        ))];
+        let having = col("count").gt(&lit(10));
+
+        let logical_plan = builder
+            .aggregate(
+                aggs.clone(),
+                vec![col("b")],
+                Default::default(),
+                Some(having.clone()),
+            )?
+            .build();
+        let PhysicalPlan::Aggregate(agg) = plan(logical_plan.as_ref(), Default::default())? else {
+            panic!("Expected a single partition aggregation");
+        };
+        assert_eq!(agg.having, Some(having.clone()));
+
+        let logical_plan = builder
+            .hash_repartition(Some(10), vec![col("a")])?
+            .aggregate(
+                aggs,
+                vec![col("b")],
+                Default::default(),
+                Some(having.clone()),
+            )?
+            .build();
+        let PhysicalPlan::Filter(filter) = plan(logical_plan.as_ref(), Default::default())? else {
+            panic!("Expected the final outputs to be filtered");
+        };
+        assert_eq!(filter.predicate, having);
+        let PhysicalPlan::Project(project) = filter.input.as_ref() else {
+            panic!("Expected the final projection");
+        };
+        let PhysicalPlan::Aggregate(final_agg) = project.input.as_ref() else {
+            panic!("Expected the final aggregation");
+        };
+        assert_eq!(final_agg.having, None);
+        Ok(())
+    }
+
+    /// Tests that the rows of each window partition are brought into the same partition before windowing them.
+    ///
+    /// Window -> Window-ReduceMerge-FanoutByHash
+    #[test]
+    fn multi_partition_window_repartitioned_by_partition_keys() -> DaftResult<()> {
+        let builder = dummy_scan_node(dummy_scan_operator(vec![
+            Field::new("a", DataType::Int64),
+            Field::new("b", DataType::Int64),
+        ]))
+        .hash_repartition(Some(10), vec![col("a")])?;
+        let window_functions = vec![WindowFunction::RowNumber("row_number".to_string())];
+
+        let logical_plan = builder
+            .window(
+                window_functions.clone(),
+                vec![col("b")],
+                vec![col("a")],
+                vec![false],
+            )?
+            .build();
+        let PhysicalPlan::Window(window) = plan(logical_plan.as_ref(), Default::default())? else {
+            panic!("Expected a window");
+        };
+        let PhysicalPlan::ReduceMerge(reduce) = window.input.as_ref() else {
+            panic!("Expected the input to be repartitioned");
+        };
+        let PhysicalPlan::FanoutByHash(fanout) = reduce.input.as_ref() else {
+            panic!("Expected a fanout by the partition keys");
+        };
+        assert_eq!(fanout.partition_by, vec![col("b")]);
+
+        // Without partition keys, the whole input is a single window partition
+        let logical_plan = builder
+            .window(window_functions, vec![], vec![col("a")], vec![false])?
+            .build();
+        let PhysicalPlan::Window(window) = plan(logical_plan.as_ref(), Default::default())? else {
+            panic!("Expected a window");
+        };
+        assert_matches!(window.input.as_ref(), PhysicalPlan::Coalesce(_));
+        Ok(())
+    }
 }
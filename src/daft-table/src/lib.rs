@@ -22,7 +22,7 @@ use daft_dsl::{col, null_lit, AggExpr, Expr};
 pub mod ffi;
 mod ops;
 
-pub use ops::infer_join_schema;
+pub use ops::{infer_join_schema, split_partial_final, StreamingAggregate, WindowFunction};
 #[cfg(feature = "python")]
 pub mod python;
 #[cfg(feature = "python")]
@@ -319,16 +319,37 @@ impl Table {
         groups: Option<&GroupIndices>,
     ) -> DaftResult<Series> {
         use daft_dsl::AggExpr::*;
+        if groups.is_none() && self.is_empty() {
+            return self.eval_empty_agg_expression(agg_expr);
+        }
         match agg_expr {
             Count(expr, mode) => Series::count(&self.eval_expression(expr)?, groups, *mode),
+            CountDistinct(expr) => Series::count_distinct(&self.eval_expression(expr)?, groups),
             Sum(expr) => Series::sum(&self.eval_expression(expr)?, groups),
+            Product(expr) => Series::product(&self.eval_expression(expr)?, groups),
             Mean(expr) => Series::mean(&self.eval_expression(expr)?, groups),
+            Median(expr) => Series::median(&self.eval_expression(expr)?, groups),
+            Mode(expr) => Series::mode(&self.eval_expression(expr)?, groups),
+            Quantile(expr, q) => Series::quantile(&self.eval_expression(expr)?, groups, q.0),
+            Stddev(expr, ddof) => Series::stddev(&self.eval_expression(expr)?, groups, *ddof),
+            Variance(expr, ddof) => Series::variance(&self.eval_expression(expr)?, groups, *ddof),
             Min(expr) => Series::min(&self.eval_expression(expr)?, groups),
             Max(expr) => Series::max(&self.eval_expression(expr)?, groups),
             AnyValue(expr, ignore_nulls) => {
                 Series::any_value(&self.eval_expression(expr)?, groups, *ignore_nulls)
             }
+            First(expr) => Series::first(&self.eval_expression(expr)?, groups),
+            Last(expr) => Series::last(&self.eval_expression(expr)?, groups),
+            BitAnd(expr) => Series::bit_and(&self.eval_expression(expr)?, groups),
+            BitOr(expr) => Series::bit_or(&self.eval_expression(expr)?, groups),
+            BitXor(expr) => Series::bit_xor(&self.eval_expression(expr)?, groups),
+            Any(expr) => Series::any(&self.eval_expression(expr)?, groups),
+            All(expr) => Series::all(&self.eval_expression(expr)?, groups),
+            StringAgg(expr, separator) => {
+                Series::string_agg(&self.eval_expression(expr)?, groups, separator)
+            }
             List(expr) => Series::agg_list(&self.eval_expression(expr)?, groups),
+            Set(expr) => Series::agg_set(&self.eval_expression(expr)?, groups),
             Concat(expr) => Series::agg_concat(&self.eval_expression(expr)?, groups),
             MapGroups { .. } => Err(DaftError::ValueError(
                 "MapGroups not supported via aggregation, use map_groups instead".to_string(),
@@ -336,6 +357,25 @@ impl Table {
         }
     }
 
+    /// Evaluates a global aggregation of an empty table, which follows SQL in giving a single row:
+    /// zero for counts, an empty list for list and set aggregations, and null for every other aggregation.
+    fn eval_empty_agg_expression(&self, agg_expr: &AggExpr) -> DaftResult<Series> {
+        use daft_dsl::AggExpr::*;
+        let field = agg_expr.to_field(&self.schema)?;
+        match agg_expr {
+            Count(..) | CountDistinct(_) => {
+                Ok(UInt64Array::from((field.name.as_str(), vec![0])).into_series())
+            }
+            List(expr) => Series::agg_list(&self.eval_expression(expr)?, None),
+            Set(expr) => Series::agg_set(&self.eval_expression(expr)?, None),
+            Concat(expr) => Series::agg_concat(&self.eval_expression(expr)?, None),
+            MapGroups { .. } => Err(DaftError::ValueError(
+                "MapGroups not supported via aggregation, use map_groups instead".to_string(),
+            )),
+            _ => Ok(Series::full_null(&field.name, &field.dtype, 1)),
+        }
+    }
+
     fn eval_expression(&self, expr: &Expr) -> DaftResult<Series> {
         use crate::Expr::*;
         let expected_field = expr.to_field(self.schema.as_ref())?;
@@ -583,7 +623,9 @@ mod test {
 
     use crate::Table;
     use common_error::DaftResult;
-    use daft_core::datatypes::{DataType, Float64Array, Int64Array};
+    use daft_core::array::ops::as_arrow::AsArrow;
+    use daft_core::count_mode::CountMode;
+    use daft_core::datatypes::{BooleanArray, DataType, Float64Array, Int64Array, Utf8Array};
     use daft_core::schema::Schema;
     use daft_core::series::IntoSeries;
     use daft_dsl::col;
@@ -608,4 +650,684 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn grouped_median() -> DaftResult<()> {
+        let g = Int64Array::from(("g", vec![0, 0, 0, 0, 1, 1, 1, 2, 2])).into_series();
+        let ints = Int64Array::from((
+            "ints",
+            Box::new(arrow2::array::Int64Array::from(vec![
+                Some(4),
+                None,
+                Some(1),
+                Some(3),
+                Some(5),
+                Some(2),
+                Some(9),
+                None,
+                None,
+            ])),
+        ))
+        .into_series();
+        let floats = Float64Array::from((
+            "floats",
+            Box::new(arrow2::array::Float64Array::from(vec![
+                Some(0.5),
+                Some(2.5),
+                None,
+                Some(1.0),
+                Some(-1.0),
+                None,
+                Some(3.0),
+                None,
+                None,
+            ])),
+        ))
+        .into_series();
+        let table = Table::from_columns(vec![g, ints, floats])?;
+
+        let result = table
+            .agg(&[col("ints").median(), col("floats").median()], &[col("g")])?
+            .sort(&[col("g")], &[false])?;
+        // Even-sized groups average the two middle values and all-null groups are null
+        let medians = |name: &str| -> DaftResult<Vec<Option<f64>>> {
+            let series = result.get_column(name)?;
+            assert_eq!(*series.data_type(), DataType::Float64);
+            Ok(series
+                .f64()?
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect())
+        };
+        assert_eq!(medians("ints")?, vec![Some(3.0), Some(5.0), None]);
+        assert_eq!(medians("floats")?, vec![Some(1.0), Some(1.0), None]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn quantiles() -> DaftResult<()> {
+        let g = Int64Array::from(("g", vec![0, 0, 0, 0, 0, 1, 2])).into_series();
+        let values = Int64Array::from((
+            "values",
+            Box::new(arrow2::array::Int64Array::from(vec![
+                Some(3),
+                Some(1),
+                None,
+                Some(4),
+                Some(2),
+                Some(10),
+                None,
+            ])),
+        ))
+        .into_series();
+        let table = Table::from_columns(vec![g, values])?;
+        let quantiles = |t: &Table| -> DaftResult<Vec<Option<f64>>> {
+            let series = t.get_column("values")?;
+            assert_eq!(*series.data_type(), DataType::Float64);
+            Ok(series
+                .f64()?
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect())
+        };
+
+        for (q, expected) in [
+            (0.0, Some(1.0)),
+            (0.25, Some(1.75)),
+            (0.5, Some(2.5)),
+            (0.95, Some(3.85)),
+            (1.0, Some(4.0)),
+        ] {
+            let grouped = table
+                .agg(&[col("values").quantile(q)], &[col("g")])?
+                .sort(&[col("g")], &[false])?;
+            let result = quantiles(&grouped)?;
+            assert!((result[0].unwrap() - expected.unwrap()).abs() < 1e-9);
+            assert_eq!(result[1..], [Some(10.0), None]);
+        }
+
+        let ungrouped = table.agg(&[col("values").quantile(0.5)], &[])?;
+        assert_eq!(quantiles(&ungrouped)?, vec![Some(3.0)]);
+
+        for q in [-0.1, 1.5] {
+            assert!(matches!(
+                col("values").quantile(q).to_field(&table.schema),
+                Err(common_error::DaftError::ValueError(_))
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn grouped_stddev_and_variance() -> DaftResult<()> {
+        let g = Int64Array::from(("g", vec![0, 0, 0, 0, 0, 0, 0, 0, 1, 2])).into_series();
+        let values = Int64Array::from((
+            "values",
+            Box::new(arrow2::array::Int64Array::from(vec![
+                Some(2),
+                Some(4),
+                Some(4),
+                Some(4),
+                Some(5),
+                Some(5),
+                Some(7),
+                Some(9),
+                Some(3),
+                None,
+            ])),
+        ))
+        .into_series();
+        let table = Table::from_columns(vec![g, values])?;
+        let aggregate = |e: daft_dsl::Expr| -> DaftResult<Vec<Option<f64>>> {
+            let result = table.agg(&[e], &[col("g")])?.sort(&[col("g")], &[false])?;
+            let series = result.get_column("values")?;
+            assert_eq!(*series.data_type(), DataType::Float64);
+            Ok(series
+                .f64()?
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect())
+        };
+
+        // Population statistics divide by N and sample statistics by N - 1, which is null for a single value
+        assert_eq!(
+            aggregate(col("values").variance(0))?,
+            vec![Some(4.0), Some(0.0), None]
+        );
+        assert_eq!(
+            aggregate(col("values").stddev(0))?,
+            vec![Some(2.0), Some(0.0), None]
+        );
+        assert_eq!(
+            aggregate(col("values").variance(1))?,
+            vec![Some(32.0 / 7.0), None, None]
+        );
+        assert_eq!(
+            aggregate(col("values").stddev(1))?,
+            vec![Some((32.0f64 / 7.0).sqrt()), None, None]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn variance_of_large_values() -> DaftResult<()> {
+        // Squaring values this large loses the digits that the variance depends on
+        let values =
+            Float64Array::from(("values", vec![1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0]))
+                .into_series();
+        let table = Table::from_columns(vec![values])?;
+        let result = table.agg(&[col("values").variance(0)], &[])?;
+        let variance = result.get_column("values")?.f64()?.get(0).unwrap();
+        assert!((variance - 22.5).abs() < 1e-6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn grouped_count_distinct() -> DaftResult<()> {
+        let g = Int64Array::from(("g", vec![0, 0, 0, 0, 1, 1, 2, 2])).into_series();
+        let ints = Int64Array::from((
+            "ints",
+            Box::new(arrow2::array::Int64Array::from(vec![
+                Some(1),
+                Some(2),
+                Some(1),
+                None,
+                Some(5),
+                Some(5),
+                None,
+                None,
+            ])),
+        ))
+        .into_series();
+        let floats = Float64Array::from((
+            "floats",
+            Box::new(arrow2::array::Float64Array::from(vec![
+                Some(0.5),
+                Some(0.5),
+                Some(1.5),
+                Some(2.5),
+                None,
+                Some(3.0),
+                None,
+                None,
+            ])),
+        ))
+        .into_series();
+        let strings = Utf8Array::from((
+            "strings",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("a"),
+                Some("b"),
+                None,
+                Some("b"),
+                Some("c"),
+                Some("d"),
+                None,
+                None,
+            ])),
+        ))
+        .into_series();
+        let table = Table::from_columns(vec![g, ints, floats, strings])?;
+
+        let result = table
+            .agg(
+                &[
+                    col("ints").count_distinct(),
+                    col("floats").count_distinct(),
+                    col("strings").count_distinct(),
+                ],
+                &[col("g")],
+            )?
+            .sort(&[col("g")], &[false])?;
+        // Nulls aren't counted, so an all-null group has no distinct values
+        let counts = |name: &str| -> DaftResult<Vec<Option<u64>>> {
+            let series = result.get_column(name)?;
+            assert_eq!(*series.data_type(), DataType::UInt64);
+            Ok(series
+                .u64()?
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect())
+        };
+        assert_eq!(counts("ints")?, vec![Some(2), Some(1), Some(0)]);
+        assert_eq!(counts("floats")?, vec![Some(3), Some(1), Some(0)]);
+        assert_eq!(counts("strings")?, vec![Some(2), Some(2), Some(0)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn grouped_first_and_last() -> DaftResult<()> {
+        let g = Int64Array::from(("g", vec![1, 0, 1, 0, 0, 2, 1, 2])).into_series();
+        let values = Utf8Array::from((
+            "values",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                None,
+                Some("a"),
+                Some("x"),
+                Some("b"),
+                None,
+                None,
+                Some("y"),
+                None,
+            ])),
+        ))
+        .into_series();
+        let order = Int64Array::from(("order", vec![7, 6, 5, 4, 3, 2, 1, 0])).into_series();
+        let table = Table::from_columns(vec![g, values, order])?;
+        let aggregate = |t: &Table, e: daft_dsl::Expr| -> DaftResult<Vec<Option<String>>> {
+            let result = t.agg(&[e], &[col("g")])?.sort(&[col("g")], &[false])?;
+            let series = result.get_column("values")?;
+            assert_eq!(*series.data_type(), DataType::Utf8);
+            Ok(series
+                .utf8()?
+                .as_arrow()
+                .iter()
+                .map(|v| v.map(str::to_string))
+                .collect())
+        };
+        let strings = |values: &[Option<&str>]| -> Vec<Option<String>> {
+            values.iter().map(|v| v.map(str::to_string)).collect()
+        };
+
+        // Nulls are skipped, and all-null groups are null
+        assert_eq!(
+            aggregate(&table, col("values").first())?,
+            strings(&[Some("a"), Some("x"), None])
+        );
+        assert_eq!(
+            aggregate(&table, col("values").last())?,
+            strings(&[Some("b"), Some("y"), None])
+        );
+
+        // Sorting beforehand determines which values come first
+        let sorted = table.sort(&[col("order")], &[false])?;
+        assert_eq!(
+            aggregate(&sorted, col("values").first())?,
+            strings(&[Some("b"), Some("y"), None])
+        );
+        assert_eq!(
+            aggregate(&sorted, col("values").last())?,
+            strings(&[Some("a"), Some("x"), None])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn grouped_mode() -> DaftResult<()> {
+        let g = Int64Array::from(("g", vec![0, 0, 0, 0, 0, 1, 1, 1, 1, 2])).into_series();
+        let ints = Int64Array::from((
+            "ints",
+            Box::new(arrow2::array::Int64Array::from(vec![
+                Some(3),
+                Some(1),
+                Some(1),
+                Some(3),
+                Some(3),
+                Some(7),
+                Some(8),
+                Some(8),
+                Some(7),
+                None,
+            ])),
+        ))
+        .into_series();
+        let strings = Utf8Array::from((
+            "strings",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("b"),
+                Some("a"),
+                Some("a"),
+                Some("b"),
+                None,
+                Some("x"),
+                Some("y"),
+                None,
+                Some("y"),
+                None,
+            ])),
+        ))
+        .into_series();
+        let table = Table::from_columns(vec![g, ints, strings])?;
+
+        let result = table
+            .agg(&[col("ints").mode(), col("strings").mode()], &[col("g")])?
+            .sort(&[col("g")], &[false])?;
+        let ints = result.get_column("ints")?;
+        assert_eq!(*ints.data_type(), DataType::Int64);
+        // Ties go to the value that occurs first, and all-null groups are null
+        assert_eq!(
+            ints.i64()?
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(3), Some(7), None]
+        );
+        let strings = result.get_column("strings")?;
+        assert_eq!(*strings.data_type(), DataType::Utf8);
+        assert_eq!(
+            strings.utf8()?.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some("b"), Some("y"), None]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn grouped_product() -> DaftResult<()> {
+        let g = Int64Array::from(("g", vec![0, 0, 0, 0, 1, 1, 1, 2])).into_series();
+        let ints = daft_core::datatypes::Int32Array::from((
+            "ints",
+            Box::new(arrow2::array::Int32Array::from(vec![
+                Some(2),
+                Some(3),
+                None,
+                Some(4),
+                Some(5),
+                Some(0),
+                Some(7),
+                None,
+            ])),
+        ))
+        .into_series();
+        let floats = Float64Array::from((
+            "floats",
+            Box::new(arrow2::array::Float64Array::from(vec![
+                Some(0.5),
+                None,
+                Some(4.0),
+                Some(-1.5),
+                Some(2.5),
+                Some(0.0),
+                None,
+                None,
+            ])),
+        ))
+        .into_series();
+        let table = Table::from_columns(vec![g, ints, floats])?;
+
+        let result = table
+            .agg(
+                &[col("ints").product(), col("floats").product()],
+                &[col("g")],
+            )?
+            .sort(&[col("g")], &[false])?;
+        // Integers are widened like sums, and all-null groups are null rather than 1
+        let ints = result.get_column("ints")?;
+        assert_eq!(*ints.data_type(), DataType::Int64);
+        assert_eq!(
+            ints.i64()?
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(24), Some(0), None]
+        );
+        let floats = result.get_column("floats")?;
+        assert_eq!(*floats.data_type(), DataType::Float64);
+        assert_eq!(
+            floats
+                .f64()?
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect::<Vec<_>>(),
+            vec![Some(-3.0), Some(0.0), None]
+        );
+
+        let overflowing = Table::from_columns(vec![
+            Int64Array::from(("ints", vec![i64::MAX, 2])).into_series()
+        ])?;
+        assert!(matches!(
+            overflowing.agg(&[col("ints").product()], &[]),
+            Err(common_error::DaftError::ComputeError(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn grouped_bitwise() -> DaftResult<()> {
+        let g = Int64Array::from(("g", vec![0, 0, 0, 1, 1, 2])).into_series();
+        let bits = daft_core::datatypes::UInt32Array::from((
+            "bits",
+            Box::new(arrow2::array::UInt32Array::from(vec![
+                Some(0b1100),
+                Some(0b1010),
+                None,
+                Some(0xFFFF_0000),
+                Some(0x0F0F_0F0F),
+                None,
+            ])),
+        ))
+        .into_series();
+        let table = Table::from_columns(vec![g, bits])?;
+
+        let result = table
+            .agg(
+                &[
+                    col("bits").bit_and().alias("and"),
+                    col("bits").bit_or().alias("or"),
+                    col("bits").bit_xor().alias("xor"),
+                ],
+                &[col("g")],
+            )?
+            .sort(&[col("g")], &[false])?;
+        let values = |name: &str| -> DaftResult<Vec<Option<u32>>> {
+            let column = result.get_column(name)?;
+            assert_eq!(*column.data_type(), DataType::UInt32);
+            Ok(column
+                .u32()?
+                .as_arrow()
+                .iter()
+                .map(|v| v.copied())
+                .collect())
+        };
+        assert_eq!(values("and")?, vec![Some(0b1000), Some(0x0F0F_0000), None]);
+        assert_eq!(values("or")?, vec![Some(0b1110), Some(0xFFFF_0F0F), None]);
+        assert_eq!(values("xor")?, vec![Some(0b0110), Some(0xF0F0_0F0F), None]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn agg_empty_input() -> DaftResult<()> {
+        let table = Table::from_columns(vec![
+            Int64Array::from(("g", Vec::<i64>::new())).into_series(),
+            Int64Array::from(("v", Vec::<i64>::new())).into_series(),
+        ])?;
+        let aggs = [
+            col("v").count(CountMode::Valid).alias("count"),
+            col("v").sum().alias("sum"),
+            col("v").mean().alias("mean"),
+        ];
+
+        // Without a groupby there is a single row, where the count is 0 and the other aggregations are null
+        let result = table.agg(&aggs, &[])?;
+        assert_eq!(result.len(), 1);
+        let count = result.get_column("count")?;
+        assert_eq!(*count.data_type(), DataType::UInt64);
+        assert_eq!(count.u64()?.get(0), Some(0));
+        let sum = result.get_column("sum")?;
+        assert_eq!(*sum.data_type(), DataType::Int64);
+        assert_eq!(sum.i64()?.get(0), None);
+        let mean = result.get_column("mean")?;
+        assert_eq!(*mean.data_type(), DataType::Float64);
+        assert_eq!(mean.f64()?.get(0), None);
+
+        // With a groupby there are no groups, so no rows
+        let result = table.agg(&aggs, &[col("g")])?;
+        assert_eq!(result.len(), 0);
+        assert_eq!(result.column_names(), vec!["g", "count", "sum", "mean"]);
+        assert_eq!(*result.get_column("count")?.data_type(), DataType::UInt64);
+        assert_eq!(*result.get_column("mean")?.data_type(), DataType::Float64);
+
+        Ok(())
+    }
+
+    #[test]
+    fn grouped_any_all() -> DaftResult<()> {
+        let g = Int64Array::from(("g", vec![0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5])).into_series();
+        let b = BooleanArray::from((
+            "b",
+            [
+                Some(true),
+                Some(true),
+                Some(true),
+                Some(false),
+                Some(false),
+                Some(false),
+                Some(true),
+                None,
+                Some(false),
+                None,
+                None,
+            ]
+            .as_slice(),
+        ))
+        .into_series();
+        let table = Table::from_columns(vec![g, b])?;
+
+        let result = table
+            .agg(
+                &[col("b").any().alias("any"), col("b").all().alias("all")],
+                &[col("g")],
+            )?
+            .sort(&[col("g")], &[false])?;
+        let values = |name: &str| -> DaftResult<Vec<Option<bool>>> {
+            let column = result.get_column(name)?;
+            assert_eq!(*column.data_type(), DataType::Boolean);
+            Ok(column.bool()?.as_arrow().iter().collect())
+        };
+        // All true, mixed, all false, true and null, false and null, only null
+        assert_eq!(
+            values("any")?,
+            vec![Some(true), Some(true), Some(false), Some(true), None, None]
+        );
+        assert_eq!(
+            values("all")?,
+            vec![
+                Some(true),
+                Some(false),
+                Some(false),
+                None,
+                Some(false),
+                None
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn grouped_string_agg() -> DaftResult<()> {
+        let g = Int64Array::from(("g", vec![0, 0, 0, 1, 1, 1, 2, 2, 3])).into_series();
+        let s = Utf8Array::from((
+            "s",
+            Box::new(arrow2::array::Utf8Array::<i64>::from(vec![
+                Some("a"),
+                None,
+                Some("b"),
+                Some(""),
+                Some("c"),
+                Some(""),
+                None,
+                None,
+                Some("d"),
+            ])),
+        ))
+        .into_series();
+        let table = Table::from_columns(vec![g, s])?;
+
+        let result = table
+            .agg(&[col("s").string_agg(", ").alias("joined")], &[col("g")])?
+            .sort(&[col("g")], &[false])?;
+        let joined = result.get_column("joined")?;
+        assert_eq!(*joined.data_type(), DataType::Utf8);
+        // Nulls skipped, empty strings kept, only nulls, single value
+        assert_eq!(
+            joined.utf8()?.as_arrow().iter().collect::<Vec<_>>(),
+            vec![Some("a, b"), Some(", c, "), None, Some("d")]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn grouped_list_and_set() -> DaftResult<()> {
+        let g = Int64Array::from(("g", vec![0, 1, 0, 0, 1, 0, 2, 1])).into_series();
+        let v = Int64Array::from((
+            "v",
+            Box::new(arrow2::array::Int64Array::from(vec![
+                Some(3),
+                None,
+                Some(1),
+                Some(3),
+                None,
+                Some(2),
+                Some(5),
+                Some(4),
+            ])),
+        ))
+        .into_series();
+        let table = Table::from_columns(vec![g, v])?;
+
+        let result = table
+            .agg(
+                &[
+                    col("v").agg_list().alias("list"),
+                    col("v").agg_set().alias("set"),
+                ],
+                &[col("g")],
+            )?
+            .sort(&[col("g")], &[false])?;
+        let values = |name: &str| -> DaftResult<Vec<Vec<Option<i64>>>> {
+            let column = result.get_column(name)?;
+            assert_eq!(
+                *column.data_type(),
+                DataType::List(Box::new(DataType::Int64))
+            );
+            column
+                .list()?
+                .into_iter()
+                .map(|s| {
+                    Ok(s.unwrap()
+                        .i64()?
+                        .as_arrow()
+                        .iter()
+                        .map(|v| v.copied())
+                        .collect())
+                })
+                .collect()
+        };
+        // Lists keep every value in input order, while sets drop nulls and repeated values
+        assert_eq!(
+            values("list")?,
+            vec![
+                vec![Some(3), Some(1), Some(3), Some(2)],
+                vec![None, None, Some(4)],
+                vec![Some(5)],
+            ]
+        );
+        assert_eq!(
+            values("set")?,
+            vec![
+                vec![Some(3), Some(1), Some(2)],
+                vec![Some(4)],
+                vec![Some(5)],
+            ]
+        );
+
+        Ok(())
+    }
 }
@@ -6,5 +6,9 @@ mod joins;
 mod partition;
 mod search_sorted;
 mod sort;
+mod streaming_agg;
+mod window;
 
 pub use joins::infer_join_schema;
+pub use streaming_agg::{split_partial_final, StreamingAggregate};
+pub use window::WindowFunction;
@@ -0,0 +1,509 @@
+use std::{collections::HashMap, sync::Arc};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{count_mode::CountMode, schema::Schema};
+use daft_dsl::{col, common_treenode::TreeNode, functions::list, AggExpr, Expr, ExprRef};
+
+use crate::Table;
+
+/// An aggregation which consumes its input in batches. Only the merged partial state of each group
+/// (e.g. a running sum and count for a mean) is kept across batches, rather than the whole input.
+pub struct StreamingAggregate {
+    /// Aggregations computing the partial state of each group within a batch.
+    partial_aggs: Vec<Expr>,
+    /// Aggregations merging the partial states of each group, keeping the names of the partial states
+    /// so that the merged states can be merged again with the next batch.
+    merge_aggs: Vec<Expr>,
+    /// Aggregations and projection computing the outputs from the merged states.
+    final_aggs: Vec<Expr>,
+    final_exprs: Vec<Expr>,
+    /// Grouping to apply to the input batches.
+    groupby: Vec<Expr>,
+    /// Grouping to apply to the partial states, which hold the values of `groupby` under their names.
+    state_groupby: Vec<Expr>,
+    /// Predicate which each output group must satisfy.
+    having: Option<Expr>,
+    state: Option<Table>,
+}
+
+impl StreamingAggregate {
+    pub fn try_new(
+        aggregations: &[AggExpr],
+        groupby: &[Expr],
+        having: Option<Expr>,
+        schema: &Schema,
+    ) -> DaftResult<Self> {
+        if aggregations
+            .iter()
+            .any(|agg_expr| matches!(agg_expr, AggExpr::MapGroups { .. }))
+        {
+            return Err(DaftError::ValueError(
+                "Grouped UDFs can not be aggregated in batches".to_string(),
+            ));
+        }
+        let (partial_aggs, final_aggs, final_exprs) = split_partial_final(aggregations, schema);
+        let merge_aggs = final_aggs
+            .iter()
+            .map(|agg_expr| {
+                Expr::Agg(agg_expr.clone()).map_children(|child| match child {
+                    Expr::Alias(child, _) => Ok(child.as_ref().clone()),
+                    child => Ok(child),
+                })
+            })
+            .collect::<DaftResult<Vec<_>>>()?;
+        let state_groupby = groupby
+            .iter()
+            .map(|e| Ok(col(e.name()?)))
+            .collect::<DaftResult<Vec<_>>>()?;
+
+        Ok(Self {
+            partial_aggs: partial_aggs.into_iter().map(Expr::Agg).collect(),
+            merge_aggs,
+            final_aggs: final_aggs.into_iter().map(Expr::Agg).collect(),
+            final_exprs: state_groupby.iter().cloned().chain(final_exprs).collect(),
+            groupby: groupby.to_vec(),
+            state_groupby,
+            having,
+            state: None,
+        })
+    }
+
+    /// Merges the partial states of each group in `batch` into the running states.
+    pub fn update(&mut self, batch: &Table) -> DaftResult<()> {
+        let partial_states = batch.agg(&self.partial_aggs, &self.groupby)?;
+        let state = match self.state.take() {
+            Some(state) => Table::concat(&[&state, &partial_states])?
+                .agg(&self.merge_aggs, &self.state_groupby)?,
+            None => partial_states,
+        };
+        self.state = Some(state);
+        Ok(())
+    }
+
+    /// Computes the outputs of the aggregation from the states merged over all batches.
+    pub fn finalize(self) -> DaftResult<Table> {
+        let state = self.state.ok_or_else(|| {
+            DaftError::ValueError("Streaming aggregation finalized without any batches".to_string())
+        })?;
+        let output = state
+            .agg(&self.final_aggs, &self.state_groupby)?
+            .eval_expression_list(&self.final_exprs)?;
+        match &self.having {
+            Some(having) => output.filter(std::slice::from_ref(having)),
+            None => Ok(output),
+        }
+    }
+}
+
+/// Splits `aggregations` into the partial aggregations which compute the partial state of each group
+/// (e.g. a sum and a count for a mean), the final aggregations which merge the partial states of each group,
+/// and the expressions which compute the outputs of `aggregations` from the merged states.
+/// Each final aggregation selects a partial state by its semantic ID, and aliases the merged state to its own ID.
+pub fn split_partial_final(
+    aggregations: &[AggExpr],
+    schema: &Schema,
+) -> (Vec<AggExpr>, Vec<AggExpr>, Vec<Expr>) {
+    use daft_dsl::AggExpr::*;
+    use daft_dsl::Expr::Column;
+
+    // Aggregations to apply in the first and second stages.
+    // Semantic column name -> AggExpr
+    let mut first_stage_aggs: HashMap<Arc<str>, AggExpr> = HashMap::new();
+    let mut second_stage_aggs: HashMap<Arc<str>, AggExpr> = HashMap::new();
+    // Project the aggregation results to their final output names
+    let mut final_exprs: Vec<Expr> = vec![];
+
+    for agg_expr in aggregations {
+        let output_name = agg_expr.name().unwrap();
+        match agg_expr {
+            Count(e, mode) => {
+                let count_id = agg_expr.semantic_id(schema).id;
+                let sum_of_count_id = Sum(Column(count_id.clone()).into()).semantic_id(schema).id;
+                first_stage_aggs
+                    .entry(count_id.clone())
+                    .or_insert(Count(e.alias(count_id.clone()).clone().into(), *mode));
+                second_stage_aggs
+                    .entry(sum_of_count_id.clone())
+                    .or_insert(Sum(Column(count_id.clone())
+                        .alias(sum_of_count_id.clone())
+                        .into()));
+                final_exprs.push(Column(sum_of_count_id.clone()).alias(output_name));
+            }
+            Sum(e) => {
+                let sum_id = agg_expr.semantic_id(schema).id;
+                let sum_of_sum_id = Sum(Column(sum_id.clone()).into()).semantic_id(schema).id;
+                first_stage_aggs
+                    .entry(sum_id.clone())
+                    .or_insert(Sum(e.alias(sum_id.clone()).clone().into()));
+                second_stage_aggs
+                    .entry(sum_of_sum_id.clone())
+                    .or_insert(Sum(Column(sum_id.clone())
+                        .alias(sum_of_sum_id.clone())
+                        .into()));
+                final_exprs.push(Column(sum_of_sum_id.clone()).alias(output_name));
+            }
+            Product(e) => {
+                let product_id = agg_expr.semantic_id(schema).id;
+                let product_of_product_id = Product(Column(product_id.clone()).into())
+                    .semantic_id(schema)
+                    .id;
+                first_stage_aggs
+                    .entry(product_id.clone())
+                    .or_insert(Product(e.alias(product_id.clone()).clone().into()));
+                second_stage_aggs
+                    .entry(product_of_product_id.clone())
+                    .or_insert(Product(
+                        Column(product_id.clone())
+                            .alias(product_of_product_id.clone())
+                            .into(),
+                    ));
+                final_exprs.push(Column(product_of_product_id.clone()).alias(output_name));
+            }
+            Mean(e) => {
+                let sum_id = Sum(e.clone()).semantic_id(schema).id;
+                let count_id = Count(e.clone(), CountMode::Valid).semantic_id(schema).id;
+                let sum_of_sum_id = Sum(Column(sum_id.clone()).into()).semantic_id(schema).id;
+                let sum_of_count_id = Sum(Column(count_id.clone()).into()).semantic_id(schema).id;
+                first_stage_aggs
+                    .entry(sum_id.clone())
+                    .or_insert(Sum(e.alias(sum_id.clone()).clone().into()));
+                first_stage_aggs.entry(count_id.clone()).or_insert(Count(
+                    e.alias(count_id.clone()).clone().into(),
+                    CountMode::Valid,
+                ));
+                second_stage_aggs
+                    .entry(sum_of_sum_id.clone())
+                    .or_insert(Sum(Column(sum_id.clone())
+                        .alias(sum_of_sum_id.clone())
+                        .into()));
+                second_stage_aggs
+                    .entry(sum_of_count_id.clone())
+                    .or_insert(Sum(Column(count_id.clone())
+                        .alias(sum_of_count_id.clone())
+                        .into()));
+                final_exprs.push(
+                    (Column(sum_of_sum_id.clone()) / Column(sum_of_count_id.clone()))
+                        .alias(output_name),
+                );
+            }
+            CountDistinct(e)
+            | Median(e)
+            | Mode(e)
+            | Quantile(e, _)
+            | Stddev(e, _)
+            | Variance(e, _) => {
+                // These can't be merged from the results of each partition, so the values of each
+                // group are gathered into a single list and the aggregation is taken over that list.
+                let list_id = List(e.clone()).semantic_id(schema).id;
+                let concat_of_list_id = Concat(Column(list_id.clone()).into())
+                    .semantic_id(schema)
+                    .id;
+                first_stage_aggs
+                    .entry(list_id.clone())
+                    .or_insert(List(e.alias(list_id.clone()).clone().into()));
+                second_stage_aggs
+                    .entry(concat_of_list_id.clone())
+                    .or_insert(Concat(
+                        Column(list_id.clone())
+                            .alias(concat_of_list_id.clone())
+                            .into(),
+                    ));
+                let values = Column(concat_of_list_id.clone());
+                let final_expr = match agg_expr {
+                    CountDistinct(_) => list::count_distinct(&values),
+                    Median(_) => list::median(&values),
+                    Mode(_) => list::mode(&values),
+                    Quantile(_, q) => list::quantile(&values, q.0),
+                    Stddev(_, ddof) => list::stddev(&values, *ddof),
+                    Variance(_, ddof) => list::variance(&values, *ddof),
+                    _ => unreachable!(),
+                };
+                final_exprs.push(final_expr.alias(output_name));
+            }
+            Min(e) => {
+                let min_id = agg_expr.semantic_id(schema).id;
+                let min_of_min_id = Min(Column(min_id.clone()).into()).semantic_id(schema).id;
+                first_stage_aggs
+                    .entry(min_id.clone())
+                    .or_insert(Min(e.alias(min_id.clone()).clone().into()));
+                second_stage_aggs
+                    .entry(min_of_min_id.clone())
+                    .or_insert(Min(Column(min_id.clone())
+                        .alias(min_of_min_id.clone())
+                        .into()));
+                final_exprs.push(Column(min_of_min_id.clone()).alias(output_name));
+            }
+            Max(e) => {
+                let max_id = agg_expr.semantic_id(schema).id;
+                let max_of_max_id = Max(Column(max_id.clone()).into()).semantic_id(schema).id;
+                first_stage_aggs
+                    .entry(max_id.clone())
+                    .or_insert(Max(e.alias(max_id.clone()).clone().into()));
+                second_stage_aggs
+                    .entry(max_of_max_id.clone())
+                    .or_insert(Max(Column(max_id.clone())
+                        .alias(max_of_max_id.clone())
+                        .into()));
+                final_exprs.push(Column(max_of_max_id.clone()).alias(output_name));
+            }
+            AnyValue(e, ignore_nulls) => {
+                let any_id = agg_expr.semantic_id(schema).id;
+                let any_of_any_id = AnyValue(Column(any_id.clone()).into(), *ignore_nulls)
+                    .semantic_id(schema)
+                    .id;
+                first_stage_aggs.entry(any_id.clone()).or_insert(AnyValue(
+                    e.alias(any_id.clone()).clone().into(),
+                    *ignore_nulls,
+                ));
+                second_stage_aggs
+                    .entry(any_of_any_id.clone())
+                    .or_insert(AnyValue(
+                        Column(any_id.clone()).alias(any_of_any_id.clone()).into(),
+                        *ignore_nulls,
+                    ));
+                final_exprs.push(Column(any_of_any_id.clone()).alias(output_name));
+            }
+            First(e) => {
+                // Partitions are merged in order, so the first value of the first partition
+                // that has one is the first value overall.
+                let first_id = agg_expr.semantic_id(schema).id;
+                let first_of_first_id = First(Column(first_id.clone()).into())
+                    .semantic_id(schema)
+                    .id;
+                first_stage_aggs
+                    .entry(first_id.clone())
+                    .or_insert(First(e.alias(first_id.clone()).clone().into()));
+                second_stage_aggs
+                    .entry(first_of_first_id.clone())
+                    .or_insert(First(
+                        Column(first_id.clone())
+                            .alias(first_of_first_id.clone())
+                            .into(),
+                    ));
+                final_exprs.push(Column(first_of_first_id.clone()).alias(output_name));
+            }
+            Last(e) => {
+                let last_id = agg_expr.semantic_id(schema).id;
+                let last_of_last_id = Last(Column(last_id.clone()).into()).semantic_id(schema).id;
+                first_stage_aggs
+                    .entry(last_id.clone())
+                    .or_insert(Last(e.alias(last_id.clone()).clone().into()));
+                second_stage_aggs
+                    .entry(last_of_last_id.clone())
+                    .or_insert(Last(
+                        Column(last_id.clone())
+                            .alias(last_of_last_id.clone())
+                            .into(),
+                    ));
+                final_exprs.push(Column(last_of_last_id.clone()).alias(output_name));
+            }
+            BitAnd(e) | BitOr(e) | BitXor(e) => {
+                // Bitwise ops are associative, so each partition's result can be reduced again
+                let op: fn(ExprRef) -> AggExpr = match agg_expr {
+                    BitAnd(_) => BitAnd,
+                    BitOr(_) => BitOr,
+                    _ => BitXor,
+                };
+                let bit_id = agg_expr.semantic_id(schema).id;
+                let bit_of_bit_id = op(Column(bit_id.clone()).into()).semantic_id(schema).id;
+                first_stage_aggs
+                    .entry(bit_id.clone())
+                    .or_insert(op(e.alias(bit_id.clone()).into()));
+                second_stage_aggs
+                    .entry(bit_of_bit_id.clone())
+                    .or_insert(op(Column(bit_id.clone())
+                        .alias(bit_of_bit_id.clone())
+                        .into()));
+                final_exprs.push(Column(bit_of_bit_id.clone()).alias(output_name));
+            }
+            Any(e) | All(e) => {
+                // Any and all follow three-valued logic, so reducing each partition's result again
+                // gives the same result as reducing all of the values at once
+                let op: fn(ExprRef) -> AggExpr = match agg_expr {
+                    Any(_) => Any,
+                    _ => All,
+                };
+                let bool_id = agg_expr.semantic_id(schema).id;
+                let bool_of_bool_id = op(Column(bool_id.clone()).into()).semantic_id(schema).id;
+                first_stage_aggs
+                    .entry(bool_id.clone())
+                    .or_insert(op(e.alias(bool_id.clone()).into()));
+                second_stage_aggs
+                    .entry(bool_of_bool_id.clone())
+                    .or_insert(op(Column(bool_id.clone())
+                        .alias(bool_of_bool_id.clone())
+                        .into()));
+                final_exprs.push(Column(bool_of_bool_id.clone()).alias(output_name));
+            }
+            StringAgg(e, separator) => {
+                // Partitions with only nulls yield null and are skipped when joining the partial results,
+                // so the separator still only appears between values
+                let string_agg_id = agg_expr.semantic_id(schema).id;
+                let string_agg_of_string_agg_id =
+                    StringAgg(Column(string_agg_id.clone()).into(), separator.clone())
+                        .semantic_id(schema)
+                        .id;
+                first_stage_aggs
+                    .entry(string_agg_id.clone())
+                    .or_insert(StringAgg(
+                        e.alias(string_agg_id.clone()).into(),
+                        separator.clone(),
+                    ));
+                second_stage_aggs
+                    .entry(string_agg_of_string_agg_id.clone())
+                    .or_insert(StringAgg(
+                        Column(string_agg_id.clone())
+                            .alias(string_agg_of_string_agg_id.clone())
+                            .into(),
+                        separator.clone(),
+                    ));
+                final_exprs.push(Column(string_agg_of_string_agg_id.clone()).alias(output_name));
+            }
+            List(e) => {
+                let list_id = agg_expr.semantic_id(schema).id;
+                let concat_of_list_id = Concat(Column(list_id.clone()).into())
+                    .semantic_id(schema)
+                    .id;
+                first_stage_aggs
+                    .entry(list_id.clone())
+                    .or_insert(List(e.alias(list_id.clone()).clone().into()));
+                second_stage_aggs
+                    .entry(concat_of_list_id.clone())
+                    .or_insert(Concat(
+                        Column(list_id.clone())
+                            .alias(concat_of_list_id.clone())
+                            .into(),
+                    ));
+                final_exprs.push(Column(concat_of_list_id.clone()).alias(output_name));
+            }
+            Set(e) => {
+                // Values can repeat across partitions, so the deduplicated values of each partition
+                // are concatenated and deduplicated again
+                let set_id = agg_expr.semantic_id(schema).id;
+                let concat_of_set_id = Concat(Column(set_id.clone()).into()).semantic_id(schema).id;
+                first_stage_aggs
+                    .entry(set_id.clone())
+                    .or_insert(Set(e.alias(set_id.clone()).into()));
+                second_stage_aggs
+                    .entry(concat_of_set_id.clone())
+                    .or_insert(Concat(
+                        Column(set_id.clone())
+                            .alias(concat_of_set_id.clone())
+                            .into(),
+                    ));
+                final_exprs
+                    .push(list::distinct(&Column(concat_of_set_id.clone())).alias(output_name));
+            }
+            Concat(e) => {
+                let concat_id = agg_expr.semantic_id(schema).id;
+                let concat_of_concat_id = Concat(Column(concat_id.clone()).into())
+                    .semantic_id(schema)
+                    .id;
+                first_stage_aggs
+                    .entry(concat_id.clone())
+                    .or_insert(Concat(e.alias(concat_id.clone()).clone().into()));
+                second_stage_aggs
+                    .entry(concat_of_concat_id.clone())
+                    .or_insert(Concat(
+                        Column(concat_id.clone())
+                            .alias(concat_of_concat_id.clone())
+                            .into(),
+                    ));
+                final_exprs.push(Column(concat_of_concat_id.clone()).alias(output_name));
+            }
+            MapGroups { func, inputs } => {
+                let func_id = agg_expr.semantic_id(schema).id;
+                // No first stage aggregation for MapGroups, do all the work in the second stage.
+                second_stage_aggs
+                    .entry(func_id.clone())
+                    .or_insert(MapGroups {
+                        func: func.clone(),
+                        inputs: inputs.to_vec(),
+                    });
+                final_exprs.push(Column(output_name.into()));
+            }
+        }
+    }
+
+    (
+        first_stage_aggs.into_values().collect(),
+        second_stage_aggs.into_values().collect(),
+        final_exprs,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::{
+        count_mode::CountMode,
+        datatypes::{Float64Array, Int64Array},
+        series::IntoSeries,
+    };
+    use daft_dsl::{col, AggExpr};
+
+    use super::StreamingAggregate;
+    use crate::Table;
+
+    /// The values of `table` as strings, column by column
+    fn values(table: &Table) -> DaftResult<Vec<Vec<String>>> {
+        (0..table.num_columns())
+            .map(|i| {
+                let column = table.get_column_by_index(i)?;
+                (0..column.len()).map(|j| column.str_value(j)).collect()
+            })
+            .collect()
+    }
+
+    fn aggregate_in_batches(table: &Table, batch_size: usize) -> DaftResult<Table> {
+        let mut aggregate = StreamingAggregate::try_new(
+            &[
+                AggExpr::Mean(col("v").alias("mean").into()),
+                AggExpr::Count(col("v").alias("count").into(), CountMode::Valid),
+                AggExpr::Min(col("v").alias("min").into()),
+                AggExpr::First(col("v").alias("first").into()),
+                AggExpr::Median(col("v").alias("median").into()),
+            ],
+            &[col("g")],
+            None,
+            &table.schema,
+        )?;
+        for start in (0..table.len()).step_by(batch_size) {
+            aggregate.update(&table.slice(start, (start + batch_size).min(table.len()))?)?;
+        }
+        aggregate.finalize()?.sort(&[col("g")], &[false])
+    }
+
+    /// Test that aggregating the input in many small batches gives the same groups as aggregating it at once.
+    #[test]
+    fn test_streaming_agg_batches() -> DaftResult<()> {
+        let g = Int64Array::from(("g", vec![0, 1, 0, 2, 1, 0, 1, 2, 0, 1])).into_series();
+        let v = Float64Array::from((
+            "v",
+            Box::new(arrow2::array::Float64Array::from(vec![
+                Some(1.0),
+                Some(5.0),
+                None,
+                Some(-2.0),
+                Some(3.5),
+                Some(4.0),
+                None,
+                Some(8.0),
+                Some(2.0),
+                Some(0.5),
+            ])),
+        ))
+        .into_series();
+        let table = Table::from_columns(vec![g, v])?;
+
+        let one_batch = values(&aggregate_in_batches(&table, table.len())?)?;
+        assert_eq!(one_batch[0], vec!["0", "1", "2"]);
+        for batch_size in [1, 2, 3] {
+            assert_eq!(
+                values(&aggregate_in_batches(&table, batch_size)?)?,
+                one_batch
+            );
+        }
+        Ok(())
+    }
+}
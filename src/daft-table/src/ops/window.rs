@@ -0,0 +1,266 @@
+use std::fmt::{Display, Formatter, Result};
+
+use common_error::{DaftError, DaftResult};
+use daft_core::{
+    array::ops::{as_arrow::AsArrow, IntoGroups},
+    datatypes::{DataType, Field, UInt64Array},
+    schema::Schema,
+    series::IntoSeries,
+    Series,
+};
+use daft_dsl::{common_treenode::TreeNode, AggExpr, Expr};
+use serde::{Deserialize, Serialize};
+
+use crate::Table;
+
+/// A function computed over the window of each row, producing one value per row.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WindowFunction {
+    /// Aggregation over the rows of the partition from its first row up to and including the current row,
+    /// e.g. a running sum. The output is named after the aggregation.
+    Agg(AggExpr),
+    /// 1-based position of the row within its partition.
+    RowNumber(String),
+    /// 1-based rank of the row within its partition, where rows with equal order-by values get the same rank
+    /// and leave a gap after them (1, 1, 3).
+    Rank(String),
+    /// Like `Rank`, but without gaps after rows with equal order-by values (1, 1, 2).
+    DenseRank(String),
+}
+
+impl WindowFunction {
+    /// Running aggregation computing `expr`, which must be an aggregation, possibly under an alias.
+    pub fn agg(expr: &Expr) -> DaftResult<Self> {
+        fn extract_agg_expr(expr: &Expr) -> DaftResult<AggExpr> {
+            match expr {
+                Expr::Agg(AggExpr::MapGroups { .. }) => Err(DaftError::ValueError(
+                    "Grouped UDFs can not be used as window functions".to_string(),
+                )),
+                Expr::Agg(agg_expr) => Ok(agg_expr.clone()),
+                // Move the alias onto the input of the aggregation, which names its output
+                Expr::Alias(e, name) => match Expr::Agg(extract_agg_expr(e)?)
+                    .map_children(|child| Ok(child.alias(name.clone())))?
+                {
+                    Expr::Agg(agg_expr) => Ok(agg_expr),
+                    _ => {
+                        unreachable!("Mapping the children of an aggregation keeps the aggregation")
+                    }
+                },
+                _ => Err(DaftError::ValueError(format!(
+                    "Window functions must be aggregations, got: {expr}"
+                ))),
+            }
+        }
+        extract_agg_expr(expr).map(Self::Agg)
+    }
+
+    pub fn to_field(&self, schema: &Schema) -> DaftResult<Field> {
+        match self {
+            Self::Agg(agg_expr) => agg_expr.to_field(schema),
+            Self::RowNumber(name) | Self::Rank(name) | Self::DenseRank(name) => {
+                Ok(Field::new(name.as_str(), DataType::UInt64))
+            }
+        }
+    }
+}
+
+impl Display for WindowFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Agg(agg_expr) => write!(f, "running_{agg_expr}"),
+            Self::RowNumber(name) => write!(f, "row_number() as {name}"),
+            Self::Rank(name) => write!(f, "rank() as {name}"),
+            Self::DenseRank(name) => write!(f, "dense_rank() as {name}"),
+        }
+    }
+}
+
+impl Table {
+    /// Computes `window_functions` over the rows of each partition of this table, which is split by the values of
+    /// `partition_by`, and appends one column per function. Unlike an aggregation, every row is kept: the output
+    /// holds the rows of each partition in turn, sorted by `order_by` (or in input order if it is empty).
+    /// This table must hold all rows of the partitions it contains. Running aggregations aggregate the window of
+    /// each row on its own, so they take time quadratic in the size of each partition.
+    pub fn window(
+        &self,
+        window_functions: &[WindowFunction],
+        partition_by: &[Expr],
+        order_by: &[Expr],
+        descending: &[bool],
+    ) -> DaftResult<Table> {
+        let num_rows = self.len();
+
+        // Row indices of each partition.
+        let mut partitions = if num_rows == 0 {
+            vec![]
+        } else if partition_by.is_empty() {
+            vec![(0..num_rows as u64).collect::<Vec<_>>()]
+        } else {
+            self.eval_expression_list(partition_by)?.make_groups()?.1
+        };
+
+        // Sort the rows of each partition, and find the rows which tie on the order-by values.
+        let mut peer_groups = vec![0; num_rows];
+        if order_by.is_empty() {
+            partitions.iter_mut().for_each(|rows| rows.sort_unstable());
+        } else if num_rows > 0 {
+            let argsort = self.argsort(order_by, descending)?;
+            let mut sort_positions = vec![0; num_rows];
+            for (position, row) in argsort.u64()?.as_arrow().values_iter().enumerate() {
+                sort_positions[*row as usize] = position;
+            }
+            partitions
+                .iter_mut()
+                .for_each(|rows| rows.sort_by_key(|row| sort_positions[*row as usize]));
+
+            let (_, peers) = self.eval_expression_list(order_by)?.make_groups()?;
+            for (peer_group, rows) in peers.iter().enumerate() {
+                for row in rows {
+                    peer_groups[*row as usize] = peer_group;
+                }
+            }
+        }
+
+        let windowed = self.take(&UInt64Array::from(("", partitions.concat())).into_series())?;
+        let mut columns = (0..windowed.num_columns())
+            .map(|i| windowed.get_column_by_index(i).cloned())
+            .collect::<DaftResult<Vec<_>>>()?;
+        for window_function in window_functions.iter() {
+            let column = match window_function {
+                WindowFunction::Agg(agg_expr) => {
+                    let agg_expr = [Expr::Agg(agg_expr.clone())];
+                    let mut windows = vec![];
+                    for rows in partitions.iter() {
+                        let partition =
+                            self.take(&UInt64Array::from(("", rows.clone())).into_series())?;
+                        for end in 1..=partition.len() {
+                            windows.push(partition.head(end)?.agg_global(&agg_expr)?);
+                        }
+                    }
+                    if windows.is_empty() {
+                        let field = window_function.to_field(&self.schema)?;
+                        Series::empty(&field.name, &field.dtype)
+                    } else {
+                        Table::concat(&windows)?.get_column_by_index(0)?.clone()
+                    }
+                }
+                WindowFunction::RowNumber(name)
+                | WindowFunction::Rank(name)
+                | WindowFunction::DenseRank(name) => {
+                    let mut values = Vec::with_capacity(num_rows);
+                    for rows in partitions.iter() {
+                        let (mut rank, mut dense_rank) = (0, 0);
+                        for (i, row) in rows.iter().enumerate() {
+                            if i == 0
+                                || peer_groups[rows[i - 1] as usize] != peer_groups[*row as usize]
+                            {
+                                rank = i as u64 + 1;
+                                dense_rank += 1;
+                            }
+                            values.push(match window_function {
+                                WindowFunction::RowNumber(_) => i as u64 + 1,
+                                WindowFunction::Rank(_) => rank,
+                                _ => dense_rank,
+                            });
+                        }
+                    }
+                    UInt64Array::from((name.as_str(), values)).into_series()
+                }
+            };
+            columns.push(column);
+        }
+        Table::from_columns(columns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_error::DaftResult;
+    use daft_core::{datatypes::Int64Array, series::IntoSeries};
+    use daft_dsl::{col, AggExpr, Expr};
+
+    use super::WindowFunction;
+    use crate::Table;
+
+    fn column_values(table: &Table, name: &str) -> DaftResult<Vec<String>> {
+        let column = table.get_column(name)?;
+        (0..column.len()).map(|i| column.str_value(i)).collect()
+    }
+
+    /// Test a running sum and row numbers, which restart with each partition.
+    #[test]
+    fn test_window_running_sum_and_row_number() -> DaftResult<()> {
+        let table = Table::from_columns(vec![
+            Int64Array::from(("g", vec![1, 0, 1, 0, 1, 0])).into_series(),
+            Int64Array::from(("o", vec![3, 2, 1, 4, 2, 1])).into_series(),
+            Int64Array::from(("v", vec![10, 20, 30, 40, 50, 60])).into_series(),
+        ])?;
+        let result = table
+            .window(
+                &[
+                    WindowFunction::Agg(AggExpr::Sum(col("v").alias("running_sum").into())),
+                    WindowFunction::RowNumber("row_number".to_string()),
+                ],
+                &[col("g")],
+                &[col("o")],
+                &[false],
+            )?
+            .sort(&[col("g"), col("o")], &[false, false])?;
+
+        assert_eq!(result.len(), table.len());
+        assert_eq!(
+            column_values(&result, "v")?,
+            ["60", "20", "40", "30", "50", "10"]
+        );
+        assert_eq!(
+            column_values(&result, "running_sum")?,
+            ["60", "80", "120", "30", "80", "90"]
+        );
+        assert_eq!(
+            column_values(&result, "row_number")?,
+            ["1", "2", "3", "1", "2", "3"]
+        );
+        Ok(())
+    }
+
+    /// Test that rows tied on the order-by values share a rank.
+    #[test]
+    fn test_window_rank() -> DaftResult<()> {
+        let table = Table::from_columns(vec![
+            Int64Array::from(("o", vec![5, 3, 5, 1, 3, 3])).into_series()
+        ])?;
+        let result = table.window(
+            &[
+                WindowFunction::Rank("rank".to_string()),
+                WindowFunction::DenseRank("dense_rank".to_string()),
+            ],
+            &[],
+            &[col("o")],
+            &[true],
+        )?;
+
+        assert_eq!(column_values(&result, "o")?, ["5", "5", "3", "3", "3", "1"]);
+        assert_eq!(
+            column_values(&result, "rank")?,
+            ["1", "1", "3", "3", "3", "6"]
+        );
+        assert_eq!(
+            column_values(&result, "dense_rank")?,
+            ["1", "1", "2", "2", "2", "3"]
+        );
+        Ok(())
+    }
+
+    /// Test that an aliased aggregation names the output of its running aggregation.
+    #[test]
+    fn test_window_function_from_aliased_agg() -> DaftResult<()> {
+        let window_function =
+            WindowFunction::agg(&Expr::Agg(AggExpr::Max(col("v").into())).alias("running_max"))?;
+        assert_eq!(
+            window_function,
+            WindowFunction::Agg(AggExpr::Max(col("v").alias("running_max").into()))
+        );
+        assert!(WindowFunction::agg(&col("v")).is_err());
+        Ok(())
+    }
+}
@@ -1,11 +1,13 @@
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyBytes, PyDict};
+use pyo3::PyTypeInfo;
 
 use crate::ffi;
-use crate::Table;
+use crate::{Table, WindowFunction};
 use common_error::DaftError;
 use daft_core::datatypes::Field;
+use daft_core::impl_bincode_py_state_serialization;
 use daft_core::schema::Schema;
 use daft_core::series::Series;
 
@@ -13,6 +15,7 @@ use daft_dsl::python::PyExpr;
 
 use daft_core::python::schema::PySchema;
 use daft_core::python::series::PySeries;
+use serde::{Deserialize, Serialize};
 
 #[pyclass]
 #[derive(Clone)]
@@ -313,6 +316,32 @@ impl PyTable {
         })
     }
 
+    pub fn window(
+        &self,
+        py: Python,
+        window_functions: Vec<PyWindowFunction>,
+        partition_by: Vec<PyExpr>,
+        order_by: Vec<PyExpr>,
+        descending: Vec<bool>,
+    ) -> PyResult<Self> {
+        let window_functions: Vec<WindowFunction> =
+            window_functions.into_iter().map(|f| f.into()).collect();
+        let partition_by: Vec<daft_dsl::Expr> =
+            partition_by.into_iter().map(|e| e.into()).collect();
+        let order_by: Vec<daft_dsl::Expr> = order_by.into_iter().map(|e| e.into()).collect();
+        py.allow_threads(|| {
+            Ok(self
+                .table
+                .window(
+                    window_functions.as_slice(),
+                    partition_by.as_slice(),
+                    order_by.as_slice(),
+                    descending.as_slice(),
+                )?
+                .into())
+        })
+    }
+
     pub fn __len__(&self) -> PyResult<usize> {
         Ok(self.table.len())
     }
@@ -449,7 +478,63 @@ impl AsRef<Table> for PyTable {
     }
 }
 
+/// A function computed over the window of each row, see [`WindowFunction`].
+#[pyclass(module = "daft.daft", name = "WindowFunction")]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PyWindowFunction {
+    pub window_function: WindowFunction,
+}
+
+#[pymethods]
+impl PyWindowFunction {
+    /// Running aggregation over the rows of the partition up to and including the current row.
+    ///
+    /// Args:
+    ///     expr: Aggregation to compute, whose name becomes the name of the output column.
+    #[staticmethod]
+    pub fn agg(expr: PyExpr) -> PyResult<Self> {
+        Ok(WindowFunction::agg(&expr.into())?.into())
+    }
+
+    /// 1-based position of each row within its partition.
+    #[staticmethod]
+    pub fn row_number(name: &str) -> Self {
+        WindowFunction::RowNumber(name.to_string()).into()
+    }
+
+    /// 1-based rank of each row within its partition, with gaps after rows tied on the order-by values.
+    #[staticmethod]
+    pub fn rank(name: &str) -> Self {
+        WindowFunction::Rank(name.to_string()).into()
+    }
+
+    /// 1-based rank of each row within its partition, without gaps after rows tied on the order-by values.
+    #[staticmethod]
+    pub fn dense_rank(name: &str) -> Self {
+        WindowFunction::DenseRank(name.to_string()).into()
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.window_function.to_string()
+    }
+}
+
+impl_bincode_py_state_serialization!(PyWindowFunction);
+
+impl From<WindowFunction> for PyWindowFunction {
+    fn from(window_function: WindowFunction) -> Self {
+        PyWindowFunction { window_function }
+    }
+}
+
+impl From<PyWindowFunction> for WindowFunction {
+    fn from(item: PyWindowFunction) -> Self {
+        item.window_function
+    }
+}
+
 pub fn register_modules(_py: Python, parent: &PyModule) -> PyResult<()> {
     parent.add_class::<PyTable>()?;
+    parent.add_class::<PyWindowFunction>()?;
     Ok(())
 }
@@ -0,0 +1,19 @@
+from __future__ import annotations
+
+import pytest
+
+import daft
+from tests.io.conftest import local_http_server  # noqa: F401
+
+NUM_URLS = 5_000
+
+
+@pytest.mark.benchmark(group="url_download")
+@pytest.mark.parametrize("multithreaded_io", [False, True])
+def test_url_download_many_small_files(benchmark, local_http_server, multithreaded_io):  # noqa: F811
+    data = {"urls": [f"{local_http_server}/data/{i}" for i in range(NUM_URLS)]}
+    df = daft.from_pydict(data)
+    df = df.with_column("data", df["urls"].url.download(max_connections=32, multithreaded_io=multithreaded_io))
+
+    result = benchmark(lambda: df.to_pydict())
+    assert result["data"] == [f"/data/{i}".encode() for i in range(NUM_URLS)]
@@ -156,6 +156,496 @@ def test_agg_groupby(make_df, repartition_nparts):
     assert freeze([list(map(set, res_list))[i] for i in arg_sort]) == freeze(list(map(set, exp_list)))
 
 
+@pytest.mark.skipif(
+    get_context().runner_config.name == "ray",
+    reason="The UDF records its calls in the driver process, which Ray workers don't share.",
+)
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_agg_groupby_shared_input_evaluated_once(make_df, repartition_nparts):
+    num_rows_evaluated = []
+
+    @daft.udf(return_dtype=DataType.int64())
+    def double(values):
+        num_rows_evaluated.append(len(values))
+        return [v * 2 for v in values.to_pylist()]
+
+    daft_df = make_df(
+        {
+            "group": [1, 1, 2, 2, 2],
+            "values": [1, 2, 3, 4, 5],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = (
+        daft_df.groupby("group")
+        .agg(
+            [
+                double(col("values")).sum().alias("sum"),
+                double(col("values")).mean().alias("mean"),
+            ]
+        )
+        .sort("group")
+    )
+
+    assert daft_df.to_pydict() == {"group": [1, 2], "sum": [6, 24], "mean": [3.0, 8.0]}
+    # Each row goes through the UDF once, rather than once for each aggregation of its output
+    assert sum(num_rows_evaluated) == 5
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 7])
+def test_agg_groupby_median(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 1, 2, 2, 2, 3, 3],
+            "ints": [4, None, 1, 3, 5, 2, 9, None, None],
+            "floats": [0.5, 2.5, None, 1.0, -1.0, None, 3.0, None, None],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.groupby("group").agg(
+        [
+            col("ints").median().alias("ints_median"),
+            col("floats").median().alias("floats_median"),
+        ]
+    )
+    expected = {
+        "group": [1, 2, 3],
+        "ints_median": [3.0, 5.0, None],
+        "floats_median": [1.0, 1.0, None],
+    }
+
+    daft_df.collect()
+    daft_cols = daft_df.to_pydict()
+
+    assert sort_arrow_table(pa.Table.from_pydict(daft_cols), "group") == sort_arrow_table(
+        pa.Table.from_pydict(expected), "group"
+    )
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 7])
+def test_agg_groupby_quantile(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 1, 1, 2, 3],
+            "values": [3, 1, None, 4, 2, 10, None],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.groupby("group").agg(
+        [
+            col("values").quantile(0.0).alias("q0"),
+            col("values").quantile(0.25).alias("q25"),
+            col("values").quantile(0.5).alias("q50"),
+            col("values").quantile(1.0).alias("q100"),
+        ]
+    )
+    expected = {
+        "group": [1, 2, 3],
+        "q0": [1.0, 10.0, None],
+        "q25": [1.75, 10.0, None],
+        "q50": [2.5, 10.0, None],
+        "q100": [4.0, 10.0, None],
+    }
+
+    daft_df.collect()
+    daft_cols = daft_df.to_pydict()
+
+    assert sort_arrow_table(pa.Table.from_pydict(daft_cols), "group") == sort_arrow_table(
+        pa.Table.from_pydict(expected), "group"
+    )
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_agg_global_quantile(make_df, repartition_nparts):
+    daft_df = make_df({"values": [3, 1, None, 4, 2]}, repartition=repartition_nparts)
+    daft_df = daft_df.agg([col("values").quantile(0.95).alias("q95")])
+    assert daft_df.to_pydict()["q95"] == pytest.approx([3.85])
+
+
+@pytest.mark.parametrize("q", [-0.1, 1.5])
+def test_agg_quantile_out_of_range(make_df, q):
+    daft_df = make_df({"values": [1, 2, 3]})
+    with pytest.raises(ValueError, match="Quantile must be between 0 and 1"):
+        daft_df.agg([col("values").quantile(q)])
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 7])
+def test_agg_groupby_stddev_variance(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 1, 1, 1, 1, 1, 2, 3],
+            "values": [2, 4, 4, 4, 5, 5, 7, 9, 3, None],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.groupby("group").agg(
+        [
+            col("values").variance(ddof=0).alias("pop_variance"),
+            col("values").stddev(ddof=0).alias("pop_stddev"),
+            col("values").variance().alias("sample_variance"),
+            col("values").stddev().alias("sample_stddev"),
+        ]
+    )
+    daft_df.collect()
+    daft_cols = daft_df.to_pydict()
+    arg_sort = np.argsort(daft_cols["group"])
+    result = {name: [values[i] for i in arg_sort] for name, values in daft_cols.items()}
+
+    assert result["pop_variance"][0] == pytest.approx(4.0)
+    assert result["pop_variance"][1:] == [0.0, None]
+    assert result["pop_stddev"][0] == pytest.approx(2.0)
+    assert result["pop_stddev"][1:] == [0.0, None]
+    assert result["sample_variance"][0] == pytest.approx(32 / 7)
+    assert result["sample_variance"][1:] == [None, None]
+    assert result["sample_stddev"][0] == pytest.approx((32 / 7) ** 0.5)
+    assert result["sample_stddev"][1:] == [None, None]
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 7])
+def test_agg_groupby_count_distinct(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 1, 2, 2, 3, 3],
+            "ints": [1, 2, 1, None, 5, 5, None, None],
+            "floats": [0.5, 0.5, 1.5, 2.5, None, 3.0, None, None],
+            "strings": ["a", "b", None, "b", "c", "d", None, None],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.groupby("group").agg(
+        [
+            col("ints").count_distinct(),
+            col("floats").count_distinct(),
+            col("strings").count_distinct(),
+        ]
+    )
+    expected = {
+        "group": [1, 2, 3],
+        "ints": [2, 1, 0],
+        "floats": [3, 1, 0],
+        "strings": [2, 2, 0],
+    }
+
+    daft_df.collect()
+    daft_cols = daft_df.to_pydict()
+
+    assert sort_arrow_table(pa.Table.from_pydict(daft_cols), "group") == sort_arrow_table(
+        pa.Table.from_pydict(expected), "group"
+    )
+
+
+def test_agg_groupby_first_last(make_df):
+    daft_df = make_df(
+        {
+            "group": [2, 1, 2, 1, 1, 3, 2, 3],
+            "values": [None, "a", "x", "b", None, None, "y", None],
+        }
+    )
+    daft_df = daft_df.groupby("group").agg(
+        [
+            col("values").first().alias("first"),
+            col("values").last().alias("last"),
+        ]
+    )
+    expected = {
+        "group": [1, 2, 3],
+        "first": ["a", "x", None],
+        "last": ["b", "y", None],
+    }
+
+    daft_df.collect()
+    daft_cols = daft_df.to_pydict()
+
+    assert sort_arrow_table(pa.Table.from_pydict(daft_cols), "group") == sort_arrow_table(
+        pa.Table.from_pydict(expected), "group"
+    )
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 7])
+def test_agg_groupby_first_last_after_sort(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [2, 1, 2, 1, 1, 3, 2, 3],
+            "values": [None, "a", "x", "b", None, None, "y", None],
+            "order": [7, 6, 5, 4, 3, 2, 1, 0],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = (
+        daft_df.sort("order")
+        .groupby("group")
+        .agg(
+            [
+                col("values").first().alias("first"),
+                col("values").last().alias("last"),
+            ]
+        )
+    )
+    expected = {
+        "group": [1, 2, 3],
+        "first": ["b", "y", None],
+        "last": ["a", "x", None],
+    }
+
+    daft_df.collect()
+    daft_cols = daft_df.to_pydict()
+
+    assert sort_arrow_table(pa.Table.from_pydict(daft_cols), "group") == sort_arrow_table(
+        pa.Table.from_pydict(expected), "group"
+    )
+
+
+def test_agg_groupby_mode(make_df):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 1, 1, 2, 2, 2, 2, 3],
+            "ints": [3, 1, 1, 3, 3, 7, 8, 8, 7, None],
+            "strings": ["b", "a", "a", "b", None, "x", "y", None, "y", None],
+        }
+    )
+    daft_df = daft_df.groupby("group").agg([col("ints").mode(), col("strings").mode()])
+    # Ties go to the value that occurs first
+    expected = {
+        "group": [1, 2, 3],
+        "ints": [3, 7, None],
+        "strings": ["b", "y", None],
+    }
+
+    daft_df.collect()
+    daft_cols = daft_df.to_pydict()
+
+    assert sort_arrow_table(pa.Table.from_pydict(daft_cols), "group") == sort_arrow_table(
+        pa.Table.from_pydict(expected), "group"
+    )
+
+
+@pytest.mark.parametrize("repartition_nparts", [2, 7])
+def test_agg_groupby_mode_multipartition(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 1, 2, 2, 2, 3],
+            "values": ["a", "b", "a", None, "x", "y", "y", None],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.groupby("group").agg([col("values").mode()])
+    expected = {"group": [1, 2, 3], "values": ["a", "y", None]}
+
+    daft_df.collect()
+    daft_cols = daft_df.to_pydict()
+
+    assert sort_arrow_table(pa.Table.from_pydict(daft_cols), "group") == sort_arrow_table(
+        pa.Table.from_pydict(expected), "group"
+    )
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 7])
+def test_agg_groupby_product(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 1, 2, 2, 2, 3],
+            "ints": [2, 3, None, 4, 5, 0, 7, None],
+            "floats": [0.5, None, 4.0, -1.5, 2.5, 0.0, None, None],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.groupby("group").agg([col("ints").product(), col("floats").product()])
+    expected = {
+        "group": [1, 2, 3],
+        "ints": [24, 0, None],
+        "floats": [-3.0, 0.0, None],
+    }
+
+    daft_df.collect()
+    daft_cols = daft_df.to_pydict()
+
+    assert sort_arrow_table(pa.Table.from_pydict(daft_cols), "group") == sort_arrow_table(
+        pa.Table.from_pydict(expected), "group"
+    )
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 5])
+def test_agg_groupby_bitwise(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 2, 2, 3],
+            "bits": [0b1100, 0b1010, None, 0xFFFF0000, 0x0F0F0F0F, None],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.with_column("bits", col("bits").cast(DataType.uint32()))
+    daft_df = daft_df.groupby("group").agg(
+        [
+            col("bits").bit_and().alias("and"),
+            col("bits").bit_or().alias("or"),
+            col("bits").bit_xor().alias("xor"),
+        ]
+    )
+    expected = {
+        "group": [1, 2, 3],
+        "and": [0b1000, 0x0F0F0000, None],
+        "or": [0b1110, 0xFFFF0F0F, None],
+        "xor": [0b0110, 0xF0F00F0F, None],
+    }
+
+    daft_df.collect()
+    daft_cols = daft_df.to_pydict()
+
+    assert daft_df.schema()["and"].dtype == DataType.uint32()
+    assert sort_arrow_table(pa.Table.from_pydict(daft_cols), "group") == sort_arrow_table(
+        pa.Table.from_pydict(expected), "group"
+    )
+
+
+def test_agg_bitwise_non_integer(make_df):
+    daft_df = make_df({"floats": [1.0, 2.0]})
+    with pytest.raises(ValueError, match="only supported on integer types"):
+        daft_df.agg([col("floats").bit_and()])
+
+
+@pytest.mark.parametrize(
+    "null_keys,expected",
+    [
+        ("group_together", {"group": [1, 2, None], "sum": [3, 7, 11]}),
+        ("drop", {"group": [1, 2], "sum": [3, 7]}),
+    ],
+)
+def test_agg_groupby_null_keys(make_df, repartition_nparts, null_keys, expected):
+    daft_df = make_df(
+        {
+            "group": [1, None, 1, 2, None, 2],
+            "values": [1, 5, 2, 3, 6, 4],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.groupby("group", null_keys=null_keys).agg([col("values").sum().alias("sum")])
+    daft_cols = daft_df.to_pydict()
+
+    assert sorted(zip(daft_cols["group"], daft_cols["sum"]), key=lambda p: (p[0] is None, p[0])) == list(
+        zip(expected["group"], expected["sum"])
+    )
+
+
+def test_agg_groupby_any_all(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6],
+            "bools": [True, True, True, False, False, False, True, None, False, None, None],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.groupby("group").agg(
+        col("bools").any().alias("any"),
+        col("bools").all().alias("all"),
+    )
+    expected = {
+        "group": [1, 2, 3, 4, 5, 6],
+        "any": [True, True, False, True, None, None],
+        "all": [True, False, False, None, False, None],
+    }
+
+    daft_df.collect()
+    daft_cols = daft_df.to_pydict()
+
+    assert daft_df.schema()["any"].dtype == DataType.bool()
+    assert sort_arrow_table(pa.Table.from_pydict(daft_cols), "group") == sort_arrow_table(
+        pa.Table.from_pydict(expected), "group"
+    )
+
+
+def test_agg_any_all_non_boolean(make_df):
+    daft_df = make_df({"ints": [1, 2]})
+    with pytest.raises(ValueError, match="only supported on boolean types"):
+        daft_df.agg([col("ints").any()])
+
+
+def test_agg_groupby_set(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 1, 2, 2, 3],
+            "values": [3, 1, 3, None, None, None, 2],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.groupby("group").agg(col("values").agg_set().alias("set")).sort("group")
+    daft_df.collect()
+    daft_cols = daft_df.to_pydict()
+
+    assert daft_df.schema()["set"].dtype == DataType.list(DataType.int64())
+    assert daft_cols["group"] == [1, 2, 3]
+    # The order of values within a group depends on how the rows are partitioned
+    assert [sorted(values) for values in daft_cols["set"]] == [[1, 3], [], [2]]
+
+
+def test_agg_groupby_string_agg(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 2, 2, 2, 3, 3, 4],
+            "strings": ["a", None, "b", "", "c", "", None, None, "d"],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.groupby("group").agg(col("strings").string_agg(", ").alias("joined")).sort("group")
+    daft_df.collect()
+    daft_cols = daft_df.to_pydict()
+
+    assert daft_df.schema()["joined"].dtype == DataType.string()
+    assert daft_cols["group"] == [1, 2, 3, 4]
+    # The order of values within a group depends on how the rows are partitioned
+    joined = [None if value is None else sorted(value.split(", ")) for value in daft_cols["joined"]]
+    assert joined == [["a", "b"], ["", "", "c"], None, ["d"]]
+
+
+def test_agg_string_agg_non_string(make_df):
+    daft_df = make_df({"ints": [1, 2]})
+    with pytest.raises(ValueError, match="only supported on string types"):
+        daft_df.agg([col("ints").string_agg(", ")])
+
+
+def test_agg_groupby_having(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 1, 1, 2, 2, 3, 3, 3, 3],
+            "values": [1, None, 3, 4, 5, 6, 7, 8, 9],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df.groupby("group").agg(
+        col("values").count().alias("count"),
+        col("values").sum().alias("sum"),
+        having=(col("count") > 1) & (col("group") != 3),
+    )
+    daft_cols = daft_df.to_pydict()
+
+    assert sorted(zip(daft_cols["group"], daft_cols["count"], daft_cols["sum"])) == [(1, 2, 4), (2, 2, 9)]
+
+
+def test_agg_groupby_having_non_output_column(make_df):
+    daft_df = make_df({"group": [1, 2], "values": [1, 2]})
+    with pytest.raises(ValueError, match="may only reference group keys and aggregation outputs"):
+        daft_df.groupby("group").agg(col("values").sum().alias("sum"), having=col("values") > 1)
+
+
+@pytest.mark.parametrize("groupby", [[], ["group"]])
+def test_agg_two_phase_matches_single_phase(make_df, groupby):
+    data = {
+        "group": [1, 1, 1, 2, 2, 3, 3, 3, 3, None],
+        "values": [1, None, 3, 4.5, 2.0, None, None, 8, -1, 7],
+    }
+    aggs = [col("values").mean().alias("mean"), col("values").count().alias("count")]
+
+    def run(df):
+        if not groupby:
+            return df.agg(aggs).to_arrow()
+        return sort_arrow_table(df.groupby(*groupby).agg(aggs).to_arrow(), "group")
+
+    # A single partition is aggregated in a single phase, and several partitions in a partial and a final phase
+    single_phase = run(make_df(data))
+    two_phase = run(make_df(data, repartition=4))
+    assert two_phase.column("count").to_pylist() == single_phase.column("count").to_pylist()
+    assert two_phase.column("mean").to_pylist() == pytest.approx(single_phase.column("mean").to_pylist())
+
+
 @pytest.mark.parametrize("repartition_nparts", [1, 2, 5])
 def test_agg_groupby_all_null(make_df, repartition_nparts):
     daft_df = make_df(
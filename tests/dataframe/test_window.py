@@ -0,0 +1,55 @@
+from __future__ import annotations
+
+import pytest
+
+from daft import col
+from daft.daft import WindowFunction
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_window_running_sum_and_row_number(make_df, repartition_nparts):
+    daft_df = make_df(
+        {
+            "group": [1, 0, 1, 0, 1, 0],
+            "order": [3, 2, 1, 4, 2, 1],
+            "values": [10, 20, 30, 40, 50, 60],
+        },
+        repartition=repartition_nparts,
+    )
+    daft_df = daft_df._window(
+        [col("values").sum().alias("running_sum"), WindowFunction.row_number("row_number")],
+        partition_by=[col("group")],
+        order_by=[col("order")],
+    ).sort(["group", "order"])
+    expected = {
+        "group": [0, 0, 0, 1, 1, 1],
+        "order": [1, 2, 4, 1, 2, 3],
+        "values": [60, 20, 40, 30, 50, 10],
+        "running_sum": [60, 80, 120, 30, 80, 90],
+        "row_number": [1, 2, 3, 1, 2, 3],
+    }
+
+    assert daft_df.to_pydict() == expected
+
+
+@pytest.mark.parametrize("repartition_nparts", [1, 2, 4])
+def test_window_rank_without_partition_by(make_df, repartition_nparts):
+    daft_df = make_df({"order": [5, 3, 5, 1, 3, 3]}, repartition=repartition_nparts)
+    daft_df = daft_df._window(
+        [WindowFunction.rank("rank"), WindowFunction.dense_rank("dense_rank")],
+        order_by=[col("order")],
+        desc=True,
+    ).sort("order", desc=True)
+    expected = {
+        "order": [5, 5, 3, 3, 3, 1],
+        "rank": [1, 1, 3, 3, 3, 6],
+        "dense_rank": [1, 1, 2, 2, 2, 3],
+    }
+
+    assert daft_df.to_pydict() == expected
+
+
+def test_window_rejects_non_aggregation(make_df):
+    daft_df = make_df({"values": [1, 2, 3]})
+    with pytest.raises(ValueError):
+        daft_df._window([col("values")])
@@ -0,0 +1,72 @@
+from __future__ import annotations
+
+import gzip
+import threading
+import time
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+
+import pytest
+
+from tests.integration.io.conftest import YieldFixture
+
+SLOW_RESPONSE_DELAY_S = 2.0
+
+
+class MockHTTPHandler(BaseHTTPRequestHandler):
+    """Serves a handful of routes used to exercise the native HTTP downloader"""
+
+    # Use HTTP/1.1 so that clients can keep connections alive across requests
+    protocol_version = "HTTP/1.1"
+
+    # Tracks the (host, port) of every client connection that issued a request
+    client_addresses: set[tuple[str, int]] = set()
+
+    # Number of requests received per path
+    request_counts: dict[str, int] = {}
+
+    def log_message(self, format, *args):
+        pass
+
+    def _respond(self, status: int, body: bytes, headers: dict[str, str] | None = None):
+        self.send_response(status)
+        self.send_header("Content-Length", str(len(body)))
+        for name, value in (headers or {}).items():
+            self.send_header(name, value)
+        self.end_headers()
+        self.wfile.write(body)
+
+    def do_GET(self):
+        MockHTTPHandler.client_addresses.add(self.client_address)
+        MockHTTPHandler.request_counts[self.path] = MockHTTPHandler.request_counts.get(self.path, 0) + 1
+        if self.path.startswith("/slow"):
+            time.sleep(SLOW_RESPONSE_DELAY_S)
+            self._respond(200, b"slow")
+        elif self.path.startswith("/flaky"):
+            # Fails twice with a retryable status code before succeeding
+            if MockHTTPHandler.request_counts[self.path] <= 2:
+                self._respond(503, b"unavailable")
+            else:
+                self._respond(200, self.path.encode("utf-8"))
+        elif self.path.startswith("/echo-header/"):
+            header_value = self.headers.get(self.path.split("/")[2], "")
+            self._respond(200, header_value.encode("utf-8"))
+        elif self.path.startswith("/gzip/"):
+            # Gzip-encodes the path repeated `<n>` times, for paths of the form /gzip/<n>
+            body = self.path.encode("utf-8") * int(self.path.split("/")[2])
+            self._respond(200, gzip.compress(body), headers={"Content-Encoding": "gzip"})
+        elif self.path.startswith("/status/"):
+            self._respond(int(self.path.split("/")[2]), b"status")
+        elif self.path.startswith("/data"):
+            self._respond(200, self.path.encode("utf-8"))
+        else:
+            self._respond(404, b"not found")
+
+
+@pytest.fixture(scope="module")
+def local_http_server() -> YieldFixture[str]:
+    server = ThreadingHTTPServer(("127.0.0.1", 0), MockHTTPHandler)
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    yield f"http://127.0.0.1:{server.server_address[1]}"
+    server.shutdown()
+    thread.join()
@@ -0,0 +1,143 @@
+from __future__ import annotations
+
+import gzip
+import time
+
+import pytest
+
+import daft
+from daft.io import HttpConfig, IOConfig
+from tests.io.conftest import SLOW_RESPONSE_DELAY_S, MockHTTPHandler
+
+
+def test_url_download_http_timeout_null(local_http_server):
+    io_config = IOConfig(http=HttpConfig(timeout_ms=200))
+    data = {"urls": [f"{local_http_server}/data/0", f"{local_http_server}/slow", f"{local_http_server}/data/2"]}
+    df = daft.from_pydict(data)
+    df = df.with_column("data", df["urls"].url.download(on_error="null", io_config=io_config))
+
+    start = time.time()
+    result = df.to_pydict()
+    assert time.time() - start < SLOW_RESPONSE_DELAY_S
+    assert result["data"] == [b"/data/0", None, b"/data/2"]
+
+
+def test_url_download_http_timeout_raise(local_http_server):
+    io_config = IOConfig(http=HttpConfig(timeout_ms=200))
+    data = {"urls": [f"{local_http_server}/slow"]}
+    df = daft.from_pydict(data)
+    df = df.with_column("data", df["urls"].url.download(on_error="raise", io_config=io_config))
+
+    with pytest.raises(ValueError):
+        df.collect()
+
+
+def test_url_download_http_no_timeout(local_http_server):
+    data = {"urls": [f"{local_http_server}/slow"]}
+    df = daft.from_pydict(data)
+    df = df.with_column("data", df["urls"].url.download(on_error="raise"))
+    assert df.to_pydict()["data"] == [b"slow"]
+
+
+def test_url_download_http_reuses_connections(local_http_server):
+    num_urls = 200
+    max_connections = 4
+    MockHTTPHandler.client_addresses.clear()
+
+    data = {"urls": [f"{local_http_server}/data/{i}" for i in range(num_urls)]}
+    df = daft.from_pydict(data)
+    df = df.with_column("data", df["urls"].url.download(max_connections=max_connections))
+    assert df.to_pydict()["data"] == [f"/data/{i}".encode() for i in range(num_urls)]
+
+    # Every download goes through one shared client, so its keep-alive pool should serve many requests per connection
+    assert 0 < len(MockHTTPHandler.client_addresses) < num_urls
+
+
+def test_url_download_http_retries_until_success(local_http_server):
+    MockHTTPHandler.request_counts.clear()
+    io_config = IOConfig(http=HttpConfig(num_tries=3, retry_initial_backoff_ms=10))
+    data = {"urls": [f"{local_http_server}/flaky/{i}" for i in range(3)]}
+    df = daft.from_pydict(data)
+    df = df.with_column("data", df["urls"].url.download(on_error="raise", io_config=io_config))
+
+    assert df.to_pydict()["data"] == [f"/flaky/{i}".encode() for i in range(3)]
+    assert all(MockHTTPHandler.request_counts[f"/flaky/{i}"] == 3 for i in range(3))
+
+
+def test_url_download_http_retries_exhausted(local_http_server):
+    MockHTTPHandler.request_counts.clear()
+    io_config = IOConfig(http=HttpConfig(num_tries=2, retry_initial_backoff_ms=10))
+    data = {"urls": [f"{local_http_server}/flaky/exhausted"]}
+    df = daft.from_pydict(data)
+    df = df.with_column("data", df["urls"].url.download(on_error="null", io_config=io_config))
+
+    assert df.to_pydict()["data"] == [None]
+    assert MockHTTPHandler.request_counts["/flaky/exhausted"] == 2
+
+
+def test_url_download_http_no_retry_on_client_error(local_http_server):
+    MockHTTPHandler.request_counts.clear()
+    io_config = IOConfig(http=HttpConfig(num_tries=5, retry_initial_backoff_ms=10))
+    data = {"urls": [f"{local_http_server}/status/400"]}
+    df = daft.from_pydict(data)
+    df = df.with_column("data", df["urls"].url.download(on_error="null", io_config=io_config))
+
+    assert df.to_pydict()["data"] == [None]
+    assert MockHTTPHandler.request_counts["/status/400"] == 1
+
+
+def test_url_download_http_custom_headers(local_http_server):
+    io_config = IOConfig(http=HttpConfig(headers=[("Authorization", "Bearer foo"), ("User-Agent", "daft-test")]))
+    data = {"urls": [f"{local_http_server}/echo-header/Authorization", f"{local_http_server}/echo-header/User-Agent"]}
+    df = daft.from_pydict(data)
+    df = df.with_column("data", df["urls"].url.download(on_error="raise", io_config=io_config))
+    assert df.to_pydict()["data"] == [b"Bearer foo", b"daft-test"]
+
+
+@pytest.mark.parametrize("header", [("Bad Header", "value"), ("X-Header", "bad\nvalue")])
+def test_url_download_http_invalid_headers(local_http_server, header):
+    MockHTTPHandler.request_counts.clear()
+    io_config = IOConfig(http=HttpConfig(headers=[header]))
+    data = {"urls": [f"{local_http_server}/data/0"]}
+    df = daft.from_pydict(data)
+    df = df.with_column("data", df["urls"].url.download(on_error="null", io_config=io_config))
+
+    with pytest.raises(ValueError, match="Invalid HTTP header"):
+        df.collect()
+    assert MockHTTPHandler.request_counts == {}
+
+
+@pytest.mark.parametrize("multithreaded_io", [False, True])
+def test_url_download_http_multithreaded_io_preserves_order(local_http_server, multithreaded_io):
+    num_urls = 500
+    data = {"urls": [f"{local_http_server}/data/{i}" for i in range(num_urls)]}
+    df = daft.from_pydict(data)
+    df = df.with_column("data", df["urls"].url.download(max_connections=64, multithreaded_io=multithreaded_io))
+    assert df.to_pydict()["data"] == [f"/data/{i}".encode() for i in range(num_urls)]
+
+
+def test_url_download_http_default_max_connections(local_http_server):
+    num_urls = 50
+    data = {"urls": [f"{local_http_server}/data/{i}" for i in range(num_urls)]}
+    df = daft.from_pydict(data)
+    df = df.with_column("data", df["urls"].url.download(max_connections=None))
+    assert df.to_pydict()["data"] == [f"/data/{i}".encode() for i in range(num_urls)]
+
+    with pytest.raises(ValueError, match="max_connections"):
+        df["urls"].url.download(max_connections=0)
+
+
+def test_url_download_http_decompress(local_http_server):
+    paths = [f"/gzip/{n}" for n in [1, 10, 100]]
+    data = {"urls": [f"{local_http_server}{path}" for path in paths]}
+    expected = [path.encode("utf-8") * int(path.split("/")[2]) for path in paths]
+
+    df = daft.from_pydict(data)
+    io_config = IOConfig(http=HttpConfig(decompress=True))
+    df = df.with_column("data", df["urls"].url.download(on_error="raise", io_config=io_config))
+    assert df.to_pydict()["data"] == expected
+
+    # Without decompression the raw gzip-encoded bytes are kept
+    df = daft.from_pydict(data)
+    df = df.with_column("data", df["urls"].url.download(on_error="raise"))
+    assert [gzip.decompress(b) for b in df.to_pydict()["data"]] == expected
@@ -56,3 +56,19 @@ def test_url_download_local_no_read_permissions(local_image_data_fixture, tmpdir
 
     with pytest.raises(ValueError, match="Permission denied"):
         df.collect()
+
+
+@pytest.mark.integration()
+def test_url_download_local_max_size_bytes(local_image_data_fixture, image_data, tmpdir):
+    small_filepath = pathlib.Path(tmpdir) / "small_file.jpeg"
+    small_filepath.write_bytes(b"foo")
+
+    data = {"urls": [local_image_data_fixture[0], str(small_filepath)]}
+    df = daft.from_pydict(data)
+    df = df.with_column("data", df["urls"].url.download(on_error="null", max_size_bytes=len(image_data) - 1))
+    assert df.to_pydict()["data"] == [None, b"foo"]
+
+    df = daft.from_pydict(data)
+    df = df.with_column("data", df["urls"].url.download(on_error="raise", max_size_bytes=len(image_data) - 1))
+    with pytest.raises(ValueError, match="exceeds the maximum size"):
+        df.collect()
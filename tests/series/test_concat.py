@@ -235,6 +235,24 @@ def test_series_concat_pyobj(chunks) -> None:
             counter += 1
 
 
+def test_series_concat_pyobj_with_nulls() -> None:
+    series = [
+        Series.from_pylist([MockObject(0), None], pyobj="force"),
+        Series.from_pylist([], pyobj="force"),
+        Series.from_pylist([None, MockObject(1)], pyobj="force"),
+    ]
+
+    concated = Series.concat(series)
+
+    assert concated.datatype() == DataType.python()
+    concated_list = concated.to_pylist()
+    assert len(concated_list) == 4
+    assert concated_list[0].test_val == 0
+    assert concated_list[1] is None
+    assert concated_list[2] is None
+    assert concated_list[3].test_val == 1
+
+
 def test_series_concat_bad_input() -> None:
     mix_types_series = [Series.from_pylist([1, 2, 3]), []]
     with pytest.raises(TypeError, match="Expected a Series for concat"):
@@ -11,6 +11,9 @@ import xxhash
 from daft.datatype import DataType
 from daft.series import Series
 
+NULL_HASH = 2**64 - 1
+NULL_MARKER = b"\xffdaft-null-marker"
+
 
 @pytest.mark.parametrize(
     "nbytes, dtype",
@@ -59,7 +62,7 @@ def test_hash_bool_array_with_reference():
 
 def test_hash_str_array_with_reference():
     arr = Series.from_pylist(["hi", "bye", None])
-    expected = [xxhash.xxh3_64_intdigest(b"hi"), xxhash.xxh3_64_intdigest(b"bye"), xxhash.xxh3_64_intdigest(b"")]
+    expected = [xxhash.xxh3_64_intdigest(b"hi"), xxhash.xxh3_64_intdigest(b"bye"), NULL_HASH]
     hashed = arr.hash()
     assert hashed.to_pylist() == expected
 
@@ -67,14 +70,14 @@ def test_hash_str_array_with_reference():
     expected = [
         xxhash.xxh3_64_intdigest(b"hi", expected[0]),
         xxhash.xxh3_64_intdigest(b"bye", expected[1]),
-        xxhash.xxh3_64_intdigest(b"", expected[2]),
+        xxhash.xxh3_64_intdigest(NULL_MARKER, expected[2]),
     ]
     assert hashed_again.to_pylist() == expected
 
 
 def test_hash_binary_array_with_reference():
     arr = Series.from_pylist([b"hi", b"bye", None])
-    expected = [xxhash.xxh3_64_intdigest(b"hi"), xxhash.xxh3_64_intdigest(b"bye"), xxhash.xxh3_64_intdigest(b"")]
+    expected = [xxhash.xxh3_64_intdigest(b"hi"), xxhash.xxh3_64_intdigest(b"bye"), NULL_HASH]
     hashed = arr.hash()
     assert hashed.to_pylist() == expected
 
@@ -82,23 +85,19 @@ def test_hash_binary_array_with_reference():
     expected = [
         xxhash.xxh3_64_intdigest(b"hi", expected[0]),
         xxhash.xxh3_64_intdigest(b"bye", expected[1]),
-        xxhash.xxh3_64_intdigest(b"", expected[2]),
+        xxhash.xxh3_64_intdigest(NULL_MARKER, expected[2]),
     ]
     assert hashed_again.to_pylist() == expected
 
 
 def test_hash_null_array_with_reference():
     arr = Series.from_pylist([None, None, None])
-    expected = [xxhash.xxh3_64_intdigest(b""), xxhash.xxh3_64_intdigest(b""), xxhash.xxh3_64_intdigest(b"")]
+    expected = [NULL_HASH, NULL_HASH, NULL_HASH]
     hashed = arr.hash()
     assert hashed.to_pylist() == expected
 
     hashed_again = arr.hash(hashed)
-    expected = [
-        xxhash.xxh3_64_intdigest(b"", expected[0]),
-        xxhash.xxh3_64_intdigest(b"", expected[1]),
-        xxhash.xxh3_64_intdigest(b"", expected[2]),
-    ]
+    expected = [xxhash.xxh3_64_intdigest(NULL_MARKER, NULL_HASH)] * 3
     assert hashed_again.to_pylist() == expected
 
 
@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+import sys
+
 import numpy as np
 import pyarrow as pa
 import pytest
@@ -511,6 +513,25 @@ def test_series_if_else_python(if_true, if_false) -> None:
     assert result.to_pylist() == [left_expected, right_expected, None]
 
 
+def test_series_if_else_python_large_refcounts() -> None:
+    num_rows = 10_000
+    obj = object()
+    if_true_series = Series.from_pylist([obj] * num_rows, pyobj="force")
+    if_false_series = Series.from_pylist([None] * num_rows, pyobj="force")
+    predicate_series = Series.from_pylist([[True, False, None][i % 3] for i in range(num_rows)])
+
+    refcount_before = sys.getrefcount(obj)
+    result = predicate_series.if_else(if_true_series, if_false_series)
+
+    # The result holds exactly one new reference to `obj` for each row taken from `if_true_series`
+    num_true = (num_rows + 2) // 3
+    assert sys.getrefcount(obj) == refcount_before + num_true
+    assert result.to_pylist() == [[obj, None, None][i % 3] for i in range(num_rows)]
+
+    del result
+    assert sys.getrefcount(obj) == refcount_before
+
+
 @pytest.mark.parametrize(
     ["predicate_value", "expected_results"], [(True, [1, 1, 1]), (False, [0, 0, 0]), (None, [None, None, None])]
 )
@@ -0,0 +1,36 @@
+from __future__ import annotations
+
+import pytest
+
+from daft.datatype import DataType
+from daft.series import Series
+
+
+def test_series_python_apply_skips_nulls() -> None:
+    s = Series.from_pylist([1, None, 3, None], pyobj="force")
+    result = Series._from_pyseries(s._series.python_apply(lambda x: x * 10, True))
+    assert result.name() == s.name()
+    assert result.datatype() == DataType.python()
+    assert result.to_pylist() == [10, None, 30, None]
+
+
+def test_series_python_apply_passes_nulls() -> None:
+    s = Series.from_pylist([1, None, 3], pyobj="force")
+    result = Series._from_pyseries(s._series.python_apply(lambda x: -1 if x is None else x, False))
+    assert result.to_pylist() == [1, -1, 3]
+
+    # Results of None are nulls
+    result = Series._from_pyseries(s._series.python_apply(lambda x: None, False))
+    assert result.to_pylist() == [None, None, None]
+
+
+def test_series_python_apply_raises() -> None:
+    s = Series.from_pylist([1, 0], pyobj="force")
+    with pytest.raises(ZeroDivisionError):
+        s._series.python_apply(lambda x: 1 / x, True)
+
+
+def test_series_python_apply_non_python() -> None:
+    s = Series.from_pylist([1, 2])
+    with pytest.raises(ValueError):
+        s._series.python_apply(lambda x: x, True)
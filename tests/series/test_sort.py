@@ -211,3 +211,37 @@ def test_series_boolean_sorting() -> None:
     taken = s.take(s_argsorted)
     assert len(taken) == len(s)
     assert taken.to_pylist() == sorted_order[::-1]
+
+
+def test_series_python_sorting() -> None:
+    s = Series.from_pylist([3, None, 1, 2, None], pyobj="force")
+
+    s_sorted = s.sort()
+    assert s_sorted.datatype() == DataType.python()
+    assert s_sorted.to_pylist() == [1, 2, 3, None, None]
+
+    # Nulls are placed first when descending, as for other types
+    assert s.sort(descending=True).to_pylist() == [None, None, 3, 2, 1]
+
+
+def test_series_python_sorting_is_stable() -> None:
+    class Key:
+        def __init__(self, key: int, tag: str) -> None:
+            self.key = key
+            self.tag = tag
+
+        def __lt__(self, other: Key) -> bool:
+            return self.key < other.key
+
+        def __eq__(self, other: object) -> bool:
+            return isinstance(other, Key) and self.key == other.key
+
+    s = Series.from_pylist([Key(1, "a"), Key(0, "b"), Key(1, "c"), Key(0, "d")], pyobj="force")
+    assert [k.tag for k in s.sort().to_pylist()] == ["b", "d", "a", "c"]
+    assert [k.tag for k in s.sort(descending=True).to_pylist()] == ["a", "c", "b", "d"]
+
+
+def test_series_python_sorting_unorderable() -> None:
+    s = Series.from_pylist([1, "a"], pyobj="force")
+    with pytest.raises(TypeError):
+        s.sort()